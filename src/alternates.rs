@@ -0,0 +1,271 @@
+//! Stylistic alternates for `BASIC_FONTS`.
+//!
+//! Some glyphs of `BASIC_LEGACY` are easy to mistake for one another, like `0` and `O`,
+//! or `1`, `l` and `I`, and its single-storey `g` and `q` only differ in their bottom
+//! row: the double-storey alternate of `g` has a closed loop that can't be mistaken for
+//! the tail of `q`.
+//!
+//! [AlternateFonts](./struct.AlternateFonts.html) layers a choice of alternate glyphs over
+//! `BASIC_FONTS`, and can be given to a renderer with
+//! [Renderer::alternates](./struct.Renderer.html#method.alternates).
+use super::{FontUnicode, UnicodeFonts, BASIC_FONTS};
+
+/// An alternate glyph for a character of `BASIC_FONTS`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Alternate {
+    /// Zero with a thin slash, which keeps its counter open.
+    ///
+    /// ```text
+    /// ░█████░░
+    /// ██░░░██░
+    /// ██░░███░
+    /// ██░█░██░
+    /// ███░░██░
+    /// ██░░░██░
+    /// ░█████░░
+    /// ░░░░░░░░
+    /// ```
+    SlashedZero,
+    /// Zero with a dot in its counter.
+    ///
+    /// ```text
+    /// ░█████░░
+    /// ██░░░██░
+    /// ██░░░██░
+    /// ██░█░██░
+    /// ██░█░██░
+    /// ██░░░██░
+    /// ░█████░░
+    /// ░░░░░░░░
+    /// ```
+    DottedZero,
+    /// Four with an open top.
+    ///
+    /// ```text
+    /// ██░░██░░
+    /// ██░░██░░
+    /// ██░░██░░
+    /// ███████░
+    /// ░░░░██░░
+    /// ░░░░██░░
+    /// ░░░████░
+    /// ░░░░░░░░
+    /// ```
+    OpenFour,
+    /// Capital `I` with full-width serifs.
+    ///
+    /// ```text
+    /// ██████░░
+    /// ░░██░░░░
+    /// ░░██░░░░
+    /// ░░██░░░░
+    /// ░░██░░░░
+    /// ░░██░░░░
+    /// ██████░░
+    /// ░░░░░░░░
+    /// ```
+    SerifedCapitalI,
+    /// Small `l` with a tail curving right.
+    ///
+    /// ```text
+    /// ░███░░░░
+    /// ░░██░░░░
+    /// ░░██░░░░
+    /// ░░██░░░░
+    /// ░░██░░░░
+    /// ░░██░█░░
+    /// ░░░██░░░
+    /// ░░░░░░░░
+    /// ```
+    TailedSmallL,
+    /// Single-storey small `a`, like the italic form.
+    ///
+    /// ```text
+    /// ░░░░░░░░
+    /// ░░░░░░░░
+    /// ░███░██░
+    /// ██░░██░░
+    /// ██░░██░░
+    /// ██░░██░░
+    /// ░███░██░
+    /// ░░░░░░░░
+    /// ```
+    SingleStoreyA,
+    /// Double-storey small `g`, with a closed loop below its bowl.
+    ///
+    /// ```text
+    /// ░████░█░
+    /// ██░░██░░
+    /// ██░░██░░
+    /// ░████░░░
+    /// ██░░░░░░
+    /// ░█████░░
+    /// ██░░░██░
+    /// ░█████░░
+    /// ```
+    DoubleStoreyG,
+}
+
+/// Every alternate, in the order of their bits in `AlternateFonts`.
+const ALTERNATES: [Alternate; 7] = [Alternate::SlashedZero, Alternate::DottedZero,
+                                    Alternate::OpenFour, Alternate::SerifedCapitalI,
+                                    Alternate::TailedSmallL, Alternate::SingleStoreyA,
+                                    Alternate::DoubleStoreyG];
+
+impl Alternate {
+    /// Returns the character that this is an alternate glyph for.
+    pub fn char(self) -> char {
+        match self {
+            Alternate::SlashedZero     => '0',
+            Alternate::DottedZero      => '0',
+            Alternate::OpenFour        => '4',
+            Alternate::SerifedCapitalI => 'I',
+            Alternate::TailedSmallL    => 'l',
+            Alternate::SingleStoreyA   => 'a',
+            Alternate::DoubleStoreyG   => 'g',
+        }
+    }
+
+    /// Returns the alternate glyph.
+    pub fn glyph(self) -> [u8; 8] {
+        match self {
+            Alternate::SlashedZero     => [0x3E, 0x63, 0x73, 0x6B, 0x67, 0x63, 0x3E, 0x00],
+            Alternate::DottedZero      => [0x3E, 0x63, 0x63, 0x6B, 0x6B, 0x63, 0x3E, 0x00],
+            Alternate::OpenFour        => [0x33, 0x33, 0x33, 0x7F, 0x30, 0x30, 0x78, 0x00],
+            Alternate::SerifedCapitalI => [0x3F, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x3F, 0x00],
+            Alternate::TailedSmallL    => [0x0E, 0x0C, 0x0C, 0x0C, 0x0C, 0x2C, 0x18, 0x00],
+            Alternate::SingleStoreyA   => [0x00, 0x00, 0x6E, 0x33, 0x33, 0x33, 0x6E, 0x00],
+            Alternate::DoubleStoreyG   => [0x5E, 0x33, 0x33, 0x1E, 0x03, 0x3E, 0x63, 0x3E],
+        }
+    }
+}
+
+/// The glyphs of `BASIC_FONTS`, with some of them replaced by their alternates.
+///
+/// Choosing an alternate replaces any alternate chosen before for the same character.
+///
+/// ```
+/// use font8x8::unicode::{Alternate, AlternateFonts};
+/// use font8x8::{UnicodeFonts, BASIC_FONTS};
+///
+/// let fonts = AlternateFonts::new().with(Alternate::SlashedZero)
+///                                  .with(Alternate::DottedZero);
+/// assert_eq!(fonts.get('0'), Some(Alternate::DottedZero.glyph()));
+/// assert_eq!(fonts.get('O'), BASIC_FONTS.get('O'));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AlternateFonts {
+    chosen: u8,
+}
+
+impl AlternateFonts {
+    /// Create a new collection of fonts, with no alternates chosen.
+    pub fn new() -> Self {
+        AlternateFonts { chosen: 0 }
+    }
+
+    /// Returns these fonts, with `alternate` chosen for its character.
+    pub fn with(mut self, alternate: Alternate) -> Self {
+        for (bit, other) in ALTERNATES.iter().enumerate() {
+            if other.char() == alternate.char() {
+                self.chosen &= !(1 << bit);
+            }
+        }
+        self.chosen |= 1 << alternate as u8;
+        self
+    }
+
+    /// Returns the chosen alternate for `key`, if there is one.
+    fn alternate(&self, key: char) -> Option<Alternate> {
+        ALTERNATES.iter()
+                  .enumerate()
+                  .find(|&(bit, alternate)| self.chosen & 1 << bit != 0 && alternate.char() == key)
+                  .map(|(_, alternate)| *alternate)
+    }
+}
+
+impl UnicodeFonts for AlternateFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        match self.alternate(key) {
+            Some(alternate) => Some(alternate.glyph()),
+            None => BASIC_FONTS.get(key),
+        }
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        self.get(key).map(|glyph| FontUnicode(key, glyph))
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, (_, font)) in self.to_vec().iter().enumerate() {
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        BASIC_FONTS.to_vec()
+                   .into_iter()
+                   .filter_map(|(key, _)| self.get_font(key).map(|font| (key, font)))
+                   .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alternate_set_implements_default_trait_with_method_new() {
+        let alternate_set: AlternateFonts = Default::default();
+        assert_eq!(alternate_set, AlternateFonts::new());
+    }
+
+    #[test]
+    fn alternate_fonts_without_alternates_are_the_basic_fonts() {
+        let fonts = AlternateFonts::new();
+        for key in "0O4Il1ag\u{0}\u{7F}".chars() {
+            assert_eq!(fonts.get(key), BASIC_FONTS.get(key));
+        }
+        assert_eq!(fonts.get('é'), None);
+    }
+
+    #[test]
+    fn alternate_glyphs_differ_from_the_basic_glyphs() {
+        for alternate in ALTERNATES.iter() {
+            assert_ne!(BASIC_FONTS.get(alternate.char()), Some(alternate.glyph()), "{:?}", alternate);
+            let fonts = AlternateFonts::new().with(*alternate);
+            assert_eq!(fonts.get(alternate.char()), Some(alternate.glyph()), "{:?}", alternate);
+        }
+    }
+
+    #[test]
+    fn alternate_fonts_keep_one_alternate_per_character() {
+        let fonts = AlternateFonts::new().with(Alternate::DottedZero)
+                                         .with(Alternate::TailedSmallL)
+                                         .with(Alternate::SlashedZero);
+        assert_eq!(fonts.get('0'), Some(Alternate::SlashedZero.glyph()));
+        assert_eq!(fonts.get('l'), Some(Alternate::TailedSmallL.glyph()));
+        assert_eq!(fonts.get('a'), BASIC_FONTS.get('a'));
+    }
+}
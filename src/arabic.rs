@@ -0,0 +1,2400 @@
+//! Arabic Presentation Forms-B. `U+FE70` - `U+FEFF`
+//!
+//! Arabic letters change shape depending on whether they join their neighbours, so
+//! the glyphs here are the isolated, final, initial and medial presentation forms,
+//! drawn so that the joining stroke sits on row 5 and runs to the edge of the cell.
+//! Use [shape_arabic](./fn.shape_arabic.html) to turn logical `U+0600` - `U+06FF`
+//! text into these forms.
+use super::{FontUnicode, UnicodeFonts};
+use core::{char, fmt};
+
+/// A constant `[FontUnicode; 142]`, for Arabic Presentation Forms-B fonts (`U+0640`, `U+FE70` - `U+FEFF`).
+pub const ARABIC_UNICODE: [FontUnicode; 142] = [FontUnicode('\u{0640}', [0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FE70}', [0x30, 0x0C, 0x30, 0x0C, 0x00, 0x00, 0x00, 0x00]),
+                                                FontUnicode('\u{FE71}', [0x30, 0x0C, 0x30, 0x0C, 0x00, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FE72}', [0x6C, 0x6C, 0x48, 0x24, 0x00, 0x00, 0x00, 0x00]),
+                                                FontUnicode('\u{FE73}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x40, 0x3C]),
+                                                FontUnicode('\u{FE74}', [0x00, 0x00, 0x00, 0x00, 0x30, 0x0C, 0x30, 0x0C]),
+                                                FontUnicode('\u{FE76}', [0x30, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                FontUnicode('\u{FE77}', [0x30, 0x0C, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FE78}', [0x18, 0x18, 0x10, 0x08, 0x00, 0x00, 0x00, 0x00]),
+                                                FontUnicode('\u{FE79}', [0x18, 0x18, 0x10, 0x08, 0x00, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FE7A}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x0C]),
+                                                FontUnicode('\u{FE7B}', [0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x30, 0x0C]),
+                                                FontUnicode('\u{FE7C}', [0x00, 0x54, 0x7C, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                FontUnicode('\u{FE7D}', [0x00, 0x54, 0x7C, 0x00, 0x00, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FE7E}', [0x18, 0x24, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                FontUnicode('\u{FE7F}', [0x18, 0x24, 0x18, 0x00, 0x00, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FE80}', [0x00, 0x00, 0x38, 0x04, 0x38, 0x0C, 0x02, 0x00]),
+                                                FontUnicode('\u{FE81}', [0x2C, 0x12, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00]),
+                                                FontUnicode('\u{FE82}', [0x2C, 0x12, 0x08, 0x08, 0x08, 0xF8, 0x00, 0x00]),
+                                                FontUnicode('\u{FE83}', [0x18, 0x0C, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00]),
+                                                FontUnicode('\u{FE84}', [0x18, 0x0C, 0x08, 0x08, 0x08, 0xF8, 0x00, 0x00]),
+                                                FontUnicode('\u{FE85}', [0x30, 0x18, 0x00, 0x30, 0x48, 0x70, 0x20, 0x1E]),
+                                                FontUnicode('\u{FE86}', [0x30, 0x18, 0x00, 0x30, 0x48, 0xF0, 0x20, 0x1E]),
+                                                FontUnicode('\u{FE87}', [0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x18, 0x0C]),
+                                                FontUnicode('\u{FE88}', [0x08, 0x08, 0x08, 0x08, 0x08, 0xF8, 0x18, 0x0C]),
+                                                FontUnicode('\u{FE89}', [0x18, 0x0C, 0x38, 0x04, 0x18, 0x22, 0x1C, 0x00]),
+                                                FontUnicode('\u{FE8A}', [0x18, 0x0C, 0x38, 0x04, 0x18, 0xE2, 0x1C, 0x00]),
+                                                FontUnicode('\u{FE8B}', [0x00, 0x30, 0x18, 0x10, 0x10, 0x1F, 0x00, 0x00]),
+                                                FontUnicode('\u{FE8C}', [0x00, 0x30, 0x18, 0x10, 0x10, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FE8D}', [0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00]),
+                                                FontUnicode('\u{FE8E}', [0x08, 0x08, 0x08, 0x08, 0x08, 0xF8, 0x00, 0x00]),
+                                                FontUnicode('\u{FE8F}', [0x00, 0x00, 0x00, 0x00, 0x42, 0x3C, 0x00, 0x10]),
+                                                FontUnicode('\u{FE90}', [0x00, 0x00, 0x00, 0x00, 0x42, 0xFC, 0x00, 0x10]),
+                                                FontUnicode('\u{FE91}', [0x00, 0x00, 0x00, 0x10, 0x10, 0x1F, 0x00, 0x10]),
+                                                FontUnicode('\u{FE92}', [0x00, 0x00, 0x00, 0x10, 0x10, 0xFF, 0x00, 0x10]),
+                                                FontUnicode('\u{FE93}', [0x28, 0x00, 0x18, 0x24, 0x24, 0x18, 0x00, 0x00]),
+                                                FontUnicode('\u{FE94}', [0x28, 0x00, 0x10, 0x18, 0x14, 0xFE, 0x00, 0x00]),
+                                                FontUnicode('\u{FE95}', [0x00, 0x00, 0x28, 0x00, 0x42, 0x3C, 0x00, 0x00]),
+                                                FontUnicode('\u{FE96}', [0x00, 0x00, 0x28, 0x00, 0x42, 0xFC, 0x00, 0x00]),
+                                                FontUnicode('\u{FE97}', [0x00, 0x28, 0x00, 0x10, 0x10, 0x1F, 0x00, 0x00]),
+                                                FontUnicode('\u{FE98}', [0x00, 0x28, 0x00, 0x10, 0x10, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FE99}', [0x00, 0x10, 0x28, 0x00, 0x42, 0x3C, 0x00, 0x00]),
+                                                FontUnicode('\u{FE9A}', [0x00, 0x10, 0x28, 0x00, 0x42, 0xFC, 0x00, 0x00]),
+                                                FontUnicode('\u{FE9B}', [0x10, 0x28, 0x00, 0x10, 0x10, 0x1F, 0x00, 0x00]),
+                                                FontUnicode('\u{FE9C}', [0x10, 0x28, 0x00, 0x10, 0x10, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FE9D}', [0x00, 0x00, 0x3E, 0x10, 0x08, 0x14, 0x04, 0x78]),
+                                                FontUnicode('\u{FE9E}', [0x00, 0x00, 0x3E, 0x10, 0x08, 0xF4, 0x04, 0x78]),
+                                                FontUnicode('\u{FE9F}', [0x00, 0x00, 0x3C, 0x20, 0x10, 0x1F, 0x00, 0x08]),
+                                                FontUnicode('\u{FEA0}', [0x00, 0x00, 0x3C, 0x20, 0x10, 0xFF, 0x00, 0x08]),
+                                                FontUnicode('\u{FEA1}', [0x00, 0x00, 0x3E, 0x10, 0x08, 0x04, 0x04, 0x78]),
+                                                FontUnicode('\u{FEA2}', [0x00, 0x00, 0x3E, 0x10, 0x08, 0xE4, 0x04, 0x78]),
+                                                FontUnicode('\u{FEA3}', [0x00, 0x00, 0x3C, 0x20, 0x10, 0x1F, 0x00, 0x00]),
+                                                FontUnicode('\u{FEA4}', [0x00, 0x00, 0x3C, 0x20, 0x10, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FEA5}', [0x08, 0x00, 0x3E, 0x10, 0x08, 0x04, 0x04, 0x78]),
+                                                FontUnicode('\u{FEA6}', [0x08, 0x00, 0x3E, 0x10, 0x08, 0xE4, 0x04, 0x78]),
+                                                FontUnicode('\u{FEA7}', [0x10, 0x00, 0x3C, 0x20, 0x10, 0x1F, 0x00, 0x00]),
+                                                FontUnicode('\u{FEA8}', [0x10, 0x00, 0x3C, 0x20, 0x10, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FEA9}', [0x00, 0x00, 0x08, 0x10, 0x20, 0x3E, 0x00, 0x00]),
+                                                FontUnicode('\u{FEAA}', [0x00, 0x00, 0x08, 0x10, 0x20, 0xFE, 0x00, 0x00]),
+                                                FontUnicode('\u{FEAB}', [0x08, 0x00, 0x08, 0x10, 0x20, 0x3E, 0x00, 0x00]),
+                                                FontUnicode('\u{FEAC}', [0x08, 0x00, 0x08, 0x10, 0x20, 0xFE, 0x00, 0x00]),
+                                                FontUnicode('\u{FEAD}', [0x00, 0x00, 0x00, 0x00, 0x20, 0x20, 0x10, 0x0E]),
+                                                FontUnicode('\u{FEAE}', [0x00, 0x00, 0x00, 0x00, 0x20, 0xE0, 0x10, 0x0E]),
+                                                FontUnicode('\u{FEAF}', [0x00, 0x00, 0x20, 0x00, 0x20, 0x20, 0x10, 0x0E]),
+                                                FontUnicode('\u{FEB0}', [0x00, 0x00, 0x20, 0x00, 0x20, 0xE0, 0x10, 0x0E]),
+                                                FontUnicode('\u{FEB1}', [0x00, 0x00, 0x00, 0xA8, 0xA9, 0xF9, 0x0E, 0x00]),
+                                                FontUnicode('\u{FEB2}', [0x00, 0x00, 0x00, 0xA8, 0xA9, 0xF9, 0x0E, 0x00]),
+                                                FontUnicode('\u{FEB3}', [0x00, 0x00, 0x00, 0x54, 0x54, 0x7F, 0x00, 0x00]),
+                                                FontUnicode('\u{FEB4}', [0x00, 0x00, 0x00, 0x54, 0x54, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FEB5}', [0x20, 0x50, 0x00, 0xA8, 0xA9, 0xF9, 0x0E, 0x00]),
+                                                FontUnicode('\u{FEB6}', [0x20, 0x50, 0x00, 0xA8, 0xA9, 0xF9, 0x0E, 0x00]),
+                                                FontUnicode('\u{FEB7}', [0x10, 0x28, 0x00, 0x54, 0x54, 0x7F, 0x00, 0x00]),
+                                                FontUnicode('\u{FEB8}', [0x10, 0x28, 0x00, 0x54, 0x54, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FEB9}', [0x00, 0x00, 0x00, 0x70, 0x89, 0xF9, 0x0E, 0x00]),
+                                                FontUnicode('\u{FEBA}', [0x00, 0x00, 0x00, 0x70, 0x89, 0xF9, 0x0E, 0x00]),
+                                                FontUnicode('\u{FEBB}', [0x00, 0x00, 0x00, 0x38, 0x44, 0x7F, 0x00, 0x00]),
+                                                FontUnicode('\u{FEBC}', [0x00, 0x00, 0x00, 0x38, 0x44, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FEBD}', [0x00, 0x20, 0x00, 0x70, 0x89, 0xF9, 0x0E, 0x00]),
+                                                FontUnicode('\u{FEBE}', [0x00, 0x20, 0x00, 0x70, 0x89, 0xF9, 0x0E, 0x00]),
+                                                FontUnicode('\u{FEBF}', [0x00, 0x10, 0x00, 0x38, 0x44, 0x7F, 0x00, 0x00]),
+                                                FontUnicode('\u{FEC0}', [0x00, 0x10, 0x00, 0x38, 0x44, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FEC1}', [0x04, 0x04, 0x04, 0x34, 0x4C, 0x7E, 0x00, 0x00]),
+                                                FontUnicode('\u{FEC2}', [0x04, 0x04, 0x04, 0x34, 0x4C, 0xFE, 0x00, 0x00]),
+                                                FontUnicode('\u{FEC3}', [0x04, 0x04, 0x04, 0x34, 0x4C, 0x7F, 0x00, 0x00]),
+                                                FontUnicode('\u{FEC4}', [0x04, 0x04, 0x04, 0x34, 0x4C, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FEC5}', [0x04, 0x14, 0x04, 0x34, 0x4C, 0x7E, 0x00, 0x00]),
+                                                FontUnicode('\u{FEC6}', [0x04, 0x14, 0x04, 0x34, 0x4C, 0xFE, 0x00, 0x00]),
+                                                FontUnicode('\u{FEC7}', [0x04, 0x14, 0x04, 0x34, 0x4C, 0x7F, 0x00, 0x00]),
+                                                FontUnicode('\u{FEC8}', [0x04, 0x14, 0x04, 0x34, 0x4C, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FEC9}', [0x00, 0x00, 0x38, 0x04, 0x38, 0x04, 0x04, 0x38]),
+                                                FontUnicode('\u{FECA}', [0x00, 0x00, 0x00, 0x18, 0x24, 0xFC, 0x04, 0x38]),
+                                                FontUnicode('\u{FECB}', [0x00, 0x00, 0x38, 0x04, 0x04, 0x3F, 0x00, 0x00]),
+                                                FontUnicode('\u{FECC}', [0x00, 0x00, 0x00, 0x18, 0x24, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FECD}', [0x10, 0x00, 0x38, 0x04, 0x38, 0x04, 0x04, 0x38]),
+                                                FontUnicode('\u{FECE}', [0x00, 0x10, 0x00, 0x18, 0x24, 0xFC, 0x04, 0x38]),
+                                                FontUnicode('\u{FECF}', [0x10, 0x00, 0x38, 0x04, 0x04, 0x3F, 0x00, 0x00]),
+                                                FontUnicode('\u{FED0}', [0x00, 0x10, 0x00, 0x18, 0x24, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FED1}', [0x20, 0x00, 0x60, 0x90, 0xE1, 0x7E, 0x00, 0x00]),
+                                                FontUnicode('\u{FED2}', [0x20, 0x00, 0x60, 0x90, 0xE1, 0xFE, 0x00, 0x00]),
+                                                FontUnicode('\u{FED3}', [0x00, 0x08, 0x00, 0x18, 0x24, 0x3F, 0x00, 0x00]),
+                                                FontUnicode('\u{FED4}', [0x00, 0x08, 0x00, 0x18, 0x24, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FED5}', [0x50, 0x00, 0x30, 0x48, 0x71, 0x41, 0x3E, 0x00]),
+                                                FontUnicode('\u{FED6}', [0x50, 0x00, 0x30, 0x48, 0x71, 0xC1, 0x3E, 0x00]),
+                                                FontUnicode('\u{FED7}', [0x00, 0x14, 0x00, 0x18, 0x24, 0x3F, 0x00, 0x00]),
+                                                FontUnicode('\u{FED8}', [0x00, 0x14, 0x00, 0x18, 0x24, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FED9}', [0x40, 0x40, 0x40, 0x58, 0x49, 0x7E, 0x00, 0x00]),
+                                                FontUnicode('\u{FEDA}', [0x40, 0x40, 0x40, 0x58, 0x49, 0xFE, 0x00, 0x00]),
+                                                FontUnicode('\u{FEDB}', [0x70, 0x08, 0x10, 0x20, 0x20, 0x3F, 0x00, 0x00]),
+                                                FontUnicode('\u{FEDC}', [0x70, 0x08, 0x10, 0x20, 0x20, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FEDD}', [0x40, 0x40, 0x40, 0x40, 0x41, 0x41, 0x3E, 0x00]),
+                                                FontUnicode('\u{FEDE}', [0x40, 0x40, 0x40, 0x40, 0x41, 0xC1, 0x3E, 0x00]),
+                                                FontUnicode('\u{FEDF}', [0x10, 0x10, 0x10, 0x10, 0x10, 0x1F, 0x00, 0x00]),
+                                                FontUnicode('\u{FEE0}', [0x10, 0x10, 0x10, 0x10, 0x10, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FEE1}', [0x00, 0x00, 0x00, 0x18, 0x24, 0x1E, 0x02, 0x02]),
+                                                FontUnicode('\u{FEE2}', [0x00, 0x00, 0x00, 0x18, 0x24, 0xFE, 0x02, 0x02]),
+                                                FontUnicode('\u{FEE3}', [0x00, 0x00, 0x00, 0x18, 0x3C, 0x3F, 0x00, 0x00]),
+                                                FontUnicode('\u{FEE4}', [0x00, 0x00, 0x00, 0x18, 0x3C, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FEE5}', [0x00, 0x00, 0x10, 0x00, 0x42, 0x42, 0x3C, 0x00]),
+                                                FontUnicode('\u{FEE6}', [0x00, 0x00, 0x10, 0x00, 0x02, 0xC2, 0x3C, 0x00]),
+                                                FontUnicode('\u{FEE7}', [0x00, 0x10, 0x00, 0x10, 0x10, 0x1F, 0x00, 0x00]),
+                                                FontUnicode('\u{FEE8}', [0x00, 0x10, 0x00, 0x10, 0x10, 0xFF, 0x00, 0x00]),
+                                                FontUnicode('\u{FEE9}', [0x00, 0x00, 0x18, 0x24, 0x24, 0x18, 0x00, 0x00]),
+                                                FontUnicode('\u{FEEA}', [0x00, 0x00, 0x10, 0x18, 0x14, 0xFE, 0x00, 0x00]),
+                                                FontUnicode('\u{FEEB}', [0x08, 0x1C, 0x2A, 0x2A, 0x2A, 0x3F, 0x00, 0x00]),
+                                                FontUnicode('\u{FEEC}', [0x00, 0x00, 0x00, 0x08, 0x14, 0xFF, 0x14, 0x08]),
+                                                FontUnicode('\u{FEED}', [0x00, 0x00, 0x00, 0x30, 0x48, 0x70, 0x20, 0x1E]),
+                                                FontUnicode('\u{FEEE}', [0x00, 0x00, 0x00, 0x30, 0x48, 0xF0, 0x20, 0x1E]),
+                                                FontUnicode('\u{FEEF}', [0x00, 0x00, 0x38, 0x04, 0x18, 0x22, 0x1C, 0x00]),
+                                                FontUnicode('\u{FEF0}', [0x00, 0x00, 0x38, 0x04, 0x18, 0xE2, 0x1C, 0x00]),
+                                                FontUnicode('\u{FEF1}', [0x00, 0x00, 0x38, 0x04, 0x18, 0x22, 0x1C, 0x14]),
+                                                FontUnicode('\u{FEF2}', [0x00, 0x00, 0x38, 0x04, 0x18, 0xE2, 0x1C, 0x14]),
+                                                FontUnicode('\u{FEF3}', [0x00, 0x00, 0x00, 0x10, 0x10, 0x1F, 0x00, 0x28]),
+                                                FontUnicode('\u{FEF4}', [0x00, 0x00, 0x00, 0x10, 0x10, 0xFF, 0x00, 0x28]),
+                                                FontUnicode('\u{FEF5}', [0x16, 0x09, 0x22, 0x24, 0x38, 0x3C, 0x00, 0x00]),
+                                                FontUnicode('\u{FEF6}', [0x16, 0x09, 0x22, 0x24, 0x38, 0xFC, 0x00, 0x00]),
+                                                FontUnicode('\u{FEF7}', [0x06, 0x03, 0x22, 0x24, 0x38, 0x3C, 0x00, 0x00]),
+                                                FontUnicode('\u{FEF8}', [0x06, 0x03, 0x22, 0x24, 0x38, 0xFC, 0x00, 0x00]),
+                                                FontUnicode('\u{FEF9}', [0x22, 0x22, 0x24, 0x28, 0x38, 0x3C, 0x06, 0x03]),
+                                                FontUnicode('\u{FEFA}', [0x22, 0x22, 0x24, 0x28, 0x38, 0xFC, 0x06, 0x03]),
+                                                FontUnicode('\u{FEFB}', [0x22, 0x22, 0x24, 0x28, 0x38, 0x3C, 0x00, 0x00]),
+                                                FontUnicode('\u{FEFC}', [0x22, 0x22, 0x24, 0x28, 0x38, 0xFC, 0x00, 0x00]),
+                                                FontUnicode('\u{FEFF}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00])];
+
+/// A convenient constant for Arabic Presentation Forms-B fonts (`U+0640`, `U+FE70` - `U+FEFF`), that implements the `UnicodeFonts` trait.
+///
+/// ## `ARABIC_UNICODE[0]`: `U+0640` `"ـ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[1]`: `U+FE70` `"ﹰ"`
+///
+/// ```text
+/// ░░░░██░░
+/// ░░██░░░░
+/// ░░░░██░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[2]`: `U+FE71` `"ﹱ"`
+///
+/// ```text
+/// ░░░░██░░
+/// ░░██░░░░
+/// ░░░░██░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[3]`: `U+FE72` `"ﹲ"`
+///
+/// ```text
+/// ░░██░██░
+/// ░░██░██░
+/// ░░░█░░█░
+/// ░░█░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[4]`: `U+FE73` `"ﹳ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░██░
+/// ░░░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[5]`: `U+FE74` `"ﹴ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░██░░
+/// ░░██░░░░
+/// ░░░░██░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[6]`: `U+FE76` `"ﹶ"`
+///
+/// ```text
+/// ░░░░██░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[7]`: `U+FE77` `"ﹷ"`
+///
+/// ```text
+/// ░░░░██░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[8]`: `U+FE78` `"ﹸ"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[9]`: `U+FE79` `"ﹹ"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[10]`: `U+FE7A` `"ﹺ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░██░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[11]`: `U+FE7B` `"ﹻ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░██░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[12]`: `U+FE7C` `"ﹼ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░█░█░█░
+/// ░░█████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[13]`: `U+FE7D` `"ﹽ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░█░█░█░
+/// ░░█████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[14]`: `U+FE7E` `"ﹾ"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[15]`: `U+FE7F` `"ﹿ"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[16]`: `U+FE80` `"ﺀ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░███░░
+/// ░░█░░░░░
+/// ░░░███░░
+/// ░░██░░░░
+/// ░█░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[17]`: `U+FE81` `"ﺁ"`
+///
+/// ```text
+/// ░░██░█░░
+/// ░█░░█░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[18]`: `U+FE82` `"ﺂ"`
+///
+/// ```text
+/// ░░██░█░░
+/// ░█░░█░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[19]`: `U+FE83` `"ﺃ"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[20]`: `U+FE84` `"ﺄ"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[21]`: `U+FE85` `"ﺅ"`
+///
+/// ```text
+/// ░░░░██░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░██░░
+/// ░░░█░░█░
+/// ░░░░███░
+/// ░░░░░█░░
+/// ░████░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[22]`: `U+FE86` `"ﺆ"`
+///
+/// ```text
+/// ░░░░██░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░██░░
+/// ░░░█░░█░
+/// ░░░░████
+/// ░░░░░█░░
+/// ░████░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[23]`: `U+FE87` `"ﺇ"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░██░░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[24]`: `U+FE88` `"ﺈ"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█████
+/// ░░░██░░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[25]`: `U+FE89` `"ﺉ"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░░░███░░
+/// ░░█░░░░░
+/// ░░░██░░░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[26]`: `U+FE8A` `"ﺊ"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░░░███░░
+/// ░░█░░░░░
+/// ░░░██░░░
+/// ░█░░░███
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[27]`: `U+FE8B` `"ﺋ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░██░░
+/// ░░░██░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// █████░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[28]`: `U+FE8C` `"ﺌ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░██░░
+/// ░░░██░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[29]`: `U+FE8D` `"ﺍ"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[30]`: `U+FE8E` `"ﺎ"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[31]`: `U+FE8F` `"ﺏ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░░░░█░
+/// ░░████░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[32]`: `U+FE90` `"ﺐ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░░░░█░
+/// ░░██████
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[33]`: `U+FE91` `"ﺑ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// █████░░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[34]`: `U+FE92` `"ﺒ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[35]`: `U+FE93` `"ﺓ"`
+///
+/// ```text
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[36]`: `U+FE94` `"ﺔ"`
+///
+/// ```text
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░██░░░
+/// ░░█░█░░░
+/// ░███████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[37]`: `U+FE95` `"ﺕ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ░█░░░░█░
+/// ░░████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[38]`: `U+FE96` `"ﺖ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ░█░░░░█░
+/// ░░██████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[39]`: `U+FE97` `"ﺗ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// █████░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[40]`: `U+FE98` `"ﺘ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[41]`: `U+FE99` `"ﺙ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ░█░░░░█░
+/// ░░████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[42]`: `U+FE9A` `"ﺚ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ░█░░░░█░
+/// ░░██████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[43]`: `U+FE9B` `"ﺛ"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// █████░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[44]`: `U+FE9C` `"ﺜ"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[45]`: `U+FE9D` `"ﺝ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█████░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░░█░░░░░
+/// ░░░████░
+/// ```
+///
+/// ## `ARABIC_UNICODE[46]`: `U+FE9E` `"ﺞ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█████░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░████
+/// ░░█░░░░░
+/// ░░░████░
+/// ```
+///
+/// ## `ARABIC_UNICODE[47]`: `U+FE9F` `"ﺟ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░████░░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// █████░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[48]`: `U+FEA0` `"ﺠ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░████░░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[49]`: `U+FEA1` `"ﺡ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█████░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░░████░
+/// ```
+///
+/// ## `ARABIC_UNICODE[50]`: `U+FEA2` `"ﺢ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█████░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░░███
+/// ░░█░░░░░
+/// ░░░████░
+/// ```
+///
+/// ## `ARABIC_UNICODE[51]`: `U+FEA3` `"ﺣ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░████░░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// █████░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[52]`: `U+FEA4` `"ﺤ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░████░░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[53]`: `U+FEA5` `"ﺥ"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░█████░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░░████░
+/// ```
+///
+/// ## `ARABIC_UNICODE[54]`: `U+FEA6` `"ﺦ"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░█████░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░░███
+/// ░░█░░░░░
+/// ░░░████░
+/// ```
+///
+/// ## `ARABIC_UNICODE[55]`: `U+FEA7` `"ﺧ"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░████░░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// █████░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[56]`: `U+FEA8` `"ﺨ"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░████░░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[57]`: `U+FEA9` `"ﺩ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░░░░█░░
+/// ░█████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[58]`: `U+FEAA` `"ﺪ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░░░░█░░
+/// ░███████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[59]`: `U+FEAB` `"ﺫ"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░░░░█░░
+/// ░█████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[60]`: `U+FEAC` `"ﺬ"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░░░░█░░
+/// ░███████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[61]`: `U+FEAD` `"ﺭ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ░███░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[62]`: `U+FEAE` `"ﺮ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░███
+/// ░░░░█░░░
+/// ░███░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[63]`: `U+FEAF` `"ﺯ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ░███░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[64]`: `U+FEB0` `"ﺰ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░███
+/// ░░░░█░░░
+/// ░███░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[65]`: `U+FEB1` `"ﺱ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░█░█
+/// █░░█░█░█
+/// █░░█████
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[66]`: `U+FEB2` `"ﺲ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░█░█
+/// █░░█░█░█
+/// █░░█████
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[67]`: `U+FEB3` `"ﺳ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░█░█░
+/// ░░█░█░█░
+/// ███████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[68]`: `U+FEB4` `"ﺴ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░█░█░
+/// ░░█░█░█░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[69]`: `U+FEB5` `"ﺵ"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░░█░█░
+/// ░░░░░░░░
+/// ░░░█░█░█
+/// █░░█░█░█
+/// █░░█████
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[70]`: `U+FEB6` `"ﺶ"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░░█░█░
+/// ░░░░░░░░
+/// ░░░█░█░█
+/// █░░█░█░█
+/// █░░█████
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[71]`: `U+FEB7` `"ﺷ"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ░░█░█░█░
+/// ░░█░█░█░
+/// ███████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[72]`: `U+FEB8` `"ﺸ"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ░░█░█░█░
+/// ░░█░█░█░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[73]`: `U+FEB9` `"ﺹ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░███░
+/// █░░█░░░█
+/// █░░█████
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[74]`: `U+FEBA` `"ﺺ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░███░
+/// █░░█░░░█
+/// █░░█████
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[75]`: `U+FEBB` `"ﺻ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░███░░
+/// ░░█░░░█░
+/// ███████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[76]`: `U+FEBC` `"ﺼ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░███░░
+/// ░░█░░░█░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[77]`: `U+FEBD` `"ﺽ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ░░░░███░
+/// █░░█░░░█
+/// █░░█████
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[78]`: `U+FEBE` `"ﺾ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ░░░░███░
+/// █░░█░░░█
+/// █░░█████
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[79]`: `U+FEBF` `"ﺿ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░░███░░
+/// ░░█░░░█░
+/// ███████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[80]`: `U+FEC0` `"ﻀ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░░███░░
+/// ░░█░░░█░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[81]`: `U+FEC1` `"ﻁ"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░██░░
+/// ░░██░░█░
+/// ░██████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[82]`: `U+FEC2` `"ﻂ"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░██░░
+/// ░░██░░█░
+/// ░███████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[83]`: `U+FEC3` `"ﻃ"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░██░░
+/// ░░██░░█░
+/// ███████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[84]`: `U+FEC4` `"ﻄ"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░██░░
+/// ░░██░░█░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[85]`: `U+FEC5` `"ﻅ"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░░█░█░░░
+/// ░░█░░░░░
+/// ░░█░██░░
+/// ░░██░░█░
+/// ░██████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[86]`: `U+FEC6` `"ﻆ"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░░█░█░░░
+/// ░░█░░░░░
+/// ░░█░██░░
+/// ░░██░░█░
+/// ░███████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[87]`: `U+FEC7` `"ﻇ"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░░█░█░░░
+/// ░░█░░░░░
+/// ░░█░██░░
+/// ░░██░░█░
+/// ███████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[88]`: `U+FEC8` `"ﻈ"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░░█░█░░░
+/// ░░█░░░░░
+/// ░░█░██░░
+/// ░░██░░█░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[89]`: `U+FEC9` `"ﻉ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░███░░
+/// ░░█░░░░░
+/// ░░░███░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░░███░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[90]`: `U+FECA` `"ﻊ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ░░██████
+/// ░░█░░░░░
+/// ░░░███░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[91]`: `U+FECB` `"ﻋ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░███░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[92]`: `U+FECC` `"ﻌ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[93]`: `U+FECD` `"ﻍ"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░░███░░
+/// ░░█░░░░░
+/// ░░░███░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░░███░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[94]`: `U+FECE` `"ﻎ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ░░██████
+/// ░░█░░░░░
+/// ░░░███░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[95]`: `U+FECF` `"ﻏ"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░░███░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[96]`: `U+FED0` `"ﻐ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[97]`: `U+FED1` `"ﻑ"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ░░░░░██░
+/// ░░░░█░░█
+/// █░░░░███
+/// ░██████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[98]`: `U+FED2` `"ﻒ"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ░░░░░██░
+/// ░░░░█░░█
+/// █░░░░███
+/// ░███████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[99]`: `U+FED3` `"ﻓ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ██████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[100]`: `U+FED4` `"ﻔ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[101]`: `U+FED5` `"ﻕ"`
+///
+/// ```text
+/// ░░░░█░█░
+/// ░░░░░░░░
+/// ░░░░██░░
+/// ░░░█░░█░
+/// █░░░███░
+/// █░░░░░█░
+/// ░█████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[102]`: `U+FED6` `"ﻖ"`
+///
+/// ```text
+/// ░░░░█░█░
+/// ░░░░░░░░
+/// ░░░░██░░
+/// ░░░█░░█░
+/// █░░░███░
+/// █░░░░░██
+/// ░█████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[103]`: `U+FED7` `"ﻗ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ██████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[104]`: `U+FED8` `"ﻘ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[105]`: `U+FED9` `"ﻙ"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░░██░█░
+/// █░░█░░█░
+/// ░██████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[106]`: `U+FEDA` `"ﻚ"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░░██░█░
+/// █░░█░░█░
+/// ░███████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[107]`: `U+FEDB` `"ﻛ"`
+///
+/// ```text
+/// ░░░░███░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ██████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[108]`: `U+FEDC` `"ﻜ"`
+///
+/// ```text
+/// ░░░░███░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[109]`: `U+FEDD` `"ﻝ"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// █░░░░░█░
+/// █░░░░░█░
+/// ░█████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[110]`: `U+FEDE` `"ﻞ"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// █░░░░░█░
+/// █░░░░░██
+/// ░█████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[111]`: `U+FEDF` `"ﻟ"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// █████░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[112]`: `U+FEE0` `"ﻠ"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[113]`: `U+FEE1` `"ﻡ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ░████░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[114]`: `U+FEE2` `"ﻢ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ░███████
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[115]`: `U+FEE3` `"ﻣ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░████░░
+/// ██████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[116]`: `U+FEE4` `"ﻤ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░████░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[117]`: `U+FEE5` `"ﻥ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[118]`: `U+FEE6` `"ﻦ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░█░░░░░░
+/// ░█░░░░██
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[119]`: `U+FEE7` `"ﻧ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// █████░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[120]`: `U+FEE8` `"ﻨ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[121]`: `U+FEE9` `"ﻩ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[122]`: `U+FEEA` `"ﻪ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░██░░░
+/// ░░█░█░░░
+/// ░███████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[123]`: `U+FEEB` `"ﻫ"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ██████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[124]`: `U+FEEC` `"ﻬ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ████████
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[125]`: `U+FEED` `"ﻭ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░██░░
+/// ░░░█░░█░
+/// ░░░░███░
+/// ░░░░░█░░
+/// ░████░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[126]`: `U+FEEE` `"ﻮ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░██░░
+/// ░░░█░░█░
+/// ░░░░████
+/// ░░░░░█░░
+/// ░████░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[127]`: `U+FEEF` `"ﻯ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░███░░
+/// ░░█░░░░░
+/// ░░░██░░░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[128]`: `U+FEF0` `"ﻰ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░███░░
+/// ░░█░░░░░
+/// ░░░██░░░
+/// ░█░░░███
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[129]`: `U+FEF1` `"ﻱ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░███░░
+/// ░░█░░░░░
+/// ░░░██░░░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░█░█░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[130]`: `U+FEF2` `"ﻲ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░███░░
+/// ░░█░░░░░
+/// ░░░██░░░
+/// ░█░░░███
+/// ░░███░░░
+/// ░░█░█░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[131]`: `U+FEF3` `"ﻳ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// █████░░░
+/// ░░░░░░░░
+/// ░░░█░█░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[132]`: `U+FEF4` `"ﻴ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░█░█░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[133]`: `U+FEF5` `"ﻵ"`
+///
+/// ```text
+/// ░██░█░░░
+/// █░░█░░░░
+/// ░█░░░█░░
+/// ░░█░░█░░
+/// ░░░███░░
+/// ░░████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[134]`: `U+FEF6` `"ﻶ"`
+///
+/// ```text
+/// ░██░█░░░
+/// █░░█░░░░
+/// ░█░░░█░░
+/// ░░█░░█░░
+/// ░░░███░░
+/// ░░██████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[135]`: `U+FEF7` `"ﻷ"`
+///
+/// ```text
+/// ░██░░░░░
+/// ██░░░░░░
+/// ░█░░░█░░
+/// ░░█░░█░░
+/// ░░░███░░
+/// ░░████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[136]`: `U+FEF8` `"ﻸ"`
+///
+/// ```text
+/// ░██░░░░░
+/// ██░░░░░░
+/// ░█░░░█░░
+/// ░░█░░█░░
+/// ░░░███░░
+/// ░░██████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[137]`: `U+FEF9` `"ﻹ"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░█░░█░░
+/// ░░░█░█░░
+/// ░░░███░░
+/// ░░████░░
+/// ░██░░░░░
+/// ██░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[138]`: `U+FEFA` `"ﻺ"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░█░░█░░
+/// ░░░█░█░░
+/// ░░░███░░
+/// ░░██████
+/// ░██░░░░░
+/// ██░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[139]`: `U+FEFB` `"ﻻ"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░█░░█░░
+/// ░░░█░█░░
+/// ░░░███░░
+/// ░░████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[140]`: `U+FEFC` `"ﻼ"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░█░░█░░
+/// ░░░█░█░░
+/// ░░░███░░
+/// ░░██████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARABIC_UNICODE[141]`: `U+FEFF` " "
+pub const ARABIC_FONTS: ArabicFonts = ArabicFonts(ARABIC_UNICODE);
+/// Strong-typed collection wrapper for [ARABIC_UNICODE](./constant.ARABIC_UNICODE.html).
+pub struct ArabicFonts([FontUnicode; 142]);
+
+impl ArabicFonts {
+    /// Create a new collection of `ARABIC_UNICODE` fonts.
+    pub fn new() -> Self {
+        ArabicFonts(ARABIC_UNICODE)
+    }
+}
+
+impl fmt::Debug for ArabicFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(ARABIC_UNICODE))
+    }
+}
+
+impl PartialEq for ArabicFonts {
+    fn eq(&self, other: &ArabicFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for ArabicFonts {
+    fn default() -> Self {
+        ArabicFonts::new()
+    }
+}
+
+impl UnicodeFonts for ArabicFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(142), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+/// Joining type of an Arabic character, as described in `ArabicShaping.txt`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Joining {
+    /// Never joins, e.g. hamza, or any non-Arabic character.
+    Isolated,
+    /// Joins only the preceding character, e.g. alef, dal, reh, waw.
+    Right,
+    /// Joins both the preceding and the following character.
+    Dual,
+    /// Forces joining on both sides without changing shape (tatweel).
+    Causing,
+    /// Ignored when deciding how the neighbours join (harakat).
+    Transparent,
+}
+
+/// Maps each Arabic letter to its isolated presentation form, and its joining type.
+///
+/// The final, initial and medial forms follow the isolated one, in that order.
+const ARABIC_SHAPES: [(char, char, Joining); 36] = [('\u{0621}', '\u{FE80}', Joining::Isolated),
+                                                     ('\u{0622}', '\u{FE81}', Joining::Right),
+                                                     ('\u{0623}', '\u{FE83}', Joining::Right),
+                                                     ('\u{0624}', '\u{FE85}', Joining::Right),
+                                                     ('\u{0625}', '\u{FE87}', Joining::Right),
+                                                     ('\u{0626}', '\u{FE89}', Joining::Dual),
+                                                     ('\u{0627}', '\u{FE8D}', Joining::Right),
+                                                     ('\u{0628}', '\u{FE8F}', Joining::Dual),
+                                                     ('\u{0629}', '\u{FE93}', Joining::Right),
+                                                     ('\u{062A}', '\u{FE95}', Joining::Dual),
+                                                     ('\u{062B}', '\u{FE99}', Joining::Dual),
+                                                     ('\u{062C}', '\u{FE9D}', Joining::Dual),
+                                                     ('\u{062D}', '\u{FEA1}', Joining::Dual),
+                                                     ('\u{062E}', '\u{FEA5}', Joining::Dual),
+                                                     ('\u{062F}', '\u{FEA9}', Joining::Right),
+                                                     ('\u{0630}', '\u{FEAB}', Joining::Right),
+                                                     ('\u{0631}', '\u{FEAD}', Joining::Right),
+                                                     ('\u{0632}', '\u{FEAF}', Joining::Right),
+                                                     ('\u{0633}', '\u{FEB1}', Joining::Dual),
+                                                     ('\u{0634}', '\u{FEB5}', Joining::Dual),
+                                                     ('\u{0635}', '\u{FEB9}', Joining::Dual),
+                                                     ('\u{0636}', '\u{FEBD}', Joining::Dual),
+                                                     ('\u{0637}', '\u{FEC1}', Joining::Dual),
+                                                     ('\u{0638}', '\u{FEC5}', Joining::Dual),
+                                                     ('\u{0639}', '\u{FEC9}', Joining::Dual),
+                                                     ('\u{063A}', '\u{FECD}', Joining::Dual),
+                                                     ('\u{0641}', '\u{FED1}', Joining::Dual),
+                                                     ('\u{0642}', '\u{FED5}', Joining::Dual),
+                                                     ('\u{0643}', '\u{FED9}', Joining::Dual),
+                                                     ('\u{0644}', '\u{FEDD}', Joining::Dual),
+                                                     ('\u{0645}', '\u{FEE1}', Joining::Dual),
+                                                     ('\u{0646}', '\u{FEE5}', Joining::Dual),
+                                                     ('\u{0647}', '\u{FEE9}', Joining::Dual),
+                                                     ('\u{0648}', '\u{FEED}', Joining::Right),
+                                                     ('\u{0649}', '\u{FEEF}', Joining::Right),
+                                                     ('\u{064A}', '\u{FEF1}', Joining::Dual)];
+
+/// Arabic letter lam, which forms a mandatory ligature with a following alef.
+const LAM: char = '\u{0644}';
+
+/// Maps each alef variant to the isolated form of its lam-alef ligature.
+const LAM_ALEF: [(char, char); 4] = [('\u{0622}', '\u{FEF5}'),
+                                     ('\u{0623}', '\u{FEF7}'),
+                                     ('\u{0625}', '\u{FEF9}'),
+                                     ('\u{0627}', '\u{FEFB}')];
+
+/// Maps each haraka to its spacing form, and to its form drawn over a tatweel. Dammatan
+/// and kasratan have no tatweel form, so they use the spacing one for both.
+const HARAKAT: [(char, char, char); 8] = [('\u{064B}', '\u{FE70}', '\u{FE71}'),
+                                         ('\u{064C}', '\u{FE72}', '\u{FE72}'),
+                                         ('\u{064D}', '\u{FE74}', '\u{FE74}'),
+                                         ('\u{064E}', '\u{FE76}', '\u{FE77}'),
+                                         ('\u{064F}', '\u{FE78}', '\u{FE79}'),
+                                         ('\u{0650}', '\u{FE7A}', '\u{FE7B}'),
+                                         ('\u{0651}', '\u{FE7C}', '\u{FE7D}'),
+                                         ('\u{0652}', '\u{FE7E}', '\u{FE7F}')];
+
+fn joining(c: char) -> Joining {
+    match c {
+        '\u{064B}'..='\u{065F}' | '\u{0670}' => Joining::Transparent,
+        '\u{0640}' => Joining::Causing,
+        _ => match ARABIC_SHAPES.binary_search_by_key(&c, |&(letter, _, _)| letter) {
+            Ok(idx) => ARABIC_SHAPES[idx].2,
+            Err(_) => Joining::Isolated,
+        },
+    }
+}
+
+fn lam_alef(c: char) -> Option<char> {
+    LAM_ALEF.iter().find(|&&(alef, _)| alef == c).map(|&(_, ligature)| ligature)
+}
+
+/// Returns `true` if a character of joining type `prev` connects to the one after it.
+fn joins_following(prev: Option<char>) -> bool {
+    matches!(prev.map(joining), Some(Joining::Dual) | Some(Joining::Causing))
+}
+
+/// Returns `true` if a character of joining type `next` connects to the one before it.
+fn joins_preceding(next: Option<char>) -> bool {
+    matches!(next.map(joining),
+             Some(Joining::Right) | Some(Joining::Dual) | Some(Joining::Causing))
+}
+
+/// Returns `true` if the letters `prev` and `next`, on either side of some harakat, are
+/// joined to each other. Lam never joins an alef, because the two form a ligature.
+fn joins_through(prev: Option<char>, next: Option<char>) -> bool {
+    let ligature = prev == Some(LAM) && next.and_then(lam_alef).is_some();
+    joins_following(prev) && joins_preceding(next) && !ligature
+}
+
+/// Picks the presentation form for `isolated`, given the letters on either side.
+fn select_form(isolated: char, kind: Joining, prev: Option<char>, next: Option<char>) -> char {
+    if kind != Joining::Right && kind != Joining::Dual {
+        return isolated;
+    }
+    let after = joins_following(prev);
+    let before = kind == Joining::Dual && joins_preceding(next);
+    let offset = match (after, before) {
+        (false, false) => 0,
+        (true, false) => 1,
+        (false, true) => 2,
+        (true, true) => 3,
+    };
+    char::from_u32(isolated as u32 + offset).unwrap_or(isolated)
+}
+
+/// Iterator over Arabic text converted into presentation forms, returned by
+/// [shape_arabic](./fn.shape_arabic.html).
+///
+/// Items are yielded in logical order. Because Arabic is written right-to-left,
+/// iterate with `.rev()` to get the characters in the order their 8x8 cells are laid
+/// out from left to right on screen.
+#[derive(Clone, Debug)]
+pub struct ArabicShaper<'a> {
+    text: &'a str,
+    front: usize,
+    back: usize,
+}
+
+impl<'a> ArabicShaper<'a> {
+    /// The nearest non-transparent character before byte offset `idx`.
+    fn before(&self, idx: usize) -> Option<char> {
+        self.text[..idx].chars().rev().find(|&c| joining(c) != Joining::Transparent)
+    }
+
+    /// The nearest non-transparent character from byte offset `idx` onwards.
+    fn after(&self, idx: usize) -> Option<char> {
+        self.text[idx..].chars().find(|&c| joining(c) != Joining::Transparent)
+    }
+
+    /// Shapes the character `c` found at byte offset `idx`. Returns `None` for an alef
+    /// that is part of a lam-alef ligature, which is returned for the lam instead.
+    fn shape(&self, idx: usize, c: char) -> Option<char> {
+        let before = self.before(idx);
+        let after = self.after(idx + c.len_utf8());
+        if let Some(ligature) = after.and_then(lam_alef).filter(|_| c == LAM) {
+            return Some(select_form(ligature, Joining::Right, before, None));
+        }
+        if lam_alef(c).is_some() && before == Some(LAM) {
+            return None;
+        }
+        if let Some(&(_, spacing, tatweel)) = HARAKAT.iter().find(|&&(haraka, _, _)| haraka == c) {
+            return Some(if joins_through(before, after) { tatweel } else { spacing });
+        }
+        match ARABIC_SHAPES.binary_search_by_key(&c, |&(letter, _, _)| letter) {
+            Ok(pos) => {
+                let (_, isolated, kind) = ARABIC_SHAPES[pos];
+                Some(select_form(isolated, kind, before, after))
+            }
+            Err(_) => Some(c),
+        }
+    }
+}
+
+impl<'a> Iterator for ArabicShaper<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            let idx = self.front;
+            let c = self.text[idx..self.back].chars().next()?;
+            self.front = idx + c.len_utf8();
+            if let Some(shaped) = self.shape(idx, c) {
+                return Some(shaped);
+            }
+        }
+    }
+}
+
+impl<'a> DoubleEndedIterator for ArabicShaper<'a> {
+    fn next_back(&mut self) -> Option<char> {
+        loop {
+            let c = self.text[self.front..self.back].chars().next_back()?;
+            let idx = self.back - c.len_utf8();
+            self.back = idx;
+            if let Some(shaped) = self.shape(idx, c) {
+                return Some(shaped);
+            }
+        }
+    }
+}
+
+/// Converts logical Arabic text (`U+0600` - `U+06FF`) into presentation forms that can be
+/// found in [ARABIC_FONTS](./constant.ARABIC_FONTS.html).
+///
+/// Each letter is replaced by its isolated, final, initial or medial form depending on
+/// its neighbours, and lam followed by alef becomes a single lam-alef ligature.
+/// Harakat do not break joining. They are replaced by their spacing forms, drawn over a
+/// tatweel when the letters on either side are joined, so that they get a cell of their
+/// own after the letter they belong to. Anything that isn't an Arabic letter or haraka
+/// is passed through unchanged.
+///
+/// The text is treated as a single right-to-left run: reverse the iterator to lay the
+/// glyphs out on screen.
+///
+/// ```
+/// use font8x8::unicode::shape_arabic;
+///
+/// let shaped: Vec<char> = shape_arabic("\u{0633}\u{0644}\u{0627}\u{0645}").collect();
+/// assert_eq!(shaped, ['\u{FEB3}', '\u{FEFC}', '\u{FEE1}']);
+///
+/// let visual: Vec<char> = shape_arabic("\u{0633}\u{0644}\u{0627}\u{0645}").rev().collect();
+/// assert_eq!(visual, ['\u{FEE1}', '\u{FEFC}', '\u{FEB3}']);
+/// ```
+pub fn shape_arabic<'a>(text: &'a str) -> ArabicShaper<'a> {
+    ArabicShaper { text,
+                   front: 0,
+                   back: text.len() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arabic_set_implements_default_trait_with_method_new() {
+        let arabic_set: ArabicFonts = Default::default();
+        assert_eq!(arabic_set, ArabicFonts::new());
+    }
+
+    #[test]
+    fn arabic_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(ARABIC_FONTS, ArabicFonts::new());
+    }
+
+    #[test]
+    fn arabic_fonts_constant_wraps_arabic_unicode_constant() {
+        let arabic = ArabicFonts::new();
+        assert!(arabic.0.len() == ARABIC_UNICODE.len());
+        for (idx, font) in arabic.0.iter().enumerate() {
+            assert_eq!(font, &ARABIC_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn shape_arabic_selects_initial_medial_and_final_forms() {
+        let shaped: Vec<char> = shape_arabic("\u{0628}\u{064A}\u{062A}").collect();
+        assert_eq!(shaped, ['\u{FE91}', '\u{FEF4}', '\u{FE96}']);
+    }
+
+    #[test]
+    fn shape_arabic_does_not_join_after_right_joining_letters() {
+        let shaped: Vec<char> = shape_arabic("\u{062F}\u{0628}").collect();
+        assert_eq!(shaped, ['\u{FEA9}', '\u{FE8F}']);
+    }
+
+    #[test]
+    fn shape_arabic_keeps_non_joining_letters_isolated() {
+        let shaped: Vec<char> = shape_arabic("\u{0634}\u{064A}\u{0621}").collect();
+        assert_eq!(shaped, ['\u{FEB7}', '\u{FEF2}', '\u{FE80}']);
+    }
+
+    #[test]
+    fn shape_arabic_skips_harakat_when_joining() {
+        let shaped: Vec<char> = shape_arabic("\u{0628}\u{064E}\u{062A}").collect();
+        assert_eq!(shaped, ['\u{FE91}', '\u{FE77}', '\u{FE96}']);
+    }
+
+    #[test]
+    fn shape_arabic_draws_harakat_over_a_tatweel_only_between_joined_letters() {
+        let text = "\u{0628}\u{0650}\u{0633}\u{0652}\u{0645}\u{064E}\u{062F}\u{064D}";
+        let shaped: Vec<char> = shape_arabic(text).collect();
+        assert_eq!(shaped,
+                   ['\u{FE91}', '\u{FE7B}', '\u{FEB4}', '\u{FE7F}',
+                    '\u{FEE4}', '\u{FE77}', '\u{FEAA}', '\u{FE74}']);
+    }
+
+    #[test]
+    fn shape_arabic_forms_lam_alef_ligatures_in_both_directions() {
+        let text = "\u{0628}\u{0644}\u{0627}";
+        let shaped: Vec<char> = shape_arabic(text).collect();
+        assert_eq!(shaped, ['\u{FE91}', '\u{FEFC}']);
+        let visual: Vec<char> = shape_arabic(text).rev().collect();
+        assert_eq!(visual, ['\u{FEFC}', '\u{FE91}']);
+    }
+
+    #[test]
+    fn shape_arabic_forms_lam_alef_ligatures_across_harakat() {
+        let text = "\u{0628}\u{0644}\u{064E}\u{0627}\u{0628}";
+        let shaped: Vec<char> = shape_arabic(text).collect();
+        assert_eq!(shaped, ['\u{FE91}', '\u{FEFC}', '\u{FE76}', '\u{FE8F}']);
+        let visual: Vec<char> = shape_arabic(text).rev().collect();
+        assert_eq!(visual, ['\u{FE8F}', '\u{FE76}', '\u{FEFC}', '\u{FE91}']);
+    }
+
+    #[test]
+    fn shape_arabic_passes_other_characters_through() {
+        let shaped: Vec<char> = shape_arabic("a \u{0628}").collect();
+        assert_eq!(shaped, ['a', ' ', '\u{FE8F}']);
+    }
+
+    #[test]
+    fn every_shaped_form_has_a_glyph() {
+        let forms = |isolated: char, kind: Joining| {
+            let count = match kind {
+                Joining::Right => 2,
+                Joining::Dual => 4,
+                _ => 1,
+            };
+            (0..count).map(move |offset| char::from_u32(isolated as u32 + offset).unwrap())
+        };
+        let letters = ARABIC_SHAPES.iter().flat_map(|&(_, isolated, kind)| forms(isolated, kind));
+        let ligatures = LAM_ALEF.iter().flat_map(|&(_, ligature)| forms(ligature, Joining::Right));
+        let harakat = HARAKAT.iter().flat_map(|&(_, spacing, tatweel)| vec![spacing, tatweel]);
+        for form in letters.chain(ligatures).chain(harakat) {
+            assert!(ARABIC_FONTS.get(form).is_some(), "U+{:04X}", form as u32);
+        }
+    }
+}
@@ -0,0 +1,1720 @@
+//! Arrows. `U+2190` - `U+21FF`
+//!
+//! Shafts use the same stroke weights as `BOX_LEGACY`: light arrows run along row 4 and
+//! column 3, and double arrows along rows 3 and 5 and columns 2 and 4, so that arrows
+//! line up with box-drawing frames.
+use super::unicode::{FontUnicode, UnicodeFonts};
+use core::fmt;
+
+/// A constant `[FontUnicode; 112]`, for Arrows fonts (`U+2190` - `U+21FF`).
+pub const ARROW_UNICODE: [FontUnicode; 112] = [FontUnicode('\u{2190}', [0x00, 0x00, 0x04, 0x02, 0xFF, 0x02, 0x04, 0x00]),
+                                               FontUnicode('\u{2191}', [0x08, 0x1C, 0x2A, 0x08, 0x08, 0x08, 0x08, 0x08]),
+                                               FontUnicode('\u{2192}', [0x00, 0x00, 0x20, 0x40, 0xFF, 0x40, 0x20, 0x00]),
+                                               FontUnicode('\u{2193}', [0x08, 0x08, 0x08, 0x08, 0x08, 0x2A, 0x1C, 0x08]),
+                                               FontUnicode('\u{2194}', [0x00, 0x00, 0x24, 0x42, 0xFF, 0x42, 0x24, 0x00]),
+                                               FontUnicode('\u{2195}', [0x08, 0x1C, 0x2A, 0x08, 0x08, 0x2A, 0x1C, 0x08]),
+                                               FontUnicode('\u{2196}', [0x0F, 0x03, 0x05, 0x09, 0x10, 0x20, 0x40, 0x80]),
+                                               FontUnicode('\u{2197}', [0xF0, 0xC0, 0xA0, 0x90, 0x08, 0x04, 0x02, 0x01]),
+                                               FontUnicode('\u{2198}', [0x01, 0x02, 0x04, 0x08, 0x90, 0xA0, 0xC0, 0xF0]),
+                                               FontUnicode('\u{2199}', [0x80, 0x40, 0x20, 0x10, 0x09, 0x05, 0x03, 0x0F]),
+                                               FontUnicode('\u{219A}', [0x00, 0x00, 0x24, 0x12, 0xFF, 0x06, 0x06, 0x00]),
+                                               FontUnicode('\u{219B}', [0x00, 0x00, 0x20, 0x50, 0xFF, 0x44, 0x22, 0x00]),
+                                               FontUnicode('\u{219C}', [0x00, 0x00, 0x04, 0x0E, 0x93, 0x62, 0x04, 0x00]),
+                                               FontUnicode('\u{219D}', [0x00, 0x00, 0x20, 0x70, 0xC9, 0x46, 0x20, 0x00]),
+                                               FontUnicode('\u{219E}', [0x00, 0x00, 0x14, 0x0A, 0xFF, 0x0A, 0x14, 0x00]),
+                                               FontUnicode('\u{219F}', [0x08, 0x1C, 0x2A, 0x1C, 0x2A, 0x08, 0x08, 0x08]),
+                                               FontUnicode('\u{21A0}', [0x00, 0x00, 0x28, 0x50, 0xFF, 0x50, 0x28, 0x00]),
+                                               FontUnicode('\u{21A1}', [0x08, 0x08, 0x08, 0x2A, 0x1C, 0x2A, 0x1C, 0x08]),
+                                               FontUnicode('\u{21A2}', [0x00, 0x00, 0x84, 0x42, 0xFF, 0x42, 0x84, 0x00]),
+                                               FontUnicode('\u{21A3}', [0x00, 0x00, 0x21, 0x42, 0xFF, 0x42, 0x21, 0x00]),
+                                               FontUnicode('\u{21A4}', [0x00, 0x00, 0x84, 0x82, 0xFF, 0x82, 0x84, 0x00]),
+                                               FontUnicode('\u{21A5}', [0x08, 0x1C, 0x2A, 0x08, 0x08, 0x08, 0x08, 0x3E]),
+                                               FontUnicode('\u{21A6}', [0x00, 0x00, 0x21, 0x41, 0xFF, 0x41, 0x21, 0x00]),
+                                               FontUnicode('\u{21A7}', [0x3E, 0x08, 0x08, 0x08, 0x08, 0x2A, 0x1C, 0x08]),
+                                               FontUnicode('\u{21A8}', [0x08, 0x1C, 0x2A, 0x2A, 0x1C, 0x08, 0x00, 0x7F]),
+                                               FontUnicode('\u{21A9}', [0x00, 0x70, 0x84, 0xC2, 0x3F, 0x02, 0x04, 0x00]),
+                                               FontUnicode('\u{21AA}', [0x00, 0x0E, 0x21, 0x43, 0xFC, 0x40, 0x20, 0x00]),
+                                               FontUnicode('\u{21AB}', [0x00, 0x00, 0x64, 0x92, 0xFF, 0x92, 0x64, 0x00]),
+                                               FontUnicode('\u{21AC}', [0x00, 0x00, 0x26, 0x49, 0xFF, 0x49, 0x26, 0x00]),
+                                               FontUnicode('\u{21AD}', [0x00, 0x00, 0x24, 0x4E, 0x93, 0x62, 0x24, 0x00]),
+                                               FontUnicode('\u{21AE}', [0x00, 0x00, 0x24, 0x52, 0xFF, 0x46, 0x26, 0x00]),
+                                               FontUnicode('\u{21AF}', [0x20, 0x10, 0x08, 0xFC, 0x20, 0x12, 0x0A, 0x0E]),
+                                               FontUnicode('\u{21B0}', [0x00, 0x00, 0x04, 0x02, 0x7F, 0x42, 0x44, 0x40]),
+                                               FontUnicode('\u{21B1}', [0x00, 0x00, 0x20, 0x40, 0xFE, 0x42, 0x22, 0x02]),
+                                               FontUnicode('\u{21B2}', [0x40, 0x40, 0x44, 0x42, 0x7F, 0x02, 0x04, 0x00]),
+                                               FontUnicode('\u{21B3}', [0x02, 0x02, 0x22, 0x42, 0xFE, 0x40, 0x20, 0x00]),
+                                               FontUnicode('\u{21B4}', [0x00, 0x3F, 0x20, 0x20, 0x20, 0xA8, 0x70, 0x20]),
+                                               FontUnicode('\u{21B5}', [0x00, 0x40, 0x44, 0x42, 0x7F, 0x02, 0x04, 0x00]),
+                                               FontUnicode('\u{21B6}', [0x00, 0x38, 0x44, 0x84, 0x9F, 0x0E, 0x04, 0x00]),
+                                               FontUnicode('\u{21B7}', [0x00, 0x1C, 0x22, 0x21, 0xF9, 0x70, 0x20, 0x00]),
+                                               FontUnicode('\u{21B8}', [0xFF, 0x00, 0x0F, 0x03, 0x05, 0x09, 0x10, 0x20]),
+                                               FontUnicode('\u{21B9}', [0x05, 0xFF, 0x05, 0x00, 0xA0, 0xFF, 0xA0, 0x00]),
+                                               FontUnicode('\u{21BA}', [0x34, 0x46, 0x8E, 0x81, 0x81, 0x42, 0x3C, 0x00]),
+                                               FontUnicode('\u{21BB}', [0x2C, 0x62, 0x71, 0x81, 0x81, 0x42, 0x3C, 0x00]),
+                                               FontUnicode('\u{21BC}', [0x00, 0x00, 0x04, 0x02, 0xFF, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{21BD}', [0x00, 0x00, 0x00, 0x00, 0xFF, 0x02, 0x04, 0x00]),
+                                               FontUnicode('\u{21BE}', [0x08, 0x18, 0x28, 0x08, 0x08, 0x08, 0x08, 0x08]),
+                                               FontUnicode('\u{21BF}', [0x08, 0x0C, 0x0A, 0x08, 0x08, 0x08, 0x08, 0x08]),
+                                               FontUnicode('\u{21C0}', [0x00, 0x00, 0x20, 0x40, 0xFF, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{21C1}', [0x00, 0x00, 0x00, 0x00, 0xFF, 0x40, 0x20, 0x00]),
+                                               FontUnicode('\u{21C2}', [0x08, 0x08, 0x08, 0x08, 0x08, 0x28, 0x18, 0x08]),
+                                               FontUnicode('\u{21C3}', [0x08, 0x08, 0x08, 0x08, 0x08, 0x0A, 0x0C, 0x08]),
+                                               FontUnicode('\u{21C4}', [0x20, 0x40, 0xFF, 0x40, 0x24, 0x02, 0xFF, 0x02]),
+                                               FontUnicode('\u{21C5}', [0x22, 0x27, 0x22, 0x22, 0x22, 0x22, 0x72, 0x22]),
+                                               FontUnicode('\u{21C6}', [0x04, 0x02, 0xFF, 0x02, 0x24, 0x40, 0xFF, 0x40]),
+                                               FontUnicode('\u{21C7}', [0x04, 0x02, 0xFF, 0x02, 0x04, 0x02, 0xFF, 0x02]),
+                                               FontUnicode('\u{21C8}', [0x22, 0x77, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22]),
+                                               FontUnicode('\u{21C9}', [0x20, 0x40, 0xFF, 0x40, 0x20, 0x40, 0xFF, 0x40]),
+                                               FontUnicode('\u{21CA}', [0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x77, 0x22]),
+                                               FontUnicode('\u{21CB}', [0x04, 0x02, 0xFF, 0x00, 0x00, 0xFF, 0x40, 0x20]),
+                                               FontUnicode('\u{21CC}', [0x20, 0x40, 0xFF, 0x00, 0x00, 0xFF, 0x02, 0x04]),
+                                               FontUnicode('\u{21CD}', [0x00, 0x20, 0x24, 0xFE, 0x01, 0xFE, 0x04, 0x04]),
+                                               FontUnicode('\u{21CE}', [0x00, 0x20, 0x24, 0x7E, 0x81, 0x7E, 0x24, 0x04]),
+                                               FontUnicode('\u{21CF}', [0x00, 0x20, 0x20, 0x7F, 0x80, 0x7F, 0x24, 0x04]),
+                                               FontUnicode('\u{21D0}', [0x00, 0x00, 0x04, 0xFE, 0x01, 0xFE, 0x04, 0x00]),
+                                               FontUnicode('\u{21D1}', [0x08, 0x14, 0x36, 0x14, 0x14, 0x14, 0x14, 0x14]),
+                                               FontUnicode('\u{21D2}', [0x00, 0x00, 0x20, 0x7F, 0x80, 0x7F, 0x20, 0x00]),
+                                               FontUnicode('\u{21D3}', [0x14, 0x14, 0x14, 0x14, 0x14, 0x36, 0x14, 0x08]),
+                                               FontUnicode('\u{21D4}', [0x00, 0x00, 0x24, 0x7E, 0x81, 0x7E, 0x24, 0x00]),
+                                               FontUnicode('\u{21D5}', [0x08, 0x14, 0x36, 0x14, 0x14, 0x36, 0x14, 0x08]),
+                                               FontUnicode('\u{21D6}', [0x1F, 0x01, 0x05, 0x0B, 0x15, 0x28, 0x50, 0xA0]),
+                                               FontUnicode('\u{21D7}', [0xF8, 0x80, 0xA0, 0xD0, 0xA8, 0x14, 0x0A, 0x05]),
+                                               FontUnicode('\u{21D8}', [0x05, 0x0A, 0x14, 0xA8, 0xD0, 0xA0, 0x80, 0xF8]),
+                                               FontUnicode('\u{21D9}', [0xA0, 0x50, 0x28, 0x15, 0x0B, 0x05, 0x01, 0x1F]),
+                                               FontUnicode('\u{21DA}', [0x00, 0x08, 0xFC, 0x02, 0xFD, 0x02, 0xFC, 0x08]),
+                                               FontUnicode('\u{21DB}', [0x00, 0x10, 0x3F, 0x40, 0xBF, 0x40, 0x3F, 0x10]),
+                                               FontUnicode('\u{21DC}', [0x00, 0x00, 0x04, 0x1A, 0x27, 0xC2, 0x04, 0x00]),
+                                               FontUnicode('\u{21DD}', [0x00, 0x00, 0x20, 0x58, 0xE4, 0x43, 0x20, 0x00]),
+                                               FontUnicode('\u{21DE}', [0x08, 0x1C, 0x2A, 0x08, 0x3E, 0x08, 0x3E, 0x08]),
+                                               FontUnicode('\u{21DF}', [0x08, 0x3E, 0x08, 0x3E, 0x08, 0x2A, 0x1C, 0x08]),
+                                               FontUnicode('\u{21E0}', [0x00, 0x00, 0x04, 0x02, 0xDB, 0x02, 0x04, 0x00]),
+                                               FontUnicode('\u{21E1}', [0x08, 0x1C, 0x22, 0x08, 0x08, 0x00, 0x08, 0x08]),
+                                               FontUnicode('\u{21E2}', [0x00, 0x00, 0x20, 0x40, 0xDB, 0x40, 0x20, 0x00]),
+                                               FontUnicode('\u{21E3}', [0x08, 0x08, 0x00, 0x08, 0x08, 0x22, 0x1C, 0x08]),
+                                               FontUnicode('\u{21E4}', [0x00, 0x01, 0x09, 0x05, 0xFF, 0x05, 0x09, 0x01]),
+                                               FontUnicode('\u{21E5}', [0x00, 0x80, 0x90, 0xA0, 0xFF, 0xA0, 0x90, 0x80]),
+                                               FontUnicode('\u{21E6}', [0x00, 0x08, 0x0C, 0xFA, 0x81, 0xFA, 0x0C, 0x08]),
+                                               FontUnicode('\u{21E7}', [0x08, 0x14, 0x22, 0x77, 0x14, 0x14, 0x14, 0x1C]),
+                                               FontUnicode('\u{21E8}', [0x00, 0x10, 0x30, 0x5F, 0x81, 0x5F, 0x30, 0x10]),
+                                               FontUnicode('\u{21E9}', [0x1C, 0x14, 0x14, 0x14, 0x77, 0x22, 0x14, 0x08]),
+                                               FontUnicode('\u{21EA}', [0x08, 0x14, 0x22, 0x77, 0x14, 0x1C, 0x00, 0x1C]),
+                                               FontUnicode('\u{21EB}', [0x08, 0x14, 0x22, 0x77, 0x14, 0x14, 0x36, 0x3E]),
+                                               FontUnicode('\u{21EC}', [0x08, 0x14, 0x22, 0x77, 0x14, 0x36, 0x22, 0x3E]),
+                                               FontUnicode('\u{21ED}', [0x08, 0x14, 0x2A, 0x6B, 0x14, 0x14, 0x36, 0x3E]),
+                                               FontUnicode('\u{21EE}', [0x08, 0x14, 0x36, 0x55, 0x22, 0x77, 0x14, 0x1C]),
+                                               FontUnicode('\u{21EF}', [0x08, 0x14, 0x36, 0x55, 0x22, 0x77, 0x36, 0x3E]),
+                                               FontUnicode('\u{21F0}', [0x00, 0x21, 0x61, 0xBF, 0x81, 0xBF, 0x61, 0x21]),
+                                               FontUnicode('\u{21F1}', [0xFF, 0x01, 0x3D, 0x0D, 0x15, 0x25, 0x41, 0x81]),
+                                               FontUnicode('\u{21F2}', [0x81, 0x82, 0xA4, 0xA8, 0xB0, 0xBC, 0x80, 0xFF]),
+                                               FontUnicode('\u{21F3}', [0x08, 0x14, 0x22, 0x77, 0x77, 0x22, 0x14, 0x08]),
+                                               FontUnicode('\u{21F4}', [0x00, 0x00, 0x20, 0x5C, 0xFF, 0x5C, 0x20, 0x00]),
+                                               FontUnicode('\u{21F5}', [0x22, 0x72, 0x22, 0x22, 0x22, 0x22, 0x27, 0x22]),
+                                               FontUnicode('\u{21F6}', [0x40, 0xFF, 0x40, 0x40, 0xFF, 0x40, 0x40, 0xFF]),
+                                               FontUnicode('\u{21F7}', [0x00, 0x00, 0x14, 0x12, 0xFF, 0x12, 0x14, 0x00]),
+                                               FontUnicode('\u{21F8}', [0x00, 0x00, 0x28, 0x48, 0xFF, 0x48, 0x28, 0x00]),
+                                               FontUnicode('\u{21F9}', [0x00, 0x00, 0x2C, 0x4A, 0xFF, 0x4A, 0x2C, 0x00]),
+                                               FontUnicode('\u{21FA}', [0x00, 0x00, 0x54, 0x52, 0xFF, 0x52, 0x54, 0x00]),
+                                               FontUnicode('\u{21FB}', [0x00, 0x00, 0x2A, 0x4A, 0xFF, 0x4A, 0x2A, 0x00]),
+                                               FontUnicode('\u{21FC}', [0x00, 0x00, 0x34, 0x56, 0xFF, 0x56, 0x34, 0x00]),
+                                               FontUnicode('\u{21FD}', [0x00, 0x00, 0x04, 0x06, 0xFD, 0x06, 0x04, 0x00]),
+                                               FontUnicode('\u{21FE}', [0x00, 0x00, 0x20, 0x60, 0xBF, 0x60, 0x20, 0x00]),
+                                               FontUnicode('\u{21FF}', [0x00, 0x00, 0x24, 0x66, 0xBD, 0x66, 0x24, 0x00])];
+
+/// A convenient constant for Arrows fonts (`U+2190` - `U+21FF`), that implements the `UnicodeFonts` trait.
+///
+/// ## `ARROW_UNICODE[0]`: `U+2190` `"←"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// ████████
+/// ░█░░░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[1]`: `U+2191` `"↑"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█░█░█░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[2]`: `U+2192` `"→"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░░█░
+/// ████████
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[3]`: `U+2193` `"↓"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[4]`: `U+2194` `"↔"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░█░░
+/// ░█░░░░█░
+/// ████████
+/// ░█░░░░█░
+/// ░░█░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[5]`: `U+2195` `"↕"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█░█░█░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[6]`: `U+2196` `"↖"`
+///
+/// ```text
+/// ████░░░░
+/// ██░░░░░░
+/// █░█░░░░░
+/// █░░█░░░░
+/// ░░░░█░░░
+/// ░░░░░█░░
+/// ░░░░░░█░
+/// ░░░░░░░█
+/// ```
+///
+/// ## `ARROW_UNICODE[7]`: `U+2197` `"↗"`
+///
+/// ```text
+/// ░░░░████
+/// ░░░░░░██
+/// ░░░░░█░█
+/// ░░░░█░░█
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// █░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[8]`: `U+2198` `"↘"`
+///
+/// ```text
+/// █░░░░░░░
+/// ░█░░░░░░
+/// ░░█░░░░░
+/// ░░░█░░░░
+/// ░░░░█░░█
+/// ░░░░░█░█
+/// ░░░░░░██
+/// ░░░░████
+/// ```
+///
+/// ## `ARROW_UNICODE[9]`: `U+2199` `"↙"`
+///
+/// ```text
+/// ░░░░░░░█
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// █░░█░░░░
+/// █░█░░░░░
+/// ██░░░░░░
+/// ████░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[10]`: `U+219A` `"↚"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░█░░
+/// ░█░░█░░░
+/// ████████
+/// ░██░░░░░
+/// ░██░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[11]`: `U+219B` `"↛"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░█░█░
+/// ████████
+/// ░░█░░░█░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[12]`: `U+219C` `"↜"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░███░░░░
+/// ██░░█░░█
+/// ░█░░░██░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[13]`: `U+219D` `"↝"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░███░
+/// █░░█░░██
+/// ░██░░░█░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[14]`: `U+219E` `"↞"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░█░░░
+/// ░█░█░░░░
+/// ████████
+/// ░█░█░░░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[15]`: `U+219F` `"↟"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░█░█░█░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[16]`: `U+21A0` `"↠"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░█░░
+/// ░░░░█░█░
+/// ████████
+/// ░░░░█░█░
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[17]`: `U+21A1` `"↡"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[18]`: `U+21A2` `"↢"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░█
+/// ░█░░░░█░
+/// ████████
+/// ░█░░░░█░
+/// ░░█░░░░█
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[19]`: `U+21A3` `"↣"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░░░░█░░
+/// ░█░░░░█░
+/// ████████
+/// ░█░░░░█░
+/// █░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[20]`: `U+21A4` `"↤"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░█
+/// ░█░░░░░█
+/// ████████
+/// ░█░░░░░█
+/// ░░█░░░░█
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[21]`: `U+21A5` `"↥"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█░█░█░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░█████░░
+/// ```
+///
+/// ## `ARROW_UNICODE[22]`: `U+21A6` `"↦"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░░░░█░░
+/// █░░░░░█░
+/// ████████
+/// █░░░░░█░
+/// █░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[23]`: `U+21A7` `"↧"`
+///
+/// ```text
+/// ░█████░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[24]`: `U+21A8` `"↨"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ███████░
+/// ```
+///
+/// ## `ARROW_UNICODE[25]`: `U+21A9` `"↩"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░███░
+/// ░░█░░░░█
+/// ░█░░░░██
+/// ██████░░
+/// ░█░░░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[26]`: `U+21AA` `"↪"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░███░░░░
+/// █░░░░█░░
+/// ██░░░░█░
+/// ░░██████
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[27]`: `U+21AB` `"↫"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░██░
+/// ░█░░█░░█
+/// ████████
+/// ░█░░█░░█
+/// ░░█░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[28]`: `U+21AC` `"↬"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██░░█░░
+/// █░░█░░█░
+/// ████████
+/// █░░█░░█░
+/// ░██░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[29]`: `U+21AD` `"↭"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░█░░
+/// ░███░░█░
+/// ██░░█░░█
+/// ░█░░░██░
+/// ░░█░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[30]`: `U+21AE` `"↮"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░█░░
+/// ░█░░█░█░
+/// ████████
+/// ░██░░░█░
+/// ░██░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[31]`: `U+21AF` `"↯"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░██████
+/// ░░░░░█░░
+/// ░█░░█░░░
+/// ░█░█░░░░
+/// ░███░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[32]`: `U+21B0` `"↰"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// ███████░
+/// ░█░░░░█░
+/// ░░█░░░█░
+/// ░░░░░░█░
+/// ```
+///
+/// ## `ARROW_UNICODE[33]`: `U+21B1` `"↱"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░░█░
+/// ░███████
+/// ░█░░░░█░
+/// ░█░░░█░░
+/// ░█░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[34]`: `U+21B2` `"↲"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░█░░░█░
+/// ░█░░░░█░
+/// ███████░
+/// ░█░░░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[35]`: `U+21B3` `"↳"`
+///
+/// ```text
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ░█░░░█░░
+/// ░█░░░░█░
+/// ░███████
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[36]`: `U+21B4` `"↴"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ░░░█░█░█
+/// ░░░░███░
+/// ░░░░░█░░
+/// ```
+///
+/// ## `ARROW_UNICODE[37]`: `U+21B5` `"↵"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░█░
+/// ░░█░░░█░
+/// ░█░░░░█░
+/// ███████░
+/// ░█░░░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[38]`: `U+21B6` `"↶"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░███░░
+/// ░░█░░░█░
+/// ░░█░░░░█
+/// █████░░█
+/// ░███░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[39]`: `U+21B7` `"↷"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░█░░
+/// █░░█████
+/// ░░░░███░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[40]`: `U+21B8` `"↸"`
+///
+/// ```text
+/// ████████
+/// ░░░░░░░░
+/// ████░░░░
+/// ██░░░░░░
+/// █░█░░░░░
+/// █░░█░░░░
+/// ░░░░█░░░
+/// ░░░░░█░░
+/// ```
+///
+/// ## `ARROW_UNICODE[41]`: `U+21B9` `"↹"`
+///
+/// ```text
+/// █░█░░░░░
+/// ████████
+/// █░█░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░█
+/// ████████
+/// ░░░░░█░█
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[42]`: `U+21BA` `"↺"`
+///
+/// ```text
+/// ░░█░██░░
+/// ░██░░░█░
+/// ░███░░░█
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[43]`: `U+21BB` `"↻"`
+///
+/// ```text
+/// ░░██░█░░
+/// ░█░░░██░
+/// █░░░███░
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[44]`: `U+21BC` `"↼"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[45]`: `U+21BD` `"↽"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░█░░░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[46]`: `U+21BE` `"↾"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░██░░░
+/// ░░░█░█░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[47]`: `U+21BF` `"↿"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░██░░░░
+/// ░█░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[48]`: `U+21C0` `"⇀"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░░█░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[49]`: `U+21C1` `"⇁"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[50]`: `U+21C2` `"⇂"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░█░░
+/// ░░░██░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[51]`: `U+21C3` `"⇃"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░█░█░░░░
+/// ░░██░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[52]`: `U+21C4` `"⇄"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░░░░█░
+/// ████████
+/// ░░░░░░█░
+/// ░░█░░█░░
+/// ░█░░░░░░
+/// ████████
+/// ░█░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[53]`: `U+21C5` `"⇅"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ███░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░███░
+/// ░█░░░█░░
+/// ```
+///
+/// ## `ARROW_UNICODE[54]`: `U+21C6` `"⇆"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// ████████
+/// ░█░░░░░░
+/// ░░█░░█░░
+/// ░░░░░░█░
+/// ████████
+/// ░░░░░░█░
+/// ```
+///
+/// ## `ARROW_UNICODE[55]`: `U+21C7` `"⇇"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// ████████
+/// ░█░░░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// ████████
+/// ░█░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[56]`: `U+21C8` `"⇈"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ███░███░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ```
+///
+/// ## `ARROW_UNICODE[57]`: `U+21C9` `"⇉"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░░░░█░
+/// ████████
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░░░░░░█░
+/// ████████
+/// ░░░░░░█░
+/// ```
+///
+/// ## `ARROW_UNICODE[58]`: `U+21CA` `"⇊"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ███░███░
+/// ░█░░░█░░
+/// ```
+///
+/// ## `ARROW_UNICODE[59]`: `U+21CB` `"⇋"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ```
+///
+/// ## `ARROW_UNICODE[60]`: `U+21CC` `"⇌"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░░░░█░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░█░░░░░░
+/// ░░█░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[61]`: `U+21CD` `"⇍"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░█░░█░░
+/// ░███████
+/// █░░░░░░░
+/// ░███████
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[62]`: `U+21CE` `"⇎"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░█░░█░░
+/// ░██████░
+/// █░░░░░░█
+/// ░██████░
+/// ░░█░░█░░
+/// ░░█░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[63]`: `U+21CF` `"⇏"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ███████░
+/// ░░░░░░░█
+/// ███████░
+/// ░░█░░█░░
+/// ░░█░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[64]`: `U+21D0` `"⇐"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░███████
+/// █░░░░░░░
+/// ░███████
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[65]`: `U+21D1` `"⇑"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░██░██░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[66]`: `U+21D2` `"⇒"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ███████░
+/// ░░░░░░░█
+/// ███████░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[67]`: `U+21D3` `"⇓"`
+///
+/// ```text
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░██░██░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[68]`: `U+21D4` `"⇔"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░█░░
+/// ░██████░
+/// █░░░░░░█
+/// ░██████░
+/// ░░█░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[69]`: `U+21D5` `"⇕"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░██░██░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░██░██░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[70]`: `U+21D6` `"⇖"`
+///
+/// ```text
+/// █████░░░
+/// █░░░░░░░
+/// █░█░░░░░
+/// ██░█░░░░
+/// █░█░█░░░
+/// ░░░█░█░░
+/// ░░░░█░█░
+/// ░░░░░█░█
+/// ```
+///
+/// ## `ARROW_UNICODE[71]`: `U+21D7` `"⇗"`
+///
+/// ```text
+/// ░░░█████
+/// ░░░░░░░█
+/// ░░░░░█░█
+/// ░░░░█░██
+/// ░░░█░█░█
+/// ░░█░█░░░
+/// ░█░█░░░░
+/// █░█░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[72]`: `U+21D8` `"⇘"`
+///
+/// ```text
+/// █░█░░░░░
+/// ░█░█░░░░
+/// ░░█░█░░░
+/// ░░░█░█░█
+/// ░░░░█░██
+/// ░░░░░█░█
+/// ░░░░░░░█
+/// ░░░█████
+/// ```
+///
+/// ## `ARROW_UNICODE[73]`: `U+21D9` `"⇙"`
+///
+/// ```text
+/// ░░░░░█░█
+/// ░░░░█░█░
+/// ░░░█░█░░
+/// █░█░█░░░
+/// ██░█░░░░
+/// █░█░░░░░
+/// █░░░░░░░
+/// █████░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[74]`: `U+21DA` `"⇚"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░██████
+/// ░█░░░░░░
+/// █░██████
+/// ░█░░░░░░
+/// ░░██████
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[75]`: `U+21DB` `"⇛"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ██████░░
+/// ░░░░░░█░
+/// ██████░█
+/// ░░░░░░█░
+/// ██████░░
+/// ░░░░█░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[76]`: `U+21DC` `"⇜"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░█░██░░░
+/// ███░░█░░
+/// ░█░░░░██
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[77]`: `U+21DD` `"⇝"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░██░█░
+/// ░░█░░███
+/// ██░░░░█░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[78]`: `U+21DE` `"⇞"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█░█░█░░
+/// ░░░█░░░░
+/// ░█████░░
+/// ░░░█░░░░
+/// ░█████░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[79]`: `U+21DF` `"⇟"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░█████░░
+/// ░░░█░░░░
+/// ░█████░░
+/// ░░░█░░░░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[80]`: `U+21E0` `"⇠"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// ██░██░██
+/// ░█░░░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[81]`: `U+21E1` `"⇡"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█░░░█░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[82]`: `U+21E2` `"⇢"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░░█░
+/// ██░██░██
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[83]`: `U+21E3` `"⇣"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[84]`: `U+21E4` `"⇤"`
+///
+/// ```text
+/// ░░░░░░░░
+/// █░░░░░░░
+/// █░░█░░░░
+/// █░█░░░░░
+/// ████████
+/// █░█░░░░░
+/// █░░█░░░░
+/// █░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[85]`: `U+21E5` `"⇥"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░█
+/// ░░░░█░░█
+/// ░░░░░█░█
+/// ████████
+/// ░░░░░█░█
+/// ░░░░█░░█
+/// ░░░░░░░█
+/// ```
+///
+/// ## `ARROW_UNICODE[86]`: `U+21E6` `"⇦"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░██░░░░
+/// ░█░█████
+/// █░░░░░░█
+/// ░█░█████
+/// ░░██░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[87]`: `U+21E7` `"⇧"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ███░███░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[88]`: `U+21E8` `"⇨"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░██░░
+/// █████░█░
+/// █░░░░░░█
+/// █████░█░
+/// ░░░░██░░
+/// ░░░░█░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[89]`: `U+21E9` `"⇩"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ███░███░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[90]`: `U+21EA` `"⇪"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ███░███░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[91]`: `U+21EB` `"⇫"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ███░███░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░██░██░░
+/// ░█████░░
+/// ```
+///
+/// ## `ARROW_UNICODE[92]`: `U+21EC` `"⇬"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ███░███░
+/// ░░█░█░░░
+/// ░██░██░░
+/// ░█░░░█░░
+/// ░█████░░
+/// ```
+///
+/// ## `ARROW_UNICODE[93]`: `U+21ED` `"⇭"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░█░█░░
+/// ██░█░██░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░██░██░░
+/// ░█████░░
+/// ```
+///
+/// ## `ARROW_UNICODE[94]`: `U+21EE` `"⇮"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░██░██░░
+/// █░█░█░█░
+/// ░█░░░█░░
+/// ███░███░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[95]`: `U+21EF` `"⇯"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░██░██░░
+/// █░█░█░█░
+/// ░█░░░█░░
+/// ███░███░
+/// ░██░██░░
+/// ░█████░░
+/// ```
+///
+/// ## `ARROW_UNICODE[96]`: `U+21F0` `"⇰"`
+///
+/// ```text
+/// ░░░░░░░░
+/// █░░░░█░░
+/// █░░░░██░
+/// ██████░█
+/// █░░░░░░█
+/// ██████░█
+/// █░░░░██░
+/// █░░░░█░░
+/// ```
+///
+/// ## `ARROW_UNICODE[97]`: `U+21F1` `"⇱"`
+///
+/// ```text
+/// ████████
+/// █░░░░░░░
+/// █░████░░
+/// █░██░░░░
+/// █░█░█░░░
+/// █░█░░█░░
+/// █░░░░░█░
+/// █░░░░░░█
+/// ```
+///
+/// ## `ARROW_UNICODE[98]`: `U+21F2` `"⇲"`
+///
+/// ```text
+/// █░░░░░░█
+/// ░█░░░░░█
+/// ░░█░░█░█
+/// ░░░█░█░█
+/// ░░░░██░█
+/// ░░████░█
+/// ░░░░░░░█
+/// ████████
+/// ```
+///
+/// ## `ARROW_UNICODE[99]`: `U+21F3` `"⇳"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ███░███░
+/// ███░███░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[100]`: `U+21F4` `"⇴"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░███░█░
+/// ████████
+/// ░░███░█░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[101]`: `U+21F5` `"⇵"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░█░░███░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ███░░█░░
+/// ░█░░░█░░
+/// ```
+///
+/// ## `ARROW_UNICODE[102]`: `U+21F6` `"⇶"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ████████
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ████████
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ████████
+/// ```
+///
+/// ## `ARROW_UNICODE[103]`: `U+21F7` `"⇷"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░█░░░
+/// ░█░░█░░░
+/// ████████
+/// ░█░░█░░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[104]`: `U+21F8` `"⇸"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░█░░
+/// ░░░█░░█░
+/// ████████
+/// ░░░█░░█░
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[105]`: `U+21F9` `"⇹"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░█░░
+/// ░█░█░░█░
+/// ████████
+/// ░█░█░░█░
+/// ░░██░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[106]`: `U+21FA` `"⇺"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░█░█░
+/// ░█░░█░█░
+/// ████████
+/// ░█░░█░█░
+/// ░░█░█░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[107]`: `U+21FB` `"⇻"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░█░█░░
+/// ░█░█░░█░
+/// ████████
+/// ░█░█░░█░
+/// ░█░█░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[108]`: `U+21FC` `"⇼"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░██░░
+/// ░██░█░█░
+/// ████████
+/// ░██░█░█░
+/// ░░█░██░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[109]`: `U+21FD` `"⇽"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░██░░░░░
+/// █░██████
+/// ░██░░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[110]`: `U+21FE` `"⇾"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░██░
+/// ██████░█
+/// ░░░░░██░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ARROW_UNICODE[111]`: `U+21FF` `"⇿"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░█░░
+/// ░██░░██░
+/// █░████░█
+/// ░██░░██░
+/// ░░█░░█░░
+/// ░░░░░░░░
+/// ```
+pub const ARROW_FONTS: ArrowFonts = ArrowFonts(ARROW_UNICODE);
+/// Strong-typed collection wrapper for [ARROW_UNICODE](./constant.ARROW_UNICODE.html).
+pub struct ArrowFonts([FontUnicode; 112]);
+
+impl ArrowFonts {
+    /// Create a new collection of `ARROW_UNICODE` fonts.
+    pub fn new() -> Self {
+        ArrowFonts(ARROW_UNICODE)
+    }
+}
+
+impl fmt::Debug for ArrowFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(ARROW_UNICODE))
+    }
+}
+
+impl PartialEq for ArrowFonts {
+    fn eq(&self, other: &ArrowFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for ArrowFonts {
+    fn default() -> Self {
+        ArrowFonts::new()
+    }
+}
+
+impl UnicodeFonts for ArrowFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(112), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use legacy::BOX_LEGACY;
+
+    #[test]
+    fn arrow_set_implements_default_trait_with_method_new() {
+        let arrow_set: ArrowFonts = Default::default();
+        assert_eq!(arrow_set, ArrowFonts::new());
+    }
+
+    #[test]
+    fn arrow_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(ARROW_FONTS, ArrowFonts::new());
+    }
+
+    #[test]
+    fn arrow_fonts_constant_wraps_arrow_unicode_constant() {
+        let arrow = ArrowFonts::new();
+        assert!(arrow.0.len() == ARROW_UNICODE.len());
+        for (idx, font) in arrow.0.iter().enumerate() {
+            assert_eq!(font, &ARROW_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn arrow_light_shafts_line_up_with_box_drawing_light_lines() {
+        let horizontal = BOX_LEGACY[0];
+        let vertical = BOX_LEGACY[2];
+        for key in "←→↔↚↛↞↠".chars() {
+            assert_eq!(ARROW_FONTS.get(key).unwrap()[4], horizontal[4]);
+        }
+        for key in "↑↓↕↟↡".chars() {
+            let arrow = ARROW_FONTS.get(key).unwrap();
+            for row in 0..8 {
+                assert_eq!(arrow[row] & vertical[row], vertical[row]);
+            }
+        }
+    }
+
+    #[test]
+    fn arrow_double_shafts_line_up_with_box_drawing_double_lines() {
+        let horizontal = BOX_LEGACY[0x50];
+        let vertical = BOX_LEGACY[0x51];
+        for key in "⇐⇒⇔".chars() {
+            let arrow = ARROW_FONTS.get(key).unwrap();
+            for row in &[3, 5] {
+                assert_eq!(arrow[*row] & 0x3C, horizontal[*row] & 0x3C);
+            }
+        }
+        for key in "⇑⇓⇕".chars() {
+            let arrow = ARROW_FONTS.get(key).unwrap();
+            for row in 3..5 {
+                assert_eq!(arrow[row], vertical[row]);
+            }
+        }
+    }
+}
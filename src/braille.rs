@@ -0,0 +1,372 @@
+//! Braille Patterns. `U+2800` - `U+28FF`
+//!
+//! The low byte of every braille codepoint is a bitmask of its eight dots, so the 256
+//! glyphs are not stored. [braille_glyph](./fn.braille_glyph.html) draws each raised
+//! dot as a 2x1 pixel run: dots `1 2 3 7` in columns 1 and 2, dots `4 5 6 8` in columns
+//! 5 and 6, on rows 0, 2, 4 and 6 from top to bottom.
+//!
+//! [BrailleCanvas](./struct.BrailleCanvas.html) uses the same layout to plot 2x4 dots
+//! per cell, for charts with four times the vertical resolution of the text grid.
+use super::{FontUnicode, UnicodeFonts};
+use core::{char, fmt};
+
+/// The blank braille pattern, `U+2800` `"⠀"`.
+const PATTERN_FIRST: u32 = 0x2800;
+/// The braille pattern with all eight dots raised, `U+28FF` `"⣿"`.
+const PATTERN_LAST: u32 = 0x28FF;
+
+/// Dot bits, indexed as `DOT_BITS[row][column]` within a cell.
+const DOT_BITS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Returns the 8x8 glyph for the braille pattern with the given dot bits, where bit
+/// `n` is dot `n + 1`.
+pub const fn braille_glyph(dots: u8) -> [u8; 8] {
+    let mut glyph = [0u8; 8];
+    let mut row = 0;
+    while row < 4 {
+        if dots & DOT_BITS[row][0] != 0 {
+            glyph[2 * row] |= 0x06;
+        }
+        if dots & DOT_BITS[row][1] != 0 {
+            glyph[2 * row] |= 0x60;
+        }
+        row += 1;
+    }
+    glyph
+}
+
+/// A convenient constant for Braille Patterns fonts (`U+2800` - `U+28FF`), that
+/// implements the `UnicodeFonts` trait.
+///
+/// ## `U+2800` `"⠀"`
+///
+/// All dots lowered, renders as a blank glyph.
+///
+/// ## `U+281D` `"⠝"`
+///
+/// ```text
+/// ░██░░██░
+/// ░░░░░░░░
+/// ░░░░░██░
+/// ░░░░░░░░
+/// ░██░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `U+28FF` `"⣿"`
+///
+/// ```text
+/// ░██░░██░
+/// ░░░░░░░░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ```
+pub const BRAILLE_FONTS: BrailleFonts = BrailleFonts;
+
+/// Strong-typed wrapper for the computed braille pattern fonts.
+#[derive(Clone, Copy, PartialEq)]
+pub struct BrailleFonts;
+
+impl BrailleFonts {
+    /// Create a new collection of braille pattern fonts.
+    pub fn new() -> Self {
+        BrailleFonts
+    }
+}
+
+impl fmt::Debug for BrailleFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(BRAILLE_FONTS))
+    }
+}
+
+impl Default for BrailleFonts {
+    fn default() -> Self {
+        BrailleFonts::new()
+    }
+}
+
+impl UnicodeFonts for BrailleFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        let code = key as u32;
+        if !(PATTERN_FIRST..=PATTERN_LAST).contains(&code) {
+            return None;
+        }
+        Some(braille_glyph((code - PATTERN_FIRST) as u8))
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        self.get(key).map(|glyph| FontUnicode(key, glyph))
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, (_, font)) in self.to_vec().iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        (PATTERN_FIRST..PATTERN_LAST + 1).filter_map(char::from_u32)
+                                         .filter_map(|c| self.get_font(c))
+                                         .fold(Vec::with_capacity(256), |mut v, font| {
+                                             v.push((font.char(), font));
+                                             v
+                                         })
+    }
+}
+
+/// A drawing surface of braille cells, each holding 2x4 dots.
+///
+/// The canvas borrows one byte of dot bits per cell, laid out row by row, so it needs
+/// no allocation. Dots are addressed by `(x, y)` from the top left corner, and dots
+/// outside the canvas are clipped.
+///
+/// ```
+/// use font8x8::unicode::BrailleCanvas;
+///
+/// let mut cells = [0u8; 2];
+/// let mut canvas = BrailleCanvas::new(&mut cells, 2);
+/// canvas.line(0, 3, 3, 0);
+/// assert_eq!(canvas.char_at(0, 0), Some('⡠'));
+/// assert_eq!(canvas.char_at(1, 0), Some('⠊'));
+/// assert_eq!(format!("{}", canvas), "⡠⠊\n");
+/// ```
+pub struct BrailleCanvas<'a> {
+    cells: &'a mut [u8],
+    columns: usize,
+}
+
+impl<'a> BrailleCanvas<'a> {
+    /// Creates a canvas over `cells`, `columns` cells wide. Any cells left over after
+    /// the last full row are ignored.
+    pub fn new(cells: &'a mut [u8], columns: usize) -> Self {
+        BrailleCanvas { cells, columns }
+    }
+
+    /// Number of cells across.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Number of cells down.
+    pub fn rows(&self) -> usize {
+        match self.columns {
+            0 => 0,
+            columns => self.cells.len() / columns,
+        }
+    }
+
+    /// Width of the canvas, in dots.
+    pub fn width(&self) -> usize {
+        2 * self.columns()
+    }
+
+    /// Height of the canvas, in dots.
+    pub fn height(&self) -> usize {
+        4 * self.rows()
+    }
+
+    /// Lowers every dot.
+    pub fn clear(&mut self) {
+        for cell in self.cells.iter_mut() {
+            *cell = 0;
+        }
+    }
+
+    /// Raises the dot at `(x, y)`.
+    pub fn set(&mut self, x: usize, y: usize) {
+        if let Some((idx, bit)) = self.locate(x, y) {
+            self.cells[idx] |= bit;
+        }
+    }
+
+    /// Lowers the dot at `(x, y)`.
+    pub fn unset(&mut self, x: usize, y: usize) {
+        if let Some((idx, bit)) = self.locate(x, y) {
+            self.cells[idx] &= !bit;
+        }
+    }
+
+    /// Returns whether the dot at `(x, y)` is raised.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        match self.locate(x, y) {
+            Some((idx, bit)) => self.cells[idx] & bit != 0,
+            None => false,
+        }
+    }
+
+    /// Raises every dot on the straight line from `(x0, y0)` to `(x1, y1)`.
+    pub fn line(&mut self, x0: usize, y0: usize, x1: usize, y1: usize) {
+        let (mut x, mut y) = (x0 as isize, y0 as isize);
+        let (x1, y1) = (x1 as isize, y1 as isize);
+        let dx = (x1 - x).abs();
+        let dy = -(y1 - y).abs();
+        let sx = if x < x1 { 1 } else { -1 };
+        let sy = if y < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        loop {
+            self.set(x as usize, y as usize);
+            if x == x1 && y == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// Returns the braille character for the cell at `(column, row)`.
+    pub fn char_at(&self, column: usize, row: usize) -> Option<char> {
+        self.dots_at(column, row)
+            .and_then(|dots| char::from_u32(PATTERN_FIRST + u32::from(dots)))
+    }
+
+    /// Returns the 8x8 glyph for the cell at `(column, row)`.
+    pub fn glyph_at(&self, column: usize, row: usize) -> Option<[u8; 8]> {
+        self.dots_at(column, row).map(braille_glyph)
+    }
+
+    fn dots_at(&self, column: usize, row: usize) -> Option<u8> {
+        if column >= self.columns() || row >= self.rows() {
+            return None;
+        }
+        Some(self.cells[row * self.columns + column])
+    }
+
+    fn locate(&self, x: usize, y: usize) -> Option<(usize, u8)> {
+        if x >= self.width() || y >= self.height() {
+            return None;
+        }
+        Some(((y / 4) * self.columns + x / 2, DOT_BITS[y % 4][x % 2]))
+    }
+}
+
+impl<'a> fmt::Display for BrailleCanvas<'a> {
+    /// Writes the canvas as braille characters, one line per row of cells.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..self.rows() {
+            for column in 0..self.columns() {
+                if let Some(c) = self.char_at(column, row) {
+                    write!(f, "{}", c)?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn braille_set_implements_default_trait_with_method_new() {
+        let braille_set: BrailleFonts = Default::default();
+        assert_eq!(braille_set, BrailleFonts::new());
+    }
+
+    #[test]
+    fn braille_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(BRAILLE_FONTS, BrailleFonts::new());
+    }
+
+    #[test]
+    fn braille_fonts_cover_every_pattern() {
+        for code in PATTERN_FIRST..PATTERN_LAST + 1 {
+            let key = char::from_u32(code).unwrap();
+            assert!(BRAILLE_FONTS.get(key).is_some());
+        }
+        assert_eq!(BRAILLE_FONTS.get('\u{27FF}'), None);
+        assert_eq!(BRAILLE_FONTS.get('\u{2900}'), None);
+        assert_eq!(BRAILLE_FONTS.get('A'), None);
+    }
+
+    #[test]
+    fn braille_glyph_is_available_in_const_context() {
+        const BLANK: [u8; 8] = braille_glyph(0x00);
+        const FULL: [u8; 8] = braille_glyph(0xFF);
+        assert_eq!(BRAILLE_FONTS.get('⠀'), Some(BLANK));
+        assert_eq!(BRAILLE_FONTS.get('⣿'), Some(FULL));
+        assert_eq!(FULL, [0x66, 0x00, 0x66, 0x00, 0x66, 0x00, 0x66, 0x00]);
+    }
+
+    #[test]
+    fn braille_glyph_draws_one_run_per_dot() {
+        for bit in 0..8 {
+            let glyph = braille_glyph(1 << bit);
+            let pixels: u32 = glyph.iter().map(|row| row.count_ones()).sum();
+            assert_eq!(pixels, 2);
+        }
+        // dot 7 sits below dot 3, and dot 8 below dot 6
+        assert_eq!(braille_glyph(0x40)[6], 0x06);
+        assert_eq!(braille_glyph(0x80)[6], 0x60);
+    }
+
+    #[test]
+    fn braille_canvas_plots_dots_into_cells() {
+        let mut cells = [0u8; 6];
+        let mut canvas = BrailleCanvas::new(&mut cells, 3);
+        assert_eq!((canvas.width(), canvas.height()), (6, 8));
+        canvas.set(0, 0);
+        canvas.set(3, 7);
+        canvas.set(6, 0);
+        canvas.set(0, 8);
+        assert!(canvas.get(0, 0));
+        assert!(!canvas.get(1, 0));
+        assert_eq!(canvas.char_at(0, 0), Some('⠁'));
+        assert_eq!(canvas.char_at(1, 1), Some('⢀'));
+        assert_eq!(canvas.glyph_at(1, 1), Some(braille_glyph(0x80)));
+        assert_eq!(canvas.char_at(3, 0), None);
+        canvas.unset(0, 0);
+        assert_eq!(canvas.char_at(0, 0), Some('⠀'));
+        canvas.clear();
+        assert_eq!(canvas.char_at(1, 1), Some('⠀'));
+    }
+
+    #[test]
+    fn braille_canvas_draws_lines_across_cells() {
+        let mut cells = [0u8; 2];
+        let mut canvas = BrailleCanvas::new(&mut cells, 1);
+        canvas.line(0, 0, 0, 7);
+        assert_eq!(canvas.char_at(0, 0), Some('⡇'));
+        assert_eq!(canvas.char_at(0, 1), Some('⡇'));
+    }
+}
@@ -0,0 +1,1963 @@
+//! Combining Diacritical Marks. `U+0300` - `U+036F`
+//!
+//! Marks are drawn where they go over a lowercase letter: marks above in rows 0 and 1,
+//! marks below in rows 6 and 7, and the strokes, slashes and horn over the letter
+//! itself. [mark_anchor](./fn.mark_anchor.html) tells which, and
+//! [CombiningFonts::overlay](./struct.CombiningFonts.html#method.overlay) draws
+//! them onto any glyph, making room for them above capitals and ascenders.
+use super::{legacy::BASIC_LEGACY, unicode::{FontUnicode, UnicodeFonts}};
+use core::fmt;
+
+/// A constant `[FontUnicode; 112]`, for Combining Diacritical Marks fonts (`U+0300` - `U+036F`).
+pub const COMBINING_UNICODE: [FontUnicode; 112] = [FontUnicode('\u{0300}', [0x06, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0301}', [0x30, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0302}', [0x18, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0303}', [0x6E, 0x3B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0304}', [0x00, 0x3E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0305}', [0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0306}', [0x22, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0307}', [0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0308}', [0x00, 0x36, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0309}', [0x1C, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{030A}', [0x1C, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{030B}', [0x24, 0x12, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{030C}', [0x24, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{030D}', [0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{030E}', [0x14, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{030F}', [0x12, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0310}', [0x2A, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0311}', [0x1C, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0312}', [0x08, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0313}', [0x18, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0314}', [0x18, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0315}', [0xC0, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0316}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x18]),
+                                                   FontUnicode('\u{0317}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x0C]),
+                                                   FontUnicode('\u{0318}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x1E]),
+                                                   FontUnicode('\u{0319}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x78]),
+                                                   FontUnicode('\u{031A}', [0x38, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{031B}', [0x00, 0x40, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{031C}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x18]),
+                                                   FontUnicode('\u{031D}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x3E]),
+                                                   FontUnicode('\u{031E}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3E, 0x08]),
+                                                   FontUnicode('\u{031F}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x1C]),
+                                                   FontUnicode('\u{0320}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C]),
+                                                   FontUnicode('\u{0321}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x60]),
+                                                   FontUnicode('\u{0322}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x03]),
+                                                   FontUnicode('\u{0323}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18]),
+                                                   FontUnicode('\u{0324}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x36]),
+                                                   FontUnicode('\u{0325}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x14]),
+                                                   FontUnicode('\u{0326}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x04]),
+                                                   FontUnicode('\u{0327}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x0C]),
+                                                   FontUnicode('\u{0328}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x30]),
+                                                   FontUnicode('\u{0329}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08]),
+                                                   FontUnicode('\u{032A}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3E, 0x22]),
+                                                   FontUnicode('\u{032B}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2A, 0x36]),
+                                                   FontUnicode('\u{032C}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x18]),
+                                                   FontUnicode('\u{032D}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x24]),
+                                                   FontUnicode('\u{032E}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x1C]),
+                                                   FontUnicode('\u{032F}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x22]),
+                                                   FontUnicode('\u{0330}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6E, 0x3B]),
+                                                   FontUnicode('\u{0331}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3E]),
+                                                   FontUnicode('\u{0332}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF]),
+                                                   FontUnicode('\u{0333}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF]),
+                                                   FontUnicode('\u{0334}', [0x00, 0x00, 0x00, 0x16, 0x09, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0335}', [0x00, 0x00, 0x00, 0x00, 0x1E, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0336}', [0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0337}', [0x00, 0x00, 0x00, 0x10, 0x08, 0x04, 0x00, 0x00]),
+                                                   FontUnicode('\u{0338}', [0x00, 0x20, 0x20, 0x10, 0x08, 0x04, 0x02, 0x00]),
+                                                   FontUnicode('\u{0339}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x0C]),
+                                                   FontUnicode('\u{033A}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x3E]),
+                                                   FontUnicode('\u{033B}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x1C]),
+                                                   FontUnicode('\u{033C}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x14]),
+                                                   FontUnicode('\u{033D}', [0x14, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{033E}', [0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{033F}', [0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0340}', [0x06, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0341}', [0x30, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0342}', [0x14, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0343}', [0x18, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0344}', [0x10, 0x2A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0345}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08]),
+                                                   FontUnicode('\u{0346}', [0x1C, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0347}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3E, 0x3E]),
+                                                   FontUnicode('\u{0348}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x14]),
+                                                   FontUnicode('\u{0349}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x1C]),
+                                                   FontUnicode('\u{034A}', [0x2C, 0x1A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{034B}', [0x04, 0x2A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{034C}', [0x0A, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{034D}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x7F]),
+                                                   FontUnicode('\u{034E}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x08]),
+                                                   FontUnicode('\u{034F}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0350}', [0x04, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0351}', [0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0352}', [0x1C, 0x2A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0353}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x08]),
+                                                   FontUnicode('\u{0354}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x04]),
+                                                   FontUnicode('\u{0355}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x08]),
+                                                   FontUnicode('\u{0356}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x0A]),
+                                                   FontUnicode('\u{0357}', [0x04, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0358}', [0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0359}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x08]),
+                                                   FontUnicode('\u{035A}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x36, 0x36]),
+                                                   FontUnicode('\u{035B}', [0x14, 0x2A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{035C}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x81, 0x7E]),
+                                                   FontUnicode('\u{035D}', [0x81, 0x7E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{035E}', [0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{035F}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF]),
+                                                   FontUnicode('\u{0360}', [0x86, 0x79, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0361}', [0x7E, 0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0362}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x7F]),
+                                                   FontUnicode('\u{0363}', [0x04, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0364}', [0x07, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0365}', [0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0366}', [0x02, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0367}', [0x05, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0368}', [0x03, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{0369}', [0x04, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{036A}', [0x01, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{036B}', [0x03, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{036C}', [0x03, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{036D}', [0x07, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{036E}', [0x05, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                   FontUnicode('\u{036F}', [0x05, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00])];
+
+/// A convenient constant for Combining Diacritical Marks fonts (`U+0300` - `U+036F`), that implements the `UnicodeFonts` trait.
+///
+/// ## `COMBINING_UNICODE[0]`: `U+0300` `"\u{300}"`
+///
+/// ```text
+/// ░██░░░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[1]`: `U+0301` `"\u{301}"`
+///
+/// ```text
+/// ░░░░██░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[2]`: `U+0302` `"\u{302}"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[3]`: `U+0303` `"\u{303}"`
+///
+/// ```text
+/// ░███░██░
+/// ██░███░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[4]`: `U+0304` `"\u{304}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[5]`: `U+0305` `"\u{305}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[6]`: `U+0306` `"\u{306}"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[7]`: `U+0307` `"\u{307}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[8]`: `U+0308` `"\u{308}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[9]`: `U+0309` `"\u{309}"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[10]`: `U+030A` `"\u{30a}"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[11]`: `U+030B` `"\u{30b}"`
+///
+/// ```text
+/// ░░█░░█░░
+/// ░█░░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[12]`: `U+030C` `"\u{30c}"`
+///
+/// ```text
+/// ░░█░░█░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[13]`: `U+030D` `"\u{30d}"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[14]`: `U+030E` `"\u{30e}"`
+///
+/// ```text
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[15]`: `U+030F` `"\u{30f}"`
+///
+/// ```text
+/// ░█░░█░░░
+/// ░░█░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[16]`: `U+0310` `"\u{310}"`
+///
+/// ```text
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[17]`: `U+0311` `"\u{311}"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[18]`: `U+0312` `"\u{312}"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[19]`: `U+0313` `"\u{313}"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[20]`: `U+0314` `"\u{314}"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[21]`: `U+0315` `"\u{315}"`
+///
+/// ```text
+/// ░░░░░░██
+/// ░░░░░░░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[22]`: `U+0316` `"\u{316}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██░░░░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[23]`: `U+0317` `"\u{317}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░██░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[24]`: `U+0318` `"\u{318}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░████░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[25]`: `U+0319` `"\u{319}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░░████░
+/// ```
+///
+/// ## `COMBINING_UNICODE[26]`: `U+031A` `"\u{31a}"`
+///
+/// ```text
+/// ░░░███░░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[27]`: `U+031B` `"\u{31b}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[28]`: `U+031C` `"\u{31c}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[29]`: `U+031D` `"\u{31d}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░█████░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[30]`: `U+031E` `"\u{31e}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█████░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[31]`: `U+031F` `"\u{31f}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[32]`: `U+0320` `"\u{320}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[33]`: `U+0321` `"\u{321}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░█░
+/// ░░░░░██░
+/// ```
+///
+/// ## `COMBINING_UNICODE[34]`: `U+0322` `"\u{322}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░░░░░░░
+/// ██░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[35]`: `U+0323` `"\u{323}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[36]`: `U+0324` `"\u{324}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██░██░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[37]`: `U+0325` `"\u{325}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░█░█░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[38]`: `U+0326` `"\u{326}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[39]`: `U+0327` `"\u{327}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[40]`: `U+0328` `"\u{328}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░░░██░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[41]`: `U+0329` `"\u{329}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[42]`: `U+032A` `"\u{32a}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█████░░
+/// ░█░░░█░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[43]`: `U+032B` `"\u{32b}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░█░█░░
+/// ░██░██░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[44]`: `U+032C` `"\u{32c}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░█░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[45]`: `U+032D` `"\u{32d}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[46]`: `U+032E` `"\u{32e}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[47]`: `U+032F` `"\u{32f}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░█░░░█░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[48]`: `U+0330` `"\u{330}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░███░██░
+/// ██░███░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[49]`: `U+0331` `"\u{331}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█████░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[50]`: `U+0332` `"\u{332}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ```
+///
+/// ## `COMBINING_UNICODE[51]`: `U+0333` `"\u{333}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ████████
+/// ```
+///
+/// ## `COMBINING_UNICODE[52]`: `U+0334` `"\u{334}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██░█░░░
+/// █░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[53]`: `U+0335` `"\u{335}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[54]`: `U+0336` `"\u{336}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[55]`: `U+0337` `"\u{337}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[56]`: `U+0338` `"\u{338}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[57]`: `U+0339` `"\u{339}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[58]`: `U+033A` `"\u{33a}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░░░█░░
+/// ░█████░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[59]`: `U+033B` `"\u{33b}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[60]`: `U+033C` `"\u{33c}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[61]`: `U+033D` `"\u{33d}"`
+///
+/// ```text
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[62]`: `U+033E` `"\u{33e}"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[63]`: `U+033F` `"\u{33f}"`
+///
+/// ```text
+/// ████████
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[64]`: `U+0340` `"\u{340}"`
+///
+/// ```text
+/// ░██░░░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[65]`: `U+0341` `"\u{341}"`
+///
+/// ```text
+/// ░░░░██░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[66]`: `U+0342` `"\u{342}"`
+///
+/// ```text
+/// ░░█░█░░░
+/// ░█░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[67]`: `U+0343` `"\u{343}"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[68]`: `U+0344` `"\u{344}"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░█░█░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[69]`: `U+0345` `"\u{345}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[70]`: `U+0346` `"\u{346}"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[71]`: `U+0347` `"\u{347}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█████░░
+/// ░█████░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[72]`: `U+0348` `"\u{348}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[73]`: `U+0349` `"\u{349}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[74]`: `U+034A` `"\u{34a}"`
+///
+/// ```text
+/// ░░██░█░░
+/// ░█░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[75]`: `U+034B` `"\u{34b}"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░█░█░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[76]`: `U+034C` `"\u{34c}"`
+///
+/// ```text
+/// ░█░█░░░░
+/// █░█░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[77]`: `U+034D` `"\u{34d}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░░░█░░
+/// ███████░
+/// ```
+///
+/// ## `COMBINING_UNICODE[78]`: `U+034E` `"\u{34e}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[79]`: `U+034F` " "
+/// ## `COMBINING_UNICODE[80]`: `U+0350` `"\u{350}"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[81]`: `U+0351` `"\u{351}"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[82]`: `U+0352` `"\u{352}"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░█░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[83]`: `U+0353` `"\u{353}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[84]`: `U+0354` `"\u{354}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[85]`: `U+0355` `"\u{355}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[86]`: `U+0356` `"\u{356}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░█░░░
+/// ░█░█░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[87]`: `U+0357` `"\u{357}"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[88]`: `U+0358` `"\u{358}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[89]`: `U+0359` `"\u{359}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[90]`: `U+035A` `"\u{35a}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██░██░░
+/// ░██░██░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[91]`: `U+035B` `"\u{35b}"`
+///
+/// ```text
+/// ░░█░█░░░
+/// ░█░█░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[92]`: `U+035C` `"\u{35c}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░░░░░░█
+/// ░██████░
+/// ```
+///
+/// ## `COMBINING_UNICODE[93]`: `U+035D` `"\u{35d}"`
+///
+/// ```text
+/// █░░░░░░█
+/// ░██████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[94]`: `U+035E` `"\u{35e}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[95]`: `U+035F` `"\u{35f}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ```
+///
+/// ## `COMBINING_UNICODE[96]`: `U+0360` `"\u{360}"`
+///
+/// ```text
+/// ░██░░░░█
+/// █░░████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[97]`: `U+0361` `"\u{361}"`
+///
+/// ```text
+/// ░██████░
+/// █░░░░░░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[98]`: `U+0362` `"\u{362}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░█░
+/// ███████░
+/// ```
+///
+/// ## `COMBINING_UNICODE[99]`: `U+0363` `"\u{363}"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░██░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[100]`: `U+0364` `"\u{364}"`
+///
+/// ```text
+/// ███░░░░░
+/// ██░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[101]`: `U+0365` `"\u{365}"`
+///
+/// ```text
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[102]`: `U+0366` `"\u{366}"`
+///
+/// ```text
+/// ░█░░░░░░
+/// █░█░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[103]`: `U+0367` `"\u{367}"`
+///
+/// ```text
+/// █░█░░░░░
+/// ░██░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[104]`: `U+0368` `"\u{368}"`
+///
+/// ```text
+/// ██░░░░░░
+/// ██░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[105]`: `U+0369` `"\u{369}"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ███░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[106]`: `U+036A` `"\u{36a}"`
+///
+/// ```text
+/// █░░░░░░░
+/// ███░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[107]`: `U+036B` `"\u{36b}"`
+///
+/// ```text
+/// ██░░░░░░
+/// ███░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[108]`: `U+036C` `"\u{36c}"`
+///
+/// ```text
+/// ██░░░░░░
+/// █░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[109]`: `U+036D` `"\u{36d}"`
+///
+/// ```text
+/// ███░░░░░
+/// ░█░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[110]`: `U+036E` `"\u{36e}"`
+///
+/// ```text
+/// █░█░░░░░
+/// ░█░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `COMBINING_UNICODE[111]`: `U+036F` `"\u{36f}"`
+///
+/// ```text
+/// █░█░░░░░
+/// █░█░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+pub const COMBINING_FONTS: CombiningFonts = CombiningFonts(COMBINING_UNICODE);
+/// Strong-typed collection wrapper for [COMBINING_UNICODE](./constant.COMBINING_UNICODE.html).
+pub struct CombiningFonts([FontUnicode; 112]);
+
+impl CombiningFonts {
+    /// Create a new collection of `COMBINING_UNICODE` fonts.
+    pub fn new() -> Self {
+        CombiningFonts(COMBINING_UNICODE)
+    }
+}
+
+impl fmt::Debug for CombiningFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(COMBINING_UNICODE))
+    }
+}
+
+impl PartialEq for CombiningFonts {
+    fn eq(&self, other: &CombiningFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for CombiningFonts {
+    fn default() -> Self {
+        CombiningFonts::new()
+    }
+}
+
+impl UnicodeFonts for CombiningFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(112), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+/// Where a combining mark is drawn, relative to its base character.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MarkAnchor {
+    /// Stacked above the base, which is moved down or compressed to make room.
+    Above,
+    /// Stacked below the base, which is moved up or compressed to make room.
+    Below,
+    /// Drawn over the base, as it is: the strokes, slashes and the horn.
+    Through,
+}
+
+/// Returns where the combining mark `c` is drawn, or `None` if `c` is not in the
+/// Combining Diacritical Marks block.
+pub fn mark_anchor(c: char) -> Option<MarkAnchor> {
+    match c {
+        '\u{031B}' | '\u{0334}'..='\u{0338}' | '\u{034F}' => Some(MarkAnchor::Through),
+        '\u{0316}'..='\u{0319}'
+        | '\u{031C}'..='\u{0333}'
+        | '\u{0339}'..='\u{033C}'
+        | '\u{0345}'
+        | '\u{0347}'..='\u{0349}'
+        | '\u{034D}'
+        | '\u{034E}'
+        | '\u{0353}'..='\u{0356}'
+        | '\u{0359}'
+        | '\u{035A}'
+        | '\u{035C}'
+        | '\u{035F}'
+        | '\u{0362}' => Some(MarkAnchor::Below),
+        '\u{0300}'..='\u{036F}' => Some(MarkAnchor::Above),
+        _ => None,
+    }
+}
+
+/// Returns `true` for the combining diacritical marks, which are drawn onto the
+/// character before them instead of taking a cell of their own.
+pub fn is_combining_mark(c: char) -> bool {
+    ('\u{0300}'..='\u{036F}').contains(&c)
+}
+
+/// Smallest number of rows a base is compressed to, however many marks it carries.
+const MIN_HEIGHT: usize = 3;
+
+/// Returns the first and last rows of `glyph` with ink in them.
+pub(crate) fn ink_rows(glyph: &[u8; 8]) -> Option<(usize, usize)> {
+    let top = glyph.iter().position(|&row| row != 0)?;
+    let bottom = glyph.iter().rposition(|&row| row != 0)?;
+    Some((top, bottom))
+}
+
+/// Returns the first and last columns of `glyph` with ink in them.
+pub(crate) fn ink_columns(glyph: &[u8; 8]) -> Option<(isize, isize)> {
+    let ink = glyph.iter().fold(0, |ink, &row| ink | row);
+    match ink {
+        0 => None,
+        _ => Some((ink.trailing_zeros() as isize, 7 - ink.leading_zeros() as isize)),
+    }
+}
+
+/// Returns `glyph` moved down by `rows` and right by `columns`, either of which can be
+/// negative. Whatever is moved out of the cell is lost.
+pub(crate) fn moved(glyph: &[u8; 8], rows: isize, columns: isize) -> [u8; 8] {
+    let mut out = [0; 8];
+    for (idx, row) in out.iter_mut().enumerate() {
+        let src = idx as isize - rows;
+        if (0..8).contains(&src) {
+            let ink = glyph[src as usize];
+            *row = if columns < 0 { ink >> -columns } else { ink << columns };
+        }
+    }
+    out
+}
+
+/// Removes one of the first `len` rows of `rows`, and returns the new length. A row that
+/// repeats the one above it goes first, then a row that adds nothing to one of its
+/// neighbours; otherwise, the two neighbours with the most ink in common are merged.
+fn squeeze(rows: &mut [u8; 8], len: usize) -> usize {
+    let contained = |idx: usize| {
+        (idx > 0 && rows[idx] & !rows[idx - 1] == 0) || (idx + 1 < len && rows[idx] & !rows[idx + 1] == 0)
+    };
+    let idx = match (1..len).find(|&idx| rows[idx] == rows[idx - 1])
+                            .or_else(|| (0..len).find(|&idx| contained(idx)))
+    {
+        Some(idx) => idx,
+        None => {
+            let idx = (1..len).max_by_key(|&idx| (rows[idx] & rows[idx - 1]).count_ones())
+                              .unwrap_or(0);
+            rows[idx - 1] |= rows[idx];
+            idx
+        }
+    };
+    rows.copy_within(idx + 1..len, idx);
+    len - 1
+}
+
+/// Moves the ink of `glyph` up, or compresses it, to leave `above` empty rows above it and
+/// `below` empty rows below it, and returns its new first and last rows. A blank glyph
+/// is taken to span the lowercase letters, from row 2 to row 6.
+fn make_room(glyph: &mut [u8; 8], above: usize, below: usize) -> (usize, usize) {
+    let (top, bottom) = match ink_rows(glyph) {
+        Some(rows) => rows,
+        None => return (2, 6),
+    };
+    let mut rows = [0; 8];
+    let mut len = bottom - top + 1;
+    rows[..len].copy_from_slice(&glyph[top..=bottom]);
+    let last = bottom.min(7usize.saturating_sub(below));
+    let height = (last + 1).saturating_sub(above).max(MIN_HEIGHT);
+    while len > height {
+        len = squeeze(&mut rows, len);
+    }
+    let last = last.max(len - 1);
+    *glyph = [0; 8];
+    glyph[last + 1 - len..=last].copy_from_slice(&rows[..len]);
+    (last + 1 - len, last)
+}
+
+impl CombiningFonts {
+    /// Returns `base` with the combining `marks` drawn onto it: marks above and below are
+    /// stacked outwards in order, centred over the base, which is moved or compressed to
+    /// make room for them. The dot of `i` and `j` is left out under marks above. Any
+    /// character of `marks` that is not a combining mark is ignored.
+    ///
+    /// ```
+    /// use font8x8::unicode::CombiningFonts;
+    /// use font8x8::{UnicodeFonts, BASIC_FONTS, COMBINING_FONTS};
+    ///
+    /// let a = BASIC_FONTS.get('a').unwrap();
+    /// let acute = COMBINING_FONTS.get('\u{0301}').unwrap();
+    /// let glyph = COMBINING_FONTS.overlay(a, "\u{0301}");
+    /// assert_eq!(glyph[..2], acute[..2]);
+    /// assert_eq!(glyph[2..], a[2..]);
+    /// ```
+    pub fn overlay(&self, base: [u8; 8], marks: &str) -> [u8; 8] {
+        let (above, below) =
+            marks.chars()
+                 .filter_map(|mark| Some((mark_anchor(mark)?, ink_rows(&self.get(mark)?)?)))
+                 .fold((0, 0), |(above, below), (anchor, (top, bottom))| match anchor {
+                     MarkAnchor::Above => (above + bottom - top + 1, below),
+                     MarkAnchor::Below => (above, below + bottom - top + 1),
+                     MarkAnchor::Through => (above, below),
+                 });
+        let mut glyph = base;
+        if above > 0 && (base == BASIC_LEGACY['i' as usize] || base == BASIC_LEGACY['j' as usize]) {
+            glyph[0] = 0;
+        }
+        let (top, bottom) = make_room(&mut glyph, above, below);
+        let (mut top, mut bottom) = (top as isize, bottom as isize);
+        let columns = ink_columns(&glyph);
+        for mark in marks.chars() {
+            let (anchor, mark) = match (mark_anchor(mark), self.get(mark)) {
+                (Some(anchor), Some(mark)) => (anchor, mark),
+                _ => continue,
+            };
+            let (first, last) = match ink_rows(&mark) {
+                Some((first, last)) => (first as isize, last as isize),
+                None => continue,
+            };
+            let shift = match (columns, ink_columns(&mark)) {
+                (Some((left, right)), Some((mark_left, mark_right))) => {
+                    (left + right - mark_left - mark_right) / 2
+                }
+                _ => 0,
+            };
+            let mark = match anchor {
+                MarkAnchor::Above => {
+                    top -= last - first + 1;
+                    moved(&mark, top - first, shift)
+                }
+                MarkAnchor::Below => {
+                    bottom += last - first + 1;
+                    moved(&mark, bottom - last, shift)
+                }
+                MarkAnchor::Through => mark,
+            };
+            for (row, ink) in glyph.iter_mut().zip(mark.iter()) {
+                *row |= ink;
+            }
+        }
+        glyph
+    }
+}
+
+/// Iterator over the grapheme clusters of a string, returned by
+/// [graphemes](./fn.graphemes.html).
+#[derive(Clone, Debug)]
+pub struct Graphemes<'a> {
+    text: &'a str,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let base = self.text.chars().next()?;
+        let end = base.len_utf8() + marks_len(&self.text[base.len_utf8()..]);
+        let (grapheme, rest) = self.text.split_at(end);
+        self.text = rest;
+        Some(grapheme)
+    }
+}
+
+/// Returns the length in bytes of the combining marks at the start of `text`.
+pub(crate) fn marks_len(text: &str) -> usize {
+    text.char_indices()
+        .find(|&(_, c)| !is_combining_mark(c))
+        .map(|(idx, _)| idx)
+        .unwrap_or_else(|| text.len())
+}
+
+/// Splits `text` into grapheme clusters that each fit in a single cell: a base character
+/// followed by any combining diacritical marks, to be drawn with
+/// [CombiningFonts::overlay](./struct.CombiningFonts.html#method.overlay).
+///
+/// ```
+/// use font8x8::unicode::graphemes;
+///
+/// let clusters: Vec<&str> = graphemes("e\u{0301}t\u{0323}\u{0302}e").collect();
+/// assert_eq!(clusters, ["e\u{0301}", "t\u{0323}\u{0302}", "e"]);
+/// ```
+pub fn graphemes<'a>(text: &'a str) -> Graphemes<'a> {
+    Graphemes { text }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combining_set_implements_default_trait_with_method_new() {
+        let combining_set: CombiningFonts = Default::default();
+        assert_eq!(combining_set, CombiningFonts::new());
+    }
+
+    #[test]
+    fn combining_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(COMBINING_FONTS, CombiningFonts::new());
+    }
+
+    #[test]
+    fn combining_fonts_constant_wraps_combining_unicode_constant() {
+        let combining = CombiningFonts::new();
+        assert!(combining.0.len() == COMBINING_UNICODE.len());
+        for (idx, font) in combining.0.iter().enumerate() {
+            assert_eq!(font, &COMBINING_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn combining_marks_are_drawn_in_the_rows_of_their_anchor() {
+        for font in COMBINING_UNICODE.iter() {
+            let glyph = font.byte_array();
+            match mark_anchor(font.char()) {
+                Some(MarkAnchor::Above) => assert_eq!(glyph[2..], [0; 6], "{:?}", font),
+                Some(MarkAnchor::Below) => assert_eq!(glyph[..6], [0; 6], "{:?}", font),
+                Some(MarkAnchor::Through) => {}
+                None => panic!("{:?}", font),
+            }
+        }
+        assert_eq!(mark_anchor('\u{0301}'), Some(MarkAnchor::Above));
+        assert_eq!(mark_anchor('\u{0327}'), Some(MarkAnchor::Below));
+        assert_eq!(mark_anchor('\u{0338}'), Some(MarkAnchor::Through));
+        assert_eq!(mark_anchor('a'), None);
+    }
+
+    #[test]
+    fn combining_fonts_overlay_compresses_uppercase_letters() {
+        for letter in b'A'..=b'Z' {
+            let glyph = COMBINING_FONTS.overlay(BASIC_LEGACY[letter as usize], "\u{0301}");
+            assert_ne!(glyph[0] | glyph[1], 0, "{}", letter as char);
+            assert!(glyph[2..7].iter().all(|&row| row != 0), "{}", letter as char);
+            assert_eq!(glyph[7], 0, "{}", letter as char);
+        }
+    }
+
+    #[test]
+    fn combining_fonts_overlay_moves_letters_up_for_marks_below() {
+        let c = BASIC_LEGACY['c' as usize];
+        let glyph = COMBINING_FONTS.overlay(c, "\u{0327}");
+        assert_eq!(glyph[1..6], c[2..7]);
+        assert_ne!(glyph[6] | glyph[7], 0);
+        let glyph = COMBINING_FONTS.overlay(c, "\u{0323}");
+        assert_eq!(glyph[..7], c[..7]);
+        assert_ne!(glyph[7], 0);
+    }
+
+    #[test]
+    fn combining_fonts_overlay_stacks_marks_in_order() {
+        let a = BASIC_LEGACY['a' as usize];
+        let glyph = COMBINING_FONTS.overlay(a, "\u{0302}\u{0301}\u{0323}");
+        let once = COMBINING_FONTS.overlay(a, "\u{0302}");
+        assert_ne!(glyph, once);
+        assert_eq!(COMBINING_FONTS.overlay(a, ""), a);
+        assert_eq!(COMBINING_FONTS.overlay(a, "xyz"), a);
+    }
+
+    #[test]
+    fn combining_fonts_overlay_drops_the_dot_of_i() {
+        let i = BASIC_LEGACY['i' as usize];
+        let glyph = COMBINING_FONTS.overlay(i, "\u{0304}");
+        assert_eq!(glyph[0], 0);
+        assert_eq!(glyph[2..], i[2..]);
+        assert_eq!(COMBINING_FONTS.overlay(i, "\u{0331}")[0], i[0]);
+    }
+
+    #[test]
+    fn graphemes_group_marks_with_their_base() {
+        let clusters: Vec<&str> = graphemes("\u{0301}a\u{0308}\u{0304}bc").collect();
+        assert_eq!(clusters, ["\u{0301}", "a\u{0308}\u{0304}", "b", "c"]);
+    }
+}
@@ -0,0 +1,428 @@
+//! Symbols for Legacy Computing. `U+1FB00` - `U+1FBFF`
+//!
+//! The sextants and the diagonal wedges are regular, so they are computed rather than
+//! stored. Sextants split the cell into 2x3 blocks of 4 columns by 3, 2 and 3 rows,
+//! and the wedges cut the cell along lines through the corners of those blocks, so
+//! both tile with each other and with the `BLOCK_FONTS` half blocks. The remaining
+//! eighth blocks, shades, triangles and light diagonals are stored in
+//! [COMPUTING_UNICODE](./constant.COMPUTING_UNICODE.html).
+//!
+//! The separated sextant mosaics of teletext are encoded in the Legacy Computing
+//! Supplement, `U+1CE51` - `U+1CE8F`, and are computed here too: each block loses its
+//! left column and bottom row.
+use super::{FontUnicode, UnicodeFonts};
+use core::{char, fmt};
+
+/// The first sextant, `U+1FB00` `"🬀"`.
+const SEXTANT_FIRST: u32 = 0x1FB00;
+/// The last sextant, `U+1FB3B` `"🬻"`.
+const SEXTANT_LAST: u32 = 0x1FB3B;
+/// The first lower wedge, `U+1FB3C` `"🬼"`. The upper wedges follow, in the same order.
+const WEDGE_FIRST: u32 = 0x1FB3C;
+/// The last upper wedge, `U+1FB67` `"🭧"`.
+const WEDGE_LAST: u32 = 0x1FB67;
+/// The first separated sextant, `U+1CE51`.
+const SEPARATED_FIRST: u32 = 0x1CE51;
+/// The last separated sextant, `U+1CE8F`.
+const SEPARATED_LAST: u32 = 0x1CE8F;
+
+/// First and last row, plus one, of the top, middle and bottom sextant blocks.
+const SEXTANT_ROWS: [(usize, usize); 3] = [(0, 3), (3, 5), (5, 8)];
+
+/// A wedge corner, in half-pixels from the top left corner of the cell.
+type Point = (i32, i32);
+
+const UPPER_LEFT: Point = (0, 0);
+const UPPER_CENTRE: Point = (8, 0);
+const UPPER_RIGHT: Point = (16, 0);
+const UPPER_MIDDLE_LEFT: Point = (0, 6);
+const UPPER_MIDDLE_RIGHT: Point = (16, 6);
+const LOWER_MIDDLE_LEFT: Point = (0, 10);
+const LOWER_MIDDLE_RIGHT: Point = (16, 10);
+const LOWER_LEFT: Point = (0, 16);
+const LOWER_CENTRE: Point = (8, 16);
+const LOWER_RIGHT: Point = (16, 16);
+
+/// The lower wedges `U+1FB3C` - `U+1FB51`, as the diagonal that cuts the cell and the
+/// corner of the filled side.
+const WEDGES: [(Point, Point, Point); 22] = [(LOWER_MIDDLE_LEFT, LOWER_CENTRE, LOWER_LEFT),
+                                             (LOWER_MIDDLE_LEFT, LOWER_RIGHT, LOWER_LEFT),
+                                             (UPPER_MIDDLE_LEFT, LOWER_CENTRE, LOWER_LEFT),
+                                             (UPPER_MIDDLE_LEFT, LOWER_RIGHT, LOWER_LEFT),
+                                             (UPPER_LEFT, LOWER_CENTRE, LOWER_LEFT),
+                                             (UPPER_MIDDLE_LEFT, UPPER_CENTRE, LOWER_RIGHT),
+                                             (UPPER_MIDDLE_LEFT, UPPER_RIGHT, LOWER_RIGHT),
+                                             (LOWER_MIDDLE_LEFT, UPPER_CENTRE, LOWER_RIGHT),
+                                             (LOWER_MIDDLE_LEFT, UPPER_RIGHT, LOWER_RIGHT),
+                                             (LOWER_LEFT, UPPER_CENTRE, LOWER_RIGHT),
+                                             (LOWER_MIDDLE_LEFT, UPPER_MIDDLE_RIGHT, LOWER_RIGHT),
+                                             (LOWER_CENTRE, LOWER_MIDDLE_RIGHT, LOWER_RIGHT),
+                                             (LOWER_LEFT, LOWER_MIDDLE_RIGHT, LOWER_RIGHT),
+                                             (LOWER_CENTRE, UPPER_MIDDLE_RIGHT, LOWER_RIGHT),
+                                             (LOWER_LEFT, UPPER_MIDDLE_RIGHT, LOWER_RIGHT),
+                                             (LOWER_CENTRE, UPPER_RIGHT, LOWER_RIGHT),
+                                             (UPPER_CENTRE, UPPER_MIDDLE_RIGHT, LOWER_LEFT),
+                                             (UPPER_LEFT, UPPER_MIDDLE_RIGHT, LOWER_LEFT),
+                                             (UPPER_CENTRE, LOWER_MIDDLE_RIGHT, LOWER_LEFT),
+                                             (UPPER_LEFT, LOWER_MIDDLE_RIGHT, LOWER_LEFT),
+                                             (UPPER_CENTRE, LOWER_RIGHT, LOWER_LEFT),
+                                             (UPPER_MIDDLE_LEFT, LOWER_MIDDLE_RIGHT, LOWER_LEFT)];
+
+/// Returns the 8x8 glyph for a sextant mosaic, where bit `n` of `blocks` is the block
+/// numbered `n + 1`: `1` and `2` on top, `3` and `4` in the middle, `5` and `6` below,
+/// left before right.
+pub const fn sextant_glyph(blocks: u8) -> [u8; 8] {
+    mosaic_glyph(blocks, false)
+}
+
+/// Returns the 8x8 glyph for a separated sextant mosaic, with the same block numbering
+/// as [sextant_glyph](./fn.sextant_glyph.html).
+pub const fn separated_sextant_glyph(blocks: u8) -> [u8; 8] {
+    mosaic_glyph(blocks, true)
+}
+
+const fn mosaic_glyph(blocks: u8, separated: bool) -> [u8; 8] {
+    let mut glyph = [0u8; 8];
+    let mut block = 0;
+    while block < 6 {
+        if blocks & (1 << block) != 0 {
+            let (top, mut bottom) = SEXTANT_ROWS[block / 2];
+            let mut columns = if block % 2 == 0 { 0x0F } else { 0xF0 };
+            if separated {
+                bottom -= 1;
+                columns &= columns << 1;
+            }
+            let mut row = top;
+            while row < bottom {
+                glyph[row] |= columns;
+                row += 1;
+            }
+        }
+        block += 1;
+    }
+    glyph
+}
+
+/// Maps a sextant codepoint offset to its blocks. The four mosaics that already exist
+/// elsewhere, blank, left half, right half and full, are skipped by the encoding.
+fn sextant_blocks(offset: u32) -> u8 {
+    let mut blocks = offset + 1;
+    if blocks >= 0b01_0101 {
+        blocks += 1;
+    }
+    if blocks >= 0b10_1010 {
+        blocks += 1;
+    }
+    blocks as u8
+}
+
+/// Returns the glyph for a lower wedge, or its complement for an upper wedge.
+fn wedge_glyph(offset: u32) -> [u8; 8] {
+    let count = WEDGES.len() as u32;
+    let (from, to, corner) = WEDGES[(offset % count) as usize];
+    let upper = offset >= count;
+    let side = |(x, y): Point| (to.0 - from.0) * (y - from.1) - (to.1 - from.1) * (x - from.0);
+    let filled = side(corner).signum();
+    let mut glyph = [0u8; 8];
+    for (row, byte) in glyph.iter_mut().enumerate() {
+        for column in 0..8 {
+            let centre = (2 * column + 1, 2 * row as i32 + 1);
+            if (side(centre) * filled >= 0) != upper {
+                *byte |= 1 << column;
+            }
+        }
+    }
+    glyph
+}
+
+/// A constant `[FontUnicode; 71]`, for the Symbols for Legacy Computing fonts that
+/// aren't computed (`U+1FB68` - `U+1FBAF`).
+pub const COMPUTING_UNICODE: [FontUnicode; 71] = [FontUnicode('\u{1FB68}', [0xFF, 0xFE, 0xFC, 0xF8, 0xF8, 0xFC, 0xFE, 0xFF]),
+                                                  FontUnicode('\u{1FB69}', [0x00, 0x81, 0xC3, 0xE7, 0xFF, 0xFF, 0xFF, 0xFF]),
+                                                  FontUnicode('\u{1FB6A}', [0xFF, 0x7F, 0x3F, 0x1F, 0x1F, 0x3F, 0x7F, 0xFF]),
+                                                  FontUnicode('\u{1FB6B}', [0xFF, 0xFF, 0xFF, 0xFF, 0xE7, 0xC3, 0x81, 0x00]),
+                                                  FontUnicode('\u{1FB6C}', [0x00, 0x01, 0x03, 0x07, 0x07, 0x03, 0x01, 0x00]),
+                                                  FontUnicode('\u{1FB6D}', [0xFF, 0x7E, 0x3C, 0x18, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FB6E}', [0x00, 0x80, 0xC0, 0xE0, 0xE0, 0xC0, 0x80, 0x00]),
+                                                  FontUnicode('\u{1FB6F}', [0x00, 0x00, 0x00, 0x00, 0x18, 0x3C, 0x7E, 0xFF]),
+                                                  FontUnicode('\u{1FB70}', [0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02]),
+                                                  FontUnicode('\u{1FB71}', [0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04]),
+                                                  FontUnicode('\u{1FB72}', [0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08]),
+                                                  FontUnicode('\u{1FB73}', [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10]),
+                                                  FontUnicode('\u{1FB74}', [0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20]),
+                                                  FontUnicode('\u{1FB75}', [0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40]),
+                                                  FontUnicode('\u{1FB76}', [0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FB77}', [0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FB78}', [0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FB79}', [0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FB7A}', [0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FB7B}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00]),
+                                                  FontUnicode('\u{1FB7C}', [0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0xFF]),
+                                                  FontUnicode('\u{1FB7D}', [0xFF, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01]),
+                                                  FontUnicode('\u{1FB7E}', [0xFF, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80]),
+                                                  FontUnicode('\u{1FB7F}', [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0xFF]),
+                                                  FontUnicode('\u{1FB80}', [0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF]),
+                                                  FontUnicode('\u{1FB81}', [0xFF, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0x00, 0xFF]),
+                                                  FontUnicode('\u{1FB82}', [0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FB83}', [0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FB84}', [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FB85}', [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FB86}', [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]),
+                                                  FontUnicode('\u{1FB87}', [0xC0, 0xC0, 0xC0, 0xC0, 0xC0, 0xC0, 0xC0, 0xC0]),
+                                                  FontUnicode('\u{1FB88}', [0xE0, 0xE0, 0xE0, 0xE0, 0xE0, 0xE0, 0xE0, 0xE0]),
+                                                  FontUnicode('\u{1FB89}', [0xF8, 0xF8, 0xF8, 0xF8, 0xF8, 0xF8, 0xF8, 0xF8]),
+                                                  FontUnicode('\u{1FB8A}', [0xFC, 0xFC, 0xFC, 0xFC, 0xFC, 0xFC, 0xFC, 0xFC]),
+                                                  FontUnicode('\u{1FB8B}', [0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE]),
+                                                  FontUnicode('\u{1FB8C}', [0x05, 0x0A, 0x05, 0x0A, 0x05, 0x0A, 0x05, 0x0A]),
+                                                  FontUnicode('\u{1FB8D}', [0x50, 0xA0, 0x50, 0xA0, 0x50, 0xA0, 0x50, 0xA0]),
+                                                  FontUnicode('\u{1FB8E}', [0x55, 0xAA, 0x55, 0xAA, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FB8F}', [0x00, 0x00, 0x00, 0x00, 0x55, 0xAA, 0x55, 0xAA]),
+                                                  FontUnicode('\u{1FB90}', [0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55]),
+                                                  FontUnicode('\u{1FB91}', [0xFF, 0xFF, 0xFF, 0xFF, 0xAA, 0x55, 0xAA, 0x55]),
+                                                  FontUnicode('\u{1FB92}', [0xAA, 0x55, 0xAA, 0x55, 0xFF, 0xFF, 0xFF, 0xFF]),
+                                                  FontUnicode('\u{1FB94}', [0xFA, 0xF5, 0xFA, 0xF5, 0xFA, 0xF5, 0xFA, 0xF5]),
+                                                  FontUnicode('\u{1FB95}', [0x33, 0x33, 0xCC, 0xCC, 0x33, 0x33, 0xCC, 0xCC]),
+                                                  FontUnicode('\u{1FB96}', [0xCC, 0xCC, 0x33, 0x33, 0xCC, 0xCC, 0x33, 0x33]),
+                                                  FontUnicode('\u{1FB97}', [0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0xFF, 0xFF]),
+                                                  FontUnicode('\u{1FB98}', [0x33, 0x66, 0xCC, 0x99, 0x33, 0x66, 0xCC, 0x99]),
+                                                  FontUnicode('\u{1FB99}', [0x33, 0x99, 0xCC, 0x66, 0x33, 0x99, 0xCC, 0x66]),
+                                                  FontUnicode('\u{1FB9A}', [0xFF, 0x7E, 0x3C, 0x18, 0x18, 0x3C, 0x7E, 0xFF]),
+                                                  FontUnicode('\u{1FB9B}', [0x00, 0x81, 0xC3, 0xE7, 0xE7, 0xC3, 0x81, 0x00]),
+                                                  FontUnicode('\u{1FB9C}', [0x55, 0x2A, 0x15, 0x0A, 0x05, 0x02, 0x01, 0x00]),
+                                                  FontUnicode('\u{1FB9D}', [0x54, 0xA8, 0x50, 0xA0, 0x40, 0x80, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FB9E}', [0x00, 0x80, 0x40, 0xA0, 0x50, 0xA8, 0x54, 0xAA]),
+                                                  FontUnicode('\u{1FB9F}', [0x01, 0x02, 0x05, 0x0A, 0x15, 0x2A, 0x55, 0xAA]),
+                                                  FontUnicode('\u{1FBA0}', [0x08, 0x04, 0x02, 0x02, 0x01, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FBA1}', [0x08, 0x10, 0x20, 0x40, 0x80, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FBA2}', [0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x04, 0x08]),
+                                                  FontUnicode('\u{1FBA3}', [0x00, 0x00, 0x00, 0x00, 0x80, 0x40, 0x30, 0x08]),
+                                                  FontUnicode('\u{1FBA4}', [0x08, 0x04, 0x02, 0x02, 0x01, 0x02, 0x04, 0x08]),
+                                                  FontUnicode('\u{1FBA5}', [0x08, 0x10, 0x20, 0x40, 0x80, 0x40, 0x30, 0x08]),
+                                                  FontUnicode('\u{1FBA6}', [0x00, 0x00, 0x00, 0x00, 0x81, 0x62, 0x14, 0x08]),
+                                                  FontUnicode('\u{1FBA7}', [0x08, 0x14, 0x24, 0x42, 0x81, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{1FBA8}', [0x08, 0x04, 0x02, 0x02, 0x81, 0x40, 0x30, 0x08]),
+                                                  FontUnicode('\u{1FBA9}', [0x08, 0x10, 0x20, 0x40, 0x81, 0x02, 0x04, 0x08]),
+                                                  FontUnicode('\u{1FBAA}', [0x08, 0x10, 0x20, 0x40, 0x81, 0x42, 0x34, 0x08]),
+                                                  FontUnicode('\u{1FBAB}', [0x08, 0x04, 0x02, 0x02, 0x81, 0x62, 0x14, 0x08]),
+                                                  FontUnicode('\u{1FBAC}', [0x08, 0x14, 0x24, 0x42, 0x81, 0x40, 0x30, 0x08]),
+                                                  FontUnicode('\u{1FBAD}', [0x08, 0x14, 0x22, 0x42, 0x81, 0x02, 0x04, 0x08]),
+                                                  FontUnicode('\u{1FBAE}', [0x08, 0x14, 0x24, 0x42, 0x81, 0x42, 0x34, 0x08]),
+                                                  FontUnicode('\u{1FBAF}', [0x00, 0x00, 0x08, 0x08, 0xFF, 0x08, 0x08, 0x00])];
+
+/// A convenient constant for Symbols for Legacy Computing fonts (`U+1FB00` - `U+1FBFF`),
+/// that implements the `UnicodeFonts` trait.
+///
+/// ## `U+1FB2A` `"🬪"`
+///
+/// ```text
+/// ████░░░░
+/// ████░░░░
+/// ████░░░░
+/// ████████
+/// ████████
+/// ░░░░████
+/// ░░░░████
+/// ░░░░████
+/// ```
+///
+/// ## `U+1FB4A` `"🭊"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░█
+/// ░░░░░░██
+/// ░░░░████
+/// ░░██████
+/// ░███████
+/// ```
+///
+/// ## `U+1CE7D`, separated
+///
+/// ```text
+/// ░███░░░░
+/// ░███░░░░
+/// ░░░░░░░░
+/// ░███░███
+/// ░░░░░░░░
+/// ░░░░░███
+/// ░░░░░███
+/// ░░░░░░░░
+/// ```
+pub const COMPUTING_FONTS: ComputingFonts = ComputingFonts;
+
+/// Strong-typed wrapper for the Symbols for Legacy Computing fonts.
+#[derive(Clone, Copy, PartialEq)]
+pub struct ComputingFonts;
+
+impl ComputingFonts {
+    /// Create a new collection of Symbols for Legacy Computing fonts.
+    pub fn new() -> Self {
+        ComputingFonts
+    }
+}
+
+impl fmt::Debug for ComputingFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(COMPUTING_FONTS))
+    }
+}
+
+impl Default for ComputingFonts {
+    fn default() -> Self {
+        ComputingFonts::new()
+    }
+}
+
+impl UnicodeFonts for ComputingFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        let code = key as u32;
+        match code {
+            SEXTANT_FIRST..=SEXTANT_LAST => Some(sextant_glyph(sextant_blocks(code - SEXTANT_FIRST))),
+            WEDGE_FIRST..=WEDGE_LAST => Some(wedge_glyph(code - WEDGE_FIRST)),
+            SEPARATED_FIRST..=SEPARATED_LAST => {
+                Some(separated_sextant_glyph((code - SEPARATED_FIRST + 1) as u8))
+            }
+            _ => {
+                match COMPUTING_UNICODE.binary_search_by_key(&key, |&f| f.char()) {
+                    Ok(idx) => Some(COMPUTING_UNICODE[idx].byte_array()),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        self.get(key).map(|glyph| FontUnicode(key, glyph))
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, (_, font)) in self.to_vec().iter().enumerate() {
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        (SEPARATED_FIRST..SEPARATED_LAST + 1).chain(SEXTANT_FIRST..WEDGE_LAST + 1)
+                                             .filter_map(char::from_u32)
+                                             .chain(COMPUTING_UNICODE.iter().map(|f| f.char()))
+                                             .filter_map(|c| self.get_font(c))
+                                             .fold(Vec::with_capacity(238), |mut v, font| {
+                                                 v.push((font.char(), font));
+                                                 v
+                                             })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use legacy::BLOCK_LEGACY;
+
+    #[test]
+    fn computing_set_implements_default_trait_with_method_new() {
+        let computing_set: ComputingFonts = Default::default();
+        assert_eq!(computing_set, ComputingFonts::new());
+    }
+
+    #[test]
+    fn computing_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(COMPUTING_FONTS, ComputingFonts::new());
+    }
+
+    #[test]
+    fn computing_unicode_is_sorted_and_outside_the_computed_ranges() {
+        for pair in COMPUTING_UNICODE.windows(2) {
+            assert!(pair[0].char() < pair[1].char());
+        }
+        for font in COMPUTING_UNICODE.iter() {
+            assert!(font.char() as u32 > WEDGE_LAST);
+        }
+    }
+
+    #[test]
+    fn computing_sextants_skip_the_half_and_full_blocks() {
+        let mut seen = [false; 64];
+        for code in SEXTANT_FIRST..SEXTANT_LAST + 1 {
+            seen[sextant_blocks(code - SEXTANT_FIRST) as usize] = true;
+        }
+        assert_eq!(seen.iter().filter(|&&s| s).count(), 60);
+        assert!(!seen[0] && !seen[0b01_0101] && !seen[0b10_1010] && !seen[0b11_1111]);
+        // the skipped mosaics are the BLOCK_LEGACY left half, right half and full block
+        assert_eq!(sextant_glyph(0b01_0101), BLOCK_LEGACY[12]);
+        assert_eq!(sextant_glyph(0b10_1010), BLOCK_LEGACY[16]);
+        assert_eq!(sextant_glyph(0b11_1111), BLOCK_LEGACY[8]);
+        assert_eq!(COMPUTING_FONTS.get('🬀'), Some(sextant_glyph(0b00_0001)));
+        assert_eq!(COMPUTING_FONTS.get('🬻'), Some(sextant_glyph(0b11_1110)));
+    }
+
+    #[test]
+    fn computing_upper_wedges_complement_the_lower_wedges() {
+        let full = BLOCK_LEGACY[8];
+        for offset in 0..22 {
+            let lower = ::core::char::from_u32(WEDGE_FIRST + offset).unwrap();
+            let upper = ::core::char::from_u32(WEDGE_FIRST + 22 + offset).unwrap();
+            let lower = COMPUTING_FONTS.get(lower).unwrap();
+            let upper = COMPUTING_FONTS.get(upper).unwrap();
+            for row in 0..8 {
+                assert_eq!(lower[row] & upper[row], 0);
+                assert_eq!(lower[row] | upper[row], full[row]);
+            }
+        }
+    }
+
+    #[test]
+    fn computing_wedges_keep_their_corner_filled() {
+        // 🬼 lower left block, diagonal lower middle left to lower centre
+        assert_eq!(COMPUTING_FONTS.get('🬼').unwrap()[7] & 0x01, 0x01);
+        assert_eq!(COMPUTING_FONTS.get('🬼').unwrap()[0], 0x00);
+        // 🭋 lower right block, diagonal lower centre to upper right
+        assert_eq!(COMPUTING_FONTS.get('🭋').unwrap()[7] & 0x80, 0x80);
+        assert_eq!(COMPUTING_FONTS.get('🭋').unwrap()[7] & 0x01, 0x00);
+    }
+
+    #[test]
+    fn computing_separated_sextants_sit_inside_the_sextants() {
+        for blocks in 1..64 {
+            let code = SEPARATED_FIRST + blocks - 1;
+            let separated = COMPUTING_FONTS.get(::core::char::from_u32(code).unwrap()).unwrap();
+            let solid = sextant_glyph(blocks as u8);
+            assert_ne!(separated, solid);
+            for row in 0..8 {
+                assert_eq!(separated[row] & !solid[row], 0);
+            }
+        }
+        assert_eq!(COMPUTING_FONTS.get('\u{1CE50}'), None);
+        assert_eq!(COMPUTING_FONTS.get('\u{1CE90}'), None);
+    }
+
+    #[test]
+    fn computing_eighth_blocks_match_the_block_elements() {
+        // 🮂 upper one quarter block extends ▔, and 🮋 right seven eighths mirrors ▉
+        assert_eq!(COMPUTING_FONTS.get('🮂').unwrap()[0], BLOCK_LEGACY[20][0]);
+        assert_eq!(COMPUTING_FONTS.get('🮋').unwrap()[0], BLOCK_LEGACY[9][0].reverse_bits());
+        assert_eq!(COMPUTING_FONTS.get('🮐'), Some([0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55]));
+    }
+}
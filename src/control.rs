@@ -0,0 +1,192 @@
+//! C1 Controls. `U+0080` - `U+009F`
+//!
+//! Control characters render as blank glyphs. See
+//! [Renderer::visible_controls](../struct.Renderer.html#method.visible_controls) to
+//! draw them instead.
+use super::{legacy::CONTROL_LEGACY, unicode::{FontUnicode, UnicodeFonts}};
+use core::fmt;
+
+/// A constant `[FontUnicode; 32]`, for C1 Control fonts (`U+0080` - `U+009F`).
+pub const CONTROL_UNICODE: [FontUnicode; 32] = [FontUnicode('\u{0080}', CONTROL_LEGACY[0]),
+                                                FontUnicode('\u{0081}', CONTROL_LEGACY[1]),
+                                                FontUnicode('\u{0082}', CONTROL_LEGACY[2]),
+                                                FontUnicode('\u{0083}', CONTROL_LEGACY[3]),
+                                                FontUnicode('\u{0084}', CONTROL_LEGACY[4]),
+                                                FontUnicode('\u{0085}', CONTROL_LEGACY[5]),
+                                                FontUnicode('\u{0086}', CONTROL_LEGACY[6]),
+                                                FontUnicode('\u{0087}', CONTROL_LEGACY[7]),
+                                                FontUnicode('\u{0088}', CONTROL_LEGACY[8]),
+                                                FontUnicode('\u{0089}', CONTROL_LEGACY[9]),
+                                                FontUnicode('\u{008A}', CONTROL_LEGACY[10]),
+                                                FontUnicode('\u{008B}', CONTROL_LEGACY[11]),
+                                                FontUnicode('\u{008C}', CONTROL_LEGACY[12]),
+                                                FontUnicode('\u{008D}', CONTROL_LEGACY[13]),
+                                                FontUnicode('\u{008E}', CONTROL_LEGACY[14]),
+                                                FontUnicode('\u{008F}', CONTROL_LEGACY[15]),
+                                                FontUnicode('\u{0090}', CONTROL_LEGACY[16]),
+                                                FontUnicode('\u{0091}', CONTROL_LEGACY[17]),
+                                                FontUnicode('\u{0092}', CONTROL_LEGACY[18]),
+                                                FontUnicode('\u{0093}', CONTROL_LEGACY[19]),
+                                                FontUnicode('\u{0094}', CONTROL_LEGACY[20]),
+                                                FontUnicode('\u{0095}', CONTROL_LEGACY[21]),
+                                                FontUnicode('\u{0096}', CONTROL_LEGACY[22]),
+                                                FontUnicode('\u{0097}', CONTROL_LEGACY[23]),
+                                                FontUnicode('\u{0098}', CONTROL_LEGACY[24]),
+                                                FontUnicode('\u{0099}', CONTROL_LEGACY[25]),
+                                                FontUnicode('\u{009A}', CONTROL_LEGACY[26]),
+                                                FontUnicode('\u{009B}', CONTROL_LEGACY[27]),
+                                                FontUnicode('\u{009C}', CONTROL_LEGACY[28]),
+                                                FontUnicode('\u{009D}', CONTROL_LEGACY[29]),
+                                                FontUnicode('\u{009E}', CONTROL_LEGACY[30]),
+                                                FontUnicode('\u{009F}', CONTROL_LEGACY[31])];
+
+/// A convenient constant for C1 Control fonts (`U+0080` - `U+009F`), that implements the `UnicodeFonts` trait.
+///
+/// ## `CONTROL_UNICODE[0]`: `U+0080` " "
+/// ## `CONTROL_UNICODE[1]`: `U+0081` " "
+/// ## `CONTROL_UNICODE[2]`: `U+0082` " "
+/// ## `CONTROL_UNICODE[3]`: `U+0083` " "
+/// ## `CONTROL_UNICODE[4]`: `U+0084` " "
+/// ## `CONTROL_UNICODE[5]`: `U+0085` " "
+/// ## `CONTROL_UNICODE[6]`: `U+0086` " "
+/// ## `CONTROL_UNICODE[7]`: `U+0087` " "
+/// ## `CONTROL_UNICODE[8]`: `U+0088` " "
+/// ## `CONTROL_UNICODE[9]`: `U+0089` " "
+/// ## `CONTROL_UNICODE[10]`: `U+008A` " "
+/// ## `CONTROL_UNICODE[11]`: `U+008B` " "
+/// ## `CONTROL_UNICODE[12]`: `U+008C` " "
+/// ## `CONTROL_UNICODE[13]`: `U+008D` " "
+/// ## `CONTROL_UNICODE[14]`: `U+008E` " "
+/// ## `CONTROL_UNICODE[15]`: `U+008F` " "
+/// ## `CONTROL_UNICODE[16]`: `U+0090` " "
+/// ## `CONTROL_UNICODE[17]`: `U+0091` " "
+/// ## `CONTROL_UNICODE[18]`: `U+0092` " "
+/// ## `CONTROL_UNICODE[19]`: `U+0093` " "
+/// ## `CONTROL_UNICODE[20]`: `U+0094` " "
+/// ## `CONTROL_UNICODE[21]`: `U+0095` " "
+/// ## `CONTROL_UNICODE[22]`: `U+0096` " "
+/// ## `CONTROL_UNICODE[23]`: `U+0097` " "
+/// ## `CONTROL_UNICODE[24]`: `U+0098` " "
+/// ## `CONTROL_UNICODE[25]`: `U+0099` " "
+/// ## `CONTROL_UNICODE[26]`: `U+009A` " "
+/// ## `CONTROL_UNICODE[27]`: `U+009B` " "
+/// ## `CONTROL_UNICODE[28]`: `U+009C` " "
+/// ## `CONTROL_UNICODE[29]`: `U+009D` " "
+/// ## `CONTROL_UNICODE[30]`: `U+009E` " "
+/// ## `CONTROL_UNICODE[31]`: `U+009F` " "
+pub const CONTROL_FONTS: ControlFonts = ControlFonts(CONTROL_UNICODE);
+/// Strong-typed collection wrapper for [CONTROL_UNICODE](./constant.CONTROL_UNICODE.html).
+pub struct ControlFonts([FontUnicode; 32]);
+
+impl ControlFonts {
+    /// Create a new collection of `CONTROL_UNICODE` fonts.
+    pub fn new() -> Self {
+        ControlFonts(CONTROL_UNICODE)
+    }
+}
+
+impl fmt::Debug for ControlFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(CONTROL_UNICODE))
+    }
+}
+
+impl PartialEq for ControlFonts {
+    fn eq(&self, other: &ControlFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for ControlFonts {
+    fn default() -> Self {
+        ControlFonts::new()
+    }
+}
+
+impl UnicodeFonts for ControlFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(32), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn control_set_implements_default_trait_with_method_new() {
+        let control_set: ControlFonts = Default::default();
+        assert_eq!(control_set, ControlFonts::new());
+    }
+
+    #[test]
+    fn control_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(CONTROL_FONTS, ControlFonts::new());
+    }
+
+    #[test]
+    fn control_fonts_constant_wraps_control_unicode_constant() {
+        let control = ControlFonts::new();
+        assert!(control.0.len() == CONTROL_UNICODE.len());
+        for (idx, font) in control.0.iter().enumerate() {
+            assert_eq!(font, &CONTROL_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn control_set_wraps_the_legacy_c1_controls() {
+        for (idx, font) in CONTROL_FONTS.0.iter().enumerate() {
+            assert_eq!(font.char() as usize, 0x80 + idx);
+            assert_eq!(font.byte_array(), CONTROL_LEGACY[idx]);
+            assert!(font.is_whitespace());
+        }
+    }
+}
@@ -0,0 +1,592 @@
+//! Currency Symbols. `U+20A0` - `U+20CF`
+//!
+//! The peseta sign `U+20A7` is the original glyph from `MISC_LEGACY[0]`.
+use super::{
+    legacy::MISC_LEGACY, unicode::{FontUnicode, UnicodeFonts},
+};
+use core::fmt;
+
+/// A constant `[FontUnicode; 33]`, for Currency Symbols fonts (`U+20A0` - `U+20CF`).
+pub const CURRENCY_UNICODE: [FontUnicode; 33] = [FontUnicode('\u{20A0}', [0x1C, 0x06, 0x7B, 0x1B, 0x3B, 0x36, 0xF4, 0x00]),
+                                                 FontUnicode('\u{20A1}', [0x14, 0x7E, 0x6B, 0x0B, 0x0B, 0x7E, 0x14, 0x00]),
+                                                 FontUnicode('\u{20A2}', [0x3C, 0x66, 0x03, 0x1B, 0x3B, 0xDE, 0x1C, 0x00]),
+                                                 FontUnicode('\u{20A3}', [0x7F, 0x46, 0x06, 0x1E, 0x06, 0x0F, 0x06, 0x00]),
+                                                 FontUnicode('\u{20A4}', [0x1C, 0x36, 0x06, 0x1F, 0x06, 0x1F, 0x3F, 0x00]),
+                                                 FontUnicode('\u{20A5}', [0x20, 0x10, 0x1B, 0x7F, 0x5F, 0x4B, 0x23, 0x00]),
+                                                 FontUnicode('\u{20A6}', [0x63, 0x67, 0x7F, 0x7B, 0x7F, 0x63, 0x63, 0x00]),
+                                                 FontUnicode('\u{20A7}', MISC_LEGACY[0]),
+                                                 FontUnicode('\u{20A8}', [0x1F, 0x36, 0x36, 0xDE, 0x36, 0xE6, 0x77, 0x00]),
+                                                 FontUnicode('\u{20A9}', [0x63, 0x63, 0x7F, 0x6B, 0x7F, 0x77, 0x63, 0x00]),
+                                                 FontUnicode('\u{20AA}', [0xDF, 0xD3, 0xDB, 0xDB, 0xDB, 0xCB, 0xFB, 0x00]),
+                                                 FontUnicode('\u{20AB}', [0x30, 0xFC, 0x30, 0x3E, 0x33, 0x7E, 0x00, 0x7E]),
+                                                 FontUnicode('\u{20AC}', [0x3C, 0x66, 0x3F, 0x06, 0x3F, 0x66, 0x3C, 0x00]),
+                                                 FontUnicode('\u{20AD}', [0x63, 0x33, 0x1B, 0x7F, 0x1B, 0x33, 0x63, 0x00]),
+                                                 FontUnicode('\u{20AE}', [0x3F, 0x0C, 0x3C, 0x1E, 0x3C, 0x1E, 0x0C, 0x00]),
+                                                 FontUnicode('\u{20AF}', [0x07, 0x0A, 0x12, 0xD2, 0x72, 0x2A, 0x27, 0x00]),
+                                                 FontUnicode('\u{20B0}', [0x1C, 0x22, 0x01, 0x1E, 0x84, 0x44, 0x3C, 0x00]),
+                                                 FontUnicode('\u{20B1}', [0x1F, 0x36, 0x7F, 0x7F, 0x06, 0x06, 0x0F, 0x00]),
+                                                 FontUnicode('\u{20B2}', [0x0C, 0x7E, 0x0B, 0x6B, 0x6B, 0x7E, 0x0C, 0x00]),
+                                                 FontUnicode('\u{20B3}', [0x0C, 0x1E, 0x33, 0x3F, 0x33, 0x3F, 0x33, 0x00]),
+                                                 FontUnicode('\u{20B4}', [0x1E, 0x33, 0x3F, 0x0C, 0x3F, 0x33, 0x1E, 0x00]),
+                                                 FontUnicode('\u{20B5}', [0x08, 0x7E, 0x0B, 0x0B, 0x0B, 0x7E, 0x08, 0x00]),
+                                                 FontUnicode('\u{20B6}', [0x03, 0x23, 0xF3, 0x23, 0x23, 0xA3, 0x3F, 0x00]),
+                                                 FontUnicode('\u{20B7}', [0x0E, 0x11, 0x62, 0xB4, 0x59, 0xAE, 0x00, 0x00]),
+                                                 FontUnicode('\u{20B8}', [0x3F, 0x00, 0x3F, 0x0C, 0x0C, 0x0C, 0x0C, 0x00]),
+                                                 FontUnicode('\u{20B9}', [0x3F, 0x18, 0x3F, 0x18, 0x0F, 0x0C, 0x38, 0x00]),
+                                                 FontUnicode('\u{20BA}', [0x0C, 0x0C, 0x3C, 0x0E, 0x3C, 0x6E, 0x3C, 0x00]),
+                                                 FontUnicode('\u{20BB}', [0x63, 0x77, 0x7F, 0x6B, 0x6B, 0x6B, 0x63, 0x00]),
+                                                 FontUnicode('\u{20BC}', [0x18, 0x18, 0x7E, 0xDB, 0xDB, 0xDB, 0xC3, 0x00]),
+                                                 FontUnicode('\u{20BD}', [0x3E, 0x66, 0x66, 0x3E, 0x0F, 0x06, 0x06, 0x00]),
+                                                 FontUnicode('\u{20BE}', [0x36, 0x36, 0xC3, 0xC3, 0xC3, 0x66, 0x3F, 0x00]),
+                                                 FontUnicode('\u{20BF}', [0x0A, 0x3F, 0x66, 0x3E, 0x66, 0x3F, 0x0A, 0x00]),
+                                                 FontUnicode('\u{20C0}', [0x3C, 0x66, 0x06, 0x3C, 0x60, 0x66, 0x3C, 0x18])];
+
+/// A convenient constant for Currency Symbols fonts (`U+20A0` - `U+20CF`), that implements the `UnicodeFonts` trait.
+///
+/// ## `CURRENCY_UNICODE[0]`: `U+20A0` `"₠"`
+///
+/// ```text
+/// ░░███░░░
+/// ░██░░░░░
+/// ██░████░
+/// ██░██░░░
+/// ██░███░░
+/// ░██░██░░
+/// ░░█░████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[1]`: `U+20A1` `"₡"`
+///
+/// ```text
+/// ░░█░█░░░
+/// ░██████░
+/// ██░█░██░
+/// ██░█░░░░
+/// ██░█░░░░
+/// ░██████░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[2]`: `U+20A2` `"₢"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░██░
+/// ██░░░░░░
+/// ██░██░░░
+/// ██░███░░
+/// ░████░██
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[3]`: `U+20A3` `"₣"`
+///
+/// ```text
+/// ███████░
+/// ░██░░░█░
+/// ░██░░░░░
+/// ░████░░░
+/// ░██░░░░░
+/// ████░░░░
+/// ░██░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[4]`: `U+20A4` `"₤"`
+///
+/// ```text
+/// ░░███░░░
+/// ░██░██░░
+/// ░██░░░░░
+/// █████░░░
+/// ░██░░░░░
+/// █████░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[5]`: `U+20A5` `"₥"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ██░██░░░
+/// ███████░
+/// █████░█░
+/// ██░█░░█░
+/// ██░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[6]`: `U+20A6` `"₦"`
+///
+/// ```text
+/// ██░░░██░
+/// ███░░██░
+/// ███████░
+/// ██░████░
+/// ███████░
+/// ██░░░██░
+/// ██░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[7]`: `U+20A7` `"₧"`
+///
+/// ```text
+/// █████░░░
+/// ██░░██░░
+/// ██░░██░░
+/// █████░█░
+/// ██░░░██░
+/// ██░░████
+/// ██░░░██░
+/// ██░░░███
+/// ```
+///
+/// ## `CURRENCY_UNICODE[8]`: `U+20A8` `"₨"`
+///
+/// ```text
+/// █████░░░
+/// ░██░██░░
+/// ░██░██░░
+/// ░████░██
+/// ░██░██░░
+/// ░██░░███
+/// ███░███░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[9]`: `U+20A9` `"₩"`
+///
+/// ```text
+/// ██░░░██░
+/// ██░░░██░
+/// ███████░
+/// ██░█░██░
+/// ███████░
+/// ███░███░
+/// ██░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[10]`: `U+20AA` `"₪"`
+///
+/// ```text
+/// █████░██
+/// ██░░█░██
+/// ██░██░██
+/// ██░██░██
+/// ██░██░██
+/// ██░█░░██
+/// ██░█████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[11]`: `U+20AB` `"₫"`
+///
+/// ```text
+/// ░░░░██░░
+/// ░░██████
+/// ░░░░██░░
+/// ░█████░░
+/// ██░░██░░
+/// ░██████░
+/// ░░░░░░░░
+/// ░██████░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[12]`: `U+20AC` `"€"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░██░
+/// ██████░░
+/// ░██░░░░░
+/// ██████░░
+/// ░██░░██░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[13]`: `U+20AD` `"₭"`
+///
+/// ```text
+/// ██░░░██░
+/// ██░░██░░
+/// ██░██░░░
+/// ███████░
+/// ██░██░░░
+/// ██░░██░░
+/// ██░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[14]`: `U+20AE` `"₮"`
+///
+/// ```text
+/// ██████░░
+/// ░░██░░░░
+/// ░░████░░
+/// ░████░░░
+/// ░░████░░
+/// ░████░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[15]`: `U+20AF` `"₯"`
+///
+/// ```text
+/// ███░░░░░
+/// ░█░█░░░░
+/// ░█░░█░░░
+/// ░█░░█░██
+/// ░█░░███░
+/// ░█░█░█░░
+/// ███░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[16]`: `U+20B0` `"₰"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░░░░
+/// ░████░░░
+/// ░░█░░░░█
+/// ░░█░░░█░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[17]`: `U+20B1` `"₱"`
+///
+/// ```text
+/// █████░░░
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ░██░░░░░
+/// ░██░░░░░
+/// ████░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[18]`: `U+20B2` `"₲"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░██████░
+/// ██░█░░░░
+/// ██░█░██░
+/// ██░█░██░
+/// ░██████░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[19]`: `U+20B3` `"₳"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░████░░░
+/// ██░░██░░
+/// ██████░░
+/// ██░░██░░
+/// ██████░░
+/// ██░░██░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[20]`: `U+20B4` `"₴"`
+///
+/// ```text
+/// ░████░░░
+/// ██░░██░░
+/// ██████░░
+/// ░░██░░░░
+/// ██████░░
+/// ██░░██░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[21]`: `U+20B5` `"₵"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░██████░
+/// ██░█░░░░
+/// ██░█░░░░
+/// ██░█░░░░
+/// ░██████░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[22]`: `U+20B6` `"₶"`
+///
+/// ```text
+/// ██░░░░░░
+/// ██░░░█░░
+/// ██░░████
+/// ██░░░█░░
+/// ██░░░█░░
+/// ██░░░█░█
+/// ██████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[23]`: `U+20B7` `"₷"`
+///
+/// ```text
+/// ░███░░░░
+/// █░░░█░░░
+/// ░█░░░██░
+/// ░░█░██░█
+/// █░░██░█░
+/// ░███░█░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[24]`: `U+20B8` `"₸"`
+///
+/// ```text
+/// ██████░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[25]`: `U+20B9` `"₹"`
+///
+/// ```text
+/// ██████░░
+/// ░░░██░░░
+/// ██████░░
+/// ░░░██░░░
+/// ████░░░░
+/// ░░██░░░░
+/// ░░░███░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[26]`: `U+20BA` `"₺"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░████░░
+/// ░███░░░░
+/// ░░████░░
+/// ░███░██░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[27]`: `U+20BB` `"₻"`
+///
+/// ```text
+/// ██░░░██░
+/// ███░███░
+/// ███████░
+/// ██░█░██░
+/// ██░█░██░
+/// ██░█░██░
+/// ██░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[28]`: `U+20BC` `"₼"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░██████░
+/// ██░██░██
+/// ██░██░██
+/// ██░██░██
+/// ██░░░░██
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[29]`: `U+20BD` `"₽"`
+///
+/// ```text
+/// ░█████░░
+/// ░██░░██░
+/// ░██░░██░
+/// ░█████░░
+/// ████░░░░
+/// ░██░░░░░
+/// ░██░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[30]`: `U+20BE` `"₾"`
+///
+/// ```text
+/// ░██░██░░
+/// ░██░██░░
+/// ██░░░░██
+/// ██░░░░██
+/// ██░░░░██
+/// ░██░░██░
+/// ██████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[31]`: `U+20BF` `"₿"`
+///
+/// ```text
+/// ░█░█░░░░
+/// ██████░░
+/// ░██░░██░
+/// ░█████░░
+/// ░██░░██░
+/// ██████░░
+/// ░█░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `CURRENCY_UNICODE[32]`: `U+20C0` `"⃀"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░██░
+/// ░██░░░░░
+/// ░░████░░
+/// ░░░░░██░
+/// ░██░░██░
+/// ░░████░░
+/// ░░░██░░░
+/// ```
+pub const CURRENCY_FONTS: CurrencyFonts = CurrencyFonts(CURRENCY_UNICODE);
+/// Strong-typed collection wrapper for [CURRENCY_UNICODE](./constant.CURRENCY_UNICODE.html).
+pub struct CurrencyFonts([FontUnicode; 33]);
+
+impl CurrencyFonts {
+    /// Create a new collection of `CURRENCY_UNICODE` fonts.
+    pub fn new() -> Self {
+        CurrencyFonts(CURRENCY_UNICODE)
+    }
+}
+
+impl fmt::Debug for CurrencyFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(CURRENCY_UNICODE))
+    }
+}
+
+impl PartialEq for CurrencyFonts {
+    fn eq(&self, other: &CurrencyFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for CurrencyFonts {
+    fn default() -> Self {
+        CurrencyFonts::new()
+    }
+}
+
+impl UnicodeFonts for CurrencyFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(33), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn currency_set_implements_default_trait_with_method_new() {
+        let currency_set: CurrencyFonts = Default::default();
+        assert_eq!(currency_set, CurrencyFonts::new());
+    }
+
+    #[test]
+    fn currency_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(CURRENCY_FONTS, CurrencyFonts::new());
+    }
+
+    #[test]
+    fn currency_fonts_constant_wraps_currency_unicode_constant() {
+        let currency = CurrencyFonts::new();
+        assert!(currency.0.len() == CURRENCY_UNICODE.len());
+        for (idx, font) in currency.0.iter().enumerate() {
+            assert_eq!(font, &CURRENCY_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn currency_set_contains_the_legacy_peseta_sign() {
+        assert_eq!(CURRENCY_FONTS.get('₧'), Some(MISC_LEGACY[0]));
+    }
+
+    #[test]
+    fn currency_set_covers_point_of_sale_symbols() {
+        for key in "€₹₽₩₪₺₴₿₫₱".chars() {
+            assert!(!CURRENCY_FONTS.get_font(key).unwrap().is_whitespace());
+        }
+    }
+}
@@ -0,0 +1,445 @@
+//! Precomposed Latin letters, computed from their canonical decomposition.
+//!
+//! Precomposed characters that no other set draws, like `"ǎ"` or `"ḱ"`, are decomposed
+//! into a base character and combining marks (Unicode Normalization Form D). The
+//! glyph is then made from the base in `BASIC_LEGACY`, with the marks drawn onto it by
+//! [CombiningFonts::overlay](./struct.CombiningFonts.html#method.overlay).
+use super::legacy::BASIC_LEGACY;
+use super::{FontUnicode, UnicodeFonts, COMBINING_FONTS};
+use core::fmt;
+
+/// Canonical decompositions of the precomposed characters whose base is in
+/// `BASIC_LEGACY`, and whose marks are all Combining Diacritical Marks, from the
+/// Unicode 14.0 Character Database. Sorted by character.
+const DECOMPOSITIONS: [(char, &str); 492] = [('\u{00C0}', "A\u{0300}"), ('\u{00C1}', "A\u{0301}"),
+                                             ('\u{00C2}', "A\u{0302}"), ('\u{00C3}', "A\u{0303}"),
+                                             ('\u{00C4}', "A\u{0308}"), ('\u{00C5}', "A\u{030A}"),
+                                             ('\u{00C7}', "C\u{0327}"), ('\u{00C8}', "E\u{0300}"),
+                                             ('\u{00C9}', "E\u{0301}"), ('\u{00CA}', "E\u{0302}"),
+                                             ('\u{00CB}', "E\u{0308}"), ('\u{00CC}', "I\u{0300}"),
+                                             ('\u{00CD}', "I\u{0301}"), ('\u{00CE}', "I\u{0302}"),
+                                             ('\u{00CF}', "I\u{0308}"), ('\u{00D1}', "N\u{0303}"),
+                                             ('\u{00D2}', "O\u{0300}"), ('\u{00D3}', "O\u{0301}"),
+                                             ('\u{00D4}', "O\u{0302}"), ('\u{00D5}', "O\u{0303}"),
+                                             ('\u{00D6}', "O\u{0308}"), ('\u{00D9}', "U\u{0300}"),
+                                             ('\u{00DA}', "U\u{0301}"), ('\u{00DB}', "U\u{0302}"),
+                                             ('\u{00DC}', "U\u{0308}"), ('\u{00DD}', "Y\u{0301}"),
+                                             ('\u{00E0}', "a\u{0300}"), ('\u{00E1}', "a\u{0301}"),
+                                             ('\u{00E2}', "a\u{0302}"), ('\u{00E3}', "a\u{0303}"),
+                                             ('\u{00E4}', "a\u{0308}"), ('\u{00E5}', "a\u{030A}"),
+                                             ('\u{00E7}', "c\u{0327}"), ('\u{00E8}', "e\u{0300}"),
+                                             ('\u{00E9}', "e\u{0301}"), ('\u{00EA}', "e\u{0302}"),
+                                             ('\u{00EB}', "e\u{0308}"), ('\u{00EC}', "i\u{0300}"),
+                                             ('\u{00ED}', "i\u{0301}"), ('\u{00EE}', "i\u{0302}"),
+                                             ('\u{00EF}', "i\u{0308}"), ('\u{00F1}', "n\u{0303}"),
+                                             ('\u{00F2}', "o\u{0300}"), ('\u{00F3}', "o\u{0301}"),
+                                             ('\u{00F4}', "o\u{0302}"), ('\u{00F5}', "o\u{0303}"),
+                                             ('\u{00F6}', "o\u{0308}"), ('\u{00F9}', "u\u{0300}"),
+                                             ('\u{00FA}', "u\u{0301}"), ('\u{00FB}', "u\u{0302}"),
+                                             ('\u{00FC}', "u\u{0308}"), ('\u{00FD}', "y\u{0301}"),
+                                             ('\u{00FF}', "y\u{0308}"), ('\u{0100}', "A\u{0304}"),
+                                             ('\u{0101}', "a\u{0304}"), ('\u{0102}', "A\u{0306}"),
+                                             ('\u{0103}', "a\u{0306}"), ('\u{0104}', "A\u{0328}"),
+                                             ('\u{0105}', "a\u{0328}"), ('\u{0106}', "C\u{0301}"),
+                                             ('\u{0107}', "c\u{0301}"), ('\u{0108}', "C\u{0302}"),
+                                             ('\u{0109}', "c\u{0302}"), ('\u{010A}', "C\u{0307}"),
+                                             ('\u{010B}', "c\u{0307}"), ('\u{010C}', "C\u{030C}"),
+                                             ('\u{010D}', "c\u{030C}"), ('\u{010E}', "D\u{030C}"),
+                                             ('\u{010F}', "d\u{030C}"), ('\u{0112}', "E\u{0304}"),
+                                             ('\u{0113}', "e\u{0304}"), ('\u{0114}', "E\u{0306}"),
+                                             ('\u{0115}', "e\u{0306}"), ('\u{0116}', "E\u{0307}"),
+                                             ('\u{0117}', "e\u{0307}"), ('\u{0118}', "E\u{0328}"),
+                                             ('\u{0119}', "e\u{0328}"), ('\u{011A}', "E\u{030C}"),
+                                             ('\u{011B}', "e\u{030C}"), ('\u{011C}', "G\u{0302}"),
+                                             ('\u{011D}', "g\u{0302}"), ('\u{011E}', "G\u{0306}"),
+                                             ('\u{011F}', "g\u{0306}"), ('\u{0120}', "G\u{0307}"),
+                                             ('\u{0121}', "g\u{0307}"), ('\u{0122}', "G\u{0327}"),
+                                             ('\u{0123}', "g\u{0327}"), ('\u{0124}', "H\u{0302}"),
+                                             ('\u{0125}', "h\u{0302}"), ('\u{0128}', "I\u{0303}"),
+                                             ('\u{0129}', "i\u{0303}"), ('\u{012A}', "I\u{0304}"),
+                                             ('\u{012B}', "i\u{0304}"), ('\u{012C}', "I\u{0306}"),
+                                             ('\u{012D}', "i\u{0306}"), ('\u{012E}', "I\u{0328}"),
+                                             ('\u{012F}', "i\u{0328}"), ('\u{0130}', "I\u{0307}"),
+                                             ('\u{0134}', "J\u{0302}"), ('\u{0135}', "j\u{0302}"),
+                                             ('\u{0136}', "K\u{0327}"), ('\u{0137}', "k\u{0327}"),
+                                             ('\u{0139}', "L\u{0301}"), ('\u{013A}', "l\u{0301}"),
+                                             ('\u{013B}', "L\u{0327}"), ('\u{013C}', "l\u{0327}"),
+                                             ('\u{013D}', "L\u{030C}"), ('\u{013E}', "l\u{030C}"),
+                                             ('\u{0143}', "N\u{0301}"), ('\u{0144}', "n\u{0301}"),
+                                             ('\u{0145}', "N\u{0327}"), ('\u{0146}', "n\u{0327}"),
+                                             ('\u{0147}', "N\u{030C}"), ('\u{0148}', "n\u{030C}"),
+                                             ('\u{014C}', "O\u{0304}"), ('\u{014D}', "o\u{0304}"),
+                                             ('\u{014E}', "O\u{0306}"), ('\u{014F}', "o\u{0306}"),
+                                             ('\u{0150}', "O\u{030B}"), ('\u{0151}', "o\u{030B}"),
+                                             ('\u{0154}', "R\u{0301}"), ('\u{0155}', "r\u{0301}"),
+                                             ('\u{0156}', "R\u{0327}"), ('\u{0157}', "r\u{0327}"),
+                                             ('\u{0158}', "R\u{030C}"), ('\u{0159}', "r\u{030C}"),
+                                             ('\u{015A}', "S\u{0301}"), ('\u{015B}', "s\u{0301}"),
+                                             ('\u{015C}', "S\u{0302}"), ('\u{015D}', "s\u{0302}"),
+                                             ('\u{015E}', "S\u{0327}"), ('\u{015F}', "s\u{0327}"),
+                                             ('\u{0160}', "S\u{030C}"), ('\u{0161}', "s\u{030C}"),
+                                             ('\u{0162}', "T\u{0327}"), ('\u{0163}', "t\u{0327}"),
+                                             ('\u{0164}', "T\u{030C}"), ('\u{0165}', "t\u{030C}"),
+                                             ('\u{0168}', "U\u{0303}"), ('\u{0169}', "u\u{0303}"),
+                                             ('\u{016A}', "U\u{0304}"), ('\u{016B}', "u\u{0304}"),
+                                             ('\u{016C}', "U\u{0306}"), ('\u{016D}', "u\u{0306}"),
+                                             ('\u{016E}', "U\u{030A}"), ('\u{016F}', "u\u{030A}"),
+                                             ('\u{0170}', "U\u{030B}"), ('\u{0171}', "u\u{030B}"),
+                                             ('\u{0172}', "U\u{0328}"), ('\u{0173}', "u\u{0328}"),
+                                             ('\u{0174}', "W\u{0302}"), ('\u{0175}', "w\u{0302}"),
+                                             ('\u{0176}', "Y\u{0302}"), ('\u{0177}', "y\u{0302}"),
+                                             ('\u{0178}', "Y\u{0308}"), ('\u{0179}', "Z\u{0301}"),
+                                             ('\u{017A}', "z\u{0301}"), ('\u{017B}', "Z\u{0307}"),
+                                             ('\u{017C}', "z\u{0307}"), ('\u{017D}', "Z\u{030C}"),
+                                             ('\u{017E}', "z\u{030C}"), ('\u{01A0}', "O\u{031B}"),
+                                             ('\u{01A1}', "o\u{031B}"), ('\u{01AF}', "U\u{031B}"),
+                                             ('\u{01B0}', "u\u{031B}"), ('\u{01CD}', "A\u{030C}"),
+                                             ('\u{01CE}', "a\u{030C}"), ('\u{01CF}', "I\u{030C}"),
+                                             ('\u{01D0}', "i\u{030C}"), ('\u{01D1}', "O\u{030C}"),
+                                             ('\u{01D2}', "o\u{030C}"), ('\u{01D3}', "U\u{030C}"),
+                                             ('\u{01D4}', "u\u{030C}"), ('\u{01D5}', "U\u{0308}\u{0304}"),
+                                             ('\u{01D6}', "u\u{0308}\u{0304}"),
+                                             ('\u{01D7}', "U\u{0308}\u{0301}"),
+                                             ('\u{01D8}', "u\u{0308}\u{0301}"),
+                                             ('\u{01D9}', "U\u{0308}\u{030C}"),
+                                             ('\u{01DA}', "u\u{0308}\u{030C}"),
+                                             ('\u{01DB}', "U\u{0308}\u{0300}"),
+                                             ('\u{01DC}', "u\u{0308}\u{0300}"),
+                                             ('\u{01DE}', "A\u{0308}\u{0304}"),
+                                             ('\u{01DF}', "a\u{0308}\u{0304}"),
+                                             ('\u{01E0}', "A\u{0307}\u{0304}"),
+                                             ('\u{01E1}', "a\u{0307}\u{0304}"), ('\u{01E6}', "G\u{030C}"),
+                                             ('\u{01E7}', "g\u{030C}"), ('\u{01E8}', "K\u{030C}"),
+                                             ('\u{01E9}', "k\u{030C}"), ('\u{01EA}', "O\u{0328}"),
+                                             ('\u{01EB}', "o\u{0328}"), ('\u{01EC}', "O\u{0328}\u{0304}"),
+                                             ('\u{01ED}', "o\u{0328}\u{0304}"), ('\u{01F0}', "j\u{030C}"),
+                                             ('\u{01F4}', "G\u{0301}"), ('\u{01F5}', "g\u{0301}"),
+                                             ('\u{01F8}', "N\u{0300}"), ('\u{01F9}', "n\u{0300}"),
+                                             ('\u{01FA}', "A\u{030A}\u{0301}"),
+                                             ('\u{01FB}', "a\u{030A}\u{0301}"), ('\u{0200}', "A\u{030F}"),
+                                             ('\u{0201}', "a\u{030F}"), ('\u{0202}', "A\u{0311}"),
+                                             ('\u{0203}', "a\u{0311}"), ('\u{0204}', "E\u{030F}"),
+                                             ('\u{0205}', "e\u{030F}"), ('\u{0206}', "E\u{0311}"),
+                                             ('\u{0207}', "e\u{0311}"), ('\u{0208}', "I\u{030F}"),
+                                             ('\u{0209}', "i\u{030F}"), ('\u{020A}', "I\u{0311}"),
+                                             ('\u{020B}', "i\u{0311}"), ('\u{020C}', "O\u{030F}"),
+                                             ('\u{020D}', "o\u{030F}"), ('\u{020E}', "O\u{0311}"),
+                                             ('\u{020F}', "o\u{0311}"), ('\u{0210}', "R\u{030F}"),
+                                             ('\u{0211}', "r\u{030F}"), ('\u{0212}', "R\u{0311}"),
+                                             ('\u{0213}', "r\u{0311}"), ('\u{0214}', "U\u{030F}"),
+                                             ('\u{0215}', "u\u{030F}"), ('\u{0216}', "U\u{0311}"),
+                                             ('\u{0217}', "u\u{0311}"), ('\u{0218}', "S\u{0326}"),
+                                             ('\u{0219}', "s\u{0326}"), ('\u{021A}', "T\u{0326}"),
+                                             ('\u{021B}', "t\u{0326}"), ('\u{021E}', "H\u{030C}"),
+                                             ('\u{021F}', "h\u{030C}"), ('\u{0226}', "A\u{0307}"),
+                                             ('\u{0227}', "a\u{0307}"), ('\u{0228}', "E\u{0327}"),
+                                             ('\u{0229}', "e\u{0327}"), ('\u{022A}', "O\u{0308}\u{0304}"),
+                                             ('\u{022B}', "o\u{0308}\u{0304}"),
+                                             ('\u{022C}', "O\u{0303}\u{0304}"),
+                                             ('\u{022D}', "o\u{0303}\u{0304}"), ('\u{022E}', "O\u{0307}"),
+                                             ('\u{022F}', "o\u{0307}"), ('\u{0230}', "O\u{0307}\u{0304}"),
+                                             ('\u{0231}', "o\u{0307}\u{0304}"), ('\u{0232}', "Y\u{0304}"),
+                                             ('\u{0233}', "y\u{0304}"), ('\u{1E00}', "A\u{0325}"),
+                                             ('\u{1E01}', "a\u{0325}"), ('\u{1E02}', "B\u{0307}"),
+                                             ('\u{1E03}', "b\u{0307}"), ('\u{1E04}', "B\u{0323}"),
+                                             ('\u{1E05}', "b\u{0323}"), ('\u{1E06}', "B\u{0331}"),
+                                             ('\u{1E07}', "b\u{0331}"), ('\u{1E08}', "C\u{0327}\u{0301}"),
+                                             ('\u{1E09}', "c\u{0327}\u{0301}"), ('\u{1E0A}', "D\u{0307}"),
+                                             ('\u{1E0B}', "d\u{0307}"), ('\u{1E0C}', "D\u{0323}"),
+                                             ('\u{1E0D}', "d\u{0323}"), ('\u{1E0E}', "D\u{0331}"),
+                                             ('\u{1E0F}', "d\u{0331}"), ('\u{1E10}', "D\u{0327}"),
+                                             ('\u{1E11}', "d\u{0327}"), ('\u{1E12}', "D\u{032D}"),
+                                             ('\u{1E13}', "d\u{032D}"), ('\u{1E14}', "E\u{0304}\u{0300}"),
+                                             ('\u{1E15}', "e\u{0304}\u{0300}"),
+                                             ('\u{1E16}', "E\u{0304}\u{0301}"),
+                                             ('\u{1E17}', "e\u{0304}\u{0301}"), ('\u{1E18}', "E\u{032D}"),
+                                             ('\u{1E19}', "e\u{032D}"), ('\u{1E1A}', "E\u{0330}"),
+                                             ('\u{1E1B}', "e\u{0330}"), ('\u{1E1C}', "E\u{0327}\u{0306}"),
+                                             ('\u{1E1D}', "e\u{0327}\u{0306}"), ('\u{1E1E}', "F\u{0307}"),
+                                             ('\u{1E1F}', "f\u{0307}"), ('\u{1E20}', "G\u{0304}"),
+                                             ('\u{1E21}', "g\u{0304}"), ('\u{1E22}', "H\u{0307}"),
+                                             ('\u{1E23}', "h\u{0307}"), ('\u{1E24}', "H\u{0323}"),
+                                             ('\u{1E25}', "h\u{0323}"), ('\u{1E26}', "H\u{0308}"),
+                                             ('\u{1E27}', "h\u{0308}"), ('\u{1E28}', "H\u{0327}"),
+                                             ('\u{1E29}', "h\u{0327}"), ('\u{1E2A}', "H\u{032E}"),
+                                             ('\u{1E2B}', "h\u{032E}"), ('\u{1E2C}', "I\u{0330}"),
+                                             ('\u{1E2D}', "i\u{0330}"), ('\u{1E2E}', "I\u{0308}\u{0301}"),
+                                             ('\u{1E2F}', "i\u{0308}\u{0301}"), ('\u{1E30}', "K\u{0301}"),
+                                             ('\u{1E31}', "k\u{0301}"), ('\u{1E32}', "K\u{0323}"),
+                                             ('\u{1E33}', "k\u{0323}"), ('\u{1E34}', "K\u{0331}"),
+                                             ('\u{1E35}', "k\u{0331}"), ('\u{1E36}', "L\u{0323}"),
+                                             ('\u{1E37}', "l\u{0323}"), ('\u{1E38}', "L\u{0323}\u{0304}"),
+                                             ('\u{1E39}', "l\u{0323}\u{0304}"), ('\u{1E3A}', "L\u{0331}"),
+                                             ('\u{1E3B}', "l\u{0331}"), ('\u{1E3C}', "L\u{032D}"),
+                                             ('\u{1E3D}', "l\u{032D}"), ('\u{1E3E}', "M\u{0301}"),
+                                             ('\u{1E3F}', "m\u{0301}"), ('\u{1E40}', "M\u{0307}"),
+                                             ('\u{1E41}', "m\u{0307}"), ('\u{1E42}', "M\u{0323}"),
+                                             ('\u{1E43}', "m\u{0323}"), ('\u{1E44}', "N\u{0307}"),
+                                             ('\u{1E45}', "n\u{0307}"), ('\u{1E46}', "N\u{0323}"),
+                                             ('\u{1E47}', "n\u{0323}"), ('\u{1E48}', "N\u{0331}"),
+                                             ('\u{1E49}', "n\u{0331}"), ('\u{1E4A}', "N\u{032D}"),
+                                             ('\u{1E4B}', "n\u{032D}"), ('\u{1E4C}', "O\u{0303}\u{0301}"),
+                                             ('\u{1E4D}', "o\u{0303}\u{0301}"),
+                                             ('\u{1E4E}', "O\u{0303}\u{0308}"),
+                                             ('\u{1E4F}', "o\u{0303}\u{0308}"),
+                                             ('\u{1E50}', "O\u{0304}\u{0300}"),
+                                             ('\u{1E51}', "o\u{0304}\u{0300}"),
+                                             ('\u{1E52}', "O\u{0304}\u{0301}"),
+                                             ('\u{1E53}', "o\u{0304}\u{0301}"), ('\u{1E54}', "P\u{0301}"),
+                                             ('\u{1E55}', "p\u{0301}"), ('\u{1E56}', "P\u{0307}"),
+                                             ('\u{1E57}', "p\u{0307}"), ('\u{1E58}', "R\u{0307}"),
+                                             ('\u{1E59}', "r\u{0307}"), ('\u{1E5A}', "R\u{0323}"),
+                                             ('\u{1E5B}', "r\u{0323}"), ('\u{1E5C}', "R\u{0323}\u{0304}"),
+                                             ('\u{1E5D}', "r\u{0323}\u{0304}"), ('\u{1E5E}', "R\u{0331}"),
+                                             ('\u{1E5F}', "r\u{0331}"), ('\u{1E60}', "S\u{0307}"),
+                                             ('\u{1E61}', "s\u{0307}"), ('\u{1E62}', "S\u{0323}"),
+                                             ('\u{1E63}', "s\u{0323}"), ('\u{1E64}', "S\u{0301}\u{0307}"),
+                                             ('\u{1E65}', "s\u{0301}\u{0307}"),
+                                             ('\u{1E66}', "S\u{030C}\u{0307}"),
+                                             ('\u{1E67}', "s\u{030C}\u{0307}"),
+                                             ('\u{1E68}', "S\u{0323}\u{0307}"),
+                                             ('\u{1E69}', "s\u{0323}\u{0307}"), ('\u{1E6A}', "T\u{0307}"),
+                                             ('\u{1E6B}', "t\u{0307}"), ('\u{1E6C}', "T\u{0323}"),
+                                             ('\u{1E6D}', "t\u{0323}"), ('\u{1E6E}', "T\u{0331}"),
+                                             ('\u{1E6F}', "t\u{0331}"), ('\u{1E70}', "T\u{032D}"),
+                                             ('\u{1E71}', "t\u{032D}"), ('\u{1E72}', "U\u{0324}"),
+                                             ('\u{1E73}', "u\u{0324}"), ('\u{1E74}', "U\u{0330}"),
+                                             ('\u{1E75}', "u\u{0330}"), ('\u{1E76}', "U\u{032D}"),
+                                             ('\u{1E77}', "u\u{032D}"), ('\u{1E78}', "U\u{0303}\u{0301}"),
+                                             ('\u{1E79}', "u\u{0303}\u{0301}"),
+                                             ('\u{1E7A}', "U\u{0304}\u{0308}"),
+                                             ('\u{1E7B}', "u\u{0304}\u{0308}"), ('\u{1E7C}', "V\u{0303}"),
+                                             ('\u{1E7D}', "v\u{0303}"), ('\u{1E7E}', "V\u{0323}"),
+                                             ('\u{1E7F}', "v\u{0323}"), ('\u{1E80}', "W\u{0300}"),
+                                             ('\u{1E81}', "w\u{0300}"), ('\u{1E82}', "W\u{0301}"),
+                                             ('\u{1E83}', "w\u{0301}"), ('\u{1E84}', "W\u{0308}"),
+                                             ('\u{1E85}', "w\u{0308}"), ('\u{1E86}', "W\u{0307}"),
+                                             ('\u{1E87}', "w\u{0307}"), ('\u{1E88}', "W\u{0323}"),
+                                             ('\u{1E89}', "w\u{0323}"), ('\u{1E8A}', "X\u{0307}"),
+                                             ('\u{1E8B}', "x\u{0307}"), ('\u{1E8C}', "X\u{0308}"),
+                                             ('\u{1E8D}', "x\u{0308}"), ('\u{1E8E}', "Y\u{0307}"),
+                                             ('\u{1E8F}', "y\u{0307}"), ('\u{1E90}', "Z\u{0302}"),
+                                             ('\u{1E91}', "z\u{0302}"), ('\u{1E92}', "Z\u{0323}"),
+                                             ('\u{1E93}', "z\u{0323}"), ('\u{1E94}', "Z\u{0331}"),
+                                             ('\u{1E95}', "z\u{0331}"), ('\u{1E96}', "h\u{0331}"),
+                                             ('\u{1E97}', "t\u{0308}"), ('\u{1E98}', "w\u{030A}"),
+                                             ('\u{1E99}', "y\u{030A}"), ('\u{1EA0}', "A\u{0323}"),
+                                             ('\u{1EA1}', "a\u{0323}"), ('\u{1EA2}', "A\u{0309}"),
+                                             ('\u{1EA3}', "a\u{0309}"), ('\u{1EA4}', "A\u{0302}\u{0301}"),
+                                             ('\u{1EA5}', "a\u{0302}\u{0301}"),
+                                             ('\u{1EA6}', "A\u{0302}\u{0300}"),
+                                             ('\u{1EA7}', "a\u{0302}\u{0300}"),
+                                             ('\u{1EA8}', "A\u{0302}\u{0309}"),
+                                             ('\u{1EA9}', "a\u{0302}\u{0309}"),
+                                             ('\u{1EAA}', "A\u{0302}\u{0303}"),
+                                             ('\u{1EAB}', "a\u{0302}\u{0303}"),
+                                             ('\u{1EAC}', "A\u{0323}\u{0302}"),
+                                             ('\u{1EAD}', "a\u{0323}\u{0302}"),
+                                             ('\u{1EAE}', "A\u{0306}\u{0301}"),
+                                             ('\u{1EAF}', "a\u{0306}\u{0301}"),
+                                             ('\u{1EB0}', "A\u{0306}\u{0300}"),
+                                             ('\u{1EB1}', "a\u{0306}\u{0300}"),
+                                             ('\u{1EB2}', "A\u{0306}\u{0309}"),
+                                             ('\u{1EB3}', "a\u{0306}\u{0309}"),
+                                             ('\u{1EB4}', "A\u{0306}\u{0303}"),
+                                             ('\u{1EB5}', "a\u{0306}\u{0303}"),
+                                             ('\u{1EB6}', "A\u{0323}\u{0306}"),
+                                             ('\u{1EB7}', "a\u{0323}\u{0306}"), ('\u{1EB8}', "E\u{0323}"),
+                                             ('\u{1EB9}', "e\u{0323}"), ('\u{1EBA}', "E\u{0309}"),
+                                             ('\u{1EBB}', "e\u{0309}"), ('\u{1EBC}', "E\u{0303}"),
+                                             ('\u{1EBD}', "e\u{0303}"), ('\u{1EBE}', "E\u{0302}\u{0301}"),
+                                             ('\u{1EBF}', "e\u{0302}\u{0301}"),
+                                             ('\u{1EC0}', "E\u{0302}\u{0300}"),
+                                             ('\u{1EC1}', "e\u{0302}\u{0300}"),
+                                             ('\u{1EC2}', "E\u{0302}\u{0309}"),
+                                             ('\u{1EC3}', "e\u{0302}\u{0309}"),
+                                             ('\u{1EC4}', "E\u{0302}\u{0303}"),
+                                             ('\u{1EC5}', "e\u{0302}\u{0303}"),
+                                             ('\u{1EC6}', "E\u{0323}\u{0302}"),
+                                             ('\u{1EC7}', "e\u{0323}\u{0302}"), ('\u{1EC8}', "I\u{0309}"),
+                                             ('\u{1EC9}', "i\u{0309}"), ('\u{1ECA}', "I\u{0323}"),
+                                             ('\u{1ECB}', "i\u{0323}"), ('\u{1ECC}', "O\u{0323}"),
+                                             ('\u{1ECD}', "o\u{0323}"), ('\u{1ECE}', "O\u{0309}"),
+                                             ('\u{1ECF}', "o\u{0309}"), ('\u{1ED0}', "O\u{0302}\u{0301}"),
+                                             ('\u{1ED1}', "o\u{0302}\u{0301}"),
+                                             ('\u{1ED2}', "O\u{0302}\u{0300}"),
+                                             ('\u{1ED3}', "o\u{0302}\u{0300}"),
+                                             ('\u{1ED4}', "O\u{0302}\u{0309}"),
+                                             ('\u{1ED5}', "o\u{0302}\u{0309}"),
+                                             ('\u{1ED6}', "O\u{0302}\u{0303}"),
+                                             ('\u{1ED7}', "o\u{0302}\u{0303}"),
+                                             ('\u{1ED8}', "O\u{0323}\u{0302}"),
+                                             ('\u{1ED9}', "o\u{0323}\u{0302}"),
+                                             ('\u{1EDA}', "O\u{031B}\u{0301}"),
+                                             ('\u{1EDB}', "o\u{031B}\u{0301}"),
+                                             ('\u{1EDC}', "O\u{031B}\u{0300}"),
+                                             ('\u{1EDD}', "o\u{031B}\u{0300}"),
+                                             ('\u{1EDE}', "O\u{031B}\u{0309}"),
+                                             ('\u{1EDF}', "o\u{031B}\u{0309}"),
+                                             ('\u{1EE0}', "O\u{031B}\u{0303}"),
+                                             ('\u{1EE1}', "o\u{031B}\u{0303}"),
+                                             ('\u{1EE2}', "O\u{031B}\u{0323}"),
+                                             ('\u{1EE3}', "o\u{031B}\u{0323}"), ('\u{1EE4}', "U\u{0323}"),
+                                             ('\u{1EE5}', "u\u{0323}"), ('\u{1EE6}', "U\u{0309}"),
+                                             ('\u{1EE7}', "u\u{0309}"), ('\u{1EE8}', "U\u{031B}\u{0301}"),
+                                             ('\u{1EE9}', "u\u{031B}\u{0301}"),
+                                             ('\u{1EEA}', "U\u{031B}\u{0300}"),
+                                             ('\u{1EEB}', "u\u{031B}\u{0300}"),
+                                             ('\u{1EEC}', "U\u{031B}\u{0309}"),
+                                             ('\u{1EED}', "u\u{031B}\u{0309}"),
+                                             ('\u{1EEE}', "U\u{031B}\u{0303}"),
+                                             ('\u{1EEF}', "u\u{031B}\u{0303}"),
+                                             ('\u{1EF0}', "U\u{031B}\u{0323}"),
+                                             ('\u{1EF1}', "u\u{031B}\u{0323}"), ('\u{1EF2}', "Y\u{0300}"),
+                                             ('\u{1EF3}', "y\u{0300}"), ('\u{1EF4}', "Y\u{0323}"),
+                                             ('\u{1EF5}', "y\u{0323}"), ('\u{1EF6}', "Y\u{0309}"),
+                                             ('\u{1EF7}', "y\u{0309}"), ('\u{1EF8}', "Y\u{0303}"),
+                                             ('\u{1EF9}', "y\u{0303}"), ('\u{212B}', "A\u{030A}"),
+                                             ('\u{2260}', "=\u{0338}"), ('\u{226E}', "<\u{0338}"),
+                                             ('\u{226F}', ">\u{0338}")];
+
+/// A constant for the computed precomposed characters.
+///
+/// Every glyph is derived from the base character and the combining marks of its
+/// decomposition, which are looked up in the sets they belong to.
+///
+/// ## `U+01CE` `"ǎ"`
+///
+/// ```text
+/// ░░█░░█░░
+/// ░░░██░░░
+/// ░████░░░
+/// ░░░░██░░
+/// ░█████░░
+/// ██░░██░░
+/// ░███░██░
+/// ░░░░░░░░
+/// ```
+pub const DECOMPOSED_FONTS: DecomposedFonts = DecomposedFonts;
+
+/// Strong-typed wrapper for the computed precomposed characters.
+#[derive(Clone, Copy, PartialEq)]
+pub struct DecomposedFonts;
+
+impl DecomposedFonts {
+    /// Create a new collection of precomposed characters.
+    pub fn new() -> Self {
+        DecomposedFonts
+    }
+}
+
+impl fmt::Debug for DecomposedFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(DECOMPOSED_FONTS))
+    }
+}
+
+impl Default for DecomposedFonts {
+    fn default() -> Self {
+        DecomposedFonts::new()
+    }
+}
+
+impl UnicodeFonts for DecomposedFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        let decomposition = decompose(key)?;
+        let base = decomposition.chars().next()?;
+        let marks = &decomposition[base.len_utf8()..];
+        Some(COMBINING_FONTS.overlay(BASIC_LEGACY[base as usize], marks))
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        self.get(key).map(|glyph| FontUnicode(key, glyph))
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, (_, font)) in self.to_vec().iter().enumerate() {
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        DECOMPOSITIONS.iter()
+                      .filter_map(|&(key, _)| self.get_font(key))
+                      .map(|font| (font.char(), font))
+                      .collect()
+    }
+}
+
+/// Returns the canonical decomposition of `c`, a base character from `BASIC_LEGACY`
+/// followed by combining marks, or `None` if `c` isn't a precomposed character with a
+/// base that can be drawn.
+///
+/// ```
+/// use font8x8::unicode::decompose;
+///
+/// assert_eq!(decompose('ǎ'), Some("a\u{030C}"));
+/// assert_eq!(decompose('ṏ'), Some("o\u{0303}\u{0308}"));
+/// assert_eq!(decompose('a'), None);
+/// assert_eq!(decompose('ł'), None);
+/// ```
+pub fn decompose(c: char) -> Option<&'static str> {
+    DECOMPOSITIONS.binary_search_by_key(&c, |&(key, _)| key)
+                  .ok()
+                  .map(|idx| DECOMPOSITIONS[idx].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::BASIC_FONTS;
+
+    #[test]
+    fn decomposed_set_implements_default_trait_with_method_new() {
+        let decomposed_set: DecomposedFonts = Default::default();
+        assert_eq!(decomposed_set, DecomposedFonts::new());
+    }
+
+    #[test]
+    fn decomposed_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(DECOMPOSED_FONTS, DecomposedFonts::new());
+    }
+
+    #[test]
+    fn decompositions_are_sorted_and_start_with_a_basic_character() {
+        for pair in DECOMPOSITIONS.windows(2) {
+            assert!(pair[0].0 < pair[1].0, "{:?}", pair[1]);
+        }
+        for &(key, decomposition) in DECOMPOSITIONS.iter() {
+            let mut chars = decomposition.chars();
+            assert!(BASIC_FONTS.get(chars.next().unwrap()).is_some(), "{:?}", key);
+            assert!(chars.all(|mark| COMBINING_FONTS.get(mark).is_some()), "{:?}", key);
+        }
+    }
+
+    #[test]
+    fn decomposed_fonts_draw_the_marks_onto_the_base() {
+        let a = BASIC_LEGACY['a' as usize];
+        assert_eq!(DECOMPOSED_FONTS.get('ǎ'), Some(COMBINING_FONTS.overlay(a, "\u{030C}")));
+        let k = BASIC_LEGACY['k' as usize];
+        assert_eq!(DECOMPOSED_FONTS.get('ḱ'), Some(COMBINING_FONTS.overlay(k, "\u{0301}")));
+        assert_eq!(DECOMPOSED_FONTS.get('k'), None);
+        assert_eq!(DECOMPOSED_FONTS.get('ø'), None);
+    }
+}
@@ -0,0 +1,931 @@
+//! Dingbats. `U+2700` - `U+27BF`
+use super::unicode::{FontUnicode, UnicodeFonts};
+use core::fmt;
+
+/// A constant `[FontUnicode; 57]`, for Dingbats fonts (`U+2700` - `U+27BF`).
+pub const DINGBAT_UNICODE: [FontUnicode; 57] = [FontUnicode('\u{2702}', [0x23, 0x13, 0x0C, 0x08, 0x0C, 0x13, 0x23, 0x00]),
+                                                FontUnicode('\u{2705}', [0x7F, 0x5F, 0x4F, 0x66, 0x71, 0x7B, 0x7F, 0x00]),
+                                                FontUnicode('\u{2708}', [0x08, 0x08, 0x1C, 0x7F, 0x08, 0x08, 0x1C, 0x00]),
+                                                FontUnicode('\u{2709}', [0x00, 0xFF, 0xC3, 0xA5, 0x99, 0x81, 0xFF, 0x00]),
+                                                FontUnicode('\u{270B}', [0x14, 0xB6, 0xB6, 0x7E, 0x7E, 0x3C, 0x1C, 0x00]),
+                                                FontUnicode('\u{270C}', [0x11, 0x0A, 0x0A, 0x1E, 0x3E, 0x3E, 0x1C, 0x00]),
+                                                FontUnicode('\u{270E}', [0xC0, 0xE0, 0x70, 0x38, 0x1C, 0x0E, 0x03, 0x01]),
+                                                FontUnicode('\u{270F}', [0x40, 0xA0, 0x50, 0x28, 0x14, 0x06, 0x03, 0x01]),
+                                                FontUnicode('\u{2712}', [0xC0, 0xE0, 0xF0, 0x78, 0x1C, 0x06, 0x05, 0x01]),
+                                                FontUnicode('\u{2713}', [0x40, 0x20, 0x20, 0x10, 0x09, 0x0A, 0x04, 0x00]),
+                                                FontUnicode('\u{2714}', [0xC0, 0x60, 0x60, 0x30, 0x1B, 0x0E, 0x04, 0x00]),
+                                                FontUnicode('\u{2715}', [0x00, 0x22, 0x14, 0x08, 0x14, 0x22, 0x00, 0x00]),
+                                                FontUnicode('\u{2716}', [0x63, 0x77, 0x3E, 0x1C, 0x3E, 0x77, 0x63, 0x00]),
+                                                FontUnicode('\u{2717}', [0x40, 0x21, 0x12, 0x0C, 0x0C, 0x12, 0x21, 0x00]),
+                                                FontUnicode('\u{2718}', [0xC0, 0x63, 0x36, 0x1C, 0x1C, 0x36, 0x63, 0x00]),
+                                                FontUnicode('\u{271A}', [0x1C, 0x1C, 0x7F, 0x7F, 0x7F, 0x1C, 0x1C, 0x00]),
+                                                FontUnicode('\u{271D}', [0x08, 0x08, 0x7F, 0x08, 0x08, 0x08, 0x08, 0x08]),
+                                                FontUnicode('\u{2720}', [0x1C, 0x49, 0x49, 0x7F, 0x49, 0x49, 0x1C, 0x00]),
+                                                FontUnicode('\u{2721}', [0x08, 0x7F, 0x22, 0x41, 0x22, 0x7F, 0x08, 0x00]),
+                                                FontUnicode('\u{2726}', [0x08, 0x08, 0x1C, 0x7F, 0x1C, 0x08, 0x08, 0x00]),
+                                                FontUnicode('\u{2727}', [0x08, 0x08, 0x14, 0x63, 0x14, 0x08, 0x08, 0x00]),
+                                                FontUnicode('\u{272A}', [0x1C, 0x36, 0x55, 0x41, 0x63, 0x2A, 0x1C, 0x00]),
+                                                FontUnicode('\u{2731}', [0x08, 0x2A, 0x1C, 0x7F, 0x1C, 0x2A, 0x08, 0x00]),
+                                                FontUnicode('\u{2733}', [0x49, 0x2A, 0x1C, 0x7F, 0x1C, 0x2A, 0x49, 0x00]),
+                                                FontUnicode('\u{2734}', [0x49, 0x3E, 0x3E, 0x7F, 0x3E, 0x3E, 0x49, 0x00]),
+                                                FontUnicode('\u{2736}', [0x08, 0x5D, 0x3E, 0x1C, 0x3E, 0x5D, 0x08, 0x00]),
+                                                FontUnicode('\u{273F}', [0x1C, 0x5D, 0x77, 0x6B, 0x77, 0x5D, 0x1C, 0x00]),
+                                                FontUnicode('\u{2740}', [0x14, 0x55, 0x22, 0x6B, 0x22, 0x55, 0x14, 0x00]),
+                                                FontUnicode('\u{2744}', [0x49, 0x2A, 0x1C, 0xEF, 0x1C, 0x2A, 0x49, 0x00]),
+                                                FontUnicode('\u{2747}', [0x08, 0x22, 0x10, 0x6B, 0x04, 0x22, 0x08, 0x00]),
+                                                FontUnicode('\u{274C}', [0xC3, 0xE7, 0x7E, 0x3C, 0x7E, 0xE7, 0xC3, 0x00]),
+                                                FontUnicode('\u{274E}', [0xFF, 0xBD, 0xDB, 0xE7, 0xDB, 0xBD, 0xFF, 0x00]),
+                                                FontUnicode('\u{2753}', [0x3E, 0x77, 0x70, 0x3C, 0x1C, 0x00, 0x1C, 0x00]),
+                                                FontUnicode('\u{2754}', [0x3E, 0x41, 0x2E, 0x14, 0x14, 0x00, 0x1C, 0x00]),
+                                                FontUnicode('\u{2755}', [0x1C, 0x14, 0x14, 0x14, 0x1C, 0x00, 0x1C, 0x00]),
+                                                FontUnicode('\u{2757}', [0x1C, 0x1C, 0x1C, 0x1C, 0x1C, 0x00, 0x1C, 0x00]),
+                                                FontUnicode('\u{2763}', [0x36, 0x7F, 0x3E, 0x1C, 0x08, 0x00, 0x08, 0x1C]),
+                                                FontUnicode('\u{2764}', [0x36, 0x7F, 0x7F, 0x7F, 0x3E, 0x1C, 0x08, 0x00]),
+                                                FontUnicode('\u{2765}', [0x0E, 0x1F, 0x7E, 0xFC, 0x7E, 0x1F, 0x0E, 0x00]),
+                                                FontUnicode('\u{2776}', [0x3C, 0x76, 0xF3, 0xF7, 0xF7, 0xE3, 0x7E, 0x3C]),
+                                                FontUnicode('\u{2777}', [0x3C, 0x72, 0xEF, 0xF7, 0xFB, 0xE3, 0x7E, 0x3C]),
+                                                FontUnicode('\u{2778}', [0x3C, 0x72, 0xEF, 0xF7, 0xEF, 0xF3, 0x7E, 0x3C]),
+                                                FontUnicode('\u{2779}', [0x3C, 0x6A, 0xEB, 0xE3, 0xEF, 0xEF, 0x7E, 0x3C]),
+                                                FontUnicode('\u{277A}', [0x3C, 0x62, 0xFB, 0xF3, 0xEF, 0xF3, 0x7E, 0x3C]),
+                                                FontUnicode('\u{277B}', [0x3C, 0x66, 0xFB, 0xE3, 0xEB, 0xE3, 0x7E, 0x3C]),
+                                                FontUnicode('\u{277C}', [0x3C, 0x62, 0xEF, 0xF7, 0xF7, 0xF7, 0x7E, 0x3C]),
+                                                FontUnicode('\u{277D}', [0x3C, 0x62, 0xEB, 0xE3, 0xEB, 0xE3, 0x7E, 0x3C]),
+                                                FontUnicode('\u{277E}', [0x3C, 0x62, 0xEB, 0xE3, 0xEF, 0xF3, 0x7E, 0x3C]),
+                                                FontUnicode('\u{277F}', [0x3C, 0x7E, 0xC5, 0x54, 0x55, 0xC5, 0x7E, 0x3C]),
+                                                FontUnicode('\u{2794}', [0x00, 0x10, 0x30, 0x7F, 0x7F, 0x30, 0x10, 0x00]),
+                                                FontUnicode('\u{2795}', [0x1C, 0x1C, 0x7F, 0x7F, 0x7F, 0x1C, 0x1C, 0x00]),
+                                                FontUnicode('\u{2796}', [0x00, 0x00, 0x7F, 0x7F, 0x7F, 0x00, 0x00, 0x00]),
+                                                FontUnicode('\u{2797}', [0x1C, 0x1C, 0x00, 0x7F, 0x00, 0x1C, 0x1C, 0x00]),
+                                                FontUnicode('\u{279C}', [0x10, 0x30, 0x7E, 0x7F, 0x7E, 0x30, 0x10, 0x00]),
+                                                FontUnicode('\u{27A1}', [0x10, 0x30, 0x7F, 0xFF, 0x7F, 0x30, 0x10, 0x00]),
+                                                FontUnicode('\u{27B0}', [0x00, 0x00, 0x0C, 0x12, 0xAD, 0x65, 0x02, 0x00]),
+                                                FontUnicode('\u{27BF}', [0x00, 0x00, 0x66, 0x99, 0xA5, 0x99, 0x66, 0x00])];
+
+/// A convenient constant for Dingbats fonts (`U+2700` - `U+27BF`), that implements the `UnicodeFonts` trait.
+///
+/// ## `DINGBAT_UNICODE[0]`: `U+2702` `"✂"`
+///
+/// ```text
+/// ██░░░█░░
+/// ██░░█░░░
+/// ░░██░░░░
+/// ░░░█░░░░
+/// ░░██░░░░
+/// ██░░█░░░
+/// ██░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[1]`: `U+2705` `"✅"`
+///
+/// ```text
+/// ███████░
+/// █████░█░
+/// ████░░█░
+/// ░██░░██░
+/// █░░░███░
+/// ██░████░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[2]`: `U+2708` `"✈"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ███████░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[3]`: `U+2709` `"✉"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ████████
+/// ██░░░░██
+/// █░█░░█░█
+/// █░░██░░█
+/// █░░░░░░█
+/// ████████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[4]`: `U+270B` `"✋"`
+///
+/// ```text
+/// ░░█░█░░░
+/// ░██░██░█
+/// ░██░██░█
+/// ░██████░
+/// ░██████░
+/// ░░████░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[5]`: `U+270C` `"✌"`
+///
+/// ```text
+/// █░░░█░░░
+/// ░█░█░░░░
+/// ░█░█░░░░
+/// ░████░░░
+/// ░█████░░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[6]`: `U+270E` `"✎"`
+///
+/// ```text
+/// ░░░░░░██
+/// ░░░░░███
+/// ░░░░███░
+/// ░░░███░░
+/// ░░███░░░
+/// ░███░░░░
+/// ██░░░░░░
+/// █░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[7]`: `U+270F` `"✏"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░░█░█
+/// ░░░░█░█░
+/// ░░░█░█░░
+/// ░░█░█░░░
+/// ░██░░░░░
+/// ██░░░░░░
+/// █░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[8]`: `U+2712` `"✒"`
+///
+/// ```text
+/// ░░░░░░██
+/// ░░░░░███
+/// ░░░░████
+/// ░░░████░
+/// ░░███░░░
+/// ░██░░░░░
+/// █░█░░░░░
+/// █░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[9]`: `U+2713` `"✓"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// █░░█░░░░
+/// ░█░█░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[10]`: `U+2714` `"✔"`
+///
+/// ```text
+/// ░░░░░░██
+/// ░░░░░██░
+/// ░░░░░██░
+/// ░░░░██░░
+/// ██░██░░░
+/// ░███░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[11]`: `U+2715` `"✕"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[12]`: `U+2716` `"✖"`
+///
+/// ```text
+/// ██░░░██░
+/// ███░███░
+/// ░█████░░
+/// ░░███░░░
+/// ░█████░░
+/// ███░███░
+/// ██░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[13]`: `U+2717` `"✗"`
+///
+/// ```text
+/// ░░░░░░█░
+/// █░░░░█░░
+/// ░█░░█░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░█░░█░░░
+/// █░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[14]`: `U+2718` `"✘"`
+///
+/// ```text
+/// ░░░░░░██
+/// ██░░░██░
+/// ░██░██░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░██░██░░
+/// ██░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[15]`: `U+271A` `"✚"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░███░░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░░███░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[16]`: `U+271D` `"✝"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ███████░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[17]`: `U+2720` `"✠"`
+///
+/// ```text
+/// ░░███░░░
+/// █░░█░░█░
+/// █░░█░░█░
+/// ███████░
+/// █░░█░░█░
+/// █░░█░░█░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[18]`: `U+2721` `"✡"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ███████░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ███████░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[19]`: `U+2726` `"✦"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ███████░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[20]`: `U+2727` `"✧"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ██░░░██░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[21]`: `U+272A` `"✪"`
+///
+/// ```text
+/// ░░███░░░
+/// ░██░██░░
+/// █░█░█░█░
+/// █░░░░░█░
+/// ██░░░██░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[22]`: `U+2731` `"✱"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ███████░
+/// ░░███░░░
+/// ░█░█░█░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[23]`: `U+2733` `"✳"`
+///
+/// ```text
+/// █░░█░░█░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ███████░
+/// ░░███░░░
+/// ░█░█░█░░
+/// █░░█░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[24]`: `U+2734` `"✴"`
+///
+/// ```text
+/// █░░█░░█░
+/// ░█████░░
+/// ░█████░░
+/// ███████░
+/// ░█████░░
+/// ░█████░░
+/// █░░█░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[25]`: `U+2736` `"✶"`
+///
+/// ```text
+/// ░░░█░░░░
+/// █░███░█░
+/// ░█████░░
+/// ░░███░░░
+/// ░█████░░
+/// █░███░█░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[26]`: `U+273F` `"✿"`
+///
+/// ```text
+/// ░░███░░░
+/// █░███░█░
+/// ███░███░
+/// ██░█░██░
+/// ███░███░
+/// █░███░█░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[27]`: `U+2740` `"❀"`
+///
+/// ```text
+/// ░░█░█░░░
+/// █░█░█░█░
+/// ░█░░░█░░
+/// ██░█░██░
+/// ░█░░░█░░
+/// █░█░█░█░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[28]`: `U+2744` `"❄"`
+///
+/// ```text
+/// █░░█░░█░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ████░███
+/// ░░███░░░
+/// ░█░█░█░░
+/// █░░█░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[29]`: `U+2747` `"❇"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░█░░░█░░
+/// ░░░░█░░░
+/// ██░█░██░
+/// ░░█░░░░░
+/// ░█░░░█░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[30]`: `U+274C` `"❌"`
+///
+/// ```text
+/// ██░░░░██
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ░██████░
+/// ███░░███
+/// ██░░░░██
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[31]`: `U+274E` `"❎"`
+///
+/// ```text
+/// ████████
+/// █░████░█
+/// ██░██░██
+/// ███░░███
+/// ██░██░██
+/// █░████░█
+/// ████████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[32]`: `U+2753` `"❓"`
+///
+/// ```text
+/// ░█████░░
+/// ███░███░
+/// ░░░░███░
+/// ░░████░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[33]`: `U+2754` `"❔"`
+///
+/// ```text
+/// ░█████░░
+/// █░░░░░█░
+/// ░███░█░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[34]`: `U+2755` `"❕"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[35]`: `U+2757` `"❗"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[36]`: `U+2763` `"❣"`
+///
+/// ```text
+/// ░██░██░░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[37]`: `U+2764` `"❤"`
+///
+/// ```text
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[38]`: `U+2765` `"❥"`
+///
+/// ```text
+/// ░███░░░░
+/// █████░░░
+/// ░██████░
+/// ░░██████
+/// ░██████░
+/// █████░░░
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[39]`: `U+2776` `"❶"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░███░
+/// ██░░████
+/// ███░████
+/// ███░████
+/// ██░░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[40]`: `U+2777` `"❷"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░███░
+/// ████░███
+/// ███░████
+/// ██░█████
+/// ██░░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[41]`: `U+2778` `"❸"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░███░
+/// ████░███
+/// ███░████
+/// ████░███
+/// ██░░████
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[42]`: `U+2779` `"❹"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░██░
+/// ██░█░███
+/// ██░░░███
+/// ████░███
+/// ████░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[43]`: `U+277A` `"❺"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░██░
+/// ██░█████
+/// ██░░████
+/// ████░███
+/// ██░░████
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[44]`: `U+277B` `"❻"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░██░
+/// ██░█████
+/// ██░░░███
+/// ██░█░███
+/// ██░░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[45]`: `U+277C` `"❼"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░██░
+/// ████░███
+/// ███░████
+/// ███░████
+/// ███░████
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[46]`: `U+277D` `"❽"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░██░
+/// ██░█░███
+/// ██░░░███
+/// ██░█░███
+/// ██░░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[47]`: `U+277E` `"❾"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░██░
+/// ██░█░███
+/// ██░░░███
+/// ████░███
+/// ██░░████
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[48]`: `U+277F` `"❿"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░█░░░██
+/// ░░█░█░█░
+/// █░█░█░█░
+/// █░█░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[49]`: `U+2794` `"➔"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░░██░░
+/// ███████░
+/// ███████░
+/// ░░░░██░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[50]`: `U+2795` `"➕"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░███░░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░░███░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[51]`: `U+2796` `"➖"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[52]`: `U+2797` `"➗"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ███████░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[53]`: `U+279C` `"➜"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░░██░░
+/// ░██████░
+/// ███████░
+/// ░██████░
+/// ░░░░██░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[54]`: `U+27A1` `"➡"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░░██░░
+/// ███████░
+/// ████████
+/// ███████░
+/// ░░░░██░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[55]`: `U+27B0` `"➰"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░█░░█░░░
+/// █░██░█░█
+/// █░█░░██░
+/// ░█░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `DINGBAT_UNICODE[56]`: `U+27BF` `"➿"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██░░██░
+/// █░░██░░█
+/// █░█░░█░█
+/// █░░██░░█
+/// ░██░░██░
+/// ░░░░░░░░
+/// ```
+pub const DINGBAT_FONTS: DingbatFonts = DingbatFonts(DINGBAT_UNICODE);
+/// Strong-typed collection wrapper for [DINGBAT_UNICODE](./constant.DINGBAT_UNICODE.html).
+pub struct DingbatFonts([FontUnicode; 57]);
+
+impl DingbatFonts {
+    /// Create a new collection of `DINGBAT_UNICODE` fonts.
+    pub fn new() -> Self {
+        DingbatFonts(DINGBAT_UNICODE)
+    }
+}
+
+impl fmt::Debug for DingbatFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(DINGBAT_UNICODE))
+    }
+}
+
+impl PartialEq for DingbatFonts {
+    fn eq(&self, other: &DingbatFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for DingbatFonts {
+    fn default() -> Self {
+        DingbatFonts::new()
+    }
+}
+
+impl UnicodeFonts for DingbatFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(57), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dingbat_set_implements_default_trait_with_method_new() {
+        let dingbat_set: DingbatFonts = Default::default();
+        assert_eq!(dingbat_set, DingbatFonts::new());
+    }
+
+    #[test]
+    fn dingbat_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(DINGBAT_FONTS, DingbatFonts::new());
+    }
+
+    #[test]
+    fn dingbat_fonts_constant_wraps_dingbat_unicode_constant() {
+        let dingbat = DingbatFonts::new();
+        assert!(dingbat.0.len() == DINGBAT_UNICODE.len());
+        for (idx, font) in dingbat.0.iter().enumerate() {
+            assert_eq!(font, &DINGBAT_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn dingbat_set_covers_check_marks_and_crosses() {
+        for c in "✓✔✗✘❤".chars() {
+            let font = DINGBAT_FONTS.get(c).unwrap();
+            assert!(font.iter().any(|&row| row != 0));
+        }
+    }
+
+    #[test]
+    fn dingbat_negative_circled_digits_are_all_distinct() {
+        for a in 0x2776..0x2780 {
+            for b in (a + 1)..0x2780 {
+                let a = ::core::char::from_u32(a).unwrap();
+                let b = ::core::char::from_u32(b).unwrap();
+                assert_ne!(DINGBAT_FONTS.get(a).unwrap(), DINGBAT_FONTS.get(b).unwrap());
+            }
+        }
+    }
+}
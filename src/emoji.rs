@@ -0,0 +1,4405 @@
+//! Emoji. `U+1F300` - `U+1FAFF`
+//!
+//! A few hundred of the most common emoji, drawn as monochrome pictograms: smileys,
+//! hands, hearts, weather, plants, food and everyday objects. Faces are filled discs
+//! with their features cut out, and coloured hearts, circles and squares share the
+//! same glyph.
+//!
+//! Emoji followed by variation selectors, skin tone modifiers or zero width joiner
+//! sequences fall back to the glyph of the base emoji: see
+//! [emoji_sequences](./fn.emoji_sequences.html).
+use super::unicode::{FontUnicode, UnicodeFonts};
+use core::fmt;
+
+/// A constant `[FontUnicode; 300]`, for Emoji fonts (`U+1F300` - `U+1FAFF`).
+pub const EMOJI_UNICODE: [FontUnicode; 300] = [FontUnicode('\u{1F300}', [0x3E, 0x41, 0x9D, 0xA5, 0xB5, 0x89, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F302}', [0x10, 0x38, 0x38, 0x38, 0x38, 0x10, 0x10, 0x18]),
+                                               FontUnicode('\u{1F308}', [0x00, 0x3C, 0x42, 0x99, 0xA5, 0xA5, 0x00, 0x00]),
+                                               FontUnicode('\u{1F30A}', [0x1C, 0x22, 0x19, 0x05, 0xC5, 0x73, 0xFE, 0x00]),
+                                               FontUnicode('\u{1F30B}', [0x22, 0x14, 0x00, 0x3C, 0x3C, 0x7E, 0x7E, 0xFF]),
+                                               FontUnicode('\u{1F30D}', [0x3C, 0x5A, 0x9D, 0xE9, 0xE1, 0xC5, 0x46, 0x3C]),
+                                               FontUnicode('\u{1F310}', [0x3C, 0x5A, 0xA5, 0xFF, 0xA5, 0xA5, 0x5A, 0x3C]),
+                                               FontUnicode('\u{1F311}', [0x3C, 0x42, 0x81, 0x81, 0x81, 0x81, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F312}', [0x3C, 0x62, 0xC1, 0xC1, 0xC1, 0xC1, 0x62, 0x3C]),
+                                               FontUnicode('\u{1F313}', [0x3C, 0x72, 0xF1, 0xF1, 0xF1, 0xF1, 0x72, 0x3C]),
+                                               FontUnicode('\u{1F314}', [0x3C, 0x7A, 0xFD, 0xFD, 0xFD, 0xFD, 0x7A, 0x3C]),
+                                               FontUnicode('\u{1F315}', [0x3C, 0x7E, 0xFF, 0xFF, 0xFF, 0xFF, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F316}', [0x3C, 0x5E, 0xBF, 0xBF, 0xBF, 0xBF, 0x5E, 0x3C]),
+                                               FontUnicode('\u{1F317}', [0x3C, 0x4E, 0x8F, 0x8F, 0x8F, 0x8F, 0x4E, 0x3C]),
+                                               FontUnicode('\u{1F318}', [0x3C, 0x46, 0x83, 0x83, 0x83, 0x83, 0x46, 0x3C]),
+                                               FontUnicode('\u{1F319}', [0x1C, 0x06, 0x03, 0x03, 0x03, 0x03, 0x06, 0x1C]),
+                                               FontUnicode('\u{1F31A}', [0x3C, 0x7E, 0xDB, 0xFF, 0xFF, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F31B}', [0x1C, 0x06, 0x0B, 0x07, 0x07, 0x0B, 0x06, 0x1C]),
+                                               FontUnicode('\u{1F31C}', [0x38, 0x60, 0xD0, 0xE0, 0xE0, 0xD0, 0x60, 0x38]),
+                                               FontUnicode('\u{1F31D}', [0x3C, 0x42, 0xA5, 0x81, 0xA5, 0x99, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F31E}', [0x99, 0x7E, 0x7E, 0xDB, 0xFF, 0x66, 0x7E, 0x99]),
+                                               FontUnicode('\u{1F31F}', [0x89, 0x1C, 0x7F, 0x3E, 0x1C, 0x36, 0x22, 0x81]),
+                                               FontUnicode('\u{1F320}', [0x20, 0x70, 0xF9, 0x72, 0x54, 0x0A, 0x05, 0x02]),
+                                               FontUnicode('\u{1F321}', [0x18, 0x24, 0x24, 0x3C, 0x3C, 0x7E, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F324}', [0x15, 0x0E, 0x1F, 0x6E, 0x8D, 0x84, 0xF8, 0x00]),
+                                               FontUnicode('\u{1F325}', [0x05, 0x06, 0x37, 0x56, 0xC2, 0x81, 0x81, 0x7E]),
+                                               FontUnicode('\u{1F326}', [0x05, 0x16, 0x37, 0xC6, 0x82, 0x7E, 0x92, 0x49]),
+                                               FontUnicode('\u{1F327}', [0x0C, 0x72, 0x81, 0x81, 0x7E, 0x00, 0x92, 0x49]),
+                                               FontUnicode('\u{1F328}', [0x0C, 0x72, 0x81, 0x81, 0x7E, 0x22, 0x88, 0x22]),
+                                               FontUnicode('\u{1F329}', [0x0C, 0x72, 0x81, 0xA1, 0xEE, 0x30, 0x18, 0x08]),
+                                               FontUnicode('\u{1F32A}', [0xFF, 0x7E, 0x7C, 0x78, 0x1C, 0x18, 0x10, 0x08]),
+                                               FontUnicode('\u{1F32B}', [0x00, 0x7E, 0x00, 0xF7, 0x00, 0x7E, 0x00, 0xEF]),
+                                               FontUnicode('\u{1F331}', [0x00, 0x63, 0x77, 0x36, 0x08, 0x08, 0x08, 0x7E]),
+                                               FontUnicode('\u{1F332}', [0x08, 0x1C, 0x3E, 0x1C, 0x3E, 0x7F, 0x08, 0x08]),
+                                               FontUnicode('\u{1F333}', [0x1C, 0x3E, 0x7F, 0x7F, 0x3E, 0x08, 0x08, 0x1C]),
+                                               FontUnicode('\u{1F335}', [0x08, 0x08, 0x09, 0x49, 0x4F, 0x78, 0x08, 0x08]),
+                                               FontUnicode('\u{1F337}', [0x15, 0x1F, 0x1F, 0x0E, 0x04, 0x15, 0x0E, 0x04]),
+                                               FontUnicode('\u{1F338}', [0x08, 0x36, 0x63, 0x2A, 0x14, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{1F339}', [0x1C, 0x2A, 0x36, 0x1C, 0x68, 0x2E, 0x08, 0x08]),
+                                               FontUnicode('\u{1F340}', [0x36, 0x6F, 0x36, 0x08, 0x36, 0x6F, 0x36, 0x08]),
+                                               FontUnicode('\u{1F341}', [0x08, 0x2A, 0x3E, 0x7F, 0x3E, 0x49, 0x08, 0x08]),
+                                               FontUnicode('\u{1F34E}', [0x10, 0x08, 0x36, 0x7F, 0x7F, 0x7F, 0x3E, 0x14]),
+                                               FontUnicode('\u{1F352}', [0x20, 0x30, 0x28, 0x44, 0x66, 0x77, 0x77, 0x22]),
+                                               FontUnicode('\u{1F354}', [0x3C, 0x7E, 0x00, 0xFF, 0x7E, 0x00, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F355}', [0xFF, 0x85, 0x42, 0x52, 0x44, 0x24, 0x28, 0x18]),
+                                               FontUnicode('\u{1F377}', [0x7E, 0x42, 0x7E, 0x3C, 0x18, 0x18, 0x18, 0x7E]),
+                                               FontUnicode('\u{1F37A}', [0x1E, 0x3F, 0xF1, 0x95, 0x95, 0xF5, 0x11, 0x1E]),
+                                               FontUnicode('\u{1F381}', [0x36, 0x08, 0x7F, 0x7F, 0x08, 0x77, 0x77, 0x77]),
+                                               FontUnicode('\u{1F382}', [0x2A, 0x2A, 0x3E, 0x7F, 0x55, 0x7F, 0x41, 0x7F]),
+                                               FontUnicode('\u{1F388}', [0x1C, 0x3E, 0x36, 0x3E, 0x1C, 0x08, 0x10, 0x08]),
+                                               FontUnicode('\u{1F389}', [0x49, 0x22, 0x89, 0x28, 0x0E, 0x4F, 0x1F, 0x40]),
+                                               FontUnicode('\u{1F393}', [0x18, 0x7E, 0xFF, 0x7E, 0x5E, 0x5E, 0x40, 0x00]),
+                                               FontUnicode('\u{1F3A4}', [0x1C, 0x3E, 0x3E, 0x1C, 0x5D, 0x22, 0x1C, 0x08]),
+                                               FontUnicode('\u{1F3A7}', [0x3C, 0x42, 0x81, 0x81, 0xC3, 0xC3, 0xC3, 0x00]),
+                                               FontUnicode('\u{1F3AE}', [0x00, 0x7E, 0xA5, 0xD1, 0xA5, 0xFF, 0xC3, 0x81]),
+                                               FontUnicode('\u{1F3B5}', [0x78, 0x48, 0x48, 0x48, 0x4E, 0x6F, 0x76, 0x60]),
+                                               FontUnicode('\u{1F3B6}', [0xFC, 0x84, 0xFC, 0x84, 0x84, 0xE7, 0xE7, 0x42]),
+                                               FontUnicode('\u{1F3C6}', [0xFF, 0xBD, 0xBD, 0x7E, 0x3C, 0x18, 0x3C, 0x7E]),
+                                               FontUnicode('\u{1F3E0}', [0x08, 0x5C, 0x7E, 0x7F, 0x62, 0x6A, 0x6A, 0xFE]),
+                                               FontUnicode('\u{1F41F}', [0x00, 0x18, 0x3D, 0x57, 0x7F, 0x7D, 0x18, 0x00]),
+                                               FontUnicode('\u{1F426}', [0x0C, 0x1A, 0x0F, 0x7E, 0xFE, 0x7C, 0x28, 0x6C]),
+                                               FontUnicode('\u{1F431}', [0x81, 0xC3, 0xDB, 0xFF, 0xFF, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F436}', [0xC3, 0xE7, 0xBD, 0x5A, 0x7E, 0x66, 0x3C, 0x18]),
+                                               FontUnicode('\u{1F440}', [0x66, 0x99, 0x99, 0xBB, 0xBB, 0x99, 0x66, 0x00]),
+                                               FontUnicode('\u{1F442}', [0x1C, 0x22, 0x49, 0x55, 0x51, 0x4A, 0x22, 0x1C]),
+                                               FontUnicode('\u{1F443}', [0x08, 0x08, 0x08, 0x14, 0x14, 0x22, 0x55, 0x22]),
+                                               FontUnicode('\u{1F444}', [0x00, 0x36, 0x55, 0x7F, 0x41, 0x22, 0x1C, 0x00]),
+                                               FontUnicode('\u{1F446}', [0x04, 0x04, 0x04, 0x34, 0xFE, 0xFB, 0xFE, 0x7C]),
+                                               FontUnicode('\u{1F447}', [0x7C, 0xFE, 0xFB, 0xFE, 0x34, 0x04, 0x04, 0x04]),
+                                               FontUnicode('\u{1F448}', [0x00, 0x78, 0xFA, 0x7F, 0x78, 0x38, 0x38, 0x30]),
+                                               FontUnicode('\u{1F449}', [0x00, 0x1E, 0x5F, 0xFE, 0x1E, 0x1C, 0x1C, 0x0C]),
+                                               FontUnicode('\u{1F44A}', [0x00, 0x7E, 0xAB, 0xFF, 0xFD, 0x7D, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F44B}', [0x01, 0x54, 0x55, 0x54, 0x7D, 0xFC, 0xF8, 0x78]),
+                                               FontUnicode('\u{1F44C}', [0xA8, 0xA8, 0xAE, 0xF9, 0xF9, 0x7E, 0x3C, 0x1C]),
+                                               FontUnicode('\u{1F44D}', [0x10, 0x18, 0x18, 0x7B, 0x3B, 0x7B, 0x3B, 0x1B]),
+                                               FontUnicode('\u{1F44E}', [0x1B, 0x3B, 0x7B, 0x3B, 0x7B, 0x18, 0x18, 0x10]),
+                                               FontUnicode('\u{1F44F}', [0x49, 0x2A, 0x18, 0x3C, 0x3E, 0x7E, 0x7C, 0x78]),
+                                               FontUnicode('\u{1F464}', [0x00, 0x1C, 0x3E, 0x3E, 0x1C, 0x3E, 0x7F, 0x7F]),
+                                               FontUnicode('\u{1F465}', [0x60, 0xF6, 0x6F, 0x6F, 0xF6, 0xEF, 0xEF, 0x0F]),
+                                               FontUnicode('\u{1F47B}', [0x1C, 0x3E, 0x55, 0x55, 0x7F, 0x7F, 0x7F, 0x55]),
+                                               FontUnicode('\u{1F47D}', [0x1C, 0x3E, 0x7F, 0x49, 0x6B, 0x3E, 0x1C, 0x08]),
+                                               FontUnicode('\u{1F47E}', [0x44, 0x28, 0x7C, 0xD6, 0xFF, 0x7D, 0x45, 0xD8]),
+                                               FontUnicode('\u{1F480}', [0x3E, 0x7F, 0x49, 0x49, 0x77, 0x3E, 0x2A, 0x00]),
+                                               FontUnicode('\u{1F493}', [0x41, 0x36, 0x7F, 0x7F, 0x7F, 0x3E, 0x5D, 0x08]),
+                                               FontUnicode('\u{1F494}', [0x36, 0x77, 0x7B, 0x77, 0x36, 0x14, 0x08, 0x00]),
+                                               FontUnicode('\u{1F495}', [0x1B, 0x1F, 0x0E, 0x54, 0xF8, 0x70, 0x20, 0x00]),
+                                               FontUnicode('\u{1F496}', [0xB6, 0x7F, 0x77, 0x63, 0x36, 0x1C, 0x08, 0x00]),
+                                               FontUnicode('\u{1F497}', [0x36, 0x49, 0x55, 0x5D, 0x2A, 0x14, 0x08, 0x00]),
+                                               FontUnicode('\u{1F498}', [0xB6, 0xBF, 0x5F, 0x6F, 0x36, 0x3A, 0x09, 0x00]),
+                                               FontUnicode('\u{1F499}', [0x36, 0x7F, 0x7F, 0x7F, 0x3E, 0x1C, 0x08, 0x00]),
+                                               FontUnicode('\u{1F49A}', [0x36, 0x7F, 0x7F, 0x7F, 0x3E, 0x1C, 0x08, 0x00]),
+                                               FontUnicode('\u{1F49B}', [0x36, 0x7F, 0x7F, 0x7F, 0x3E, 0x1C, 0x08, 0x00]),
+                                               FontUnicode('\u{1F49C}', [0x36, 0x7F, 0x7F, 0x7F, 0x3E, 0x1C, 0x08, 0x00]),
+                                               FontUnicode('\u{1F49D}', [0x36, 0x77, 0x7F, 0x77, 0x36, 0x14, 0x08, 0x00]),
+                                               FontUnicode('\u{1F49E}', [0x1B, 0x1F, 0x0E, 0x64, 0xF3, 0x63, 0x40, 0x00]),
+                                               FontUnicode('\u{1F49F}', [0xFF, 0xC9, 0xC1, 0xC1, 0xE3, 0xF7, 0xFF, 0x00]),
+                                               FontUnicode('\u{1F4A1}', [0x1C, 0x22, 0x41, 0x49, 0x2A, 0x1C, 0x1C, 0x08]),
+                                               FontUnicode('\u{1F4A3}', [0x40, 0xA0, 0x10, 0x1C, 0x3E, 0x3B, 0x7F, 0x3E]),
+                                               FontUnicode('\u{1F4A4}', [0xF0, 0x40, 0x20, 0xFE, 0x08, 0x04, 0x0E, 0x00]),
+                                               FontUnicode('\u{1F4A5}', [0x49, 0x2A, 0x1C, 0xEF, 0x1C, 0x2A, 0x49, 0x00]),
+                                               FontUnicode('\u{1F4A6}', [0x42, 0x62, 0x67, 0xF7, 0xF2, 0xF8, 0x70, 0x00]),
+                                               FontUnicode('\u{1F4A7}', [0x08, 0x08, 0x1C, 0x3E, 0x36, 0x3A, 0x1C, 0x00]),
+                                               FontUnicode('\u{1F4A8}', [0x00, 0x6E, 0xA0, 0x9F, 0x80, 0x77, 0x00, 0x00]),
+                                               FontUnicode('\u{1F4A9}', [0x08, 0x04, 0x1C, 0x3E, 0x2A, 0x7F, 0x41, 0x3E]),
+                                               FontUnicode('\u{1F4AA}', [0x0C, 0x1E, 0x1A, 0x58, 0xF8, 0xFC, 0x7E, 0x3F]),
+                                               FontUnicode('\u{1F4AC}', [0x7E, 0x81, 0x95, 0x81, 0x7E, 0x04, 0x02, 0x00]),
+                                               FontUnicode('\u{1F4AD}', [0x7E, 0x81, 0x81, 0x81, 0x7E, 0x10, 0x20, 0x40]),
+                                               FontUnicode('\u{1F4AF}', [0x00, 0xDD, 0xD5, 0x55, 0xDD, 0x00, 0x3F, 0x7E]),
+                                               FontUnicode('\u{1F4B0}', [0x22, 0x1C, 0x2A, 0x5D, 0x4D, 0x59, 0x6D, 0x3E]),
+                                               FontUnicode('\u{1F4B3}', [0x00, 0xFF, 0x81, 0xFF, 0xFF, 0x81, 0x8D, 0xFF]),
+                                               FontUnicode('\u{1F4BB}', [0x7E, 0x42, 0x42, 0x42, 0x7E, 0x00, 0xFF, 0xFF]),
+                                               FontUnicode('\u{1F4BE}', [0x3F, 0x65, 0x65, 0x7F, 0x41, 0x41, 0x41, 0x7F]),
+                                               FontUnicode('\u{1F4C1}', [0x00, 0x07, 0x79, 0x81, 0x81, 0x81, 0xFF, 0x00]),
+                                               FontUnicode('\u{1F4C2}', [0x00, 0x07, 0x79, 0x41, 0xFD, 0x83, 0x41, 0x3F]),
+                                               FontUnicode('\u{1F4C4}', [0x1F, 0x31, 0x51, 0xFD, 0x81, 0xBD, 0x81, 0xFF]),
+                                               FontUnicode('\u{1F4C5}', [0x22, 0x7F, 0x41, 0x7F, 0x55, 0x7F, 0x55, 0x7F]),
+                                               FontUnicode('\u{1F4C8}', [0x01, 0x41, 0x61, 0x11, 0x0D, 0x03, 0x01, 0xFF]),
+                                               FontUnicode('\u{1F4C9}', [0x01, 0x03, 0x0D, 0x11, 0x61, 0x41, 0x01, 0xFF]),
+                                               FontUnicode('\u{1F4CA}', [0x00, 0x10, 0x14, 0x54, 0x55, 0x55, 0x55, 0xFF]),
+                                               FontUnicode('\u{1F4CC}', [0x3C, 0x18, 0x3C, 0x7E, 0x18, 0x08, 0x08, 0x08]),
+                                               FontUnicode('\u{1F4CD}', [0x1C, 0x3E, 0x36, 0x3E, 0x1C, 0x08, 0x08, 0x08]),
+                                               FontUnicode('\u{1F4CE}', [0x1C, 0x22, 0x2A, 0x2A, 0x2A, 0x2A, 0x22, 0x1C]),
+                                               FontUnicode('\u{1F4D6}', [0x00, 0x63, 0xAD, 0x91, 0xD5, 0x91, 0xDF, 0x08]),
+                                               FontUnicode('\u{1F4DD}', [0x1F, 0x51, 0x1D, 0x51, 0x2D, 0x11, 0x41, 0x7F]),
+                                               FontUnicode('\u{1F4DE}', [0x03, 0x07, 0x03, 0x02, 0x06, 0xCC, 0xF8, 0x70]),
+                                               FontUnicode('\u{1F4E2}', [0x40, 0x70, 0x5D, 0x47, 0x47, 0x5D, 0x78, 0x60]),
+                                               FontUnicode('\u{1F4E6}', [0x3C, 0x52, 0xBF, 0xA9, 0xA9, 0xA1, 0x61, 0x3F]),
+                                               FontUnicode('\u{1F4E7}', [0x00, 0xFF, 0xC3, 0xA5, 0x99, 0x81, 0xFF, 0x00]),
+                                               FontUnicode('\u{1F4F1}', [0x3E, 0x22, 0x22, 0x22, 0x22, 0x3E, 0x36, 0x3E]),
+                                               FontUnicode('\u{1F4F7}', [0x00, 0x1C, 0xFF, 0xB9, 0xA5, 0xA5, 0x99, 0xFF]),
+                                               FontUnicode('\u{1F4FA}', [0x22, 0x14, 0xFF, 0xC1, 0x41, 0xC1, 0xFF, 0x42]),
+                                               FontUnicode('\u{1F500}', [0x20, 0x63, 0xF4, 0x68, 0x68, 0xF4, 0x63, 0x20]),
+                                               FontUnicode('\u{1F504}', [0x3C, 0x42, 0x41, 0xE1, 0x87, 0x82, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F507}', [0x08, 0x0C, 0xAF, 0x4F, 0xAF, 0x0C, 0x08, 0x00]),
+                                               FontUnicode('\u{1F50A}', [0x88, 0x2C, 0x4F, 0xAF, 0x4F, 0x2C, 0x88, 0x00]),
+                                               FontUnicode('\u{1F50B}', [0x00, 0xFF, 0x81, 0xDD, 0xDD, 0x81, 0xFF, 0x00]),
+                                               FontUnicode('\u{1F50C}', [0x24, 0x24, 0x7E, 0x7E, 0x7E, 0x3C, 0x18, 0x18]),
+                                               FontUnicode('\u{1F50D}', [0x1E, 0x21, 0x21, 0x21, 0x21, 0x3E, 0x60, 0xC0]),
+                                               FontUnicode('\u{1F50E}', [0x78, 0x84, 0x84, 0x84, 0x84, 0x7C, 0x06, 0x03]),
+                                               FontUnicode('\u{1F511}', [0x0E, 0x11, 0x15, 0x11, 0x1E, 0x08, 0x38, 0x18]),
+                                               FontUnicode('\u{1F512}', [0x1C, 0x22, 0x22, 0x7F, 0x77, 0x77, 0x7F, 0x00]),
+                                               FontUnicode('\u{1F513}', [0x1C, 0x22, 0x02, 0x7F, 0x77, 0x77, 0x7F, 0x00]),
+                                               FontUnicode('\u{1F514}', [0x08, 0x1C, 0x3E, 0x3E, 0x3E, 0x7F, 0x00, 0x08]),
+                                               FontUnicode('\u{1F515}', [0x09, 0x1E, 0x3E, 0x36, 0x2E, 0x5F, 0x80, 0x08]),
+                                               FontUnicode('\u{1F517}', [0x00, 0x0E, 0x11, 0x39, 0x4E, 0x88, 0x70, 0x00]),
+                                               FontUnicode('\u{1F525}', [0x08, 0x18, 0x5C, 0x7E, 0x7E, 0xDB, 0xCB, 0x7E]),
+                                               FontUnicode('\u{1F534}', [0x00, 0x3C, 0x7E, 0x7E, 0x7E, 0x7E, 0x3C, 0x00]),
+                                               FontUnicode('\u{1F535}', [0x00, 0x3C, 0x7E, 0x7E, 0x7E, 0x7E, 0x3C, 0x00]),
+                                               FontUnicode('\u{1F536}', [0x18, 0x3C, 0x7E, 0xFF, 0xFF, 0x7E, 0x3C, 0x18]),
+                                               FontUnicode('\u{1F537}', [0x18, 0x3C, 0x7E, 0xFF, 0xFF, 0x7E, 0x3C, 0x18]),
+                                               FontUnicode('\u{1F538}', [0x00, 0x00, 0x18, 0x3C, 0x3C, 0x18, 0x00, 0x00]),
+                                               FontUnicode('\u{1F539}', [0x00, 0x00, 0x18, 0x3C, 0x3C, 0x18, 0x00, 0x00]),
+                                               FontUnicode('\u{1F53A}', [0x00, 0x18, 0x18, 0x3C, 0x3C, 0x7E, 0x7E, 0x00]),
+                                               FontUnicode('\u{1F53B}', [0x00, 0x7E, 0x7E, 0x3C, 0x3C, 0x18, 0x18, 0x00]),
+                                               FontUnicode('\u{1F550}', [0x3C, 0x5A, 0x89, 0x89, 0x81, 0x81, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F551}', [0x3C, 0x4A, 0xA9, 0x99, 0x81, 0x81, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F552}', [0x3C, 0x4A, 0x89, 0xB9, 0x81, 0x81, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F553}', [0x3C, 0x4A, 0x89, 0x99, 0xA1, 0x81, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F554}', [0x3C, 0x4A, 0x89, 0x89, 0x89, 0x91, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F555}', [0x3C, 0x4A, 0x89, 0x89, 0x89, 0x89, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F556}', [0x3C, 0x4A, 0x89, 0x89, 0x89, 0x85, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F557}', [0x3C, 0x4A, 0x89, 0x8D, 0x83, 0x81, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F558}', [0x3C, 0x4A, 0x89, 0x8F, 0x81, 0x81, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F559}', [0x3C, 0x4A, 0x8B, 0x8D, 0x81, 0x81, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F55A}', [0x3C, 0x4E, 0x89, 0x89, 0x81, 0x81, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F55B}', [0x3C, 0x4A, 0x89, 0x89, 0x81, 0x81, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F55C}', [0x3C, 0x52, 0x89, 0x89, 0x89, 0x89, 0x4A, 0x3C]),
+                                               FontUnicode('\u{1F55D}', [0x3C, 0x42, 0xA1, 0x99, 0x89, 0x89, 0x4A, 0x3C]),
+                                               FontUnicode('\u{1F55E}', [0x3C, 0x42, 0x81, 0xB9, 0x89, 0x89, 0x4A, 0x3C]),
+                                               FontUnicode('\u{1F55F}', [0x3C, 0x42, 0x81, 0x99, 0xA9, 0x89, 0x4A, 0x3C]),
+                                               FontUnicode('\u{1F560}', [0x3C, 0x42, 0x81, 0x89, 0x89, 0x99, 0x4A, 0x3C]),
+                                               FontUnicode('\u{1F561}', [0x3C, 0x42, 0x81, 0x89, 0x89, 0x89, 0x4A, 0x3C]),
+                                               FontUnicode('\u{1F562}', [0x3C, 0x42, 0x81, 0x89, 0x89, 0x8D, 0x4A, 0x3C]),
+                                               FontUnicode('\u{1F563}', [0x3C, 0x42, 0x81, 0x8D, 0x8B, 0x89, 0x4A, 0x3C]),
+                                               FontUnicode('\u{1F564}', [0x3C, 0x42, 0x81, 0x8F, 0x89, 0x89, 0x4A, 0x3C]),
+                                               FontUnicode('\u{1F565}', [0x3C, 0x42, 0x83, 0x8D, 0x89, 0x89, 0x4A, 0x3C]),
+                                               FontUnicode('\u{1F566}', [0x3C, 0x46, 0x89, 0x89, 0x89, 0x89, 0x4A, 0x3C]),
+                                               FontUnicode('\u{1F567}', [0x3C, 0x4A, 0x89, 0x89, 0x89, 0x89, 0x4A, 0x3C]),
+                                               FontUnicode('\u{1F590}', [0x14, 0x55, 0x55, 0x55, 0xBF, 0x7F, 0x3E, 0x3C]),
+                                               FontUnicode('\u{1F5A4}', [0x36, 0x7F, 0x7F, 0x7F, 0x3E, 0x1C, 0x08, 0x00]),
+                                               FontUnicode('\u{1F5D1}', [0x1C, 0xFF, 0x42, 0x6A, 0x6A, 0x6A, 0x6A, 0x3C]),
+                                               FontUnicode('\u{1F600}', [0x3C, 0x7E, 0xDB, 0xFF, 0x81, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F601}', [0x3C, 0x7E, 0xDB, 0xA5, 0x81, 0xA5, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F602}', [0x3C, 0x7E, 0xDB, 0x24, 0x00, 0x00, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F603}', [0x3C, 0x7E, 0xDB, 0xDB, 0x81, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F604}', [0x3C, 0x7E, 0xDB, 0xA5, 0x81, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F605}', [0xFC, 0xFE, 0xDB, 0xA5, 0x81, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F606}', [0x3C, 0x7E, 0xBD, 0xDB, 0x81, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F607}', [0x3C, 0x7E, 0xDB, 0xA5, 0xDB, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F608}', [0xBD, 0x99, 0xE7, 0xDB, 0xDB, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F609}', [0x3C, 0x7E, 0xDF, 0xF9, 0xDB, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F60A}', [0x3C, 0x7E, 0xDB, 0xA5, 0xDB, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F60B}', [0x3C, 0x7E, 0xDB, 0xA5, 0xC3, 0xCF, 0x4E, 0x3C]),
+                                               FontUnicode('\u{1F60C}', [0x3C, 0x7E, 0xFF, 0x99, 0xDB, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F60D}', [0x3C, 0x7E, 0xA5, 0xDB, 0x81, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F60E}', [0x3C, 0x7E, 0x00, 0x99, 0xDB, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F60F}', [0x3C, 0x7E, 0x99, 0xBB, 0xDF, 0xE3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F610}', [0x3C, 0x7E, 0xDB, 0xFF, 0xFF, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F611}', [0x3C, 0x7E, 0x99, 0xFF, 0xFF, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F612}', [0x3C, 0x7E, 0x99, 0xBB, 0xE7, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F613}', [0xFC, 0xFE, 0xFF, 0x99, 0xE7, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F614}', [0x3C, 0x7E, 0xFF, 0x99, 0xFF, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F615}', [0x3C, 0x7E, 0xDB, 0xFF, 0xDF, 0xE3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F616}', [0x3C, 0x7E, 0xBD, 0xDB, 0xA9, 0xD7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F617}', [0x3C, 0x7E, 0xDB, 0xFF, 0xEF, 0xF7, 0x6E, 0x3C]),
+                                               FontUnicode('\u{1F618}', [0x3C, 0x7E, 0xDF, 0xF9, 0xEF, 0xF7, 0x6E, 0x3C]),
+                                               FontUnicode('\u{1F619}', [0x3C, 0x7E, 0xDB, 0xA5, 0xEF, 0xF7, 0x6E, 0x3C]),
+                                               FontUnicode('\u{1F61A}', [0x3C, 0x7E, 0xFF, 0x99, 0xEF, 0xF7, 0x6E, 0x3C]),
+                                               FontUnicode('\u{1F61B}', [0x3C, 0x7E, 0xDB, 0xFF, 0xC3, 0xCF, 0x4E, 0x3C]),
+                                               FontUnicode('\u{1F61C}', [0x3C, 0x7E, 0xDF, 0xF9, 0xC3, 0xCF, 0x4E, 0x3C]),
+                                               FontUnicode('\u{1F61D}', [0x3C, 0x7E, 0xBD, 0xDB, 0x81, 0xCF, 0x4E, 0x3C]),
+                                               FontUnicode('\u{1F61E}', [0x3C, 0x7E, 0xFF, 0x99, 0xE7, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F61F}', [0x3C, 0x66, 0xDB, 0xDB, 0xE7, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F620}', [0x3C, 0x5A, 0xE7, 0xDB, 0xE7, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F621}', [0x3C, 0x5A, 0xE7, 0xDB, 0xFF, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F622}', [0x3C, 0x7E, 0xDB, 0xBF, 0xA7, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F623}', [0x3C, 0x7E, 0xBD, 0xDB, 0xA9, 0xD7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F624}', [0x3C, 0x5A, 0xE7, 0xDB, 0xFF, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F625}', [0xFC, 0xE6, 0xDB, 0xDB, 0xE7, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F626}', [0x3C, 0x7E, 0xDB, 0xFF, 0xC3, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F627}', [0x3C, 0x7E, 0xDB, 0xDB, 0xC3, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F628}', [0x3C, 0x66, 0xDB, 0xDB, 0xC3, 0xDB, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F629}', [0x3C, 0x7E, 0xBD, 0xDB, 0x81, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F62A}', [0x3C, 0x7E, 0xFF, 0x99, 0xA7, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F62B}', [0x3C, 0x7E, 0xBD, 0xDB, 0x81, 0xDB, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F62C}', [0x3C, 0x7E, 0xDB, 0xFF, 0x81, 0xA5, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F62D}', [0x3C, 0x7E, 0xFF, 0x18, 0x00, 0x18, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F62E}', [0x3C, 0x7E, 0xDB, 0xFF, 0xE7, 0xDB, 0x66, 0x3C]),
+                                               FontUnicode('\u{1F62F}', [0x3C, 0x7E, 0xDB, 0xDB, 0xE7, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F630}', [0xFC, 0xE6, 0xDB, 0xDB, 0xC3, 0xDB, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F631}', [0x3C, 0x7E, 0x99, 0x99, 0xC3, 0xDB, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F632}', [0x3C, 0x7E, 0xDB, 0xDB, 0xC3, 0xDB, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F633}', [0x3C, 0x7E, 0x99, 0x99, 0xFF, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F634}', [0x3C, 0x7E, 0xFF, 0x99, 0xE7, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F635}', [0x3C, 0x7E, 0x55, 0xFF, 0xE7, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F636}', [0x3C, 0x7E, 0xDB, 0xFF, 0xFF, 0xFF, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F637}', [0x3C, 0x7E, 0xDB, 0xFF, 0x00, 0x81, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F638}', [0x81, 0xC3, 0xDB, 0xA5, 0x81, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F639}', [0x81, 0xC3, 0xDB, 0x24, 0x00, 0x00, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F63A}', [0x81, 0xC3, 0xDB, 0xFF, 0x81, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F63B}', [0x81, 0xC3, 0xA5, 0xDB, 0x81, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F63C}', [0x81, 0xC3, 0x99, 0xBB, 0xDF, 0xE3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F63D}', [0x81, 0xC3, 0xFF, 0x99, 0xEF, 0xF7, 0x6E, 0x3C]),
+                                               FontUnicode('\u{1F63E}', [0x81, 0xE7, 0xE7, 0xDB, 0xE7, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F63F}', [0x81, 0xC3, 0xDB, 0xBF, 0xA7, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F640}', [0x81, 0xC3, 0x99, 0x99, 0xC3, 0xDB, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F641}', [0x3C, 0x7E, 0xDB, 0xFF, 0xE7, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F642}', [0x3C, 0x7E, 0xDB, 0xFF, 0xDB, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F643}', [0x3C, 0x7E, 0xE7, 0xDB, 0xFF, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F644}', [0x3C, 0x7E, 0x99, 0xDD, 0xFF, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F64C}', [0xA5, 0xA5, 0xE7, 0xE7, 0x66, 0x66, 0x66, 0x00]),
+                                               FontUnicode('\u{1F64F}', [0x18, 0x3C, 0x3C, 0x7E, 0x7E, 0x7F, 0xDB, 0x99]),
+                                               FontUnicode('\u{1F680}', [0x18, 0x3C, 0x24, 0x3C, 0x3C, 0x7E, 0xDB, 0x99]),
+                                               FontUnicode('\u{1F697}', [0x00, 0x3C, 0x42, 0xFF, 0xFF, 0xFF, 0x66, 0x00]),
+                                               FontUnicode('\u{1F6AA}', [0x7E, 0x42, 0x42, 0x42, 0x62, 0x42, 0x42, 0xFF]),
+                                               FontUnicode('\u{1F6AB}', [0x3C, 0x42, 0x85, 0x89, 0x91, 0xA1, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F6D1}', [0x3C, 0x42, 0xBD, 0xBD, 0xBD, 0xBD, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F6D2}', [0x03, 0xFE, 0xAA, 0xFE, 0xAA, 0x7E, 0x02, 0x44]),
+                                               FontUnicode('\u{1F7E0}', [0x00, 0x3C, 0x7E, 0x7E, 0x7E, 0x7E, 0x3C, 0x00]),
+                                               FontUnicode('\u{1F7E1}', [0x00, 0x3C, 0x7E, 0x7E, 0x7E, 0x7E, 0x3C, 0x00]),
+                                               FontUnicode('\u{1F7E2}', [0x00, 0x3C, 0x7E, 0x7E, 0x7E, 0x7E, 0x3C, 0x00]),
+                                               FontUnicode('\u{1F7E3}', [0x00, 0x3C, 0x7E, 0x7E, 0x7E, 0x7E, 0x3C, 0x00]),
+                                               FontUnicode('\u{1F7E4}', [0x00, 0x3C, 0x7E, 0x7E, 0x7E, 0x7E, 0x3C, 0x00]),
+                                               FontUnicode('\u{1F7E5}', [0x00, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x00]),
+                                               FontUnicode('\u{1F7E6}', [0x00, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x00]),
+                                               FontUnicode('\u{1F7E7}', [0x00, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x00]),
+                                               FontUnicode('\u{1F7E8}', [0x00, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x00]),
+                                               FontUnicode('\u{1F7E9}', [0x00, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x00]),
+                                               FontUnicode('\u{1F7EA}', [0x00, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x00]),
+                                               FontUnicode('\u{1F7EB}', [0x00, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x00]),
+                                               FontUnicode('\u{1F90D}', [0x36, 0x49, 0x41, 0x41, 0x22, 0x14, 0x08, 0x00]),
+                                               FontUnicode('\u{1F90E}', [0x36, 0x7F, 0x7F, 0x7F, 0x3E, 0x1C, 0x08, 0x00]),
+                                               FontUnicode('\u{1F90F}', [0x00, 0x03, 0x04, 0x10, 0x5E, 0x7D, 0x7F, 0x1E]),
+                                               FontUnicode('\u{1F910}', [0x3C, 0x7E, 0xDB, 0xFF, 0xAB, 0x81, 0x2A, 0x3C]),
+                                               FontUnicode('\u{1F913}', [0x3C, 0x7E, 0x00, 0xA5, 0x81, 0xA5, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F914}', [0x3C, 0x7E, 0x99, 0xBB, 0xDF, 0xE3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F916}', [0x08, 0x3E, 0x41, 0x55, 0x41, 0x5D, 0x3E, 0x22]),
+                                               FontUnicode('\u{1F918}', [0x22, 0x22, 0x22, 0x2A, 0x7E, 0xFE, 0x7C, 0x3C]),
+                                               FontUnicode('\u{1F919}', [0x83, 0xC6, 0x7C, 0x54, 0x7C, 0x7C, 0x38, 0x38]),
+                                               FontUnicode('\u{1F91D}', [0x00, 0xC3, 0xDB, 0xFF, 0x7E, 0x7C, 0x38, 0x00]),
+                                               FontUnicode('\u{1F91E}', [0x14, 0x08, 0x14, 0x7C, 0x7E, 0x3E, 0x1C, 0x1C]),
+                                               FontUnicode('\u{1F922}', [0x3C, 0x7E, 0xFF, 0x99, 0xD7, 0xAB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F924}', [0x3C, 0x7E, 0xFF, 0x99, 0xDB, 0xE7, 0x5E, 0x1C]),
+                                               FontUnicode('\u{1F928}', [0x3C, 0x7E, 0x99, 0xBB, 0xFF, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F929}', [0x3C, 0x7E, 0xA5, 0xA5, 0x81, 0xC3, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F92A}', [0x3C, 0x7E, 0xFB, 0x9F, 0xC3, 0xCF, 0x4E, 0x3C]),
+                                               FontUnicode('\u{1F92C}', [0x3C, 0x5A, 0xE7, 0xDB, 0xEB, 0xD7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F970}', [0x3C, 0x7E, 0xDB, 0xA5, 0xDB, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F971}', [0x3C, 0x7E, 0xFF, 0x99, 0xC3, 0xDB, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F972}', [0x3C, 0x7E, 0xDB, 0xA5, 0x9B, 0xE7, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F974}', [0x3C, 0x7E, 0xFB, 0x9F, 0xD7, 0xAB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F975}', [0xFC, 0xFE, 0xBD, 0xDB, 0x81, 0xCF, 0x4E, 0x3C]),
+                                               FontUnicode('\u{1F976}', [0x3C, 0x7E, 0x99, 0x99, 0x81, 0xA5, 0x42, 0x3C]),
+                                               FontUnicode('\u{1F97A}', [0x3C, 0x7E, 0x99, 0x99, 0xE7, 0xDB, 0x7E, 0x3C]),
+                                               FontUnicode('\u{1F9E0}', [0x36, 0x49, 0xAD, 0xC9, 0xAB, 0x6E, 0x08, 0x00]),
+                                               FontUnicode('\u{1F9E1}', [0x36, 0x7F, 0x7F, 0x7F, 0x3E, 0x1C, 0x08, 0x00]),
+                                               FontUnicode('\u{1F9ED}', [0x3C, 0x42, 0xA1, 0x99, 0x99, 0x85, 0x42, 0x3C]),
+                                               FontUnicode('\u{1FA75}', [0x36, 0x7F, 0x7F, 0x7F, 0x3E, 0x1C, 0x08, 0x00]),
+                                               FontUnicode('\u{1FA76}', [0x36, 0x7F, 0x7F, 0x7F, 0x3E, 0x1C, 0x08, 0x00]),
+                                               FontUnicode('\u{1FA77}', [0x36, 0x7F, 0x7F, 0x7F, 0x3E, 0x1C, 0x08, 0x00])];
+
+/// A convenient constant for Emoji fonts (`U+1F300` - `U+1FAFF`), that implements the `UnicodeFonts` trait.
+///
+/// ## `EMOJI_UNICODE[0]`: `U+1F300` `"🌀"`
+///
+/// ```text
+/// ░█████░░
+/// █░░░░░█░
+/// █░███░░█
+/// █░█░░█░█
+/// █░█░██░█
+/// █░░█░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[1]`: `U+1F302` `"🌂"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░███░░
+/// ░░░███░░
+/// ░░░███░░
+/// ░░░███░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[2]`: `U+1F308` `"🌈"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░██░░█
+/// █░█░░█░█
+/// █░█░░█░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[3]`: `U+1F30A` `"🌊"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░██░░░
+/// █░█░░░░░
+/// █░█░░░██
+/// ██░░███░
+/// ░███████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[4]`: `U+1F30B` `"🌋"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ░░████░░
+/// ░░████░░
+/// ░██████░
+/// ░██████░
+/// ████████
+/// ```
+///
+/// ## `EMOJI_UNICODE[5]`: `U+1F30D` `"🌍"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░███░░█
+/// █░░█░███
+/// █░░░░███
+/// █░█░░░██
+/// ░██░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[6]`: `U+1F310` `"🌐"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░█░░█░█
+/// ████████
+/// █░█░░█░█
+/// █░█░░█░█
+/// ░█░██░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[7]`: `U+1F311` `"🌑"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░░░░░░█
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[8]`: `U+1F312` `"🌒"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░██░
+/// █░░░░░██
+/// █░░░░░██
+/// █░░░░░██
+/// █░░░░░██
+/// ░█░░░██░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[9]`: `U+1F313` `"🌓"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░███░
+/// █░░░████
+/// █░░░████
+/// █░░░████
+/// █░░░████
+/// ░█░░███░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[10]`: `U+1F314` `"🌔"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░████░
+/// █░██████
+/// █░██████
+/// █░██████
+/// █░██████
+/// ░█░████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[11]`: `U+1F315` `"🌕"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ████████
+/// ████████
+/// ████████
+/// ████████
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[12]`: `U+1F316` `"🌖"`
+///
+/// ```text
+/// ░░████░░
+/// ░████░█░
+/// ██████░█
+/// ██████░█
+/// ██████░█
+/// ██████░█
+/// ░████░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[13]`: `U+1F317` `"🌗"`
+///
+/// ```text
+/// ░░████░░
+/// ░███░░█░
+/// ████░░░█
+/// ████░░░█
+/// ████░░░█
+/// ████░░░█
+/// ░███░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[14]`: `U+1F318` `"🌘"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░░█░
+/// ██░░░░░█
+/// ██░░░░░█
+/// ██░░░░░█
+/// ██░░░░░█
+/// ░██░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[15]`: `U+1F319` `"🌙"`
+///
+/// ```text
+/// ░░███░░░
+/// ░██░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ░██░░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[16]`: `U+1F31A` `"🌚"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// ████████
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[17]`: `U+1F31B` `"🌛"`
+///
+/// ```text
+/// ░░███░░░
+/// ░██░░░░░
+/// ██░█░░░░
+/// ███░░░░░
+/// ███░░░░░
+/// ██░█░░░░
+/// ░██░░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[18]`: `U+1F31C` `"🌜"`
+///
+/// ```text
+/// ░░░███░░
+/// ░░░░░██░
+/// ░░░░█░██
+/// ░░░░░███
+/// ░░░░░███
+/// ░░░░█░██
+/// ░░░░░██░
+/// ░░░███░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[19]`: `U+1F31D` `"🌝"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░█░░█░█
+/// █░░░░░░█
+/// █░█░░█░█
+/// █░░██░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[20]`: `U+1F31E` `"🌞"`
+///
+/// ```text
+/// █░░██░░█
+/// ░██████░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// ░██░░██░
+/// ░██████░
+/// █░░██░░█
+/// ```
+///
+/// ## `EMOJI_UNICODE[21]`: `U+1F31F` `"🌟"`
+///
+/// ```text
+/// █░░█░░░█
+/// ░░███░░░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░██░██░░
+/// ░█░░░█░░
+/// █░░░░░░█
+/// ```
+///
+/// ## `EMOJI_UNICODE[22]`: `U+1F320` `"🌠"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░░███░
+/// █░░█████
+/// ░█░░███░
+/// ░░█░█░█░
+/// ░█░█░░░░
+/// █░█░░░░░
+/// ░█░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[23]`: `U+1F321` `"🌡"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░░████░░
+/// ░░████░░
+/// ░██████░
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[24]`: `U+1F324` `"🌤"`
+///
+/// ```text
+/// █░█░█░░░
+/// ░███░░░░
+/// █████░░░
+/// ░███░██░
+/// █░██░░░█
+/// ░░█░░░░█
+/// ░░░█████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[25]`: `U+1F325` `"🌥"`
+///
+/// ```text
+/// █░█░░░░░
+/// ░██░░░░░
+/// ███░██░░
+/// ░██░█░█░
+/// ░█░░░░██
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░██████░
+/// ```
+///
+/// ## `EMOJI_UNICODE[26]`: `U+1F326` `"🌦"`
+///
+/// ```text
+/// █░█░░░░░
+/// ░██░█░░░
+/// ███░██░░
+/// ░██░░░██
+/// ░█░░░░░█
+/// ░██████░
+/// ░█░░█░░█
+/// █░░█░░█░
+/// ```
+///
+/// ## `EMOJI_UNICODE[27]`: `U+1F327` `"🌧"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░█░░███░
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░██████░
+/// ░░░░░░░░
+/// ░█░░█░░█
+/// █░░█░░█░
+/// ```
+///
+/// ## `EMOJI_UNICODE[28]`: `U+1F328` `"🌨"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░█░░███░
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░██████░
+/// ░█░░░█░░
+/// ░░░█░░░█
+/// ░█░░░█░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[29]`: `U+1F329` `"🌩"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░█░░███░
+/// █░░░░░░█
+/// █░░░░█░█
+/// ░███░███
+/// ░░░░██░░
+/// ░░░██░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[30]`: `U+1F32A` `"🌪"`
+///
+/// ```text
+/// ████████
+/// ░██████░
+/// ░░█████░
+/// ░░░████░
+/// ░░███░░░
+/// ░░░██░░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[31]`: `U+1F32B` `"🌫"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░░░░░░░░
+/// ███░████
+/// ░░░░░░░░
+/// ░██████░
+/// ░░░░░░░░
+/// ████░███
+/// ```
+///
+/// ## `EMOJI_UNICODE[32]`: `U+1F331` `"🌱"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░░██░
+/// ███░███░
+/// ░██░██░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░██████░
+/// ```
+///
+/// ## `EMOJI_UNICODE[33]`: `U+1F332` `"🌲"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█████░░
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[34]`: `U+1F333` `"🌳"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[35]`: `U+1F335` `"🌵"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// █░░█░░░░
+/// █░░█░░█░
+/// ████░░█░
+/// ░░░████░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[36]`: `U+1F337` `"🌷"`
+///
+/// ```text
+/// █░█░█░░░
+/// █████░░░
+/// █████░░░
+/// ░███░░░░
+/// ░░█░░░░░
+/// █░█░█░░░
+/// ░███░░░░
+/// ░░█░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[37]`: `U+1F338` `"🌸"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░██░██░░
+/// ██░░░██░
+/// ░█░█░█░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[38]`: `U+1F339` `"🌹"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░█░█░░
+/// ░██░██░░
+/// ░░███░░░
+/// ░░░█░██░
+/// ░███░█░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[39]`: `U+1F340` `"🍀"`
+///
+/// ```text
+/// ░██░██░░
+/// ████░██░
+/// ░██░██░░
+/// ░░░█░░░░
+/// ░██░██░░
+/// ████░██░
+/// ░██░██░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[40]`: `U+1F341` `"🍁"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░█░█░█░░
+/// ░█████░░
+/// ███████░
+/// ░█████░░
+/// █░░█░░█░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[41]`: `U+1F34E` `"🍎"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░█░█░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[42]`: `U+1F352` `"🍒"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░░██░░
+/// ░░░█░█░░
+/// ░░█░░░█░
+/// ░██░░██░
+/// ███░███░
+/// ███░███░
+/// ░█░░░█░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[43]`: `U+1F354` `"🍔"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ░░░░░░░░
+/// ████████
+/// ░██████░
+/// ░░░░░░░░
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[44]`: `U+1F355` `"🍕"`
+///
+/// ```text
+/// ████████
+/// █░█░░░░█
+/// ░█░░░░█░
+/// ░█░░█░█░
+/// ░░█░░░█░
+/// ░░█░░█░░
+/// ░░░█░█░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[45]`: `U+1F377` `"🍷"`
+///
+/// ```text
+/// ░██████░
+/// ░█░░░░█░
+/// ░██████░
+/// ░░████░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░██████░
+/// ```
+///
+/// ## `EMOJI_UNICODE[46]`: `U+1F37A` `"🍺"`
+///
+/// ```text
+/// ░████░░░
+/// ██████░░
+/// █░░░████
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░█░████
+/// █░░░█░░░
+/// ░████░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[47]`: `U+1F381` `"🎁"`
+///
+/// ```text
+/// ░██░██░░
+/// ░░░█░░░░
+/// ███████░
+/// ███████░
+/// ░░░█░░░░
+/// ███░███░
+/// ███░███░
+/// ███░███░
+/// ```
+///
+/// ## `EMOJI_UNICODE[48]`: `U+1F382` `"🎂"`
+///
+/// ```text
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░█████░░
+/// ███████░
+/// █░█░█░█░
+/// ███████░
+/// █░░░░░█░
+/// ███████░
+/// ```
+///
+/// ## `EMOJI_UNICODE[49]`: `U+1F388` `"🎈"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█████░░
+/// ░██░██░░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[50]`: `U+1F389` `"🎉"`
+///
+/// ```text
+/// █░░█░░█░
+/// ░█░░░█░░
+/// █░░█░░░█
+/// ░░░█░█░░
+/// ░███░░░░
+/// ████░░█░
+/// █████░░░
+/// ░░░░░░█░
+/// ```
+///
+/// ## `EMOJI_UNICODE[51]`: `U+1F393` `"🎓"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░██████░
+/// ████████
+/// ░██████░
+/// ░████░█░
+/// ░████░█░
+/// ░░░░░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[52]`: `U+1F3A4` `"🎤"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█████░░
+/// ░█████░░
+/// ░░███░░░
+/// █░███░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[53]`: `U+1F3A7` `"🎧"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░░░░░░█
+/// ██░░░░██
+/// ██░░░░██
+/// ██░░░░██
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[54]`: `U+1F3AE` `"🎮"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// █░█░░█░█
+/// █░░░█░██
+/// █░█░░█░█
+/// ████████
+/// ██░░░░██
+/// █░░░░░░█
+/// ```
+///
+/// ## `EMOJI_UNICODE[55]`: `U+1F3B5` `"🎵"`
+///
+/// ```text
+/// ░░░████░
+/// ░░░█░░█░
+/// ░░░█░░█░
+/// ░░░█░░█░
+/// ░███░░█░
+/// ████░██░
+/// ░██░███░
+/// ░░░░░██░
+/// ```
+///
+/// ## `EMOJI_UNICODE[56]`: `U+1F3B6` `"🎶"`
+///
+/// ```text
+/// ░░██████
+/// ░░█░░░░█
+/// ░░██████
+/// ░░█░░░░█
+/// ░░█░░░░█
+/// ███░░███
+/// ███░░███
+/// ░█░░░░█░
+/// ```
+///
+/// ## `EMOJI_UNICODE[57]`: `U+1F3C6` `"🏆"`
+///
+/// ```text
+/// ████████
+/// █░████░█
+/// █░████░█
+/// ░██████░
+/// ░░████░░
+/// ░░░██░░░
+/// ░░████░░
+/// ░██████░
+/// ```
+///
+/// ## `EMOJI_UNICODE[58]`: `U+1F3E0` `"🏠"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░█░
+/// ░██████░
+/// ███████░
+/// ░█░░░██░
+/// ░█░█░██░
+/// ░█░█░██░
+/// ░███████
+/// ```
+///
+/// ## `EMOJI_UNICODE[59]`: `U+1F41F` `"🐟"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░██░░░
+/// █░████░░
+/// ███░█░█░
+/// ███████░
+/// █░█████░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[60]`: `U+1F426` `"🐦"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░█░██░░░
+/// ████░░░░
+/// ░██████░
+/// ░███████
+/// ░░█████░
+/// ░░░█░█░░
+/// ░░██░██░
+/// ```
+///
+/// ## `EMOJI_UNICODE[61]`: `U+1F431` `"🐱"`
+///
+/// ```text
+/// █░░░░░░█
+/// ██░░░░██
+/// ██░██░██
+/// ████████
+/// ████████
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[62]`: `U+1F436` `"🐶"`
+///
+/// ```text
+/// ██░░░░██
+/// ███░░███
+/// █░████░█
+/// ░█░██░█░
+/// ░██████░
+/// ░██░░██░
+/// ░░████░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[63]`: `U+1F440` `"👀"`
+///
+/// ```text
+/// ░██░░██░
+/// █░░██░░█
+/// █░░██░░█
+/// ██░███░█
+/// ██░███░█
+/// █░░██░░█
+/// ░██░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[64]`: `U+1F442` `"👂"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░█░░█░
+/// █░█░█░█░
+/// █░░░█░█░
+/// ░█░█░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[65]`: `U+1F443` `"👃"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// █░█░█░█░
+/// ░█░░░█░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[66]`: `U+1F444` `"👄"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░██░░
+/// █░█░█░█░
+/// ███████░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[67]`: `U+1F446` `"👆"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░██░░
+/// ░███████
+/// ██░█████
+/// ░███████
+/// ░░█████░
+/// ```
+///
+/// ## `EMOJI_UNICODE[68]`: `U+1F447` `"👇"`
+///
+/// ```text
+/// ░░█████░
+/// ░███████
+/// ██░█████
+/// ░███████
+/// ░░█░██░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[69]`: `U+1F448` `"👈"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░████░
+/// ░█░█████
+/// ███████░
+/// ░░░████░
+/// ░░░███░░
+/// ░░░███░░
+/// ░░░░██░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[70]`: `U+1F449` `"👉"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░████░░░
+/// █████░█░
+/// ░███████
+/// ░████░░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[71]`: `U+1F44A` `"👊"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ██░█░█░█
+/// ████████
+/// █░██████
+/// █░█████░
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[72]`: `U+1F44B` `"👋"`
+///
+/// ```text
+/// █░░░░░░░
+/// ░░█░█░█░
+/// █░█░█░█░
+/// ░░█░█░█░
+/// █░█████░
+/// ░░██████
+/// ░░░█████
+/// ░░░████░
+/// ```
+///
+/// ## `EMOJI_UNICODE[73]`: `U+1F44C` `"👌"`
+///
+/// ```text
+/// ░░░█░█░█
+/// ░░░█░█░█
+/// ░███░█░█
+/// █░░█████
+/// █░░█████
+/// ░██████░
+/// ░░████░░
+/// ░░███░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[74]`: `U+1F44D` `"👍"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ██░████░
+/// ██░███░░
+/// ██░████░
+/// ██░███░░
+/// ██░██░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[75]`: `U+1F44E` `"👎"`
+///
+/// ```text
+/// ██░██░░░
+/// ██░███░░
+/// ██░████░
+/// ██░███░░
+/// ██░████░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░░█░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[76]`: `U+1F44F` `"👏"`
+///
+/// ```text
+/// █░░█░░█░
+/// ░█░█░█░░
+/// ░░░██░░░
+/// ░░████░░
+/// ░█████░░
+/// ░██████░
+/// ░░█████░
+/// ░░░████░
+/// ```
+///
+/// ## `EMOJI_UNICODE[77]`: `U+1F464` `"👤"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░█████░░
+/// ░█████░░
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// ███████░
+/// ```
+///
+/// ## `EMOJI_UNICODE[78]`: `U+1F465` `"👥"`
+///
+/// ```text
+/// ░░░░░██░
+/// ░██░████
+/// ████░██░
+/// ████░██░
+/// ░██░████
+/// ████░███
+/// ████░███
+/// ████░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[79]`: `U+1F47B` `"👻"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█████░░
+/// █░█░█░█░
+/// █░█░█░█░
+/// ███████░
+/// ███████░
+/// ███████░
+/// █░█░█░█░
+/// ```
+///
+/// ## `EMOJI_UNICODE[80]`: `U+1F47D` `"👽"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// █░░█░░█░
+/// ██░█░██░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[81]`: `U+1F47E` `"👾"`
+///
+/// ```text
+/// ░░█░░░█░
+/// ░░░█░█░░
+/// ░░█████░
+/// ░██░█░██
+/// ████████
+/// █░█████░
+/// █░█░░░█░
+/// ░░░██░██
+/// ```
+///
+/// ## `EMOJI_UNICODE[82]`: `U+1F480` `"💀"`
+///
+/// ```text
+/// ░█████░░
+/// ███████░
+/// █░░█░░█░
+/// █░░█░░█░
+/// ███░███░
+/// ░█████░░
+/// ░█░█░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[83]`: `U+1F493` `"💓"`
+///
+/// ```text
+/// █░░░░░█░
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// █░███░█░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[84]`: `U+1F494` `"💔"`
+///
+/// ```text
+/// ░██░██░░
+/// ███░███░
+/// ██░████░
+/// ███░███░
+/// ░██░██░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[85]`: `U+1F495` `"💕"`
+///
+/// ```text
+/// ██░██░░░
+/// █████░░░
+/// ░███░░░░
+/// ░░█░█░█░
+/// ░░░█████
+/// ░░░░███░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[86]`: `U+1F496` `"💖"`
+///
+/// ```text
+/// ░██░██░█
+/// ███████░
+/// ███░███░
+/// ██░░░██░
+/// ░██░██░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[87]`: `U+1F497` `"💗"`
+///
+/// ```text
+/// ░██░██░░
+/// █░░█░░█░
+/// █░█░█░█░
+/// █░███░█░
+/// ░█░█░█░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[88]`: `U+1F498` `"💘"`
+///
+/// ```text
+/// ░██░██░█
+/// ██████░█
+/// █████░█░
+/// ████░██░
+/// ░██░██░░
+/// ░█░███░░
+/// █░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[89]`: `U+1F499` `"💙"`
+///
+/// ```text
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[90]`: `U+1F49A` `"💚"`
+///
+/// ```text
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[91]`: `U+1F49B` `"💛"`
+///
+/// ```text
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[92]`: `U+1F49C` `"💜"`
+///
+/// ```text
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[93]`: `U+1F49D` `"💝"`
+///
+/// ```text
+/// ░██░██░░
+/// ███░███░
+/// ███████░
+/// ███░███░
+/// ░██░██░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[94]`: `U+1F49E` `"💞"`
+///
+/// ```text
+/// ██░██░░░
+/// █████░░░
+/// ░███░░░░
+/// ░░█░░██░
+/// ██░░████
+/// ██░░░██░
+/// ░░░░░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[95]`: `U+1F49F` `"💟"`
+///
+/// ```text
+/// ████████
+/// █░░█░░██
+/// █░░░░░██
+/// █░░░░░██
+/// ██░░░███
+/// ███░████
+/// ████████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[96]`: `U+1F4A1` `"💡"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// █░░█░░█░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[97]`: `U+1F4A3` `"💣"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░░█░█
+/// ░░░░█░░░
+/// ░░███░░░
+/// ░█████░░
+/// ██░███░░
+/// ███████░
+/// ░█████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[98]`: `U+1F4A4` `"💤"`
+///
+/// ```text
+/// ░░░░████
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░███████
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[99]`: `U+1F4A5` `"💥"`
+///
+/// ```text
+/// █░░█░░█░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ████░███
+/// ░░███░░░
+/// ░█░█░█░░
+/// █░░█░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[100]`: `U+1F4A6` `"💦"`
+///
+/// ```text
+/// ░█░░░░█░
+/// ░█░░░██░
+/// ███░░██░
+/// ███░████
+/// ░█░░████
+/// ░░░█████
+/// ░░░░███░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[101]`: `U+1F4A7` `"💧"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█████░░
+/// ░██░██░░
+/// ░█░███░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[102]`: `U+1F4A8` `"💨"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░███░██░
+/// ░░░░░█░█
+/// █████░░█
+/// ░░░░░░░█
+/// ███░███░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[103]`: `U+1F4A9` `"💩"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░░███░░░
+/// ░█████░░
+/// ░█░█░█░░
+/// ███████░
+/// █░░░░░█░
+/// ░█████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[104]`: `U+1F4AA` `"💪"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░████░░░
+/// ░█░██░░░
+/// ░░░██░█░
+/// ░░░█████
+/// ░░██████
+/// ░██████░
+/// ██████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[105]`: `U+1F4AC` `"💬"`
+///
+/// ```text
+/// ░██████░
+/// █░░░░░░█
+/// █░█░█░░█
+/// █░░░░░░█
+/// ░██████░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[106]`: `U+1F4AD` `"💭"`
+///
+/// ```text
+/// ░██████░
+/// █░░░░░░█
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░██████░
+/// ░░░░█░░░
+/// ░░░░░█░░
+/// ░░░░░░█░
+/// ```
+///
+/// ## `EMOJI_UNICODE[107]`: `U+1F4AF` `"💯"`
+///
+/// ```text
+/// ░░░░░░░░
+/// █░███░██
+/// █░█░█░██
+/// █░█░█░█░
+/// █░███░██
+/// ░░░░░░░░
+/// ██████░░
+/// ░██████░
+/// ```
+///
+/// ## `EMOJI_UNICODE[108]`: `U+1F4B0` `"💰"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░█░█░█░░
+/// █░███░█░
+/// █░██░░█░
+/// █░░██░█░
+/// █░██░██░
+/// ░█████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[109]`: `U+1F4B3` `"💳"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ████████
+/// █░░░░░░█
+/// ████████
+/// ████████
+/// █░░░░░░█
+/// █░██░░░█
+/// ████████
+/// ```
+///
+/// ## `EMOJI_UNICODE[110]`: `U+1F4BB` `"💻"`
+///
+/// ```text
+/// ░██████░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ░██████░
+/// ░░░░░░░░
+/// ████████
+/// ████████
+/// ```
+///
+/// ## `EMOJI_UNICODE[111]`: `U+1F4BE` `"💾"`
+///
+/// ```text
+/// ██████░░
+/// █░█░░██░
+/// █░█░░██░
+/// ███████░
+/// █░░░░░█░
+/// █░░░░░█░
+/// █░░░░░█░
+/// ███████░
+/// ```
+///
+/// ## `EMOJI_UNICODE[112]`: `U+1F4C1` `"📁"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░░░░░
+/// █░░████░
+/// █░░░░░░█
+/// █░░░░░░█
+/// █░░░░░░█
+/// ████████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[113]`: `U+1F4C2` `"📂"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░░░░░
+/// █░░████░
+/// █░░░░░█░
+/// █░██████
+/// ██░░░░░█
+/// █░░░░░█░
+/// ██████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[114]`: `U+1F4C4` `"📄"`
+///
+/// ```text
+/// █████░░░
+/// █░░░██░░
+/// █░░░█░█░
+/// █░██████
+/// █░░░░░░█
+/// █░████░█
+/// █░░░░░░█
+/// ████████
+/// ```
+///
+/// ## `EMOJI_UNICODE[115]`: `U+1F4C5` `"📅"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ███████░
+/// █░░░░░█░
+/// ███████░
+/// █░█░█░█░
+/// ███████░
+/// █░█░█░█░
+/// ███████░
+/// ```
+///
+/// ## `EMOJI_UNICODE[116]`: `U+1F4C8` `"📈"`
+///
+/// ```text
+/// █░░░░░░░
+/// █░░░░░█░
+/// █░░░░██░
+/// █░░░█░░░
+/// █░██░░░░
+/// ██░░░░░░
+/// █░░░░░░░
+/// ████████
+/// ```
+///
+/// ## `EMOJI_UNICODE[117]`: `U+1F4C9` `"📉"`
+///
+/// ```text
+/// █░░░░░░░
+/// ██░░░░░░
+/// █░██░░░░
+/// █░░░█░░░
+/// █░░░░██░
+/// █░░░░░█░
+/// █░░░░░░░
+/// ████████
+/// ```
+///
+/// ## `EMOJI_UNICODE[118]`: `U+1F4CA` `"📊"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░█░█░░░
+/// ░░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// ████████
+/// ```
+///
+/// ## `EMOJI_UNICODE[119]`: `U+1F4CC` `"📌"`
+///
+/// ```text
+/// ░░████░░
+/// ░░░██░░░
+/// ░░████░░
+/// ░██████░
+/// ░░░██░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[120]`: `U+1F4CD` `"📍"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█████░░
+/// ░██░██░░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[121]`: `U+1F4CE` `"📎"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[122]`: `U+1F4D6` `"📖"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░░██░
+/// █░██░█░█
+/// █░░░█░░█
+/// █░█░█░██
+/// █░░░█░░█
+/// █████░██
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[123]`: `U+1F4DD` `"📝"`
+///
+/// ```text
+/// █████░░░
+/// █░░░█░█░
+/// █░███░░░
+/// █░░░█░█░
+/// █░██░█░░
+/// █░░░█░░░
+/// █░░░░░█░
+/// ███████░
+/// ```
+///
+/// ## `EMOJI_UNICODE[124]`: `U+1F4DE` `"📞"`
+///
+/// ```text
+/// ██░░░░░░
+/// ███░░░░░
+/// ██░░░░░░
+/// ░█░░░░░░
+/// ░██░░░░░
+/// ░░██░░██
+/// ░░░█████
+/// ░░░░███░
+/// ```
+///
+/// ## `EMOJI_UNICODE[125]`: `U+1F4E2` `"📢"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░███░
+/// █░███░█░
+/// ███░░░█░
+/// ███░░░█░
+/// █░███░█░
+/// ░░░████░
+/// ░░░░░██░
+/// ```
+///
+/// ## `EMOJI_UNICODE[126]`: `U+1F4E6` `"📦"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░█░█░
+/// ██████░█
+/// █░░█░█░█
+/// █░░█░█░█
+/// █░░░░█░█
+/// █░░░░██░
+/// ██████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[127]`: `U+1F4E7` `"📧"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ████████
+/// ██░░░░██
+/// █░█░░█░█
+/// █░░██░░█
+/// █░░░░░░█
+/// ████████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[128]`: `U+1F4F1` `"📱"`
+///
+/// ```text
+/// ░█████░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█████░░
+/// ░██░██░░
+/// ░█████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[129]`: `U+1F4F7` `"📷"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░███░░░
+/// ████████
+/// █░░███░█
+/// █░█░░█░█
+/// █░█░░█░█
+/// █░░██░░█
+/// ████████
+/// ```
+///
+/// ## `EMOJI_UNICODE[130]`: `U+1F4FA` `"📺"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ████████
+/// █░░░░░██
+/// █░░░░░█░
+/// █░░░░░██
+/// ████████
+/// ░█░░░░█░
+/// ```
+///
+/// ## `EMOJI_UNICODE[131]`: `U+1F500` `"🔀"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ██░░░██░
+/// ░░█░████
+/// ░░░█░██░
+/// ░░░█░██░
+/// ░░█░████
+/// ██░░░██░
+/// ░░░░░█░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[132]`: `U+1F504` `"🔄"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░░░░█░
+/// █░░░░███
+/// ███░░░░█
+/// ░█░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[133]`: `U+1F507` `"🔇"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░██░░░░
+/// ████░█░█
+/// ████░░█░
+/// ████░█░█
+/// ░░██░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[134]`: `U+1F50A` `"🔊"`
+///
+/// ```text
+/// ░░░█░░░█
+/// ░░██░█░░
+/// ████░░█░
+/// ████░█░█
+/// ████░░█░
+/// ░░██░█░░
+/// ░░░█░░░█
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[135]`: `U+1F50B` `"🔋"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ████████
+/// █░░░░░░█
+/// █░███░██
+/// █░███░██
+/// █░░░░░░█
+/// ████████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[136]`: `U+1F50C` `"🔌"`
+///
+/// ```text
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░████░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[137]`: `U+1F50D` `"🔍"`
+///
+/// ```text
+/// ░████░░░
+/// █░░░░█░░
+/// █░░░░█░░
+/// █░░░░█░░
+/// █░░░░█░░
+/// ░█████░░
+/// ░░░░░██░
+/// ░░░░░░██
+/// ```
+///
+/// ## `EMOJI_UNICODE[138]`: `U+1F50E` `"🔎"`
+///
+/// ```text
+/// ░░░████░
+/// ░░█░░░░█
+/// ░░█░░░░█
+/// ░░█░░░░█
+/// ░░█░░░░█
+/// ░░█████░
+/// ░██░░░░░
+/// ██░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[139]`: `U+1F511` `"🔑"`
+///
+/// ```text
+/// ░███░░░░
+/// █░░░█░░░
+/// █░█░█░░░
+/// █░░░█░░░
+/// ░████░░░
+/// ░░░█░░░░
+/// ░░░███░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[140]`: `U+1F512` `"🔒"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ███████░
+/// ███░███░
+/// ███░███░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[141]`: `U+1F513` `"🔓"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// ░█░░░░░░
+/// ███████░
+/// ███░███░
+/// ███░███░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[142]`: `U+1F514` `"🔔"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█████░░
+/// ░█████░░
+/// ░█████░░
+/// ███████░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[143]`: `U+1F515` `"🔕"`
+///
+/// ```text
+/// █░░█░░░░
+/// ░████░░░
+/// ░█████░░
+/// ░██░██░░
+/// ░███░█░░
+/// █████░█░
+/// ░░░░░░░█
+/// ░░░█░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[144]`: `U+1F517` `"🔗"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░███░░░░
+/// █░░░█░░░
+/// █░░███░░
+/// ░███░░█░
+/// ░░░█░░░█
+/// ░░░░███░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[145]`: `U+1F525` `"🔥"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░██░░░
+/// ░░███░█░
+/// ░██████░
+/// ░██████░
+/// ██░██░██
+/// ██░█░░██
+/// ░██████░
+/// ```
+///
+/// ## `EMOJI_UNICODE[146]`: `U+1F534` `"🔴"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░████░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[147]`: `U+1F535` `"🔵"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░████░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[148]`: `U+1F536` `"🔶"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░████░░
+/// ░██████░
+/// ████████
+/// ████████
+/// ░██████░
+/// ░░████░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[149]`: `U+1F537` `"🔷"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░████░░
+/// ░██████░
+/// ████████
+/// ████████
+/// ░██████░
+/// ░░████░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[150]`: `U+1F538` `"🔸"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░████░░
+/// ░░████░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[151]`: `U+1F539` `"🔹"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░████░░
+/// ░░████░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[152]`: `U+1F53A` `"🔺"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░████░░
+/// ░░████░░
+/// ░██████░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[153]`: `U+1F53B` `"🔻"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░██████░
+/// ░░████░░
+/// ░░████░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[154]`: `U+1F550` `"🕐"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[155]`: `U+1F551` `"🕑"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░░█░█░█
+/// █░░██░░█
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[156]`: `U+1F552` `"🕒"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░░█░░░█
+/// █░░███░█
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[157]`: `U+1F553` `"🕓"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░░█░░░█
+/// █░░██░░█
+/// █░░░░█░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[158]`: `U+1F554` `"🕔"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[159]`: `U+1F555` `"🕕"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[160]`: `U+1F556` `"🕖"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░█░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[161]`: `U+1F557` `"🕗"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░░█░░░█
+/// █░██░░░█
+/// ██░░░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[162]`: `U+1F558` `"🕘"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░░█░░░█
+/// ████░░░█
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[163]`: `U+1F559` `"🕙"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// ██░█░░░█
+/// █░██░░░█
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[164]`: `U+1F55A` `"🕚"`
+///
+/// ```text
+/// ░░████░░
+/// ░███░░█░
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[165]`: `U+1F55B` `"🕛"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[166]`: `U+1F55C` `"🕜"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░█░█░
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// ░█░█░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[167]`: `U+1F55D` `"🕝"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░░░█░█
+/// █░░██░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// ░█░█░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[168]`: `U+1F55E` `"🕞"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░░███░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// ░█░█░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[169]`: `U+1F55F` `"🕟"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░░██░░█
+/// █░░█░█░█
+/// █░░█░░░█
+/// ░█░█░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[170]`: `U+1F560` `"🕠"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░██░░█
+/// ░█░█░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[171]`: `U+1F561` `"🕡"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// ░█░█░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[172]`: `U+1F562` `"🕢"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░██░░░█
+/// ░█░█░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[173]`: `U+1F563` `"🕣"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░██░░░█
+/// ██░█░░░█
+/// █░░█░░░█
+/// ░█░█░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[174]`: `U+1F564` `"🕤"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░░░░░█
+/// ████░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// ░█░█░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[175]`: `U+1F565` `"🕥"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// ██░░░░░█
+/// █░██░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// ░█░█░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[176]`: `U+1F566` `"🕦"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░░█░
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// ░█░█░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[177]`: `U+1F567` `"🕧"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// ░█░█░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[178]`: `U+1F590` `"🖐"`
+///
+/// ```text
+/// ░░█░█░░░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// ██████░█
+/// ███████░
+/// ░█████░░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[179]`: `U+1F5A4` `"🖤"`
+///
+/// ```text
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[180]`: `U+1F5D1` `"🗑"`
+///
+/// ```text
+/// ░░███░░░
+/// ████████
+/// ░█░░░░█░
+/// ░█░█░██░
+/// ░█░█░██░
+/// ░█░█░██░
+/// ░█░█░██░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[181]`: `U+1F600` `"😀"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// █░░░░░░█
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[182]`: `U+1F601` `"😁"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// █░█░░█░█
+/// █░░░░░░█
+/// █░█░░█░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[183]`: `U+1F602` `"😂"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ░░█░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[184]`: `U+1F603` `"😃"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ██░██░██
+/// █░░░░░░█
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[185]`: `U+1F604` `"😄"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// █░█░░█░█
+/// █░░░░░░█
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[186]`: `U+1F605` `"😅"`
+///
+/// ```text
+/// ░░██████
+/// ░███████
+/// ██░██░██
+/// █░█░░█░█
+/// █░░░░░░█
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[187]`: `U+1F606` `"😆"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░████░█
+/// ██░██░██
+/// █░░░░░░█
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[188]`: `U+1F607` `"😇"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// █░█░░█░█
+/// ██░██░██
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[189]`: `U+1F608` `"😈"`
+///
+/// ```text
+/// █░████░█
+/// █░░██░░█
+/// ███░░███
+/// ██░██░██
+/// ██░██░██
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[190]`: `U+1F609` `"😉"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █████░██
+/// █░░█████
+/// ██░██░██
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[191]`: `U+1F60A` `"😊"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// █░█░░█░█
+/// ██░██░██
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[192]`: `U+1F60B` `"😋"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// █░█░░█░█
+/// ██░░░░██
+/// ████░░██
+/// ░███░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[193]`: `U+1F60C` `"😌"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ████████
+/// █░░██░░█
+/// ██░██░██
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[194]`: `U+1F60D` `"😍"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░█░░█░█
+/// ██░██░██
+/// █░░░░░░█
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[195]`: `U+1F60E` `"😎"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ░░░░░░░░
+/// █░░██░░█
+/// ██░██░██
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[196]`: `U+1F60F` `"😏"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░░██░░█
+/// ██░███░█
+/// █████░██
+/// ██░░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[197]`: `U+1F610` `"😐"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// ████████
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[198]`: `U+1F611` `"😑"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░░██░░█
+/// ████████
+/// ████████
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[199]`: `U+1F612` `"😒"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░░██░░█
+/// ██░███░█
+/// ███░░███
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[200]`: `U+1F613` `"😓"`
+///
+/// ```text
+/// ░░██████
+/// ░███████
+/// ████████
+/// █░░██░░█
+/// ███░░███
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[201]`: `U+1F614` `"😔"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ████████
+/// █░░██░░█
+/// ████████
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[202]`: `U+1F615` `"😕"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// █████░██
+/// ██░░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[203]`: `U+1F616` `"😖"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░████░█
+/// ██░██░██
+/// █░░█░█░█
+/// ███░█░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[204]`: `U+1F617` `"😗"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// ████░███
+/// ███░████
+/// ░███░██░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[205]`: `U+1F618` `"😘"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █████░██
+/// █░░█████
+/// ████░███
+/// ███░████
+/// ░███░██░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[206]`: `U+1F619` `"😙"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// █░█░░█░█
+/// ████░███
+/// ███░████
+/// ░███░██░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[207]`: `U+1F61A` `"😚"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ████████
+/// █░░██░░█
+/// ████░███
+/// ███░████
+/// ░███░██░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[208]`: `U+1F61B` `"😛"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// ██░░░░██
+/// ████░░██
+/// ░███░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[209]`: `U+1F61C` `"😜"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █████░██
+/// █░░█████
+/// ██░░░░██
+/// ████░░██
+/// ░███░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[210]`: `U+1F61D` `"😝"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░████░█
+/// ██░██░██
+/// █░░░░░░█
+/// ████░░██
+/// ░███░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[211]`: `U+1F61E` `"😞"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ████████
+/// █░░██░░█
+/// ███░░███
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[212]`: `U+1F61F` `"😟"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░██░
+/// ██░██░██
+/// ██░██░██
+/// ███░░███
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[213]`: `U+1F620` `"😠"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// ███░░███
+/// ██░██░██
+/// ███░░███
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[214]`: `U+1F621` `"😡"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// ███░░███
+/// ██░██░██
+/// ████████
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[215]`: `U+1F622` `"😢"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ██████░█
+/// ███░░█░█
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[216]`: `U+1F623` `"😣"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░████░█
+/// ██░██░██
+/// █░░█░█░█
+/// ███░█░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[217]`: `U+1F624` `"😤"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// ███░░███
+/// ██░██░██
+/// ████████
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[218]`: `U+1F625` `"😥"`
+///
+/// ```text
+/// ░░██████
+/// ░██░░███
+/// ██░██░██
+/// ██░██░██
+/// ███░░███
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[219]`: `U+1F626` `"😦"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// ██░░░░██
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[220]`: `U+1F627` `"😧"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ██░██░██
+/// ██░░░░██
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[221]`: `U+1F628` `"😨"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░██░
+/// ██░██░██
+/// ██░██░██
+/// ██░░░░██
+/// ██░██░██
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[222]`: `U+1F629` `"😩"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░████░█
+/// ██░██░██
+/// █░░░░░░█
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[223]`: `U+1F62A` `"😪"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ████████
+/// █░░██░░█
+/// ███░░█░█
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[224]`: `U+1F62B` `"😫"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░████░█
+/// ██░██░██
+/// █░░░░░░█
+/// ██░██░██
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[225]`: `U+1F62C` `"😬"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// █░░░░░░█
+/// █░█░░█░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[226]`: `U+1F62D` `"😭"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ████████
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[227]`: `U+1F62E` `"😮"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// ███░░███
+/// ██░██░██
+/// ░██░░██░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[228]`: `U+1F62F` `"😯"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ██░██░██
+/// ███░░███
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[229]`: `U+1F630` `"😰"`
+///
+/// ```text
+/// ░░██████
+/// ░██░░███
+/// ██░██░██
+/// ██░██░██
+/// ██░░░░██
+/// ██░██░██
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[230]`: `U+1F631` `"😱"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░░██░░█
+/// █░░██░░█
+/// ██░░░░██
+/// ██░██░██
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[231]`: `U+1F632` `"😲"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ██░██░██
+/// ██░░░░██
+/// ██░██░██
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[232]`: `U+1F633` `"😳"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░░██░░█
+/// █░░██░░█
+/// ████████
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[233]`: `U+1F634` `"😴"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ████████
+/// █░░██░░█
+/// ███░░███
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[234]`: `U+1F635` `"😵"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░█░█░█░
+/// ████████
+/// ███░░███
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[235]`: `U+1F636` `"😶"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// ████████
+/// ████████
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[236]`: `U+1F637` `"😷"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// ░░░░░░░░
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[237]`: `U+1F638` `"😸"`
+///
+/// ```text
+/// █░░░░░░█
+/// ██░░░░██
+/// ██░██░██
+/// █░█░░█░█
+/// █░░░░░░█
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[238]`: `U+1F639` `"😹"`
+///
+/// ```text
+/// █░░░░░░█
+/// ██░░░░██
+/// ██░██░██
+/// ░░█░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[239]`: `U+1F63A` `"😺"`
+///
+/// ```text
+/// █░░░░░░█
+/// ██░░░░██
+/// ██░██░██
+/// ████████
+/// █░░░░░░█
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[240]`: `U+1F63B` `"😻"`
+///
+/// ```text
+/// █░░░░░░█
+/// ██░░░░██
+/// █░█░░█░█
+/// ██░██░██
+/// █░░░░░░█
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[241]`: `U+1F63C` `"😼"`
+///
+/// ```text
+/// █░░░░░░█
+/// ██░░░░██
+/// █░░██░░█
+/// ██░███░█
+/// █████░██
+/// ██░░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[242]`: `U+1F63D` `"😽"`
+///
+/// ```text
+/// █░░░░░░█
+/// ██░░░░██
+/// ████████
+/// █░░██░░█
+/// ████░███
+/// ███░████
+/// ░███░██░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[243]`: `U+1F63E` `"😾"`
+///
+/// ```text
+/// █░░░░░░█
+/// ███░░███
+/// ███░░███
+/// ██░██░██
+/// ███░░███
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[244]`: `U+1F63F` `"😿"`
+///
+/// ```text
+/// █░░░░░░█
+/// ██░░░░██
+/// ██░██░██
+/// ██████░█
+/// ███░░█░█
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[245]`: `U+1F640` `"🙀"`
+///
+/// ```text
+/// █░░░░░░█
+/// ██░░░░██
+/// █░░██░░█
+/// █░░██░░█
+/// ██░░░░██
+/// ██░██░██
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[246]`: `U+1F641` `"🙁"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// ███░░███
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[247]`: `U+1F642` `"🙂"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// ██░██░██
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[248]`: `U+1F643` `"🙃"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ███░░███
+/// ██░██░██
+/// ████████
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[249]`: `U+1F644` `"🙄"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░░██░░█
+/// █░███░██
+/// ████████
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[250]`: `U+1F64C` `"🙌"`
+///
+/// ```text
+/// █░█░░█░█
+/// █░█░░█░█
+/// ███░░███
+/// ███░░███
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[251]`: `U+1F64F` `"🙏"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░████░░
+/// ░░████░░
+/// ░██████░
+/// ░██████░
+/// ███████░
+/// ██░██░██
+/// █░░██░░█
+/// ```
+///
+/// ## `EMOJI_UNICODE[252]`: `U+1F680` `"🚀"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░████░░
+/// ░░█░░█░░
+/// ░░████░░
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// █░░██░░█
+/// ```
+///
+/// ## `EMOJI_UNICODE[253]`: `U+1F697` `"🚗"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░████░░
+/// ░█░░░░█░
+/// ████████
+/// ████████
+/// ████████
+/// ░██░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[254]`: `U+1F6AA` `"🚪"`
+///
+/// ```text
+/// ░██████░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ░█░░░██░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ████████
+/// ```
+///
+/// ## `EMOJI_UNICODE[255]`: `U+1F6AB` `"🚫"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░█░░░░█
+/// █░░█░░░█
+/// █░░░█░░█
+/// █░░░░█░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[256]`: `U+1F6D1` `"🛑"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░████░█
+/// █░████░█
+/// █░████░█
+/// █░████░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[257]`: `U+1F6D2` `"🛒"`
+///
+/// ```text
+/// ██░░░░░░
+/// ░███████
+/// ░█░█░█░█
+/// ░███████
+/// ░█░█░█░█
+/// ░██████░
+/// ░█░░░░░░
+/// ░░█░░░█░
+/// ```
+///
+/// ## `EMOJI_UNICODE[258]`: `U+1F7E0` `"🟠"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░████░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[259]`: `U+1F7E1` `"🟡"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░████░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[260]`: `U+1F7E2` `"🟢"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░████░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[261]`: `U+1F7E3` `"🟣"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░████░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[262]`: `U+1F7E4` `"🟤"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░████░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[263]`: `U+1F7E5` `"🟥"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[264]`: `U+1F7E6` `"🟦"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[265]`: `U+1F7E7` `"🟧"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[266]`: `U+1F7E8` `"🟨"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[267]`: `U+1F7E9` `"🟩"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[268]`: `U+1F7EA` `"🟪"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[269]`: `U+1F7EB` `"🟫"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[270]`: `U+1F90D` `"🤍"`
+///
+/// ```text
+/// ░██░██░░
+/// █░░█░░█░
+/// █░░░░░█░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[271]`: `U+1F90E` `"🤎"`
+///
+/// ```text
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[272]`: `U+1F90F` `"🤏"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░░░░░
+/// ░░█░░░░░
+/// ░░░░█░░░
+/// ░████░█░
+/// █░█████░
+/// ███████░
+/// ░████░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[273]`: `U+1F910` `"🤐"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// ████████
+/// ██░█░█░█
+/// █░░░░░░█
+/// ░█░█░█░░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[274]`: `U+1F913` `"🤓"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ░░░░░░░░
+/// █░█░░█░█
+/// █░░░░░░█
+/// █░█░░█░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[275]`: `U+1F914` `"🤔"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░░██░░█
+/// ██░███░█
+/// █████░██
+/// ██░░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[276]`: `U+1F916` `"🤖"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░█████░░
+/// █░░░░░█░
+/// █░█░█░█░
+/// █░░░░░█░
+/// █░███░█░
+/// ░█████░░
+/// ░█░░░█░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[277]`: `U+1F918` `"🤘"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░█░█░░
+/// ░██████░
+/// ░███████
+/// ░░█████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[278]`: `U+1F919` `"🤙"`
+///
+/// ```text
+/// ██░░░░░█
+/// ░██░░░██
+/// ░░█████░
+/// ░░█░█░█░
+/// ░░█████░
+/// ░░█████░
+/// ░░░███░░
+/// ░░░███░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[279]`: `U+1F91D` `"🤝"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░░░██
+/// ██░██░██
+/// ████████
+/// ░██████░
+/// ░░█████░
+/// ░░░███░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[280]`: `U+1F91E` `"🤞"`
+///
+/// ```text
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░░█████░
+/// ░██████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[281]`: `U+1F922` `"🤢"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ████████
+/// █░░██░░█
+/// ███░█░██
+/// ██░█░█░█
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[282]`: `U+1F924` `"🤤"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ████████
+/// █░░██░░█
+/// ██░██░██
+/// ███░░███
+/// ░████░█░
+/// ░░███░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[283]`: `U+1F928` `"🤨"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░░██░░█
+/// ██░███░█
+/// ████████
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[284]`: `U+1F929` `"🤩"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░█░░█░█
+/// █░█░░█░█
+/// █░░░░░░█
+/// ██░░░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[285]`: `U+1F92A` `"🤪"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░█████
+/// █████░░█
+/// ██░░░░██
+/// ████░░██
+/// ░███░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[286]`: `U+1F92C` `"🤬"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// ███░░███
+/// ██░██░██
+/// ██░█░███
+/// ███░█░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[287]`: `U+1F970` `"🥰"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// █░█░░█░█
+/// ██░██░██
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[288]`: `U+1F971` `"🥱"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ████████
+/// █░░██░░█
+/// ██░░░░██
+/// ██░██░██
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[289]`: `U+1F972` `"🥲"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░██
+/// █░█░░█░█
+/// ██░██░░█
+/// ███░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[290]`: `U+1F974` `"🥴"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░█████
+/// █████░░█
+/// ███░█░██
+/// ██░█░█░█
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[291]`: `U+1F975` `"🥵"`
+///
+/// ```text
+/// ░░██████
+/// ░███████
+/// █░████░█
+/// ██░██░██
+/// █░░░░░░█
+/// ████░░██
+/// ░███░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[292]`: `U+1F976` `"🥶"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░░██░░█
+/// █░░██░░█
+/// █░░░░░░█
+/// █░█░░█░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[293]`: `U+1F97A` `"🥺"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░░██░░█
+/// █░░██░░█
+/// ███░░███
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[294]`: `U+1F9E0` `"🧠"`
+///
+/// ```text
+/// ░██░██░░
+/// █░░█░░█░
+/// █░██░█░█
+/// █░░█░░██
+/// ██░█░█░█
+/// ░███░██░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[295]`: `U+1F9E1` `"🧡"`
+///
+/// ```text
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[296]`: `U+1F9ED` `"🧭"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░░░█░█
+/// █░░██░░█
+/// █░░██░░█
+/// █░█░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[297]`: `U+1FA75` `"\u{1fa75}"`
+///
+/// ```text
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[298]`: `U+1FA76` `"\u{1fa76}"`
+///
+/// ```text
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `EMOJI_UNICODE[299]`: `U+1FA77` `"\u{1fa77}"`
+///
+/// ```text
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+pub const EMOJI_FONTS: EmojiFonts = EmojiFonts(EMOJI_UNICODE);
+/// Strong-typed collection wrapper for [EMOJI_UNICODE](./constant.EMOJI_UNICODE.html).
+pub struct EmojiFonts([FontUnicode; 300]);
+
+impl EmojiFonts {
+    /// Create a new collection of `EMOJI_UNICODE` fonts.
+    pub fn new() -> Self {
+        EmojiFonts(EMOJI_UNICODE)
+    }
+}
+
+impl fmt::Debug for EmojiFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(EMOJI_UNICODE))
+    }
+}
+
+impl PartialEq for EmojiFonts {
+    fn eq(&self, other: &EmojiFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for EmojiFonts {
+    fn default() -> Self {
+        EmojiFonts::new()
+    }
+}
+
+impl UnicodeFonts for EmojiFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(300), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+/// Returns `true` for the characters that only modify the emoji before them: the
+/// variation selectors, skin tone modifiers, tag characters and the combining keycap.
+pub fn is_emoji_modifier(c: char) -> bool {
+    matches!(c,
+             '\u{FE0E}' | '\u{FE0F}' | '\u{20E3}' | '\u{1F3FB}'..='\u{1F3FF}' | '\u{E0020}'..='\u{E007F}')
+}
+
+/// Returns `true` for the pictographic characters that ZWJ sequences are built from.
+fn is_pictographic(c: char) -> bool {
+    matches!(c, '\u{2190}'..='\u{2BFF}' | '\u{1F000}'..='\u{1FAFF}')
+}
+
+/// Returns the length in bytes of the modifiers, and of the zero width joiners with the
+/// pictographs they join, that follow `base` at the start of `rest`.
+pub(crate) fn sequence_tail(base: char, rest: &str) -> usize {
+    let emoji = is_pictographic(base);
+    let mut joining = false;
+    rest.char_indices()
+        .find(|&(_, c)| {
+            let joined = is_emoji_modifier(c) || (joining && is_pictographic(c));
+            joining = emoji && c == '\u{200D}';
+            !joined && !joining
+        })
+        .map(|(idx, _)| idx)
+        .unwrap_or_else(|| rest.len())
+}
+
+/// Iterator over the emoji sequences of a string, returned by
+/// [emoji_sequences](./fn.emoji_sequences.html).
+#[derive(Clone, Debug)]
+pub struct EmojiSequences<'a> {
+    text: &'a str,
+}
+
+impl<'a> Iterator for EmojiSequences<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let base = self.text.chars().next()?;
+        let end = base.len_utf8() + sequence_tail(base, &self.text[base.len_utf8()..]);
+        let (sequence, rest) = self.text.split_at(end);
+        self.text = rest;
+        Some(sequence)
+    }
+}
+
+/// Splits `text` into sequences that each fall back to the glyph of their first
+/// character: a base character followed by any variation selectors and skin tone
+/// modifiers, and for emoji, by the pictographs joined to it with `U+200D`.
+///
+/// ```
+/// use font8x8::unicode::emoji_sequences;
+///
+/// let sequences: Vec<&str> = emoji_sequences("\u{1F44D}\u{1F3FD}\u{2764}\u{FE0F}").collect();
+/// assert_eq!(sequences, ["\u{1F44D}\u{1F3FD}", "\u{2764}\u{FE0F}"]);
+/// ```
+pub fn emoji_sequences<'a>(text: &'a str) -> EmojiSequences<'a> {
+    EmojiSequences { text }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emoji_set_implements_default_trait_with_method_new() {
+        let emoji_set: EmojiFonts = Default::default();
+        assert_eq!(emoji_set, EmojiFonts::new());
+    }
+
+    #[test]
+    fn emoji_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(EMOJI_FONTS, EmojiFonts::new());
+    }
+
+    #[test]
+    fn emoji_fonts_constant_wraps_emoji_unicode_constant() {
+        let emoji = EmojiFonts::new();
+        assert!(emoji.0.len() == EMOJI_UNICODE.len());
+        for (idx, font) in emoji.0.iter().enumerate() {
+            assert_eq!(font, &EMOJI_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn emoji_set_covers_common_emoji() {
+        for key in "😀😂😊😍😢😭😡👍👎👌👋👏🙏💪💔💙🔥💧🌈🌙🌧📱💻🔑🔒🏠🚗🎉🎁🕐🌕🐶".chars() {
+            assert!(EMOJI_FONTS.get(key).is_some(), "{:?}", key);
+        }
+    }
+
+    #[test]
+    fn emoji_sequences_fall_back_to_their_base() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let sequences: Vec<&str> = emoji_sequences(family).collect();
+        assert_eq!(sequences, [family]);
+        let sequences: Vec<&str> = emoji_sequences("\u{1F44B}\u{1F3FF}\u{FE0E}a\u{FE0F}b").collect();
+        assert_eq!(sequences, ["\u{1F44B}\u{1F3FF}\u{FE0E}", "a\u{FE0F}", "b"]);
+    }
+
+    #[test]
+    fn emoji_sequences_only_join_pictographs() {
+        let sequences: Vec<&str> = emoji_sequences("a\u{200D}b\u{1F600}\u{200D}c").collect();
+        assert_eq!(sequences, ["a", "\u{200D}", "b", "\u{1F600}\u{200D}", "c"]);
+    }
+}
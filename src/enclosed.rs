@@ -0,0 +1,2391 @@
+//! Enclosed Alphanumerics. `U+2460` - `U+24FF`
+//!
+//! Every digit and letter is a tiny 3x5 shape, with the digits and capitals of the
+//! control pictures. Numbers from ten to twenty use a narrow one or two and 3x4 digits,
+//! so that both digits fit in six columns; around them, circles and parentheses stay
+//! open at the sides.
+use super::unicode::{FontUnicode, UnicodeFonts};
+use core::fmt;
+
+/// A constant `[FontUnicode; 160]`, for Enclosed Alphanumerics fonts (`U+2460` - `U+24FF`).
+pub const ENCLOSED_UNICODE: [FontUnicode; 160] = [FontUnicode('\u{2460}', [0x3C, 0x4A, 0x8D, 0x89, 0x89, 0x9D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{2461}', [0x3C, 0x4E, 0x91, 0x89, 0x85, 0x9D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{2462}', [0x3C, 0x4E, 0x91, 0x89, 0x91, 0x8D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{2463}', [0x3C, 0x56, 0x95, 0x9D, 0x91, 0x91, 0x42, 0x3C]),
+                                                  FontUnicode('\u{2464}', [0x3C, 0x5E, 0x85, 0x8D, 0x91, 0x8D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{2465}', [0x3C, 0x5A, 0x85, 0x9D, 0x95, 0x9D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{2466}', [0x3C, 0x5E, 0x91, 0x89, 0x89, 0x89, 0x42, 0x3C]),
+                                                  FontUnicode('\u{2467}', [0x3C, 0x5E, 0x95, 0x9D, 0x95, 0x9D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{2468}', [0x3C, 0x5E, 0x95, 0x9D, 0x91, 0x8D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{2469}', [0x3C, 0x42, 0x74, 0x54, 0x54, 0x74, 0x42, 0x3C]),
+                                                  FontUnicode('\u{246A}', [0x3C, 0x42, 0x14, 0x14, 0x14, 0x14, 0x42, 0x3C]),
+                                                  FontUnicode('\u{246B}', [0x3C, 0x42, 0x34, 0x44, 0x24, 0x74, 0x42, 0x3C]),
+                                                  FontUnicode('\u{246C}', [0x3C, 0x42, 0x74, 0x64, 0x44, 0x74, 0x42, 0x3C]),
+                                                  FontUnicode('\u{246D}', [0x3C, 0x42, 0x54, 0x74, 0x44, 0x44, 0x42, 0x3C]),
+                                                  FontUnicode('\u{246E}', [0x3C, 0x42, 0x74, 0x34, 0x44, 0x34, 0x42, 0x3C]),
+                                                  FontUnicode('\u{246F}', [0x3C, 0x42, 0x14, 0x74, 0x54, 0x74, 0x42, 0x3C]),
+                                                  FontUnicode('\u{2470}', [0x3C, 0x42, 0x74, 0x44, 0x24, 0x24, 0x42, 0x3C]),
+                                                  FontUnicode('\u{2471}', [0x3C, 0x42, 0x64, 0x74, 0x54, 0x34, 0x42, 0x3C]),
+                                                  FontUnicode('\u{2472}', [0x3C, 0x42, 0x74, 0x54, 0x74, 0x44, 0x42, 0x3C]),
+                                                  FontUnicode('\u{2473}', [0x3C, 0x42, 0x76, 0x54, 0x52, 0x76, 0x42, 0x3C]),
+                                                  FontUnicode('\u{2474}', [0x42, 0x89, 0x8D, 0x89, 0x89, 0x9D, 0x81, 0x42]),
+                                                  FontUnicode('\u{2475}', [0x42, 0x8D, 0x91, 0x89, 0x85, 0x9D, 0x81, 0x42]),
+                                                  FontUnicode('\u{2476}', [0x42, 0x8D, 0x91, 0x89, 0x91, 0x8D, 0x81, 0x42]),
+                                                  FontUnicode('\u{2477}', [0x42, 0x95, 0x95, 0x9D, 0x91, 0x91, 0x81, 0x42]),
+                                                  FontUnicode('\u{2478}', [0x42, 0x9D, 0x85, 0x8D, 0x91, 0x8D, 0x81, 0x42]),
+                                                  FontUnicode('\u{2479}', [0x42, 0x99, 0x85, 0x9D, 0x95, 0x9D, 0x81, 0x42]),
+                                                  FontUnicode('\u{247A}', [0x42, 0x9D, 0x91, 0x89, 0x89, 0x89, 0x81, 0x42]),
+                                                  FontUnicode('\u{247B}', [0x42, 0x9D, 0x95, 0x9D, 0x95, 0x9D, 0x81, 0x42]),
+                                                  FontUnicode('\u{247C}', [0x42, 0x9D, 0x95, 0x9D, 0x91, 0x8D, 0x81, 0x42]),
+                                                  FontUnicode('\u{247D}', [0x42, 0x81, 0x74, 0x54, 0x54, 0x74, 0x81, 0x42]),
+                                                  FontUnicode('\u{247E}', [0x42, 0x81, 0x14, 0x14, 0x14, 0x14, 0x81, 0x42]),
+                                                  FontUnicode('\u{247F}', [0x42, 0x81, 0x34, 0x44, 0x24, 0x74, 0x81, 0x42]),
+                                                  FontUnicode('\u{2480}', [0x42, 0x81, 0x74, 0x64, 0x44, 0x74, 0x81, 0x42]),
+                                                  FontUnicode('\u{2481}', [0x42, 0x81, 0x54, 0x74, 0x44, 0x44, 0x81, 0x42]),
+                                                  FontUnicode('\u{2482}', [0x42, 0x81, 0x74, 0x34, 0x44, 0x34, 0x81, 0x42]),
+                                                  FontUnicode('\u{2483}', [0x42, 0x81, 0x14, 0x74, 0x54, 0x74, 0x81, 0x42]),
+                                                  FontUnicode('\u{2484}', [0x42, 0x81, 0x74, 0x44, 0x24, 0x24, 0x81, 0x42]),
+                                                  FontUnicode('\u{2485}', [0x42, 0x81, 0x64, 0x74, 0x54, 0x34, 0x81, 0x42]),
+                                                  FontUnicode('\u{2486}', [0x42, 0x81, 0x74, 0x54, 0x74, 0x44, 0x81, 0x42]),
+                                                  FontUnicode('\u{2487}', [0x42, 0x81, 0x76, 0x54, 0x52, 0x76, 0x81, 0x42]),
+                                                  FontUnicode('\u{2488}', [0x00, 0x04, 0x06, 0x04, 0x04, 0x2E, 0x00, 0x00]),
+                                                  FontUnicode('\u{2489}', [0x00, 0x06, 0x08, 0x04, 0x02, 0x2E, 0x00, 0x00]),
+                                                  FontUnicode('\u{248A}', [0x00, 0x06, 0x08, 0x04, 0x08, 0x26, 0x00, 0x00]),
+                                                  FontUnicode('\u{248B}', [0x00, 0x0A, 0x0A, 0x0E, 0x08, 0x28, 0x00, 0x00]),
+                                                  FontUnicode('\u{248C}', [0x00, 0x0E, 0x02, 0x06, 0x08, 0x26, 0x00, 0x00]),
+                                                  FontUnicode('\u{248D}', [0x00, 0x0C, 0x02, 0x0E, 0x0A, 0x2E, 0x00, 0x00]),
+                                                  FontUnicode('\u{248E}', [0x00, 0x0E, 0x08, 0x04, 0x04, 0x24, 0x00, 0x00]),
+                                                  FontUnicode('\u{248F}', [0x00, 0x0E, 0x0A, 0x0E, 0x0A, 0x2E, 0x00, 0x00]),
+                                                  FontUnicode('\u{2490}', [0x00, 0x0E, 0x0A, 0x0E, 0x08, 0x26, 0x00, 0x00]),
+                                                  FontUnicode('\u{2491}', [0x00, 0x3A, 0x2A, 0x2A, 0x2A, 0xBA, 0x00, 0x00]),
+                                                  FontUnicode('\u{2492}', [0x00, 0x12, 0x1A, 0x12, 0x12, 0xBA, 0x00, 0x00]),
+                                                  FontUnicode('\u{2493}', [0x00, 0x1A, 0x22, 0x12, 0x0A, 0xBA, 0x00, 0x00]),
+                                                  FontUnicode('\u{2494}', [0x00, 0x1A, 0x22, 0x12, 0x22, 0x9A, 0x00, 0x00]),
+                                                  FontUnicode('\u{2495}', [0x00, 0x2A, 0x2A, 0x3A, 0x22, 0xA2, 0x00, 0x00]),
+                                                  FontUnicode('\u{2496}', [0x00, 0x3A, 0x0A, 0x1A, 0x22, 0x9A, 0x00, 0x00]),
+                                                  FontUnicode('\u{2497}', [0x00, 0x32, 0x0A, 0x3A, 0x2A, 0xBA, 0x00, 0x00]),
+                                                  FontUnicode('\u{2498}', [0x00, 0x3A, 0x22, 0x12, 0x12, 0x92, 0x00, 0x00]),
+                                                  FontUnicode('\u{2499}', [0x00, 0x3A, 0x2A, 0x3A, 0x2A, 0xBA, 0x00, 0x00]),
+                                                  FontUnicode('\u{249A}', [0x00, 0x3A, 0x2A, 0x3A, 0x22, 0x9A, 0x00, 0x00]),
+                                                  FontUnicode('\u{249B}', [0x00, 0x3B, 0x2A, 0x2B, 0x29, 0xBB, 0x00, 0x00]),
+                                                  FontUnicode('\u{249C}', [0x42, 0x81, 0x8D, 0x99, 0x95, 0x99, 0x81, 0x42]),
+                                                  FontUnicode('\u{249D}', [0x42, 0x85, 0x8D, 0x95, 0x95, 0x8D, 0x81, 0x42]),
+                                                  FontUnicode('\u{249E}', [0x42, 0x81, 0x99, 0x85, 0x85, 0x99, 0x81, 0x42]),
+                                                  FontUnicode('\u{249F}', [0x42, 0x91, 0x99, 0x95, 0x95, 0x99, 0x81, 0x42]),
+                                                  FontUnicode('\u{24A0}', [0x42, 0x81, 0x89, 0x9D, 0x85, 0x99, 0x81, 0x42]),
+                                                  FontUnicode('\u{24A1}', [0x42, 0x99, 0x89, 0x9D, 0x89, 0x89, 0x81, 0x42]),
+                                                  FontUnicode('\u{24A2}', [0x42, 0x81, 0x99, 0x95, 0x99, 0x8D, 0x81, 0x42]),
+                                                  FontUnicode('\u{24A3}', [0x42, 0x85, 0x85, 0x8D, 0x95, 0x95, 0x81, 0x42]),
+                                                  FontUnicode('\u{24A4}', [0x42, 0x89, 0x81, 0x8D, 0x89, 0x9D, 0x81, 0x42]),
+                                                  FontUnicode('\u{24A5}', [0x42, 0x91, 0x81, 0x91, 0x95, 0x89, 0x81, 0x42]),
+                                                  FontUnicode('\u{24A6}', [0x42, 0x85, 0x95, 0x8D, 0x8D, 0x95, 0x81, 0x42]),
+                                                  FontUnicode('\u{24A7}', [0x42, 0x8D, 0x89, 0x89, 0x89, 0x9D, 0x81, 0x42]),
+                                                  FontUnicode('\u{24A8}', [0x42, 0x81, 0x8D, 0x9D, 0x95, 0x95, 0x81, 0x42]),
+                                                  FontUnicode('\u{24A9}', [0x42, 0x81, 0x8D, 0x95, 0x95, 0x95, 0x81, 0x42]),
+                                                  FontUnicode('\u{24AA}', [0x42, 0x81, 0x89, 0x95, 0x95, 0x89, 0x81, 0x42]),
+                                                  FontUnicode('\u{24AB}', [0x42, 0x81, 0x8D, 0x95, 0x8D, 0x85, 0x81, 0x42]),
+                                                  FontUnicode('\u{24AC}', [0x42, 0x81, 0x99, 0x95, 0x99, 0x91, 0x81, 0x42]),
+                                                  FontUnicode('\u{24AD}', [0x42, 0x81, 0x95, 0x8D, 0x85, 0x85, 0x81, 0x42]),
+                                                  FontUnicode('\u{24AE}', [0x42, 0x81, 0x99, 0x89, 0x91, 0x8D, 0x81, 0x42]),
+                                                  FontUnicode('\u{24AF}', [0x42, 0x89, 0x9D, 0x89, 0x89, 0x99, 0x81, 0x42]),
+                                                  FontUnicode('\u{24B0}', [0x42, 0x81, 0x95, 0x95, 0x95, 0x99, 0x81, 0x42]),
+                                                  FontUnicode('\u{24B1}', [0x42, 0x81, 0x95, 0x95, 0x95, 0x89, 0x81, 0x42]),
+                                                  FontUnicode('\u{24B2}', [0x42, 0x81, 0x95, 0x95, 0x9D, 0x95, 0x81, 0x42]),
+                                                  FontUnicode('\u{24B3}', [0x42, 0x81, 0x95, 0x89, 0x89, 0x95, 0x81, 0x42]),
+                                                  FontUnicode('\u{24B4}', [0x42, 0x81, 0x95, 0x95, 0x99, 0x8D, 0x81, 0x42]),
+                                                  FontUnicode('\u{24B5}', [0x42, 0x81, 0x9D, 0x91, 0x89, 0x9D, 0x81, 0x42]),
+                                                  FontUnicode('\u{24B6}', [0x3C, 0x4A, 0x95, 0x9D, 0x95, 0x95, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24B7}', [0x3C, 0x4E, 0x95, 0x8D, 0x95, 0x8D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24B8}', [0x3C, 0x5A, 0x85, 0x85, 0x85, 0x99, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24B9}', [0x3C, 0x4E, 0x95, 0x95, 0x95, 0x8D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24BA}', [0x3C, 0x5E, 0x85, 0x8D, 0x85, 0x9D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24BB}', [0x3C, 0x5E, 0x85, 0x8D, 0x85, 0x85, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24BC}', [0x3C, 0x5A, 0x85, 0x95, 0x95, 0x99, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24BD}', [0x3C, 0x56, 0x95, 0x9D, 0x95, 0x95, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24BE}', [0x3C, 0x5E, 0x89, 0x89, 0x89, 0x9D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24BF}', [0x3C, 0x52, 0x91, 0x91, 0x95, 0x89, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24C0}', [0x3C, 0x56, 0x95, 0x8D, 0x95, 0x95, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24C1}', [0x3C, 0x46, 0x85, 0x85, 0x85, 0x9D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24C2}', [0x3C, 0x56, 0x9D, 0x9D, 0x95, 0x95, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24C3}', [0x3C, 0x5E, 0x95, 0x95, 0x95, 0x95, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24C4}', [0x3C, 0x4A, 0x95, 0x95, 0x95, 0x89, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24C5}', [0x3C, 0x4E, 0x95, 0x8D, 0x85, 0x85, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24C6}', [0x3C, 0x4A, 0x95, 0x95, 0x8D, 0x99, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24C7}', [0x3C, 0x4E, 0x95, 0x8D, 0x95, 0x95, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24C8}', [0x3C, 0x5A, 0x85, 0x89, 0x91, 0x8D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24C9}', [0x3C, 0x5E, 0x89, 0x89, 0x89, 0x89, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24CA}', [0x3C, 0x56, 0x95, 0x95, 0x95, 0x9D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24CB}', [0x3C, 0x56, 0x95, 0x95, 0x95, 0x89, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24CC}', [0x3C, 0x56, 0x95, 0x9D, 0x9D, 0x95, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24CD}', [0x3C, 0x56, 0x95, 0x89, 0x95, 0x95, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24CE}', [0x3C, 0x56, 0x95, 0x89, 0x89, 0x89, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24CF}', [0x3C, 0x5E, 0x91, 0x89, 0x85, 0x9D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24D0}', [0x3C, 0x42, 0x8D, 0x99, 0x95, 0x99, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24D1}', [0x3C, 0x46, 0x8D, 0x95, 0x95, 0x8D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24D2}', [0x3C, 0x42, 0x99, 0x85, 0x85, 0x99, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24D3}', [0x3C, 0x52, 0x99, 0x95, 0x95, 0x99, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24D4}', [0x3C, 0x42, 0x89, 0x9D, 0x85, 0x99, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24D5}', [0x3C, 0x5A, 0x89, 0x9D, 0x89, 0x89, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24D6}', [0x3C, 0x42, 0x99, 0x95, 0x99, 0x8D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24D7}', [0x3C, 0x46, 0x85, 0x8D, 0x95, 0x95, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24D8}', [0x3C, 0x4A, 0x81, 0x8D, 0x89, 0x9D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24D9}', [0x3C, 0x52, 0x81, 0x91, 0x95, 0x89, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24DA}', [0x3C, 0x46, 0x95, 0x8D, 0x8D, 0x95, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24DB}', [0x3C, 0x4E, 0x89, 0x89, 0x89, 0x9D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24DC}', [0x3C, 0x42, 0x8D, 0x9D, 0x95, 0x95, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24DD}', [0x3C, 0x42, 0x8D, 0x95, 0x95, 0x95, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24DE}', [0x3C, 0x42, 0x89, 0x95, 0x95, 0x89, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24DF}', [0x3C, 0x42, 0x8D, 0x95, 0x8D, 0x85, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24E0}', [0x3C, 0x42, 0x99, 0x95, 0x99, 0x91, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24E1}', [0x3C, 0x42, 0x95, 0x8D, 0x85, 0x85, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24E2}', [0x3C, 0x42, 0x99, 0x89, 0x91, 0x8D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24E3}', [0x3C, 0x4A, 0x9D, 0x89, 0x89, 0x99, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24E4}', [0x3C, 0x42, 0x95, 0x95, 0x95, 0x99, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24E5}', [0x3C, 0x42, 0x95, 0x95, 0x95, 0x89, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24E6}', [0x3C, 0x42, 0x95, 0x95, 0x9D, 0x95, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24E7}', [0x3C, 0x42, 0x95, 0x89, 0x89, 0x95, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24E8}', [0x3C, 0x42, 0x95, 0x95, 0x99, 0x8D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24E9}', [0x3C, 0x42, 0x9D, 0x91, 0x89, 0x9D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24EA}', [0x3C, 0x5E, 0x95, 0x95, 0x95, 0x9D, 0x42, 0x3C]),
+                                                  FontUnicode('\u{24EB}', [0x3C, 0x7E, 0xEB, 0xEB, 0xEB, 0xEB, 0x7E, 0x3C]),
+                                                  FontUnicode('\u{24EC}', [0x3C, 0x7E, 0xCB, 0xBB, 0xDB, 0x8B, 0x7E, 0x3C]),
+                                                  FontUnicode('\u{24ED}', [0x3C, 0x7E, 0x8B, 0x9B, 0xBB, 0x8B, 0x7E, 0x3C]),
+                                                  FontUnicode('\u{24EE}', [0x3C, 0x7E, 0xAB, 0x8B, 0xBB, 0xBB, 0x7E, 0x3C]),
+                                                  FontUnicode('\u{24EF}', [0x3C, 0x7E, 0x8B, 0xCB, 0xBB, 0xCB, 0x7E, 0x3C]),
+                                                  FontUnicode('\u{24F0}', [0x3C, 0x7E, 0xEB, 0x8B, 0xAB, 0x8B, 0x7E, 0x3C]),
+                                                  FontUnicode('\u{24F1}', [0x3C, 0x7E, 0x8B, 0xBB, 0xDB, 0xDB, 0x7E, 0x3C]),
+                                                  FontUnicode('\u{24F2}', [0x3C, 0x7E, 0x9B, 0x8B, 0xAB, 0xCB, 0x7E, 0x3C]),
+                                                  FontUnicode('\u{24F3}', [0x3C, 0x7E, 0x8B, 0xAB, 0x8B, 0xBB, 0x7E, 0x3C]),
+                                                  FontUnicode('\u{24F4}', [0x3C, 0x7E, 0x89, 0xAB, 0xAD, 0x89, 0x7E, 0x3C]),
+                                                  FontUnicode('\u{24F5}', [0x3C, 0x5A, 0x85, 0x85, 0x85, 0x85, 0x5A, 0x3C]),
+                                                  FontUnicode('\u{24F6}', [0x3C, 0x5A, 0x8D, 0x91, 0x89, 0x9D, 0x5A, 0x3C]),
+                                                  FontUnicode('\u{24F7}', [0x3C, 0x5A, 0x9D, 0x99, 0x91, 0x9D, 0x5A, 0x3C]),
+                                                  FontUnicode('\u{24F8}', [0x3C, 0x5A, 0x95, 0x9D, 0x91, 0x91, 0x5A, 0x3C]),
+                                                  FontUnicode('\u{24F9}', [0x3C, 0x5A, 0x9D, 0x8D, 0x91, 0x8D, 0x5A, 0x3C]),
+                                                  FontUnicode('\u{24FA}', [0x3C, 0x5A, 0x85, 0x9D, 0x95, 0x9D, 0x5A, 0x3C]),
+                                                  FontUnicode('\u{24FB}', [0x3C, 0x5A, 0x9D, 0x91, 0x89, 0x89, 0x5A, 0x3C]),
+                                                  FontUnicode('\u{24FC}', [0x3C, 0x5A, 0x99, 0x9D, 0x95, 0x8D, 0x5A, 0x3C]),
+                                                  FontUnicode('\u{24FD}', [0x3C, 0x5A, 0x9D, 0x95, 0x9D, 0x91, 0x5A, 0x3C]),
+                                                  FontUnicode('\u{24FE}', [0x3C, 0x5A, 0x74, 0x54, 0x54, 0x74, 0x5A, 0x3C]),
+                                                  FontUnicode('\u{24FF}', [0x3C, 0x62, 0xEB, 0xEB, 0xEB, 0xE3, 0x7E, 0x3C])];
+
+/// A convenient constant for Enclosed Alphanumerics fonts (`U+2460` - `U+24FF`), that implements the `UnicodeFonts` trait.
+///
+/// ## `ENCLOSED_UNICODE[0]`: `U+2460` `"①"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░██░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░███░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[1]`: `U+2461` `"②"`
+///
+/// ```text
+/// ░░████░░
+/// ░███░░█░
+/// █░░░█░░█
+/// █░░█░░░█
+/// █░█░░░░█
+/// █░███░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[2]`: `U+2462` `"③"`
+///
+/// ```text
+/// ░░████░░
+/// ░███░░█░
+/// █░░░█░░█
+/// █░░█░░░█
+/// █░░░█░░█
+/// █░██░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[3]`: `U+2463` `"④"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░█░█░
+/// █░█░█░░█
+/// █░███░░█
+/// █░░░█░░█
+/// █░░░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[4]`: `U+2464` `"⑤"`
+///
+/// ```text
+/// ░░████░░
+/// ░████░█░
+/// █░█░░░░█
+/// █░██░░░█
+/// █░░░█░░█
+/// █░██░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[5]`: `U+2465` `"⑥"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░█░░░░█
+/// █░███░░█
+/// █░█░█░░█
+/// █░███░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[6]`: `U+2466` `"⑦"`
+///
+/// ```text
+/// ░░████░░
+/// ░████░█░
+/// █░░░█░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[7]`: `U+2467` `"⑧"`
+///
+/// ```text
+/// ░░████░░
+/// ░████░█░
+/// █░█░█░░█
+/// █░███░░█
+/// █░█░█░░█
+/// █░███░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[8]`: `U+2468` `"⑨"`
+///
+/// ```text
+/// ░░████░░
+/// ░████░█░
+/// █░█░█░░█
+/// █░███░░█
+/// █░░░█░░█
+/// █░██░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[9]`: `U+2469` `"⑩"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// ░░█░███░
+/// ░░█░█░█░
+/// ░░█░█░█░
+/// ░░█░███░
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[10]`: `U+246A` `"⑪"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[11]`: `U+246B` `"⑫"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// ░░█░██░░
+/// ░░█░░░█░
+/// ░░█░░█░░
+/// ░░█░███░
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[12]`: `U+246C` `"⑬"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// ░░█░███░
+/// ░░█░░██░
+/// ░░█░░░█░
+/// ░░█░███░
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[13]`: `U+246D` `"⑭"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// ░░█░█░█░
+/// ░░█░███░
+/// ░░█░░░█░
+/// ░░█░░░█░
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[14]`: `U+246E` `"⑮"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// ░░█░███░
+/// ░░█░██░░
+/// ░░█░░░█░
+/// ░░█░██░░
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[15]`: `U+246F` `"⑯"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// ░░█░█░░░
+/// ░░█░███░
+/// ░░█░█░█░
+/// ░░█░███░
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[16]`: `U+2470` `"⑰"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// ░░█░███░
+/// ░░█░░░█░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[17]`: `U+2471` `"⑱"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// ░░█░░██░
+/// ░░█░███░
+/// ░░█░█░█░
+/// ░░█░██░░
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[18]`: `U+2472` `"⑲"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// ░░█░███░
+/// ░░█░█░█░
+/// ░░█░███░
+/// ░░█░░░█░
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[19]`: `U+2473` `"⑳"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// ░██░███░
+/// ░░█░█░█░
+/// ░█░░█░█░
+/// ░██░███░
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[20]`: `U+2474` `"⑴"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░█░░░█
+/// █░██░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░███░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[21]`: `U+2475` `"⑵"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░██░░░█
+/// █░░░█░░█
+/// █░░█░░░█
+/// █░█░░░░█
+/// █░███░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[22]`: `U+2476` `"⑶"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░██░░░█
+/// █░░░█░░█
+/// █░░█░░░█
+/// █░░░█░░█
+/// █░██░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[23]`: `U+2477` `"⑷"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░███░░█
+/// █░░░█░░█
+/// █░░░█░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[24]`: `U+2478` `"⑸"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░███░░█
+/// █░█░░░░█
+/// █░██░░░█
+/// █░░░█░░█
+/// █░██░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[25]`: `U+2479` `"⑹"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░██░░█
+/// █░█░░░░█
+/// █░███░░█
+/// █░█░█░░█
+/// █░███░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[26]`: `U+247A` `"⑺"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░███░░█
+/// █░░░█░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[27]`: `U+247B` `"⑻"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░███░░█
+/// █░█░█░░█
+/// █░███░░█
+/// █░█░█░░█
+/// █░███░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[28]`: `U+247C` `"⑼"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░███░░█
+/// █░█░█░░█
+/// █░███░░█
+/// █░░░█░░█
+/// █░██░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[29]`: `U+247D` `"⑽"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// ░░█░███░
+/// ░░█░█░█░
+/// ░░█░█░█░
+/// ░░█░███░
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[30]`: `U+247E` `"⑾"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[31]`: `U+247F` `"⑿"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// ░░█░██░░
+/// ░░█░░░█░
+/// ░░█░░█░░
+/// ░░█░███░
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[32]`: `U+2480` `"⒀"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// ░░█░███░
+/// ░░█░░██░
+/// ░░█░░░█░
+/// ░░█░███░
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[33]`: `U+2481` `"⒁"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// ░░█░█░█░
+/// ░░█░███░
+/// ░░█░░░█░
+/// ░░█░░░█░
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[34]`: `U+2482` `"⒂"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// ░░█░███░
+/// ░░█░██░░
+/// ░░█░░░█░
+/// ░░█░██░░
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[35]`: `U+2483` `"⒃"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// ░░█░█░░░
+/// ░░█░███░
+/// ░░█░█░█░
+/// ░░█░███░
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[36]`: `U+2484` `"⒄"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// ░░█░███░
+/// ░░█░░░█░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[37]`: `U+2485` `"⒅"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// ░░█░░██░
+/// ░░█░███░
+/// ░░█░█░█░
+/// ░░█░██░░
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[38]`: `U+2486` `"⒆"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// ░░█░███░
+/// ░░█░█░█░
+/// ░░█░███░
+/// ░░█░░░█░
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[39]`: `U+2487` `"⒇"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// ░██░███░
+/// ░░█░█░█░
+/// ░█░░█░█░
+/// ░██░███░
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[40]`: `U+2488` `"⒈"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░██░░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░███░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[41]`: `U+2489` `"⒉"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░░░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// ░███░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[42]`: `U+248A` `"⒊"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░░░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░░░█░░░░
+/// ░██░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[43]`: `U+248B` `"⒋"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░█░░░░
+/// ░█░█░░░░
+/// ░███░░░░
+/// ░░░█░░░░
+/// ░░░█░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[44]`: `U+248C` `"⒌"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░███░░░░
+/// ░█░░░░░░
+/// ░██░░░░░
+/// ░░░█░░░░
+/// ░██░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[45]`: `U+248D` `"⒍"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░█░░░░░░
+/// ░███░░░░
+/// ░█░█░░░░
+/// ░███░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[46]`: `U+248E` `"⒎"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░███░░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[47]`: `U+248F` `"⒏"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░███░░░░
+/// ░█░█░░░░
+/// ░███░░░░
+/// ░█░█░░░░
+/// ░███░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[48]`: `U+2490` `"⒐"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░███░░░░
+/// ░█░█░░░░
+/// ░███░░░░
+/// ░░░█░░░░
+/// ░██░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[49]`: `U+2491` `"⒑"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░███░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░█░███░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[50]`: `U+2492` `"⒒"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░░█░░░
+/// ░█░██░░░
+/// ░█░░█░░░
+/// ░█░░█░░░
+/// ░█░███░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[51]`: `U+2493` `"⒓"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░██░░░
+/// ░█░░░█░░
+/// ░█░░█░░░
+/// ░█░█░░░░
+/// ░█░███░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[52]`: `U+2494` `"⒔"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░██░░░
+/// ░█░░░█░░
+/// ░█░░█░░░
+/// ░█░░░█░░
+/// ░█░██░░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[53]`: `U+2495` `"⒕"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░█░███░░
+/// ░█░░░█░░
+/// ░█░░░█░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[54]`: `U+2496` `"⒖"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░███░░
+/// ░█░█░░░░
+/// ░█░██░░░
+/// ░█░░░█░░
+/// ░█░██░░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[55]`: `U+2497` `"⒗"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░░██░░
+/// ░█░█░░░░
+/// ░█░███░░
+/// ░█░█░█░░
+/// ░█░███░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[56]`: `U+2498` `"⒘"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░███░░
+/// ░█░░░█░░
+/// ░█░░█░░░
+/// ░█░░█░░░
+/// ░█░░█░░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[57]`: `U+2499` `"⒙"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░███░░
+/// ░█░█░█░░
+/// ░█░███░░
+/// ░█░█░█░░
+/// ░█░███░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[58]`: `U+249A` `"⒚"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░███░░
+/// ░█░█░█░░
+/// ░█░███░░
+/// ░█░░░█░░
+/// ░█░██░░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[59]`: `U+249B` `"⒛"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░███░░
+/// ░█░█░█░░
+/// ██░█░█░░
+/// █░░█░█░░
+/// ██░███░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[60]`: `U+249C` `"⒜"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░██░░░█
+/// █░░██░░█
+/// █░█░█░░█
+/// █░░██░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[61]`: `U+249D` `"⒝"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░█░░░░█
+/// █░██░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░██░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[62]`: `U+249E` `"⒞"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░░██░░█
+/// █░█░░░░█
+/// █░█░░░░█
+/// █░░██░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[63]`: `U+249F` `"⒟"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░█░░█
+/// █░░██░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░██░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[64]`: `U+24A0` `"⒠"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░░█░░░█
+/// █░███░░█
+/// █░█░░░░█
+/// █░░██░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[65]`: `U+24A1` `"⒡"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░██░░█
+/// █░░█░░░█
+/// █░███░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[66]`: `U+24A2` `"⒢"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░░██░░█
+/// █░█░█░░█
+/// █░░██░░█
+/// █░██░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[67]`: `U+24A3` `"⒣"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░█░░░░█
+/// █░█░░░░█
+/// █░██░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[68]`: `U+24A4` `"⒤"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░█░░░█
+/// █░░░░░░█
+/// █░██░░░█
+/// █░░█░░░█
+/// █░███░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[69]`: `U+24A5` `"⒥"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░█░░█
+/// █░░░░░░█
+/// █░░░█░░█
+/// █░█░█░░█
+/// █░░█░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[70]`: `U+24A6` `"⒦"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░█░░░░█
+/// █░█░█░░█
+/// █░██░░░█
+/// █░██░░░█
+/// █░█░█░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[71]`: `U+24A7` `"⒧"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░██░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░███░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[72]`: `U+24A8` `"⒨"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░██░░░█
+/// █░███░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[73]`: `U+24A9` `"⒩"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░██░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[74]`: `U+24AA` `"⒪"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░░█░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░█░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[75]`: `U+24AB` `"⒫"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░██░░░█
+/// █░█░█░░█
+/// █░██░░░█
+/// █░█░░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[76]`: `U+24AC` `"⒬"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░░██░░█
+/// █░█░█░░█
+/// █░░██░░█
+/// █░░░█░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[77]`: `U+24AD` `"⒭"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░█░█░░█
+/// █░██░░░█
+/// █░█░░░░█
+/// █░█░░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[78]`: `U+24AE` `"⒮"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░░██░░█
+/// █░░█░░░█
+/// █░░░█░░█
+/// █░██░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[79]`: `U+24AF` `"⒯"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░█░░░█
+/// █░███░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░██░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[80]`: `U+24B0` `"⒰"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░██░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[81]`: `U+24B1` `"⒱"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░█░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[82]`: `U+24B2` `"⒲"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░███░░█
+/// █░█░█░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[83]`: `U+24B3` `"⒳"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░█░█░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░█░█░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[84]`: `U+24B4` `"⒴"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░██░░█
+/// █░██░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[85]`: `U+24B5` `"⒵"`
+///
+/// ```text
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░███░░█
+/// █░░░█░░█
+/// █░░█░░░█
+/// █░███░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[86]`: `U+24B6` `"Ⓐ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░█░█░░█
+/// █░███░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[87]`: `U+24B7` `"Ⓑ"`
+///
+/// ```text
+/// ░░████░░
+/// ░███░░█░
+/// █░█░█░░█
+/// █░██░░░█
+/// █░█░█░░█
+/// █░██░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[88]`: `U+24B8` `"Ⓒ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░█░░░░█
+/// █░█░░░░█
+/// █░█░░░░█
+/// █░░██░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[89]`: `U+24B9` `"Ⓓ"`
+///
+/// ```text
+/// ░░████░░
+/// ░███░░█░
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░██░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[90]`: `U+24BA` `"Ⓔ"`
+///
+/// ```text
+/// ░░████░░
+/// ░████░█░
+/// █░█░░░░█
+/// █░██░░░█
+/// █░█░░░░█
+/// █░███░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[91]`: `U+24BB` `"Ⓕ"`
+///
+/// ```text
+/// ░░████░░
+/// ░████░█░
+/// █░█░░░░█
+/// █░██░░░█
+/// █░█░░░░█
+/// █░█░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[92]`: `U+24BC` `"Ⓖ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░█░░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░██░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[93]`: `U+24BD` `"Ⓗ"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░█░█░
+/// █░█░█░░█
+/// █░███░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[94]`: `U+24BE` `"Ⓘ"`
+///
+/// ```text
+/// ░░████░░
+/// ░████░█░
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░███░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[95]`: `U+24BF` `"Ⓙ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░█░█░
+/// █░░░█░░█
+/// █░░░█░░█
+/// █░█░█░░█
+/// █░░█░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[96]`: `U+24C0` `"Ⓚ"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░█░█░
+/// █░█░█░░█
+/// █░██░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[97]`: `U+24C1` `"Ⓛ"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░░█░
+/// █░█░░░░█
+/// █░█░░░░█
+/// █░█░░░░█
+/// █░███░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[98]`: `U+24C2` `"Ⓜ"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░█░█░
+/// █░███░░█
+/// █░███░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[99]`: `U+24C3` `"Ⓝ"`
+///
+/// ```text
+/// ░░████░░
+/// ░████░█░
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[100]`: `U+24C4` `"Ⓞ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░█░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[101]`: `U+24C5` `"Ⓟ"`
+///
+/// ```text
+/// ░░████░░
+/// ░███░░█░
+/// █░█░█░░█
+/// █░██░░░█
+/// █░█░░░░█
+/// █░█░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[102]`: `U+24C6` `"Ⓠ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░██░░░█
+/// █░░██░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[103]`: `U+24C7` `"Ⓡ"`
+///
+/// ```text
+/// ░░████░░
+/// ░███░░█░
+/// █░█░█░░█
+/// █░██░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[104]`: `U+24C8` `"Ⓢ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░█░░░░█
+/// █░░█░░░█
+/// █░░░█░░█
+/// █░██░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[105]`: `U+24C9` `"Ⓣ"`
+///
+/// ```text
+/// ░░████░░
+/// ░████░█░
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[106]`: `U+24CA` `"Ⓤ"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░█░█░
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░███░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[107]`: `U+24CB` `"Ⓥ"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░█░█░
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░█░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[108]`: `U+24CC` `"Ⓦ"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░█░█░
+/// █░█░█░░█
+/// █░███░░█
+/// █░███░░█
+/// █░█░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[109]`: `U+24CD` `"Ⓧ"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░█░█░
+/// █░█░█░░█
+/// █░░█░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[110]`: `U+24CE` `"Ⓨ"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░█░█░
+/// █░█░█░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[111]`: `U+24CF` `"Ⓩ"`
+///
+/// ```text
+/// ░░████░░
+/// ░████░█░
+/// █░░░█░░█
+/// █░░█░░░█
+/// █░█░░░░█
+/// █░███░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[112]`: `U+24D0` `"ⓐ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░██░░░█
+/// █░░██░░█
+/// █░█░█░░█
+/// █░░██░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[113]`: `U+24D1` `"ⓑ"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░░█░
+/// █░██░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░██░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[114]`: `U+24D2` `"ⓒ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░██░░█
+/// █░█░░░░█
+/// █░█░░░░█
+/// █░░██░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[115]`: `U+24D3` `"ⓓ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░█░█░
+/// █░░██░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░██░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[116]`: `U+24D4` `"ⓔ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░█░░░█
+/// █░███░░█
+/// █░█░░░░█
+/// █░░██░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[117]`: `U+24D5` `"ⓕ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░░█░░░█
+/// █░███░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[118]`: `U+24D6` `"ⓖ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░██░░█
+/// █░█░█░░█
+/// █░░██░░█
+/// █░██░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[119]`: `U+24D7` `"ⓗ"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░░█░
+/// █░█░░░░█
+/// █░██░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[120]`: `U+24D8` `"ⓘ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░░░░░░█
+/// █░██░░░█
+/// █░░█░░░█
+/// █░███░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[121]`: `U+24D9` `"ⓙ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░█░█░
+/// █░░░░░░█
+/// █░░░█░░█
+/// █░█░█░░█
+/// █░░█░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[122]`: `U+24DA` `"ⓚ"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░░█░
+/// █░█░█░░█
+/// █░██░░░█
+/// █░██░░░█
+/// █░█░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[123]`: `U+24DB` `"ⓛ"`
+///
+/// ```text
+/// ░░████░░
+/// ░███░░█░
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░███░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[124]`: `U+24DC` `"ⓜ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░██░░░█
+/// █░███░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[125]`: `U+24DD` `"ⓝ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░██░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[126]`: `U+24DE` `"ⓞ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░█░░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░█░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[127]`: `U+24DF` `"ⓟ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░██░░░█
+/// █░█░█░░█
+/// █░██░░░█
+/// █░█░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[128]`: `U+24E0` `"ⓠ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░██░░█
+/// █░█░█░░█
+/// █░░██░░█
+/// █░░░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[129]`: `U+24E1` `"ⓡ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░█░█░░█
+/// █░██░░░█
+/// █░█░░░░█
+/// █░█░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[130]`: `U+24E2` `"ⓢ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░██░░█
+/// █░░█░░░█
+/// █░░░█░░█
+/// █░██░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[131]`: `U+24E3` `"ⓣ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░█░░█░
+/// █░███░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░░██░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[132]`: `U+24E4` `"ⓤ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░██░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[133]`: `U+24E5` `"ⓥ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░█░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[134]`: `U+24E6` `"ⓦ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░███░░█
+/// █░█░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[135]`: `U+24E7` `"ⓧ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░█░█░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// █░█░█░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[136]`: `U+24E8` `"ⓨ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░░██░░█
+/// █░██░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[137]`: `U+24E9` `"ⓩ"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░███░░█
+/// █░░░█░░█
+/// █░░█░░░█
+/// █░███░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[138]`: `U+24EA` `"⓪"`
+///
+/// ```text
+/// ░░████░░
+/// ░████░█░
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░█░█░░█
+/// █░███░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[139]`: `U+24EB` `"⓫"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░█░███
+/// ██░█░███
+/// ██░█░███
+/// ██░█░███
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[140]`: `U+24EC` `"⓬"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░█░░██
+/// ██░███░█
+/// ██░██░██
+/// ██░█░░░█
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[141]`: `U+24ED` `"⓭"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░█░░░█
+/// ██░██░░█
+/// ██░███░█
+/// ██░█░░░█
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[142]`: `U+24EE` `"⓮"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░█░█░█
+/// ██░█░░░█
+/// ██░███░█
+/// ██░███░█
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[143]`: `U+24EF` `"⓯"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░█░░░█
+/// ██░█░░██
+/// ██░███░█
+/// ██░█░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[144]`: `U+24F0` `"⓰"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░█░███
+/// ██░█░░░█
+/// ██░█░█░█
+/// ██░█░░░█
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[145]`: `U+24F1` `"⓱"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░█░░░█
+/// ██░███░█
+/// ██░██░██
+/// ██░██░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[146]`: `U+24F2` `"⓲"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░██░░█
+/// ██░█░░░█
+/// ██░█░█░█
+/// ██░█░░██
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[147]`: `U+24F3` `"⓳"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// ██░█░░░█
+/// ██░█░█░█
+/// ██░█░░░█
+/// ██░███░█
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[148]`: `U+24F4` `"⓴"`
+///
+/// ```text
+/// ░░████░░
+/// ░██████░
+/// █░░█░░░█
+/// ██░█░█░█
+/// █░██░█░█
+/// █░░█░░░█
+/// ░██████░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[149]`: `U+24F5` `"⓵"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░█░░░░█
+/// █░█░░░░█
+/// █░█░░░░█
+/// █░█░░░░█
+/// ░█░██░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[150]`: `U+24F6` `"⓶"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░██░░░█
+/// █░░░█░░█
+/// █░░█░░░█
+/// █░███░░█
+/// ░█░██░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[151]`: `U+24F7` `"⓷"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░███░░█
+/// █░░██░░█
+/// █░░░█░░█
+/// █░███░░█
+/// ░█░██░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[152]`: `U+24F8` `"⓸"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░█░█░░█
+/// █░███░░█
+/// █░░░█░░█
+/// █░░░█░░█
+/// ░█░██░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[153]`: `U+24F9` `"⓹"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░███░░█
+/// █░██░░░█
+/// █░░░█░░█
+/// █░██░░░█
+/// ░█░██░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[154]`: `U+24FA` `"⓺"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░█░░░░█
+/// █░███░░█
+/// █░█░█░░█
+/// █░███░░█
+/// ░█░██░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[155]`: `U+24FB` `"⓻"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░███░░█
+/// █░░░█░░█
+/// █░░█░░░█
+/// █░░█░░░█
+/// ░█░██░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[156]`: `U+24FC` `"⓼"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░░██░░█
+/// █░███░░█
+/// █░█░█░░█
+/// █░██░░░█
+/// ░█░██░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[157]`: `U+24FD` `"⓽"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// █░███░░█
+/// █░█░█░░█
+/// █░███░░█
+/// █░░░█░░█
+/// ░█░██░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[158]`: `U+24FE` `"⓾"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░██░█░
+/// ░░█░███░
+/// ░░█░█░█░
+/// ░░█░█░█░
+/// ░░█░███░
+/// ░█░██░█░
+/// ░░████░░
+/// ```
+///
+/// ## `ENCLOSED_UNICODE[159]`: `U+24FF` `"⓿"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░██░
+/// ██░█░███
+/// ██░█░███
+/// ██░█░███
+/// ██░░░███
+/// ░██████░
+/// ░░████░░
+/// ```
+pub const ENCLOSED_FONTS: EnclosedFonts = EnclosedFonts(ENCLOSED_UNICODE);
+/// Strong-typed collection wrapper for [ENCLOSED_UNICODE](./constant.ENCLOSED_UNICODE.html).
+pub struct EnclosedFonts([FontUnicode; 160]);
+
+impl EnclosedFonts {
+    /// Create a new collection of `ENCLOSED_UNICODE` fonts.
+    pub fn new() -> Self {
+        EnclosedFonts(ENCLOSED_UNICODE)
+    }
+}
+
+impl fmt::Debug for EnclosedFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(ENCLOSED_UNICODE))
+    }
+}
+
+impl PartialEq for EnclosedFonts {
+    fn eq(&self, other: &EnclosedFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for EnclosedFonts {
+    fn default() -> Self {
+        EnclosedFonts::new()
+    }
+}
+
+impl UnicodeFonts for EnclosedFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(160), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pictures::TINY_HEX;
+
+    #[test]
+    fn enclosed_set_implements_default_trait_with_method_new() {
+        let enclosed_set: EnclosedFonts = Default::default();
+        assert_eq!(enclosed_set, EnclosedFonts::new());
+    }
+
+    #[test]
+    fn enclosed_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(ENCLOSED_FONTS, EnclosedFonts::new());
+    }
+
+    #[test]
+    fn enclosed_fonts_constant_wraps_enclosed_unicode_constant() {
+        let enclosed = EnclosedFonts::new();
+        assert!(enclosed.0.len() == ENCLOSED_UNICODE.len());
+        for (idx, font) in enclosed.0.iter().enumerate() {
+            assert_eq!(font, &ENCLOSED_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn enclosed_set_covers_the_whole_block() {
+        for code in 0x2460..0x2500 {
+            let key = ::core::char::from_u32(code).unwrap();
+            assert!(ENCLOSED_FONTS.get(key).is_some(), "{:?}", key);
+        }
+    }
+
+    #[test]
+    fn enclosed_circled_digits_are_the_tiny_control_picture_digits() {
+        for digit in 0..10 {
+            let code = if digit == 0 { 0x24EA } else { 0x245F + digit };
+            let glyph = ENCLOSED_FONTS.get(::core::char::from_u32(code).unwrap()).unwrap();
+            for row in 0..5 {
+                assert_eq!(glyph[row + 1] & 0x3C, TINY_HEX[digit as usize][row] << 2);
+            }
+        }
+    }
+
+    #[test]
+    fn enclosed_numbers_are_all_distinct() {
+        for &(first, last) in &[('①', '⑳'), ('⑴', '⒇'), ('⒈', '⒛'), ('⓫', '⓴'), ('⓵', '⓾')] {
+            for a in first as u32..last as u32 {
+                for b in a + 1..last as u32 + 1 {
+                    assert_ne!(ENCLOSED_FONTS.get(::core::char::from_u32(a).unwrap()),
+                               ENCLOSED_FONTS.get(::core::char::from_u32(b).unwrap()));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Halfwidth and Fullwidth Forms. `U+FF01` - `U+FF5E`, `U+FFE0` - `U+FFE6`
+//!
+//! The fullwidth forms of ASCII, and of the signs `¢ £ ¬ ¯ ¦ ¥ ₩`, are not stored.
+//! Instead, the glyph of the narrow character is stretched to a double-width 16x8
+//! cell, by doubling every column and moving it one column right, so that it stays
+//! centered. Glyphs with ink in their last column, such as `_`, are not moved, so
+//! that they keep their full width.
+use super::legacy::BASIC_LEGACY;
+use super::{FontUnicode, UnicodeFonts, CURRENCY_FONTS, LATIN_FONTS};
+use core::{char, fmt};
+
+/// The first fullwidth form of ASCII, `U+FF01` `"！"`.
+const ASCII_FIRST: u32 = 0xFF01;
+/// The last fullwidth form of ASCII, `U+FF5E` `"～"`.
+const ASCII_LAST: u32 = 0xFF5E;
+/// Distance from a fullwidth form of ASCII down to its ASCII character.
+const ASCII_OFFSET: u32 = 0xFEE0;
+/// Narrow characters of the fullwidth signs, `U+FFE0` - `U+FFE6`.
+const SIGNS: [char; 7] = ['¢', '£', '¬', '¯', '¦', '¥', '₩'];
+/// The first fullwidth sign, `U+FFE0` `"￠"`.
+const SIGN_FIRST: u32 = 0xFFE0;
+
+/// Ranges of the Wide and Fullwidth characters of the East Asian Width property
+/// (Unicode Standard Annex #11), with the unassigned code points of the CJK ideograph
+/// blocks, which default to wide.
+const WIDE: [(u32, u32); 121] = [(0x1100, 0x115F), (0x231A, 0x231B), (0x2329, 0x232A), (0x23E9, 0x23EC),
+                                 (0x23F0, 0x23F0), (0x23F3, 0x23F3), (0x25FD, 0x25FE), (0x2614, 0x2615),
+                                 (0x2648, 0x2653), (0x267F, 0x267F), (0x2693, 0x2693), (0x26A1, 0x26A1),
+                                 (0x26AA, 0x26AB), (0x26BD, 0x26BE), (0x26C4, 0x26C5), (0x26CE, 0x26CE),
+                                 (0x26D4, 0x26D4), (0x26EA, 0x26EA), (0x26F2, 0x26F3), (0x26F5, 0x26F5),
+                                 (0x26FA, 0x26FA), (0x26FD, 0x26FD), (0x2705, 0x2705), (0x270A, 0x270B),
+                                 (0x2728, 0x2728), (0x274C, 0x274C), (0x274E, 0x274E), (0x2753, 0x2755),
+                                 (0x2757, 0x2757), (0x2795, 0x2797), (0x27B0, 0x27B0), (0x27BF, 0x27BF),
+                                 (0x2B1B, 0x2B1C), (0x2B50, 0x2B50), (0x2B55, 0x2B55), (0x2E80, 0x2E99),
+                                 (0x2E9B, 0x2EF3), (0x2F00, 0x2FD5), (0x2FF0, 0x2FFB), (0x3000, 0x303E),
+                                 (0x3041, 0x3096), (0x3099, 0x30FF), (0x3105, 0x312F), (0x3131, 0x318E),
+                                 (0x3190, 0x31E3), (0x31F0, 0x321E), (0x3220, 0x3247), (0x3250, 0x4DBF),
+                                 (0x4E00, 0xA48C), (0xA490, 0xA4C6), (0xA960, 0xA97C), (0xAC00, 0xD7A3),
+                                 (0xF900, 0xFAFF), (0xFE10, 0xFE19), (0xFE30, 0xFE52), (0xFE54, 0xFE66),
+                                 (0xFE68, 0xFE6B), (0xFF01, 0xFF60), (0xFFE0, 0xFFE6), (0x16FE0, 0x16FE4),
+                                 (0x16FF0, 0x16FF1), (0x17000, 0x187F7), (0x18800, 0x18CD5), (0x18D00, 0x18D08),
+                                 (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122),
+                                 (0x1B150, 0x1B152), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1F004, 0x1F004),
+                                 (0x1F0CF, 0x1F0CF), (0x1F18E, 0x1F18E), (0x1F191, 0x1F19A), (0x1F200, 0x1F202),
+                                 (0x1F210, 0x1F23B), (0x1F240, 0x1F248), (0x1F250, 0x1F251), (0x1F260, 0x1F265),
+                                 (0x1F300, 0x1F320), (0x1F32D, 0x1F335), (0x1F337, 0x1F37C), (0x1F37E, 0x1F393),
+                                 (0x1F3A0, 0x1F3CA), (0x1F3CF, 0x1F3D3), (0x1F3E0, 0x1F3F0), (0x1F3F4, 0x1F3F4),
+                                 (0x1F3F8, 0x1F43E), (0x1F440, 0x1F440), (0x1F442, 0x1F4FC), (0x1F4FF, 0x1F53D),
+                                 (0x1F54B, 0x1F54E), (0x1F550, 0x1F567), (0x1F57A, 0x1F57A), (0x1F595, 0x1F596),
+                                 (0x1F5A4, 0x1F5A4), (0x1F5FB, 0x1F64F), (0x1F680, 0x1F6C5), (0x1F6CC, 0x1F6CC),
+                                 (0x1F6D0, 0x1F6D2), (0x1F6D5, 0x1F6D7), (0x1F6DD, 0x1F6DF), (0x1F6EB, 0x1F6EC),
+                                 (0x1F6F4, 0x1F6FC), (0x1F7E0, 0x1F7EB), (0x1F7F0, 0x1F7F0), (0x1F90C, 0x1F93A),
+                                 (0x1F93C, 0x1F945), (0x1F947, 0x1F9FF), (0x1FA70, 0x1FA74), (0x1FA78, 0x1FA7C),
+                                 (0x1FA80, 0x1FA86), (0x1FA90, 0x1FAAC), (0x1FAB0, 0x1FABA), (0x1FAC0, 0x1FAC5),
+                                 (0x1FAD0, 0x1FAD9), (0x1FAE0, 0x1FAE7), (0x1FAF0, 0x1FAF6), (0x20000, 0x2FFFD),
+                                 (0x30000, 0x3FFFD)];
+
+/// A computed set of fullwidth fonts (`U+FF01` - `U+FF5E`, `U+FFE0` - `U+FFE6`), that
+/// implements the `UnicodeFonts` trait.
+///
+/// Every glyph is derived from the narrow character, and is designed for a double-width
+/// 16x8 cell, which is available from
+/// [get_wide](./struct.FullwidthFonts.html#method.get_wide). `UnicodeFonts::get` returns
+/// the glyph of the narrow character, for renderers that only have single cells.
+///
+/// ## `U+FF21` `"Ａ"`, 16x8
+///
+/// ```text
+/// ░░░░░████░░░░░░░
+/// ░░░████████░░░░░
+/// ░████░░░░████░░░
+/// ░████░░░░████░░░
+/// ░████████████░░░
+/// ░████░░░░████░░░
+/// ░████░░░░████░░░
+/// ░░░░░░░░░░░░░░░░
+/// ```
+///
+/// ## `U+FF11` `"１"`, 16x8
+///
+/// ```text
+/// ░░░░░████░░░░░░░
+/// ░░░██████░░░░░░░
+/// ░░░░░████░░░░░░░
+/// ░░░░░████░░░░░░░
+/// ░░░░░████░░░░░░░
+/// ░░░░░████░░░░░░░
+/// ░████████████░░░
+/// ░░░░░░░░░░░░░░░░
+/// ```
+///
+/// ## `U+FF21` `"Ａ"`, 8x8
+///
+/// ```text
+/// ░░█░░░░░
+/// ░███░░░░
+/// ██░██░░░
+/// ██░██░░░
+/// █████░░░
+/// ██░██░░░
+/// ██░██░░░
+/// ░░░░░░░░
+/// ```
+pub const FULLWIDTH_FONTS: FullwidthFonts = FullwidthFonts;
+
+/// Strong-typed wrapper for the computed fullwidth fonts.
+#[derive(Clone, Copy, PartialEq)]
+pub struct FullwidthFonts;
+
+impl FullwidthFonts {
+    /// Create a new collection of fullwidth fonts.
+    pub fn new() -> Self {
+        FullwidthFonts
+    }
+
+    /// Returns the 16x8 rendering of a fullwidth character as its left and right 8x8
+    /// cells, or `None` if `key` isn't in the set.
+    pub fn get_wide(&self, key: char) -> Option<[[u8; 8]; 2]> {
+        self.get(key).map(stretch)
+    }
+}
+
+impl fmt::Debug for FullwidthFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(FULLWIDTH_FONTS))
+    }
+}
+
+impl Default for FullwidthFonts {
+    fn default() -> Self {
+        FullwidthFonts::new()
+    }
+}
+
+impl UnicodeFonts for FullwidthFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        let code = key as u32;
+        if (ASCII_FIRST..=ASCII_LAST).contains(&code) {
+            return Some(BASIC_LEGACY[(code - ASCII_OFFSET) as usize]);
+        }
+        let narrow = SIGNS.get(code.wrapping_sub(SIGN_FIRST) as usize)?;
+        LATIN_FONTS.get(*narrow).or_else(|| CURRENCY_FONTS.get(*narrow))
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        self.get(key).map(|glyph| FontUnicode(key, glyph))
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, (_, font)) in self.to_vec().iter().enumerate() {
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        (ASCII_FIRST..SIGN_FIRST + SIGNS.len() as u32).filter_map(char::from_u32)
+                                                      .filter_map(|c| self.get_font(c))
+                                                      .fold(Vec::with_capacity(101), |mut v, font| {
+                                                          v.push((font.char(), font));
+                                                          v
+                                                      })
+    }
+}
+
+/// Returns a `bool` indicating whether `c` takes two cells in East Asian text, because
+/// its East Asian Width is Wide or Fullwidth, like `"Ａ"`, `"あ"` or `"한"`.
+pub fn is_wide(c: char) -> bool {
+    let code = c as u32;
+    match WIDE.binary_search_by_key(&code, |&(first, _)| first) {
+        Ok(_) => true,
+        Err(0) => false,
+        Err(idx) => code <= WIDE[idx - 1].1,
+    }
+}
+
+/// Stretches an 8x8 glyph into the left and right cells of a 16x8 glyph, doubling
+/// every column and, when the last column is empty, moving it one column right.
+pub(crate) fn stretch(glyph: [u8; 8]) -> [[u8; 8]; 2] {
+    let shift = glyph.iter().all(|row| row & 0x80 == 0) as u32;
+    let mut cells = [[0u8; 8]; 2];
+    for (idx, row) in glyph.iter().enumerate() {
+        let mut wide = 0u16;
+        for bit in 0..8 {
+            if *row & 1 << bit != 0 {
+                wide |= 0b11 << (2 * bit);
+            }
+        }
+        wide <<= shift;
+        cells[0][idx] = wide as u8;
+        cells[1][idx] = (wide >> 8) as u8;
+    }
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fullwidth_set_implements_default_trait_with_method_new() {
+        let fullwidth_set: FullwidthFonts = Default::default();
+        assert_eq!(fullwidth_set, FullwidthFonts::new());
+    }
+
+    #[test]
+    fn fullwidth_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(FULLWIDTH_FONTS, FullwidthFonts::new());
+    }
+
+    #[test]
+    fn fullwidth_forms_are_the_basic_latin_glyphs() {
+        for (wide, narrow) in "ＡＢＣ１２３！～".chars().zip("ABC123!~".chars()) {
+            assert_eq!(FULLWIDTH_FONTS.get(wide), Some(BASIC_LEGACY[narrow as usize]));
+        }
+        assert_eq!(FULLWIDTH_FONTS.get('￥'), LATIN_FONTS.get('¥'));
+        assert_eq!(FULLWIDTH_FONTS.get('￦'), CURRENCY_FONTS.get('₩'));
+        assert_eq!(FULLWIDTH_FONTS.get('A'), None);
+        assert_eq!(FULLWIDTH_FONTS.get('\u{FF5F}'), None);
+        assert_eq!(FULLWIDTH_FONTS.get('\u{FFE7}'), None);
+    }
+
+    #[test]
+    fn fullwidth_wide_glyphs_double_every_column() {
+        let [left, right] = FULLWIDTH_FONTS.get_wide('Ａ').unwrap();
+        assert_eq!(left, [0xE0, 0xF8, 0x1E, 0x1E, 0xFE, 0x1E, 0x1E, 0x00]);
+        assert_eq!(right, [0x01, 0x07, 0x1E, 0x1E, 0x1F, 0x1E, 0x1E, 0x00]);
+    }
+
+    #[test]
+    fn fullwidth_wide_glyphs_keep_ink_in_the_last_column() {
+        let [left, right] = FULLWIDTH_FONTS.get_wide('＿').unwrap();
+        assert!(left.iter().zip(right.iter()).any(|(&l, &r)| l == 0xFF && r == 0xFF));
+    }
+
+    #[test]
+    fn fullwidth_is_wide_follows_the_east_asian_width() {
+        for c in "Ａ～￥あア한中\u{3000}\u{20000}".chars() {
+            assert!(is_wide(c), "{:?}", c);
+        }
+        for c in "A~¥ｱ\u{0301}\u{FF61}─é".chars() {
+            assert!(!is_wide(c), "{:?}", c);
+        }
+    }
+}
@@ -0,0 +1,1497 @@
+//! Geometric Shapes. `U+25A0` - `U+25FF`
+//!
+//! Filled shapes are solid runs of the same pixels as the `BLOCK_LEGACY` full block: the
+//! corner triangles split it in two, and the inverse shapes are cut out of it.
+use super::unicode::{FontUnicode, UnicodeFonts};
+use core::fmt;
+
+/// A constant `[FontUnicode; 96]`, for Geometric Shapes fonts (`U+25A0` - `U+25FF`).
+pub const GEOMETRIC_UNICODE: [FontUnicode; 96] = [FontUnicode('\u{25A0}', [0x00, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25A1}', [0x00, 0x7E, 0x42, 0x42, 0x42, 0x42, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25A2}', [0x00, 0x3C, 0x42, 0x42, 0x42, 0x42, 0x3C, 0x00]),
+                                                  FontUnicode('\u{25A3}', [0x00, 0x7E, 0x42, 0x5A, 0x5A, 0x42, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25A4}', [0x00, 0x7E, 0x42, 0x7E, 0x42, 0x7E, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25A5}', [0x00, 0x7E, 0x6A, 0x6A, 0x6A, 0x6A, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25A6}', [0x00, 0x7E, 0x6A, 0x7E, 0x6A, 0x7E, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25A7}', [0x00, 0x7E, 0x66, 0x4A, 0x52, 0x66, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25A8}', [0x00, 0x7E, 0x52, 0x4A, 0x66, 0x52, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25A9}', [0x00, 0x7E, 0x66, 0x5A, 0x5A, 0x66, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25AA}', [0x00, 0x00, 0x1C, 0x1C, 0x1C, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{25AB}', [0x00, 0x00, 0x1C, 0x14, 0x1C, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{25AC}', [0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00]),
+                                                  FontUnicode('\u{25AD}', [0x00, 0x00, 0xFF, 0x81, 0x81, 0xFF, 0x00, 0x00]),
+                                                  FontUnicode('\u{25AE}', [0x3C, 0x3C, 0x3C, 0x3C, 0x3C, 0x3C, 0x3C, 0x3C]),
+                                                  FontUnicode('\u{25AF}', [0x3C, 0x24, 0x24, 0x24, 0x24, 0x24, 0x24, 0x3C]),
+                                                  FontUnicode('\u{25B0}', [0x00, 0x00, 0xF8, 0x7C, 0x3E, 0x1F, 0x00, 0x00]),
+                                                  FontUnicode('\u{25B1}', [0x00, 0x00, 0xF8, 0x44, 0x22, 0x1F, 0x00, 0x00]),
+                                                  FontUnicode('\u{25B2}', [0x00, 0x08, 0x1C, 0x1C, 0x3E, 0x3E, 0x7F, 0x00]),
+                                                  FontUnicode('\u{25B3}', [0x00, 0x08, 0x14, 0x14, 0x22, 0x22, 0x7F, 0x00]),
+                                                  FontUnicode('\u{25B4}', [0x00, 0x00, 0x00, 0x08, 0x1C, 0x3E, 0x00, 0x00]),
+                                                  FontUnicode('\u{25B5}', [0x00, 0x00, 0x00, 0x08, 0x14, 0x3E, 0x00, 0x00]),
+                                                  FontUnicode('\u{25B6}', [0x02, 0x0E, 0x3E, 0x7E, 0x3E, 0x0E, 0x02, 0x00]),
+                                                  FontUnicode('\u{25B7}', [0x02, 0x0E, 0x32, 0x42, 0x32, 0x0E, 0x02, 0x00]),
+                                                  FontUnicode('\u{25B8}', [0x00, 0x08, 0x18, 0x38, 0x18, 0x08, 0x00, 0x00]),
+                                                  FontUnicode('\u{25B9}', [0x00, 0x08, 0x18, 0x28, 0x18, 0x08, 0x00, 0x00]),
+                                                  FontUnicode('\u{25BA}', [0x01, 0x07, 0x1F, 0x7F, 0x1F, 0x07, 0x01, 0x00]),
+                                                  FontUnicode('\u{25BB}', [0x01, 0x07, 0x19, 0x61, 0x19, 0x07, 0x01, 0x00]),
+                                                  FontUnicode('\u{25BC}', [0x00, 0x7F, 0x3E, 0x3E, 0x1C, 0x1C, 0x08, 0x00]),
+                                                  FontUnicode('\u{25BD}', [0x00, 0x7F, 0x22, 0x22, 0x14, 0x14, 0x08, 0x00]),
+                                                  FontUnicode('\u{25BE}', [0x00, 0x00, 0x3E, 0x1C, 0x08, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{25BF}', [0x00, 0x00, 0x3E, 0x14, 0x08, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{25C0}', [0x20, 0x38, 0x3E, 0x3F, 0x3E, 0x38, 0x20, 0x00]),
+                                                  FontUnicode('\u{25C1}', [0x20, 0x38, 0x26, 0x21, 0x26, 0x38, 0x20, 0x00]),
+                                                  FontUnicode('\u{25C2}', [0x00, 0x08, 0x0C, 0x0E, 0x0C, 0x08, 0x00, 0x00]),
+                                                  FontUnicode('\u{25C3}', [0x00, 0x08, 0x0C, 0x0A, 0x0C, 0x08, 0x00, 0x00]),
+                                                  FontUnicode('\u{25C4}', [0x40, 0x70, 0x7C, 0x7F, 0x7C, 0x70, 0x40, 0x00]),
+                                                  FontUnicode('\u{25C5}', [0x40, 0x70, 0x4C, 0x43, 0x4C, 0x70, 0x40, 0x00]),
+                                                  FontUnicode('\u{25C6}', [0x08, 0x1C, 0x3E, 0x7F, 0x3E, 0x1C, 0x08, 0x00]),
+                                                  FontUnicode('\u{25C7}', [0x08, 0x14, 0x22, 0x41, 0x22, 0x14, 0x08, 0x00]),
+                                                  FontUnicode('\u{25C8}', [0x08, 0x14, 0x2A, 0x5D, 0x2A, 0x14, 0x08, 0x00]),
+                                                  FontUnicode('\u{25C9}', [0x1C, 0x22, 0x5D, 0x5D, 0x5D, 0x22, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25CA}', [0x08, 0x14, 0x22, 0x22, 0x22, 0x14, 0x08, 0x00]),
+                                                  FontUnicode('\u{25CB}', [0x1C, 0x22, 0x41, 0x41, 0x41, 0x22, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25CC}', [0x08, 0x00, 0x00, 0x41, 0x00, 0x00, 0x08, 0x00]),
+                                                  FontUnicode('\u{25CD}', [0x1C, 0x2A, 0x6B, 0x6B, 0x6B, 0x2A, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25CE}', [0x1C, 0x22, 0x5D, 0x55, 0x5D, 0x22, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25CF}', [0x1C, 0x3E, 0x7F, 0x7F, 0x7F, 0x3E, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25D0}', [0x1C, 0x2E, 0x4F, 0x4F, 0x4F, 0x2E, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25D1}', [0x1C, 0x3A, 0x79, 0x79, 0x79, 0x3A, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25D2}', [0x1C, 0x22, 0x41, 0x7F, 0x7F, 0x3E, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25D3}', [0x1C, 0x3E, 0x7F, 0x7F, 0x41, 0x22, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25D4}', [0x1C, 0x3A, 0x79, 0x79, 0x41, 0x22, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25D5}', [0x1C, 0x2E, 0x4F, 0x7F, 0x7F, 0x3E, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25D6}', [0x0C, 0x0E, 0x0F, 0x0F, 0x0F, 0x0E, 0x0C, 0x00]),
+                                                  FontUnicode('\u{25D7}', [0x18, 0x38, 0x78, 0x78, 0x78, 0x38, 0x18, 0x00]),
+                                                  FontUnicode('\u{25D8}', [0xFF, 0xFF, 0xF3, 0xE1, 0xE1, 0xF3, 0xFF, 0xFF]),
+                                                  FontUnicode('\u{25D9}', [0xE3, 0xDD, 0xBE, 0xBE, 0xBE, 0xDD, 0xE3, 0xFF]),
+                                                  FontUnicode('\u{25DA}', [0xE3, 0xDD, 0xBE, 0xBE, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{25DB}', [0x00, 0x00, 0x00, 0x00, 0xBE, 0xDD, 0xE3, 0xFF]),
+                                                  FontUnicode('\u{25DC}', [0x0C, 0x02, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{25DD}', [0x18, 0x20, 0x40, 0x40, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{25DE}', [0x00, 0x00, 0x00, 0x40, 0x40, 0x20, 0x18, 0x00]),
+                                                  FontUnicode('\u{25DF}', [0x00, 0x00, 0x00, 0x01, 0x01, 0x02, 0x0C, 0x00]),
+                                                  FontUnicode('\u{25E0}', [0x1C, 0x22, 0x41, 0x41, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{25E1}', [0x00, 0x00, 0x00, 0x41, 0x41, 0x22, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25E2}', [0x80, 0xC0, 0xE0, 0xF0, 0xF8, 0xFC, 0xFE, 0xFF]),
+                                                  FontUnicode('\u{25E3}', [0x01, 0x03, 0x07, 0x0F, 0x1F, 0x3F, 0x7F, 0xFF]),
+                                                  FontUnicode('\u{25E4}', [0x7F, 0x3F, 0x1F, 0x0F, 0x07, 0x03, 0x01, 0x00]),
+                                                  FontUnicode('\u{25E5}', [0xFE, 0xFC, 0xF8, 0xF0, 0xE0, 0xC0, 0x80, 0x00]),
+                                                  FontUnicode('\u{25E6}', [0x00, 0x00, 0x0C, 0x12, 0x12, 0x0C, 0x00, 0x00]),
+                                                  FontUnicode('\u{25E7}', [0x00, 0x7E, 0x4E, 0x4E, 0x4E, 0x4E, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25E8}', [0x00, 0x7E, 0x72, 0x72, 0x72, 0x72, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25E9}', [0x00, 0x7E, 0x7E, 0x5E, 0x4E, 0x46, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25EA}', [0x00, 0x7E, 0x62, 0x72, 0x7A, 0x7E, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25EB}', [0x00, 0x7E, 0x5A, 0x5A, 0x5A, 0x5A, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25EC}', [0x00, 0x08, 0x14, 0x14, 0x2A, 0x22, 0x7F, 0x00]),
+                                                  FontUnicode('\u{25ED}', [0x00, 0x08, 0x1C, 0x1C, 0x2E, 0x2E, 0x7F, 0x00]),
+                                                  FontUnicode('\u{25EE}', [0x00, 0x08, 0x1C, 0x1C, 0x3A, 0x3A, 0x7F, 0x00]),
+                                                  FontUnicode('\u{25EF}', [0x3C, 0x42, 0x81, 0x81, 0x81, 0x81, 0x42, 0x3C]),
+                                                  FontUnicode('\u{25F0}', [0x00, 0x7E, 0x4A, 0x4E, 0x42, 0x42, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25F1}', [0x00, 0x7E, 0x42, 0x4E, 0x4A, 0x4A, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25F2}', [0x00, 0x7E, 0x42, 0x7A, 0x4A, 0x4A, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25F3}', [0x00, 0x7E, 0x4A, 0x7A, 0x42, 0x42, 0x7E, 0x00]),
+                                                  FontUnicode('\u{25F4}', [0x1C, 0x2A, 0x49, 0x4F, 0x41, 0x22, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25F5}', [0x1C, 0x22, 0x41, 0x4F, 0x49, 0x2A, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25F6}', [0x1C, 0x22, 0x41, 0x79, 0x49, 0x2A, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25F7}', [0x1C, 0x2A, 0x49, 0x79, 0x41, 0x22, 0x1C, 0x00]),
+                                                  FontUnicode('\u{25F8}', [0x7F, 0x21, 0x11, 0x09, 0x05, 0x03, 0x01, 0x00]),
+                                                  FontUnicode('\u{25F9}', [0xFE, 0x84, 0x88, 0x90, 0xA0, 0xC0, 0x80, 0x00]),
+                                                  FontUnicode('\u{25FA}', [0x01, 0x03, 0x05, 0x09, 0x11, 0x21, 0x41, 0xFF]),
+                                                  FontUnicode('\u{25FB}', [0x00, 0x3E, 0x22, 0x22, 0x22, 0x3E, 0x00, 0x00]),
+                                                  FontUnicode('\u{25FC}', [0x00, 0x3E, 0x3E, 0x3E, 0x3E, 0x3E, 0x00, 0x00]),
+                                                  FontUnicode('\u{25FD}', [0x00, 0x00, 0x3C, 0x24, 0x24, 0x3C, 0x00, 0x00]),
+                                                  FontUnicode('\u{25FE}', [0x00, 0x00, 0x3C, 0x3C, 0x3C, 0x3C, 0x00, 0x00]),
+                                                  FontUnicode('\u{25FF}', [0x80, 0xC0, 0xA0, 0x90, 0x88, 0x84, 0x82, 0xFF])];
+
+/// A convenient constant for Geometric Shapes fonts (`U+25A0` - `U+25FF`), that implements the `UnicodeFonts` trait.
+///
+/// ## `GEOMETRIC_UNICODE[0]`: `U+25A0` `"■"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[1]`: `U+25A1` `"□"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[2]`: `U+25A2` `"▢"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░████░░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[3]`: `U+25A3` `"▣"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░█░░░░█░
+/// ░█░██░█░
+/// ░█░██░█░
+/// ░█░░░░█░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[4]`: `U+25A4` `"▤"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░█░░░░█░
+/// ░██████░
+/// ░█░░░░█░
+/// ░██████░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[5]`: `U+25A5` `"▥"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░█░█░██░
+/// ░█░█░██░
+/// ░█░█░██░
+/// ░█░█░██░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[6]`: `U+25A6` `"▦"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░█░█░██░
+/// ░██████░
+/// ░█░█░██░
+/// ░██████░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[7]`: `U+25A7` `"▧"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░██░░██░
+/// ░█░█░░█░
+/// ░█░░█░█░
+/// ░██░░██░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[8]`: `U+25A8` `"▨"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░█░░█░█░
+/// ░█░█░░█░
+/// ░██░░██░
+/// ░█░░█░█░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[9]`: `U+25A9` `"▩"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░██░░██░
+/// ░█░██░█░
+/// ░█░██░█░
+/// ░██░░██░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[10]`: `U+25AA` `"▪"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[11]`: `U+25AB` `"▫"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[12]`: `U+25AC` `"▬"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ████████
+/// ████████
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[13]`: `U+25AD` `"▭"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// █░░░░░░█
+/// █░░░░░░█
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[14]`: `U+25AE` `"▮"`
+///
+/// ```text
+/// ░░████░░
+/// ░░████░░
+/// ░░████░░
+/// ░░████░░
+/// ░░████░░
+/// ░░████░░
+/// ░░████░░
+/// ░░████░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[15]`: `U+25AF` `"▯"`
+///
+/// ```text
+/// ░░████░░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░░████░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[16]`: `U+25B0` `"▰"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█████
+/// ░░█████░
+/// ░█████░░
+/// █████░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[17]`: `U+25B1` `"▱"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█████
+/// ░░█░░░█░
+/// ░█░░░█░░
+/// █████░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[18]`: `U+25B2` `"▲"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░█████░░
+/// ░█████░░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[19]`: `U+25B3` `"△"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[20]`: `U+25B4` `"▴"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[21]`: `U+25B5` `"▵"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[22]`: `U+25B6` `"▶"`
+///
+/// ```text
+/// ░█░░░░░░
+/// ░███░░░░
+/// ░█████░░
+/// ░██████░
+/// ░█████░░
+/// ░███░░░░
+/// ░█░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[23]`: `U+25B7` `"▷"`
+///
+/// ```text
+/// ░█░░░░░░
+/// ░███░░░░
+/// ░█░░██░░
+/// ░█░░░░█░
+/// ░█░░██░░
+/// ░███░░░░
+/// ░█░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[24]`: `U+25B8` `"▸"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░░██░░░
+/// ░░░███░░
+/// ░░░██░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[25]`: `U+25B9` `"▹"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░░██░░░
+/// ░░░█░█░░
+/// ░░░██░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[26]`: `U+25BA` `"►"`
+///
+/// ```text
+/// █░░░░░░░
+/// ███░░░░░
+/// █████░░░
+/// ███████░
+/// █████░░░
+/// ███░░░░░
+/// █░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[27]`: `U+25BB` `"▻"`
+///
+/// ```text
+/// █░░░░░░░
+/// ███░░░░░
+/// █░░██░░░
+/// █░░░░██░
+/// █░░██░░░
+/// ███░░░░░
+/// █░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[28]`: `U+25BC` `"▼"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███████░
+/// ░█████░░
+/// ░█████░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[29]`: `U+25BD` `"▽"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███████░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[30]`: `U+25BE` `"▾"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[31]`: `U+25BF` `"▿"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█████░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[32]`: `U+25C0` `"◀"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░███░░
+/// ░█████░░
+/// ██████░░
+/// ░█████░░
+/// ░░░███░░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[33]`: `U+25C1` `"◁"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░███░░
+/// ░██░░█░░
+/// █░░░░█░░
+/// ░██░░█░░
+/// ░░░███░░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[34]`: `U+25C2` `"◂"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░██░░░░
+/// ░███░░░░
+/// ░░██░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[35]`: `U+25C3` `"◃"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░██░░░░
+/// ░█░█░░░░
+/// ░░██░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[36]`: `U+25C4` `"◄"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░███░
+/// ░░█████░
+/// ███████░
+/// ░░█████░
+/// ░░░░███░
+/// ░░░░░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[37]`: `U+25C5` `"◅"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░███░
+/// ░░██░░█░
+/// ██░░░░█░
+/// ░░██░░█░
+/// ░░░░███░
+/// ░░░░░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[38]`: `U+25C6` `"◆"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[39]`: `U+25C7` `"◇"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[40]`: `U+25C8` `"◈"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░█░█░░
+/// █░███░█░
+/// ░█░█░█░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[41]`: `U+25C9` `"◉"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░███░█░
+/// █░███░█░
+/// █░███░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[42]`: `U+25CA` `"◊"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[43]`: `U+25CB` `"○"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// █░░░░░█░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[44]`: `U+25CC` `"◌"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░░░░░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[45]`: `U+25CD` `"◍"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░█░█░░
+/// ██░█░██░
+/// ██░█░██░
+/// ██░█░██░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[46]`: `U+25CE` `"◎"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░███░█░
+/// █░█░█░█░
+/// █░███░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[47]`: `U+25CF` `"●"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[48]`: `U+25D0` `"◐"`
+///
+/// ```text
+/// ░░███░░░
+/// ░███░█░░
+/// ████░░█░
+/// ████░░█░
+/// ████░░█░
+/// ░███░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[49]`: `U+25D1` `"◑"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░███░░
+/// █░░████░
+/// █░░████░
+/// █░░████░
+/// ░█░███░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[50]`: `U+25D2` `"◒"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[51]`: `U+25D3` `"◓"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// ███████░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[52]`: `U+25D4` `"◔"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░███░░
+/// █░░████░
+/// █░░████░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[53]`: `U+25D5` `"◕"`
+///
+/// ```text
+/// ░░███░░░
+/// ░███░█░░
+/// ████░░█░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[54]`: `U+25D6` `"◖"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░███░░░░
+/// ████░░░░
+/// ████░░░░
+/// ████░░░░
+/// ░███░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[55]`: `U+25D7` `"◗"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░░███░░
+/// ░░░████░
+/// ░░░████░
+/// ░░░████░
+/// ░░░███░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[56]`: `U+25D8` `"◘"`
+///
+/// ```text
+/// ████████
+/// ████████
+/// ██░░████
+/// █░░░░███
+/// █░░░░███
+/// ██░░████
+/// ████████
+/// ████████
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[57]`: `U+25D9` `"◙"`
+///
+/// ```text
+/// ██░░░███
+/// █░███░██
+/// ░█████░█
+/// ░█████░█
+/// ░█████░█
+/// █░███░██
+/// ██░░░███
+/// ████████
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[58]`: `U+25DA` `"◚"`
+///
+/// ```text
+/// ██░░░███
+/// █░███░██
+/// ░█████░█
+/// ░█████░█
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[59]`: `U+25DB` `"◛"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█████░█
+/// █░███░██
+/// ██░░░███
+/// ████████
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[60]`: `U+25DC` `"◜"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░█░░░░░░
+/// █░░░░░░░
+/// █░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[61]`: `U+25DD` `"◝"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░░░░█░░
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[62]`: `U+25DE` `"◞"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[63]`: `U+25DF` `"◟"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░░░░░░░
+/// █░░░░░░░
+/// ░█░░░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[64]`: `U+25E0` `"◠"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// █░░░░░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[65]`: `U+25E1` `"◡"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░░░░░█░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[66]`: `U+25E2` `"◢"`
+///
+/// ```text
+/// ░░░░░░░█
+/// ░░░░░░██
+/// ░░░░░███
+/// ░░░░████
+/// ░░░█████
+/// ░░██████
+/// ░███████
+/// ████████
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[67]`: `U+25E3` `"◣"`
+///
+/// ```text
+/// █░░░░░░░
+/// ██░░░░░░
+/// ███░░░░░
+/// ████░░░░
+/// █████░░░
+/// ██████░░
+/// ███████░
+/// ████████
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[68]`: `U+25E4` `"◤"`
+///
+/// ```text
+/// ███████░
+/// ██████░░
+/// █████░░░
+/// ████░░░░
+/// ███░░░░░
+/// ██░░░░░░
+/// █░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[69]`: `U+25E5` `"◥"`
+///
+/// ```text
+/// ░███████
+/// ░░██████
+/// ░░░█████
+/// ░░░░████
+/// ░░░░░███
+/// ░░░░░░██
+/// ░░░░░░░█
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[70]`: `U+25E6` `"◦"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░█░░█░░░
+/// ░█░░█░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[71]`: `U+25E7` `"◧"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░███░░█░
+/// ░███░░█░
+/// ░███░░█░
+/// ░███░░█░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[72]`: `U+25E8` `"◨"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░█░░███░
+/// ░█░░███░
+/// ░█░░███░
+/// ░█░░███░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[73]`: `U+25E9` `"◩"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░██████░
+/// ░████░█░
+/// ░███░░█░
+/// ░██░░░█░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[74]`: `U+25EA` `"◪"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░█░░░██░
+/// ░█░░███░
+/// ░█░████░
+/// ░██████░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[75]`: `U+25EB` `"◫"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░█░██░█░
+/// ░█░██░█░
+/// ░█░██░█░
+/// ░█░██░█░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[76]`: `U+25EC` `"◬"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░█░█░█░░
+/// ░█░░░█░░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[77]`: `U+25ED` `"◭"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░███░█░░
+/// ░███░█░░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[78]`: `U+25EE` `"◮"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░█░███░░
+/// ░█░███░░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[79]`: `U+25EF` `"◯"`
+///
+/// ```text
+/// ░░████░░
+/// ░█░░░░█░
+/// █░░░░░░█
+/// █░░░░░░█
+/// █░░░░░░█
+/// █░░░░░░█
+/// ░█░░░░█░
+/// ░░████░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[80]`: `U+25F0` `"◰"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░█░█░░█░
+/// ░███░░█░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[81]`: `U+25F1` `"◱"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░█░░░░█░
+/// ░███░░█░
+/// ░█░█░░█░
+/// ░█░█░░█░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[82]`: `U+25F2` `"◲"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░█░░░░█░
+/// ░█░████░
+/// ░█░█░░█░
+/// ░█░█░░█░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[83]`: `U+25F3` `"◳"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░█░█░░█░
+/// ░█░████░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[84]`: `U+25F4` `"◴"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░█░█░░
+/// █░░█░░█░
+/// ████░░█░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[85]`: `U+25F5` `"◵"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// ████░░█░
+/// █░░█░░█░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[86]`: `U+25F6` `"◶"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// █░░████░
+/// █░░█░░█░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[87]`: `U+25F7` `"◷"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░█░█░░
+/// █░░█░░█░
+/// █░░████░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[88]`: `U+25F8` `"◸"`
+///
+/// ```text
+/// ███████░
+/// █░░░░█░░
+/// █░░░█░░░
+/// █░░█░░░░
+/// █░█░░░░░
+/// ██░░░░░░
+/// █░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[89]`: `U+25F9` `"◹"`
+///
+/// ```text
+/// ░███████
+/// ░░█░░░░█
+/// ░░░█░░░█
+/// ░░░░█░░█
+/// ░░░░░█░█
+/// ░░░░░░██
+/// ░░░░░░░█
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[90]`: `U+25FA` `"◺"`
+///
+/// ```text
+/// █░░░░░░░
+/// ██░░░░░░
+/// █░█░░░░░
+/// █░░█░░░░
+/// █░░░█░░░
+/// █░░░░█░░
+/// █░░░░░█░
+/// ████████
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[91]`: `U+25FB` `"◻"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█████░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[92]`: `U+25FC` `"◼"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█████░░
+/// ░█████░░
+/// ░█████░░
+/// ░█████░░
+/// ░█████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[93]`: `U+25FD` `"◽"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░████░░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░░████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[94]`: `U+25FE` `"◾"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░████░░
+/// ░░████░░
+/// ░░████░░
+/// ░░████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `GEOMETRIC_UNICODE[95]`: `U+25FF` `"◿"`
+///
+/// ```text
+/// ░░░░░░░█
+/// ░░░░░░██
+/// ░░░░░█░█
+/// ░░░░█░░█
+/// ░░░█░░░█
+/// ░░█░░░░█
+/// ░█░░░░░█
+/// ████████
+/// ```
+pub const GEOMETRIC_FONTS: GeometricFonts = GeometricFonts(GEOMETRIC_UNICODE);
+/// Strong-typed collection wrapper for [GEOMETRIC_UNICODE](./constant.GEOMETRIC_UNICODE.html).
+pub struct GeometricFonts([FontUnicode; 96]);
+
+impl GeometricFonts {
+    /// Create a new collection of `GEOMETRIC_UNICODE` fonts.
+    pub fn new() -> Self {
+        GeometricFonts(GEOMETRIC_UNICODE)
+    }
+}
+
+impl fmt::Debug for GeometricFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(GEOMETRIC_UNICODE))
+    }
+}
+
+impl PartialEq for GeometricFonts {
+    fn eq(&self, other: &GeometricFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for GeometricFonts {
+    fn default() -> Self {
+        GeometricFonts::new()
+    }
+}
+
+impl UnicodeFonts for GeometricFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(96), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use legacy::BLOCK_LEGACY;
+
+    #[test]
+    fn geometric_set_implements_default_trait_with_method_new() {
+        let geometric_set: GeometricFonts = Default::default();
+        assert_eq!(geometric_set, GeometricFonts::new());
+    }
+
+    #[test]
+    fn geometric_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(GEOMETRIC_FONTS, GeometricFonts::new());
+    }
+
+    #[test]
+    fn geometric_fonts_constant_wraps_geometric_unicode_constant() {
+        let geometric = GeometricFonts::new();
+        assert!(geometric.0.len() == GEOMETRIC_UNICODE.len());
+        for (idx, font) in geometric.0.iter().enumerate() {
+            assert_eq!(font, &GEOMETRIC_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn geometric_corner_triangles_split_the_full_block() {
+        let full = BLOCK_LEGACY[8];
+        let pairs = [('◢', '◤'), ('◣', '◥')];
+        for &(a, b) in &pairs {
+            let a = GEOMETRIC_FONTS.get(a).unwrap();
+            let b = GEOMETRIC_FONTS.get(b).unwrap();
+            for row in 0..8 {
+                assert_eq!(a[row] & b[row], 0);
+                assert_eq!(a[row] | b[row], full[row]);
+            }
+        }
+    }
+
+    #[test]
+    fn geometric_inverse_circle_is_cut_out_of_the_full_block() {
+        let full = BLOCK_LEGACY[8];
+        let circle = GEOMETRIC_FONTS.get('○').unwrap();
+        let inverse = GEOMETRIC_FONTS.get('◙').unwrap();
+        for row in 0..8 {
+            assert_eq!(circle[row] ^ inverse[row], full[row]);
+        }
+    }
+
+    #[test]
+    fn geometric_black_square_is_a_solid_run() {
+        let square = GEOMETRIC_FONTS.get('■').unwrap();
+        let white = GEOMETRIC_FONTS.get('□').unwrap();
+        for row in 1..7 {
+            assert_eq!(square[row], 0x7E);
+            assert_eq!(white[row] & !square[row], 0);
+        }
+        assert_eq!(square[0] | square[7], 0);
+    }
+}
@@ -0,0 +1,478 @@
+//! Hangul Syllables. `U+AC00` - `U+D7A3`
+//!
+//! The 11,172 precomposed syllables are not stored. Instead, every syllable is split
+//! into its leading consonant, vowel and optional trailing consonant, and the glyph is
+//! built by OR-ing together one piece from each of `HANGUL_LEAD`, `HANGUL_VOWEL` and
+//! `HANGUL_TAIL`.
+//!
+//! Each piece is a 16x8 bitmap stored as `[u16; 8]`, where bit `n` of each row is
+//! column `n`, counting from the left, just like the bytes of the 8x8 fonts.
+//!
+//! Pieces that share the cell with a trailing consonant only get three or four rows, so
+//! they are drawn without stacking two strokes on adjacent rows: `ㄹ` becomes `⊐`, `ㅂ`
+//! becomes `⊔`, and so on.
+use super::{FontUnicode, UnicodeFonts};
+use core::{char, fmt};
+
+/// The first precomposed Hangul syllable, `U+AC00` `"가"`.
+const SYLLABLE_FIRST: u32 = 0xAC00;
+/// The last precomposed Hangul syllable, `U+D7A3` `"힣"`.
+const SYLLABLE_LAST: u32 = 0xD7A3;
+/// Number of vowels (`U+1161` - `U+1175`).
+const VOWEL_COUNT: u32 = 21;
+/// Number of trailing consonants (`U+11A8` - `U+11C2`), plus one for "no tail".
+const TAIL_COUNT: u32 = 28;
+
+/// Leading consonant pieces (`ㄱ` ... `ㅎ`, in `U+1100` - `U+1112` order), indexed
+/// as `HANGUL_LEAD[layout][lead]`.
+///
+/// The six layouts depend on where the vowel sits, and whether there is a trailing
+/// consonant below:
+///
+/// * `0`, `1`: the vowel is to the right (`ㅏ ㅐ ㅑ ㅒ ㅓ ㅔ ㅕ ㅖ ㅣ`).
+/// * `2`, `3`: the vowel is below (`ㅗ ㅛ ㅜ ㅠ ㅡ`).
+/// * `4`, `5`: the vowel wraps around (`ㅘ ㅙ ㅚ ㅝ ㅞ ㅟ ㅢ`).
+///
+/// Odd layouts leave the bottom three rows free for a trailing consonant.
+pub const HANGUL_LEAD: [[[u16; 8]; 19]; 6] = [[[0x007F, 0x0040, 0x0040, 0x0040, 0x0040, 0x0020, 0x0018, 0x0000],
+                                               [0x0077, 0x0044, 0x0044, 0x0044, 0x0044, 0x0044, 0x0044, 0x0000],
+                                               [0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x007F, 0x0000],
+                                               [0x007F, 0x0001, 0x0001, 0x0001, 0x0001, 0x0001, 0x007F, 0x0000],
+                                               [0x0077, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0077, 0x0000],
+                                               [0x007F, 0x0040, 0x0040, 0x007F, 0x0001, 0x0001, 0x007F, 0x0000],
+                                               [0x007F, 0x0041, 0x0041, 0x0041, 0x0041, 0x0041, 0x007F, 0x0000],
+                                               [0x0041, 0x0041, 0x007F, 0x0041, 0x0041, 0x0041, 0x007F, 0x0000],
+                                               [0x0055, 0x0055, 0x0077, 0x0055, 0x0055, 0x0055, 0x0077, 0x0000],
+                                               [0x0008, 0x0008, 0x0014, 0x0014, 0x0022, 0x0022, 0x0041, 0x0000],
+                                               [0x0022, 0x0022, 0x0022, 0x0055, 0x0055, 0x0055, 0x0055, 0x0000],
+                                               [0x003E, 0x0041, 0x0041, 0x0041, 0x0041, 0x0041, 0x003E, 0x0000],
+                                               [0x003F, 0x0008, 0x0008, 0x0014, 0x0014, 0x0022, 0x0041, 0x0000],
+                                               [0x0077, 0x0022, 0x0022, 0x0055, 0x0055, 0x0055, 0x0055, 0x0000],
+                                               [0x0008, 0x003F, 0x0008, 0x0014, 0x0022, 0x0022, 0x0041, 0x0000],
+                                               [0x007F, 0x0040, 0x0040, 0x007F, 0x0040, 0x0040, 0x0040, 0x0000],
+                                               [0x007F, 0x0001, 0x0001, 0x007F, 0x0001, 0x0001, 0x007F, 0x0000],
+                                               [0x007F, 0x0022, 0x0022, 0x0022, 0x0022, 0x0022, 0x007F, 0x0000],
+                                               [0x0008, 0x007F, 0x0000, 0x003E, 0x0041, 0x0041, 0x003E, 0x0000]],
+                                              [[0x007F, 0x0040, 0x0040, 0x0040, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0077, 0x0044, 0x0044, 0x0044, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0001, 0x0001, 0x0001, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0001, 0x0001, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0077, 0x0011, 0x0011, 0x0077, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0040, 0x0001, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0041, 0x0041, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0041, 0x007F, 0x0041, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0055, 0x0077, 0x0055, 0x0077, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0008, 0x0014, 0x0022, 0x0041, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0022, 0x0022, 0x0055, 0x0055, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x003E, 0x0041, 0x0041, 0x003E, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0008, 0x0014, 0x0063, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0077, 0x0022, 0x0055, 0x0055, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0008, 0x003F, 0x0022, 0x0041, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0040, 0x007F, 0x0040, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0001, 0x000D, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0022, 0x0022, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0008, 0x007F, 0x0022, 0x001C, 0x0000, 0x0000, 0x0000, 0x0000]],
+                                              [[0x1FF8, 0x1000, 0x1000, 0x1000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1E78, 0x1040, 0x1040, 0x1040, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0008, 0x0008, 0x0008, 0x1FF8, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x0008, 0x0008, 0x1FF8, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1E78, 0x0208, 0x0208, 0x1E78, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x1000, 0x0008, 0x1FF8, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x1008, 0x1008, 0x1FF8, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1008, 0x1FF8, 0x1008, 0x1FF8, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1248, 0x1E78, 0x1248, 0x1E78, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x03C0, 0x0420, 0x0810, 0x1008, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0C30, 0x0C30, 0x1248, 0x1248, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0FF0, 0x1008, 0x1008, 0x0FF0, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x0180, 0x0660, 0x1818, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1E78, 0x0C30, 0x1248, 0x1248, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0180, 0x1FF8, 0x0660, 0x1818, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x1000, 0x1FF8, 0x1000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x0008, 0x0068, 0x1FF8, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x0810, 0x0810, 0x1FF8, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0180, 0x1FF8, 0x0810, 0x07E0, 0x0000, 0x0000, 0x0000, 0x0000]],
+                                              [[0x1FF8, 0x1000, 0x1000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1E78, 0x1040, 0x1040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0008, 0x0008, 0x1FF8, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x0008, 0x1FF8, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1E78, 0x0208, 0x1E78, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x1000, 0x1FF8, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x1008, 0x1FF8, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1008, 0x1FF8, 0x1008, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1248, 0x1E78, 0x1248, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x07E0, 0x0810, 0x1008, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0C30, 0x1248, 0x1248, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0FF0, 0x1008, 0x0FF0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x0180, 0x0660, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1E78, 0x0C30, 0x1248, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0180, 0x1FF8, 0x0660, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x1300, 0x1000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x0068, 0x1FF8, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x0810, 0x1FF8, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x1FF8, 0x0810, 0x07E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000]],
+                                              [[0x007F, 0x0040, 0x0040, 0x0040, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0077, 0x0044, 0x0044, 0x0044, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0001, 0x0001, 0x0001, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0001, 0x0001, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0077, 0x0011, 0x0011, 0x0077, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0040, 0x0001, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0041, 0x0041, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0041, 0x007F, 0x0041, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0055, 0x0077, 0x0055, 0x0077, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0008, 0x0014, 0x0022, 0x0041, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0022, 0x0022, 0x0055, 0x0055, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x003E, 0x0041, 0x0041, 0x003E, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0008, 0x0014, 0x0063, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0077, 0x0022, 0x0055, 0x0055, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0008, 0x003F, 0x0022, 0x0041, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0040, 0x007F, 0x0040, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0001, 0x000D, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0022, 0x0022, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0008, 0x007F, 0x0022, 0x001C, 0x0000, 0x0000, 0x0000, 0x0000]],
+                                              [[0x007F, 0x0040, 0x0040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0077, 0x0044, 0x0044, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0001, 0x0001, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0001, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0077, 0x0011, 0x0077, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0040, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0041, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0041, 0x007F, 0x0041, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0055, 0x0077, 0x0055, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x001C, 0x0022, 0x0041, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0022, 0x0055, 0x0055, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x003E, 0x0041, 0x003E, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0008, 0x0036, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0077, 0x0022, 0x0055, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x0008, 0x007F, 0x0036, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x004C, 0x0040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x000D, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0022, 0x007F, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                               [0x007F, 0x0022, 0x001C, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000]]];
+
+/// Vowel pieces (`ㅏ` ... `ㅣ`, in `U+1161` - `U+1175` order), indexed as
+/// `HANGUL_VOWEL[has_tail][vowel]`.
+pub const HANGUL_VOWEL: [[[u16; 8]; 21]; 2] = [[[0x0800, 0x0800, 0x0800, 0x3800, 0x0800, 0x0800, 0x0800, 0x0800],
+                                                [0x4800, 0x4800, 0x4800, 0x5800, 0x4800, 0x4800, 0x4800, 0x4800],
+                                                [0x0800, 0x0800, 0x3800, 0x0800, 0x0800, 0x3800, 0x0800, 0x0800],
+                                                [0x4800, 0x4800, 0x5800, 0x4800, 0x4800, 0x5800, 0x4800, 0x4800],
+                                                [0x0800, 0x0800, 0x0800, 0x0E00, 0x0800, 0x0800, 0x0800, 0x0800],
+                                                [0x2800, 0x2800, 0x2800, 0x2E00, 0x2800, 0x2800, 0x2800, 0x2800],
+                                                [0x0800, 0x0800, 0x0E00, 0x0800, 0x0800, 0x0E00, 0x0800, 0x0800],
+                                                [0x2800, 0x2800, 0x2E00, 0x2800, 0x2800, 0x2E00, 0x2800, 0x2800],
+                                                [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0180, 0x3FFC, 0x0000],
+                                                [0x1000, 0x1000, 0x1000, 0x7000, 0x1000, 0x1030, 0x13FF, 0x1000],
+                                                [0x9000, 0x9000, 0x9000, 0xB000, 0x9000, 0x9030, 0x93FF, 0x9000],
+                                                [0x1000, 0x1000, 0x1000, 0x1000, 0x1000, 0x1030, 0x13FF, 0x1000],
+                                                [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0420, 0x3FFC, 0x0000],
+                                                [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3FFC, 0x0180, 0x0180],
+                                                [0x1000, 0x1000, 0x1000, 0x1C00, 0x1000, 0x13FF, 0x1030, 0x1030],
+                                                [0x5000, 0x5000, 0x5000, 0x5C00, 0x5000, 0x53FF, 0x5030, 0x5030],
+                                                [0x1000, 0x1000, 0x1000, 0x1000, 0x1000, 0x13FF, 0x1030, 0x1030],
+                                                [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3FFC, 0x0420, 0x0420],
+                                                [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3FFC, 0x0000, 0x0000],
+                                                [0x1000, 0x1000, 0x1000, 0x1000, 0x1000, 0x13FF, 0x1000, 0x1000],
+                                                [0x0800, 0x0800, 0x0800, 0x0800, 0x0800, 0x0800, 0x0800, 0x0800]],
+                                               [[0x0800, 0x0800, 0x3800, 0x0800, 0x0800, 0x0000, 0x0000, 0x0000],
+                                                [0x4800, 0x4800, 0x5800, 0x4800, 0x4800, 0x0000, 0x0000, 0x0000],
+                                                [0x0800, 0x3800, 0x0800, 0x3800, 0x0800, 0x0000, 0x0000, 0x0000],
+                                                [0x4800, 0x5800, 0x4800, 0x5800, 0x4800, 0x0000, 0x0000, 0x0000],
+                                                [0x0800, 0x0800, 0x0E00, 0x0800, 0x0800, 0x0000, 0x0000, 0x0000],
+                                                [0x2800, 0x2800, 0x2E00, 0x2800, 0x2800, 0x0000, 0x0000, 0x0000],
+                                                [0x0800, 0x0E00, 0x0800, 0x0E00, 0x0800, 0x0000, 0x0000, 0x0000],
+                                                [0x2800, 0x2E00, 0x2800, 0x2E00, 0x2800, 0x0000, 0x0000, 0x0000],
+                                                [0x0000, 0x0000, 0x0000, 0x0180, 0x3FFC, 0x0000, 0x0000, 0x0000],
+                                                [0x1000, 0x1000, 0x7000, 0x1030, 0x13FF, 0x0000, 0x0000, 0x0000],
+                                                [0x9000, 0x9000, 0xB000, 0x9030, 0x93FF, 0x0000, 0x0000, 0x0000],
+                                                [0x1000, 0x1000, 0x1000, 0x1030, 0x13FF, 0x0000, 0x0000, 0x0000],
+                                                [0x0000, 0x0000, 0x0000, 0x0420, 0x3FFC, 0x0000, 0x0000, 0x0000],
+                                                [0x0000, 0x0000, 0x0000, 0x3FFC, 0x0180, 0x0000, 0x0000, 0x0000],
+                                                [0x1000, 0x1000, 0x1C00, 0x13FF, 0x1030, 0x0000, 0x0000, 0x0000],
+                                                [0x5000, 0x5000, 0x5C00, 0x53FF, 0x5030, 0x0000, 0x0000, 0x0000],
+                                                [0x1000, 0x1000, 0x1000, 0x13FF, 0x1030, 0x0000, 0x0000, 0x0000],
+                                                [0x0000, 0x0000, 0x0000, 0x3FFC, 0x0420, 0x0000, 0x0000, 0x0000],
+                                                [0x0000, 0x0000, 0x0000, 0x3FFC, 0x0000, 0x0000, 0x0000, 0x0000],
+                                                [0x1000, 0x1000, 0x1000, 0x13FF, 0x1000, 0x0000, 0x0000, 0x0000],
+                                                [0x0800, 0x0800, 0x0800, 0x0800, 0x0800, 0x0000, 0x0000, 0x0000]]];
+
+/// Trailing consonant pieces (`U+11A8` - `U+11C2` order), drawn in the bottom three rows.
+/// Index `0` is the empty tail of open syllables.
+pub const HANGUL_TAIL: [[u16; 8]; 28] = [[0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1FF8, 0x1000, 0x1000],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1E78, 0x1040, 0x1040],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0C78, 0x1240, 0x1240],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0008, 0x0008, 0x1FF8],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1E08, 0x0C08, 0x1278],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1F08, 0x1108, 0x0E78],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1FF8, 0x0008, 0x1FF8],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1FF8, 0x1000, 0x1FF8],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1E78, 0x1040, 0x1078],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1E78, 0x1240, 0x1E78],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1278, 0x1240, 0x1E78],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0C78, 0x1240, 0x1278],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1E78, 0x0A40, 0x1E78],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1F78, 0x0A40, 0x1F78],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1F78, 0x1140, 0x0E78],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1FF8, 0x1008, 0x1FF8],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1008, 0x1008, 0x1FF8],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0C48, 0x1248, 0x1278],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x07E0, 0x0810, 0x1008],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0C30, 0x1248, 0x1248],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0FF0, 0x1008, 0x0FF0],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1FF8, 0x0180, 0x0660],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0180, 0x1FF8, 0x0660],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1FF8, 0x1300, 0x1000],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1FF8, 0x0068, 0x1FF8],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1FF8, 0x0810, 0x1FF8],
+                                         [0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1FF8, 0x0810, 0x07E0]];
+
+/// A computed set of Hangul syllable fonts (`U+AC00` - `U+D7A3`), that implements the
+/// `UnicodeFonts` trait.
+///
+/// Syllables are designed for a double-width 16x8 cell, which is available from
+/// [get_wide](./struct.HangulFonts.html#method.get_wide). `UnicodeFonts::get` squeezes
+/// them into a single 8x8 cell by merging each pair of columns.
+///
+/// ## `U+D55C` `"한"`, 16x8
+///
+/// ```text
+/// ░░░█░░░░░░░█░░░░
+/// ███████░░░░█░░░░
+/// ░█░░░█░░░░░███░░
+/// ░░███░░░░░░█░░░░
+/// ░░░░░░░░░░░█░░░░
+/// ░░░█░░░░░░░░░░░░
+/// ░░░█░░░░░░░░░░░░
+/// ░░░██████████░░░
+/// ```
+///
+/// ## `U+AE00` `"글"`, 16x8
+///
+/// ```text
+/// ░░░██████████░░░
+/// ░░░░░░░░░░░░█░░░
+/// ░░░░░░░░░░░░█░░░
+/// ░░████████████░░
+/// ░░░░░░░░░░░░░░░░
+/// ░░░██████████░░░
+/// ░░░░░░░░░░░░█░░░
+/// ░░░██████████░░░
+/// ```
+///
+/// ## `U+D55C` `"한"`, 8x8
+///
+/// ```text
+/// ░█░░░█░░
+/// ████░█░░
+/// █░█░░██░
+/// ░██░░█░░
+/// ░░░░░█░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ░██████░
+/// ```
+pub const HANGUL_FONTS: HangulFonts = HangulFonts;
+
+/// Strong-typed wrapper for the computed Hangul syllable fonts.
+#[derive(Clone, Copy, PartialEq)]
+pub struct HangulFonts;
+
+impl HangulFonts {
+    /// Create a new collection of Hangul syllable fonts.
+    pub fn new() -> Self {
+        HangulFonts
+    }
+
+    /// Returns the 16x8 rendering of a Hangul syllable as its left and right 8x8 cells,
+    /// or `None` if `key` isn't in `U+AC00` - `U+D7A3`.
+    pub fn get_wide(&self, key: char) -> Option<[[u8; 8]; 2]> {
+        compose(key).map(split)
+    }
+}
+
+impl fmt::Debug for HangulFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(HANGUL_FONTS))
+    }
+}
+
+impl Default for HangulFonts {
+    fn default() -> Self {
+        HangulFonts::new()
+    }
+}
+
+impl UnicodeFonts for HangulFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        compose(key).map(squash)
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        self.get(key).map(|glyph| FontUnicode(key, glyph))
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, (_, font)) in self.to_vec().iter().enumerate() {
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        (SYLLABLE_FIRST..SYLLABLE_LAST + 1).filter_map(char::from_u32)
+                                           .filter_map(|c| self.get_font(c))
+                                           .fold(Vec::with_capacity(11_172), |mut v, font| {
+                                               v.push((font.char(), font));
+                                               v
+                                           })
+    }
+}
+
+/// Picks the `HANGUL_LEAD` layout for a vowel, and whether a tail follows.
+fn layout(vowel: usize, has_tail: bool) -> usize {
+    let shape = match vowel {
+        // ㅗ ㅛ ㅜ ㅠ ㅡ
+        8 | 12 | 13 | 17 | 18 => 1,
+        // ㅘ ㅙ ㅚ ㅝ ㅞ ㅟ ㅢ
+        9 | 10 | 11 | 14 | 15 | 16 | 19 => 2,
+        _ => 0,
+    };
+    2 * shape + has_tail as usize
+}
+
+/// Builds the 16x8 glyph for a precomposed Hangul syllable.
+fn compose(key: char) -> Option<[u16; 8]> {
+    let code = key as u32;
+    if !(SYLLABLE_FIRST..=SYLLABLE_LAST).contains(&code) {
+        return None;
+    }
+    let index = code - SYLLABLE_FIRST;
+    let lead = (index / (VOWEL_COUNT * TAIL_COUNT)) as usize;
+    let vowel = ((index / TAIL_COUNT) % VOWEL_COUNT) as usize;
+    let tail = (index % TAIL_COUNT) as usize;
+    let has_tail = tail != 0;
+
+    let mut glyph = HANGUL_LEAD[layout(vowel, has_tail)][lead];
+    for (row, (v, t)) in glyph.iter_mut()
+                              .zip(HANGUL_VOWEL[has_tail as usize][vowel].iter()
+                                                                         .zip(HANGUL_TAIL[tail].iter()))
+    {
+        *row |= v | t;
+    }
+    Some(glyph)
+}
+
+/// Splits a 16x8 glyph into its left and right 8x8 cells.
+fn split(wide: [u16; 8]) -> [[u8; 8]; 2] {
+    let mut cells = [[0u8; 8]; 2];
+    for (idx, row) in wide.iter().enumerate() {
+        cells[0][idx] = *row as u8;
+        cells[1][idx] = (*row >> 8) as u8;
+    }
+    cells
+}
+
+/// Squeezes a 16x8 glyph into 8x8, by merging each pair of adjacent columns.
+fn squash(wide: [u16; 8]) -> [u8; 8] {
+    let mut glyph = [0u8; 8];
+    for (idx, row) in wide.iter().enumerate() {
+        for bit in 0..8 {
+            if *row & (0b11 << (2 * bit)) != 0 {
+                glyph[idx] |= 1 << bit;
+            }
+        }
+    }
+    glyph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hangul_set_implements_default_trait_with_method_new() {
+        let hangul_set: HangulFonts = Default::default();
+        assert_eq!(hangul_set, HangulFonts::new());
+    }
+
+    #[test]
+    fn hangul_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(HANGUL_FONTS, HangulFonts::new());
+    }
+
+    #[test]
+    fn hangul_fonts_compose_every_syllable() {
+        for code in SYLLABLE_FIRST..SYLLABLE_LAST + 1 {
+            let key = char::from_u32(code).unwrap();
+            assert!(HANGUL_FONTS.get(key).is_some());
+        }
+        assert_eq!(HANGUL_FONTS.get('\u{ABFF}'), None);
+        assert_eq!(HANGUL_FONTS.get('\u{D7A4}'), None);
+        assert_eq!(HANGUL_FONTS.get('A'), None);
+    }
+
+    fn stacks_strokes(piece: &[u16; 8]) -> bool {
+        piece.windows(2)
+             .any(|rows| (0..14).any(|col| rows.iter().all(|row| row >> col & 0b111 == 0b111)))
+    }
+
+    #[test]
+    fn hangul_pieces_keep_a_gap_between_their_strokes() {
+        let leads = HANGUL_LEAD.iter().flat_map(|layout| layout.iter());
+        let vowels = HANGUL_VOWEL.iter().flat_map(|vowels| vowels.iter());
+        assert!(!leads.chain(vowels).chain(HANGUL_TAIL.iter()).any(stacks_strokes));
+    }
+
+    #[test]
+    fn hangul_fonts_compose_lead_and_vowel_of_open_syllables() {
+        // 가 = ㄱ + ㅏ
+        assert_eq!(HANGUL_FONTS.get_wide('가'),
+                   Some([[0x7F, 0x40, 0x40, 0x40, 0x40, 0x20, 0x18, 0x00],
+                         [0x08, 0x08, 0x08, 0x38, 0x08, 0x08, 0x08, 0x08]]));
+    }
+
+    #[test]
+    fn hangul_fonts_leave_room_for_the_tail() {
+        // 한 = ㅎ + ㅏ + ㄴ
+        assert_eq!(HANGUL_FONTS.get_wide('한'),
+                   Some([[0x08, 0x7F, 0x22, 0x1C, 0x00, 0x08, 0x08, 0xF8],
+                         [0x08, 0x08, 0x38, 0x08, 0x08, 0x00, 0x00, 0x1F]]));
+        // 글 = ㄱ + ㅡ + ㄹ
+        assert_eq!(HANGUL_FONTS.get_wide('글'),
+                   Some([[0xF8, 0x00, 0x00, 0xFC, 0x00, 0xF8, 0x00, 0xF8],
+                         [0x1F, 0x10, 0x10, 0x3F, 0x00, 0x1F, 0x10, 0x1F]]));
+    }
+
+    #[test]
+    fn hangul_fonts_wrap_compound_vowels_around_the_lead() {
+        // 뷁 = ㅂ + ㅞ + ㄺ
+        assert_eq!(HANGUL_FONTS.get_wide('뷁'),
+                   Some([[0x41, 0x7F, 0x41, 0xFF, 0x30, 0x78, 0x40, 0x78],
+                         [0x50, 0x50, 0x5C, 0x53, 0x50, 0x1E, 0x10, 0x10]]));
+    }
+
+    #[test]
+    fn hangul_fonts_squash_the_wide_glyph_into_one_cell() {
+        assert_eq!(HANGUL_FONTS.get('글'),
+                   Some([0x7E, 0x40, 0x40, 0x7E, 0x00, 0x7E, 0x40, 0x7E]));
+    }
+}
@@ -1,4 +1,9 @@
 //! Hiragana. `U+3040 - U+309F`
+//!
+//! Kana followed by the combining voiced and semi-voiced sound marks, `U+3099` and
+//! `U+309A`, as sent in decomposed text, are drawn as the precomposed kana, like `"が"`
+//! for `"か\u{3099}"`. Other characters get the mark drawn onto their top right corner.
+use super::combining::{ink_columns, ink_rows, moved};
 use super::{legacy::HIRAGANA_LEGACY, FontUnicode, UnicodeFonts};
 use core::fmt;
 
@@ -1284,6 +1289,53 @@ impl Default for HiraganaFonts {
     }
 }
 
+impl HiraganaFonts {
+    /// Returns `base` with the spacing forms of the voiced and semi-voiced sound `marks`
+    /// drawn onto it, for the characters that have no precomposed form. Any character of
+    /// `marks` that is not a voicing mark is ignored.
+    pub fn overlay(&self, base: [u8; 8], marks: &str) -> [u8; 8] {
+        let mut glyph = base;
+        for mark in marks.chars() {
+            let spacing = match mark {
+                VOICED_MARK => '\u{309B}',
+                SEMI_VOICED_MARK => '\u{309C}',
+                _ => continue,
+            };
+            for (row, ink) in glyph.iter_mut().zip(self.get(spacing).unwrap_or_default().iter()) {
+                *row |= ink;
+            }
+        }
+        glyph
+    }
+
+    /// Returns the glyph of `key` for vertical text, or `None` if `key` isn't in the set.
+    /// Small kana are moved to the top right of their cell, instead of the bottom left,
+    /// and the iteration marks are centred in their cell.
+    ///
+    /// ```
+    /// use font8x8::{UnicodeFonts, HIRAGANA_FONTS};
+    ///
+    /// let small_i = HIRAGANA_FONTS.get_vertical('ぃ').unwrap();
+    /// assert_eq!(small_i[0], HIRAGANA_FONTS.get('ぃ').unwrap()[3] << 2);
+    /// assert_eq!(HIRAGANA_FONTS.get_vertical('あ'), HIRAGANA_FONTS.get('あ'));
+    /// ```
+    pub fn get_vertical(&self, key: char) -> Option<[u8; 8]> {
+        let glyph = self.get(key)?;
+        let (top, bottom) = match ink_rows(&glyph) {
+            Some((top, bottom)) => (top as isize, bottom as isize),
+            None => return Some(glyph),
+        };
+        let (left, right) = ink_columns(&glyph)?;
+        if SMALL_KANA.contains(&key) {
+            Some(moved(&glyph, -top, 7 - right))
+        } else if ITERATION_MARKS.contains(&key) {
+            Some(moved(&glyph, (7 - top - bottom) / 2, (7 - left - right) / 2))
+        } else {
+            Some(glyph)
+        }
+    }
+}
+
 impl UnicodeFonts for HiraganaFonts {
     fn get(&self, key: char) -> Option<[u8; 8]> {
         match self.get_font(key) {
@@ -1339,6 +1391,68 @@ impl UnicodeFonts for HiraganaFonts {
     }
 }
 
+/// Combining voiced sound mark (dakuten), `U+3099`.
+const VOICED_MARK: char = '\u{3099}';
+/// Combining semi-voiced sound mark (handakuten), `U+309A`.
+const SEMI_VOICED_MARK: char = '\u{309A}';
+/// Kana, and their precomposed forms with the voiced sound mark.
+const VOICED: [(char, char); 22] = [('う', 'ゔ'), ('か', 'が'), ('き', 'ぎ'), ('く', 'ぐ'), ('け', 'げ'),
+                                    ('こ', 'ご'), ('さ', 'ざ'), ('し', 'じ'), ('す', 'ず'), ('せ', 'ぜ'),
+                                    ('そ', 'ぞ'), ('た', 'だ'), ('ち', 'ぢ'), ('つ', 'づ'), ('て', 'で'),
+                                    ('と', 'ど'), ('は', 'ば'), ('ひ', 'び'), ('ふ', 'ぶ'), ('へ', 'べ'),
+                                    ('ほ', 'ぼ'), ('ゝ', 'ゞ')];
+/// Kana, and their precomposed forms with the semi-voiced sound mark.
+const SEMI_VOICED: [(char, char); 5] = [('は', 'ぱ'), ('ひ', 'ぴ'), ('ふ', 'ぷ'), ('へ', 'ぺ'), ('ほ', 'ぽ')];
+/// Small kana, drawn at the top right of their cell in vertical text.
+const SMALL_KANA: [char; 12] = ['ぁ', 'ぃ', 'ぅ', 'ぇ', 'ぉ', 'っ', 'ゃ', 'ゅ', 'ょ', 'ゎ', 'ゕ', 'ゖ'];
+/// Iteration marks, centred in their cell in vertical text.
+const ITERATION_MARKS: [char; 2] = ['ゝ', 'ゞ'];
+
+/// Returns `true` for the combining voiced and semi-voiced sound marks, `U+3099` and
+/// `U+309A`, which are drawn onto the kana before them.
+pub fn is_voicing_mark(c: char) -> bool {
+    c == VOICED_MARK || c == SEMI_VOICED_MARK
+}
+
+/// Returns the precomposed kana for `base` followed by the combining voicing `mark`, or
+/// `None` if there is none.
+///
+/// ```
+/// use font8x8::unicode::compose_kana;
+///
+/// assert_eq!(compose_kana('か', '\u{3099}'), Some('が'));
+/// assert_eq!(compose_kana('は', '\u{309A}'), Some('ぱ'));
+/// assert_eq!(compose_kana('か', '\u{309A}'), None);
+/// assert_eq!(compose_kana('あ', '\u{3099}'), None);
+/// ```
+pub fn compose_kana(base: char, mark: char) -> Option<char> {
+    let table: &[(char, char)] = match mark {
+        VOICED_MARK => &VOICED,
+        SEMI_VOICED_MARK => &SEMI_VOICED,
+        _ => return None,
+    };
+    table.binary_search_by_key(&base, |&(kana, _)| kana)
+         .ok()
+         .map(|idx| table[idx].1)
+}
+
+/// Returns the length in bytes of the voicing marks at the start of `rest`.
+pub(crate) fn voicing_len(rest: &str) -> usize {
+    rest.char_indices()
+        .find(|&(_, c)| !is_voicing_mark(c))
+        .map(|(idx, _)| idx)
+        .unwrap_or_else(|| rest.len())
+}
+
+/// Returns `base` composed with the first of the voicing `marks`, if they have a
+/// precomposed form, and the marks that are left to draw onto it.
+pub(crate) fn compose_voicing(base: char, marks: &str) -> (char, &str) {
+    match marks.chars().next().and_then(|mark| compose_kana(base, mark)) {
+        Some(kana) => (kana, &marks[VOICED_MARK.len_utf8()..]),
+        None => (base, marks),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1362,4 +1476,48 @@ mod tests {
             assert_eq!(font, &HIRAGANA_UNICODE[idx]);
         }
     }
+
+    #[test]
+    fn hiragana_voicing_marks_compose_with_their_kana() {
+        for &(kana, voiced) in VOICED.iter() {
+            assert_eq!(compose_kana(kana, VOICED_MARK), Some(voiced));
+            assert!(HIRAGANA_FONTS.get(voiced).is_some());
+        }
+        for &(kana, semi_voiced) in SEMI_VOICED.iter() {
+            assert_eq!(compose_kana(kana, SEMI_VOICED_MARK), Some(semi_voiced));
+        }
+        assert_eq!(compose_voicing('か', "\u{3099}\u{309A}"), ('が', "\u{309A}"));
+        assert_eq!(compose_voicing('あ', "\u{3099}"), ('あ', "\u{3099}"));
+        assert_eq!(voicing_len("\u{3099}\u{309A}か"), 6);
+    }
+
+    #[test]
+    fn hiragana_fonts_overlay_draws_the_spacing_marks() {
+        let a = HIRAGANA_FONTS.get('あ').unwrap();
+        let mark = HIRAGANA_FONTS.get('\u{309B}').unwrap();
+        let glyph = HIRAGANA_FONTS.overlay(a, "\u{3099}");
+        for row in 0..8 {
+            assert_eq!(glyph[row], a[row] | mark[row]);
+        }
+        assert_eq!(HIRAGANA_FONTS.overlay(a, "\u{0301}"), a);
+    }
+
+    #[test]
+    fn hiragana_vertical_glyphs_move_small_kana_to_the_top_right() {
+        for key in SMALL_KANA.iter() {
+            let glyph = HIRAGANA_FONTS.get_vertical(*key).unwrap();
+            if let (Some((top, _)), Some((_, right))) = (ink_rows(&glyph), ink_columns(&glyph)) {
+                assert_eq!((top, right), (0, 7), "{:?}", key);
+            }
+        }
+        for key in ITERATION_MARKS.iter() {
+            let glyph = HIRAGANA_FONTS.get_vertical(*key).unwrap();
+            let (top, bottom) = ink_rows(&glyph).unwrap();
+            let (left, right) = ink_columns(&glyph).unwrap();
+            assert!((top as isize - (7 - bottom as isize)).abs() <= 1, "{:?}", key);
+            assert!((left - (7 - right)).abs() <= 1, "{:?}", key);
+        }
+        assert_eq!(HIRAGANA_FONTS.get_vertical('か'), HIRAGANA_FONTS.get('か'));
+        assert_eq!(HIRAGANA_FONTS.get_vertical('A'), None);
+    }
 }
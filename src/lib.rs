@@ -20,6 +20,12 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "unicode")]
+mod alternates;
+#[cfg(feature = "unicode")]
+mod arabic;
+#[cfg(feature = "unicode")]
+mod arrows;
 #[cfg(feature = "unicode")]
 mod basic;
 #[cfg(feature = "unicode")]
@@ -28,29 +34,77 @@ mod block;
 #[path = "box.rs"]
 mod box_chars;
 #[cfg(feature = "unicode")]
+mod braille;
+#[cfg(feature = "unicode")]
+mod combining;
+#[cfg(feature = "unicode")]
+mod computing;
+#[cfg(feature = "unicode")]
+mod control;
+#[cfg(feature = "unicode")]
+mod currency;
+#[cfg(feature = "unicode")]
+mod decomposed;
+#[cfg(feature = "unicode")]
+mod dingbats;
+#[cfg(feature = "unicode")]
+mod emoji;
+#[cfg(feature = "unicode")]
+mod enclosed;
+#[cfg(feature = "unicode")]
+mod fullwidth;
+#[cfg(feature = "unicode")]
+mod geometric;
+#[cfg(feature = "unicode")]
 mod greek;
 #[cfg(feature = "unicode")]
+mod hangul;
+#[cfg(feature = "unicode")]
 mod hiragana;
 #[cfg(feature = "unicode")]
 mod latin;
 /// Re-export the original `[u8; 8]` constants, taken from C-header files.
 pub mod legacy;
 #[cfg(feature = "unicode")]
+mod math;
+#[cfg(feature = "unicode")]
 mod misc;
 #[cfg(feature = "unicode")]
+mod numbers;
+#[cfg(feature = "unicode")]
+mod pictures;
+#[cfg(feature = "unicode")]
+mod powerline;
+#[cfg(feature = "unicode")]
+mod punctuation;
+#[cfg(feature = "unicode")]
+mod render;
+#[cfg(feature = "unicode")]
+mod romaji;
+#[cfg(feature = "unicode")]
+mod scripts;
+#[cfg(feature = "unicode")]
 mod sga;
+#[cfg(feature = "unicode")]
+mod symbols;
+#[cfg(feature = "unicode")]
+mod technical;
+#[cfg(feature = "unicode")]
+mod thai;
+#[cfg(feature = "unicode")]
+mod transliteration;
 
 #[cfg(feature = "unicode")]
 pub mod unicode;
 
 #[cfg(feature = "unicode")]
-pub use self::basic::BASIC_FONTS;
+pub use self::arabic::ARABIC_FONTS;
 
 #[cfg(feature = "unicode")]
-pub use self::latin::LATIN_FONTS;
+pub use self::arrows::ARROW_FONTS;
 
 #[cfg(feature = "unicode")]
-pub use self::greek::GREEK_FONTS;
+pub use self::basic::BASIC_FONTS;
 
 #[cfg(feature = "unicode")]
 pub use self::block::BLOCK_FONTS;
@@ -58,15 +112,87 @@ pub use self::block::BLOCK_FONTS;
 #[cfg(feature = "unicode")]
 pub use self::box_chars::BOX_FONTS;
 
+#[cfg(feature = "unicode")]
+pub use self::braille::BRAILLE_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::combining::COMBINING_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::computing::COMPUTING_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::control::CONTROL_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::currency::CURRENCY_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::decomposed::DECOMPOSED_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::dingbats::DINGBAT_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::emoji::EMOJI_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::enclosed::ENCLOSED_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::fullwidth::FULLWIDTH_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::geometric::GEOMETRIC_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::greek::GREEK_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::hangul::HANGUL_FONTS;
+
 #[cfg(feature = "unicode")]
 pub use self::hiragana::HIRAGANA_FONTS;
 
+#[cfg(feature = "unicode")]
+pub use self::latin::LATIN_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::math::MATH_FONTS;
+
 #[cfg(feature = "unicode")]
 pub use self::misc::MISC_FONTS;
 
+#[cfg(feature = "unicode")]
+pub use self::numbers::NUMBER_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::pictures::PICTURE_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::powerline::POWERLINE_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::punctuation::PUNCTUATION_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::scripts::SCRIPT_FONTS;
+
 #[cfg(feature = "unicode")]
 pub use self::sga::SGA_FONTS;
 
+#[cfg(feature = "unicode")]
+pub use self::symbols::SYMBOL_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::technical::TECHNICAL_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::thai::THAI_FONTS;
+
+#[cfg(feature = "unicode")]
+pub use self::render::{Fallback, Renderer};
+
 #[cfg(all(feature = "unicode", feature = "std"))]
 pub use self::unicode::FromUtf16Error;
 #[cfg(feature = "unicode")]
@@ -0,0 +1,1323 @@
+//! Mathematical Operators. `U+2200` - `U+22FF`
+//!
+//! A subset with the operators in common use. The less-than-or-equal and
+//! greater-than-or-equal signs are the original glyphs from `MISC_LEGACY[5]` and
+//! `MISC_LEGACY[6]`. The Latin-1 operators `±`, `×`, `÷`, and `¬` live in `LATIN_FONTS`.
+use super::{
+    legacy::MISC_LEGACY, unicode::{FontUnicode, UnicodeFonts},
+};
+use core::fmt;
+
+/// A constant `[FontUnicode; 85]`, for Mathematical Operators fonts (`U+2200` - `U+22FF`).
+pub const MATH_UNICODE: [FontUnicode; 85] = [FontUnicode('\u{2200}', [0x33, 0x33, 0x3F, 0x36, 0x1E, 0x0C, 0x0C, 0x00]),
+                                             FontUnicode('\u{2202}', [0x1C, 0x60, 0x7C, 0x66, 0x63, 0x33, 0x1E, 0x00]),
+                                             FontUnicode('\u{2203}', [0x3F, 0x30, 0x30, 0x3E, 0x30, 0x30, 0x3F, 0x00]),
+                                             FontUnicode('\u{2204}', [0xBF, 0x70, 0x30, 0x3E, 0x38, 0x34, 0x3F, 0x00]),
+                                             FontUnicode('\u{2205}', [0x60, 0x3E, 0x73, 0x6B, 0x67, 0x3E, 0x03, 0x00]),
+                                             FontUnicode('\u{2206}', [0x0C, 0x0C, 0x1E, 0x12, 0x33, 0x33, 0x3F, 0x00]),
+                                             FontUnicode('\u{2207}', [0x3F, 0x33, 0x33, 0x12, 0x1E, 0x0C, 0x0C, 0x00]),
+                                             FontUnicode('\u{2208}', [0x3C, 0x06, 0x03, 0x3F, 0x03, 0x06, 0x3C, 0x00]),
+                                             FontUnicode('\u{2209}', [0x10, 0x3C, 0x16, 0x3F, 0x0B, 0x0E, 0x3C, 0x04]),
+                                             FontUnicode('\u{220B}', [0x1E, 0x30, 0x60, 0x7E, 0x60, 0x30, 0x1E, 0x00]),
+                                             FontUnicode('\u{220C}', [0x08, 0x1E, 0x38, 0x7C, 0x64, 0x32, 0x1E, 0x02]),
+                                             FontUnicode('\u{220F}', [0x7F, 0x66, 0x66, 0x66, 0x66, 0x66, 0xE7, 0x00]),
+                                             FontUnicode('\u{2210}', [0xE7, 0x66, 0x66, 0x66, 0x66, 0x66, 0x7F, 0x00]),
+                                             FontUnicode('\u{2211}', [0x7F, 0x43, 0x06, 0x0C, 0x06, 0x43, 0x7F, 0x00]),
+                                             FontUnicode('\u{2212}', [0x00, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{2213}', [0x00, 0x3F, 0x00, 0x0C, 0x3F, 0x0C, 0x0C, 0x00]),
+                                             FontUnicode('\u{2215}', [0x60, 0x30, 0x18, 0x0C, 0x06, 0x03, 0x00, 0x00]),
+                                             FontUnicode('\u{2216}', [0x03, 0x06, 0x0C, 0x18, 0x30, 0x60, 0x00, 0x00]),
+                                             FontUnicode('\u{2217}', [0x00, 0x2A, 0x1C, 0x7F, 0x1C, 0x2A, 0x00, 0x00]),
+                                             FontUnicode('\u{2218}', [0x00, 0x00, 0x0C, 0x12, 0x12, 0x0C, 0x00, 0x00]),
+                                             FontUnicode('\u{2219}', [0x00, 0x00, 0x0C, 0x1E, 0x0C, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{221A}', [0xF0, 0x30, 0x30, 0x30, 0x1B, 0x1E, 0x0C, 0x00]),
+                                             FontUnicode('\u{221B}', [0xF7, 0x34, 0x36, 0x34, 0x0F, 0x1E, 0x0C, 0x00]),
+                                             FontUnicode('\u{221C}', [0xF5, 0x37, 0x34, 0x30, 0x1B, 0x1E, 0x0C, 0x00]),
+                                             FontUnicode('\u{221D}', [0x00, 0x00, 0x66, 0x1B, 0x1B, 0x66, 0x00, 0x00]),
+                                             FontUnicode('\u{221E}', [0x00, 0x00, 0x36, 0x49, 0x49, 0x36, 0x00, 0x00]),
+                                             FontUnicode('\u{221F}', [0x00, 0x00, 0x00, 0x03, 0x03, 0x03, 0x3F, 0x00]),
+                                             FontUnicode('\u{2220}', [0x00, 0x60, 0x30, 0x18, 0x0C, 0x06, 0x7F, 0x00]),
+                                             FontUnicode('\u{2223}', [0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x00]),
+                                             FontUnicode('\u{2224}', [0x18, 0x18, 0x78, 0x1C, 0x1E, 0x18, 0x18, 0x00]),
+                                             FontUnicode('\u{2225}', [0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x00]),
+                                             FontUnicode('\u{2226}', [0x66, 0x66, 0xF6, 0x3E, 0x3F, 0x66, 0x66, 0x00]),
+                                             FontUnicode('\u{2227}', [0x00, 0x0C, 0x1E, 0x33, 0x33, 0x33, 0x00, 0x00]),
+                                             FontUnicode('\u{2228}', [0x00, 0x33, 0x33, 0x33, 0x1E, 0x0C, 0x00, 0x00]),
+                                             FontUnicode('\u{2229}', [0x00, 0x1E, 0x33, 0x33, 0x33, 0x33, 0x00, 0x00]),
+                                             FontUnicode('\u{222A}', [0x00, 0x33, 0x33, 0x33, 0x33, 0x1E, 0x00, 0x00]),
+                                             FontUnicode('\u{222B}', [0x70, 0xD8, 0x18, 0x18, 0x18, 0x1B, 0x0E, 0x00]),
+                                             FontUnicode('\u{222C}', [0x6C, 0x36, 0x36, 0x36, 0x36, 0x36, 0x1B, 0x00]),
+                                             FontUnicode('\u{222D}', [0x2A, 0x2A, 0x2A, 0x2A, 0x2A, 0x2A, 0x15, 0x00]),
+                                             FontUnicode('\u{222E}', [0x70, 0xD8, 0x3C, 0xB6, 0x3C, 0x18, 0x1B, 0x0E]),
+                                             FontUnicode('\u{2234}', [0x00, 0x0C, 0x0C, 0x00, 0x33, 0x33, 0x00, 0x00]),
+                                             FontUnicode('\u{2235}', [0x00, 0x33, 0x33, 0x00, 0x0C, 0x0C, 0x00, 0x00]),
+                                             FontUnicode('\u{2236}', [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00, 0x00]),
+                                             FontUnicode('\u{2237}', [0x00, 0x33, 0x33, 0x00, 0x33, 0x33, 0x00, 0x00]),
+                                             FontUnicode('\u{223C}', [0x00, 0x00, 0x00, 0x6E, 0x3B, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{223D}', [0x00, 0x00, 0x00, 0x3B, 0x6E, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{2243}', [0x00, 0x00, 0x6E, 0x3B, 0x00, 0x3F, 0x00, 0x00]),
+                                             FontUnicode('\u{2245}', [0x00, 0x6E, 0x3B, 0x00, 0x3F, 0x00, 0x3F, 0x00]),
+                                             FontUnicode('\u{2248}', [0x00, 0x6E, 0x3B, 0x00, 0x6E, 0x3B, 0x00, 0x00]),
+                                             FontUnicode('\u{2249}', [0x20, 0x6E, 0x3B, 0x08, 0x6E, 0x3B, 0x02, 0x00]),
+                                             FontUnicode('\u{2259}', [0x0C, 0x12, 0x00, 0x3F, 0x00, 0x3F, 0x00, 0x00]),
+                                             FontUnicode('\u{225F}', [0x0E, 0x18, 0x0C, 0x00, 0x3F, 0x00, 0x3F, 0x00]),
+                                             FontUnicode('\u{2260}', [0x00, 0x30, 0x3F, 0x18, 0x3F, 0x06, 0x00, 0x00]),
+                                             FontUnicode('\u{2261}', [0x00, 0x3F, 0x00, 0x3F, 0x00, 0x3F, 0x00, 0x00]),
+                                             FontUnicode('\u{2262}', [0x20, 0x3F, 0x10, 0x3F, 0x04, 0x3F, 0x01, 0x00]),
+                                             FontUnicode('\u{2263}', [0x3F, 0x00, 0x3F, 0x00, 0x3F, 0x00, 0x3F, 0x00]),
+                                             FontUnicode('\u{2264}', MISC_LEGACY[5]),
+                                             FontUnicode('\u{2265}', MISC_LEGACY[6]),
+                                             FontUnicode('\u{2266}', [0x30, 0x0C, 0x03, 0x0C, 0x30, 0x3F, 0x00, 0x3F]),
+                                             FontUnicode('\u{2267}', [0x03, 0x0C, 0x30, 0x0C, 0x03, 0x3F, 0x00, 0x3F]),
+                                             FontUnicode('\u{226A}', [0x48, 0x24, 0x12, 0x09, 0x12, 0x24, 0x48, 0x00]),
+                                             FontUnicode('\u{226B}', [0x09, 0x12, 0x24, 0x48, 0x24, 0x12, 0x09, 0x00]),
+                                             FontUnicode('\u{226E}', [0x18, 0x2C, 0x16, 0x0B, 0x06, 0x0E, 0x19, 0x00]),
+                                             FontUnicode('\u{226F}', [0x06, 0x2C, 0x18, 0x38, 0x1C, 0x0E, 0x07, 0x00]),
+                                             FontUnicode('\u{2270}', [0x30, 0x58, 0x2C, 0x18, 0x38, 0x04, 0x7E, 0x00]),
+                                             FontUnicode('\u{2271}', [0x0C, 0x58, 0x30, 0x18, 0x0C, 0x04, 0x7E, 0x00]),
+                                             FontUnicode('\u{2282}', [0x00, 0x3C, 0x06, 0x03, 0x06, 0x3C, 0x00, 0x00]),
+                                             FontUnicode('\u{2283}', [0x00, 0x1E, 0x30, 0x60, 0x30, 0x1E, 0x00, 0x00]),
+                                             FontUnicode('\u{2284}', [0x00, 0x20, 0x3C, 0x16, 0x0B, 0x06, 0x3D, 0x00]),
+                                             FontUnicode('\u{2285}', [0x00, 0x20, 0x1E, 0x30, 0x68, 0x34, 0x1E, 0x00]),
+                                             FontUnicode('\u{2286}', [0x3C, 0x06, 0x03, 0x06, 0x3C, 0x00, 0x3F, 0x00]),
+                                             FontUnicode('\u{2287}', [0x1E, 0x30, 0x60, 0x30, 0x1E, 0x00, 0x3F, 0x00]),
+                                             FontUnicode('\u{2295}', [0x1C, 0x2A, 0x49, 0x7F, 0x49, 0x2A, 0x1C, 0x00]),
+                                             FontUnicode('\u{2296}', [0x1C, 0x22, 0x41, 0x7F, 0x41, 0x22, 0x1C, 0x00]),
+                                             FontUnicode('\u{2297}', [0x1C, 0x22, 0x55, 0x49, 0x55, 0x22, 0x1C, 0x00]),
+                                             FontUnicode('\u{2298}', [0x1C, 0x22, 0x61, 0x49, 0x43, 0x22, 0x1C, 0x00]),
+                                             FontUnicode('\u{2299}', [0x1C, 0x22, 0x41, 0x49, 0x41, 0x22, 0x1C, 0x00]),
+                                             FontUnicode('\u{22A2}', [0x03, 0x03, 0x03, 0x3F, 0x03, 0x03, 0x03, 0x00]),
+                                             FontUnicode('\u{22A3}', [0x30, 0x30, 0x30, 0x3F, 0x30, 0x30, 0x30, 0x00]),
+                                             FontUnicode('\u{22A4}', [0x3F, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x00]),
+                                             FontUnicode('\u{22A5}', [0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x3F, 0x00]),
+                                             FontUnicode('\u{22C5}', [0x00, 0x00, 0x00, 0x0C, 0x0C, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{22C6}', [0x00, 0x08, 0x1C, 0x7F, 0x3E, 0x36, 0x22, 0x00]),
+                                             FontUnicode('\u{22EE}', [0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C]),
+                                             FontUnicode('\u{22EF}', [0x00, 0x00, 0x00, 0xDB, 0xDB, 0x00, 0x00, 0x00])];
+
+/// A convenient constant for Mathematical Operators fonts (`U+2200` - `U+22FF`), that implements the `UnicodeFonts` trait.
+///
+/// ## `MATH_UNICODE[0]`: `U+2200` `"∀"`
+///
+/// ```text
+/// ██░░██░░
+/// ██░░██░░
+/// ██████░░
+/// ░██░██░░
+/// ░████░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[1]`: `U+2202` `"∂"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░░░░██░
+/// ░░█████░
+/// ░██░░██░
+/// ██░░░██░
+/// ██░░██░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[2]`: `U+2203` `"∃"`
+///
+/// ```text
+/// ██████░░
+/// ░░░░██░░
+/// ░░░░██░░
+/// ░█████░░
+/// ░░░░██░░
+/// ░░░░██░░
+/// ██████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[3]`: `U+2204` `"∄"`
+///
+/// ```text
+/// ██████░█
+/// ░░░░███░
+/// ░░░░██░░
+/// ░█████░░
+/// ░░░███░░
+/// ░░█░██░░
+/// ██████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[4]`: `U+2205` `"∅"`
+///
+/// ```text
+/// ░░░░░██░
+/// ░█████░░
+/// ██░░███░
+/// ██░█░██░
+/// ███░░██░
+/// ░█████░░
+/// ██░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[5]`: `U+2206` `"∆"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░████░░░
+/// ░█░░█░░░
+/// ██░░██░░
+/// ██░░██░░
+/// ██████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[6]`: `U+2207` `"∇"`
+///
+/// ```text
+/// ██████░░
+/// ██░░██░░
+/// ██░░██░░
+/// ░█░░█░░░
+/// ░████░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[7]`: `U+2208` `"∈"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░░░░
+/// ██░░░░░░
+/// ██████░░
+/// ██░░░░░░
+/// ░██░░░░░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[8]`: `U+2209` `"∉"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░████░░
+/// ░██░█░░░
+/// ██████░░
+/// ██░█░░░░
+/// ░███░░░░
+/// ░░████░░
+/// ░░█░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[9]`: `U+220B` `"∋"`
+///
+/// ```text
+/// ░████░░░
+/// ░░░░██░░
+/// ░░░░░██░
+/// ░██████░
+/// ░░░░░██░
+/// ░░░░██░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[10]`: `U+220C` `"∌"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░████░░░
+/// ░░░███░░
+/// ░░█████░
+/// ░░█░░██░
+/// ░█░░██░░
+/// ░████░░░
+/// ░█░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[11]`: `U+220F` `"∏"`
+///
+/// ```text
+/// ███████░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ███░░███
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[12]`: `U+2210` `"∐"`
+///
+/// ```text
+/// ███░░███
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[13]`: `U+2211` `"∑"`
+///
+/// ```text
+/// ███████░
+/// ██░░░░█░
+/// ░██░░░░░
+/// ░░██░░░░
+/// ░██░░░░░
+/// ██░░░░█░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[14]`: `U+2212` `"−"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[15]`: `U+2213` `"∓"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ██████░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[16]`: `U+2215` `"∕"`
+///
+/// ```text
+/// ░░░░░██░
+/// ░░░░██░░
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░██░░░░░
+/// ██░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[17]`: `U+2216` `"∖"`
+///
+/// ```text
+/// ██░░░░░░
+/// ░██░░░░░
+/// ░░██░░░░
+/// ░░░██░░░
+/// ░░░░██░░
+/// ░░░░░██░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[18]`: `U+2217` `"∗"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ███████░
+/// ░░███░░░
+/// ░█░█░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[19]`: `U+2218` `"∘"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░█░░█░░░
+/// ░█░░█░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[20]`: `U+2219` `"∙"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░████░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[21]`: `U+221A` `"√"`
+///
+/// ```text
+/// ░░░░████
+/// ░░░░██░░
+/// ░░░░██░░
+/// ░░░░██░░
+/// ██░██░░░
+/// ░████░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[22]`: `U+221B` `"∛"`
+///
+/// ```text
+/// ███░████
+/// ░░█░██░░
+/// ░██░██░░
+/// ░░█░██░░
+/// ████░░░░
+/// ░████░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[23]`: `U+221C` `"∜"`
+///
+/// ```text
+/// █░█░████
+/// ███░██░░
+/// ░░█░██░░
+/// ░░░░██░░
+/// ██░██░░░
+/// ░████░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[24]`: `U+221D` `"∝"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██░░██░
+/// ██░██░░░
+/// ██░██░░░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[25]`: `U+221E` `"∞"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██░██░░
+/// █░░█░░█░
+/// █░░█░░█░
+/// ░██░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[26]`: `U+221F` `"∟"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[27]`: `U+2220` `"∠"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░██░
+/// ░░░░██░░
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░██░░░░░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[28]`: `U+2223` `"∣"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[29]`: `U+2224` `"∤"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░████░
+/// ░░███░░░
+/// ░████░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[30]`: `U+2225` `"∥"`
+///
+/// ```text
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[31]`: `U+2226` `"∦"`
+///
+/// ```text
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░████
+/// ░█████░░
+/// ██████░░
+/// ░██░░██░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[32]`: `U+2227` `"∧"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░████░░░
+/// ██░░██░░
+/// ██░░██░░
+/// ██░░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[33]`: `U+2228` `"∨"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░██░░
+/// ██░░██░░
+/// ██░░██░░
+/// ░████░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[34]`: `U+2229` `"∩"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░████░░░
+/// ██░░██░░
+/// ██░░██░░
+/// ██░░██░░
+/// ██░░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[35]`: `U+222A` `"∪"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░██░░
+/// ██░░██░░
+/// ██░░██░░
+/// ██░░██░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[36]`: `U+222B` `"∫"`
+///
+/// ```text
+/// ░░░░███░
+/// ░░░██░██
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ██░██░░░
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[37]`: `U+222C` `"∬"`
+///
+/// ```text
+/// ░░██░██░
+/// ░██░██░░
+/// ░██░██░░
+/// ░██░██░░
+/// ░██░██░░
+/// ░██░██░░
+/// ██░██░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[38]`: `U+222D` `"∭"`
+///
+/// ```text
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// █░█░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[39]`: `U+222E` `"∮"`
+///
+/// ```text
+/// ░░░░███░
+/// ░░░██░██
+/// ░░████░░
+/// ░██░██░█
+/// ░░████░░
+/// ░░░██░░░
+/// ██░██░░░
+/// ░███░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[40]`: `U+2234` `"∴"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ██░░██░░
+/// ██░░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[41]`: `U+2235` `"∵"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░██░░
+/// ██░░██░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[42]`: `U+2236` `"∶"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[43]`: `U+2237` `"∷"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░██░░
+/// ██░░██░░
+/// ░░░░░░░░
+/// ██░░██░░
+/// ██░░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[44]`: `U+223C` `"∼"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░███░██░
+/// ██░███░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[45]`: `U+223D` `"∽"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░███░░
+/// ░███░██░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[46]`: `U+2243` `"≃"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░███░██░
+/// ██░███░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[47]`: `U+2245` `"≅"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░███░██░
+/// ██░███░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[48]`: `U+2248` `"≈"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░███░██░
+/// ██░███░░
+/// ░░░░░░░░
+/// ░███░██░
+/// ██░███░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[49]`: `U+2249` `"≉"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░███░██░
+/// ██░███░░
+/// ░░░█░░░░
+/// ░███░██░
+/// ██░███░░
+/// ░█░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[50]`: `U+2259` `"≙"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░█░░█░░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[51]`: `U+225F` `"≟"`
+///
+/// ```text
+/// ░███░░░░
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[52]`: `U+2260` `"≠"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░██░░
+/// ██████░░
+/// ░░░██░░░
+/// ██████░░
+/// ░██░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[53]`: `U+2261` `"≡"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[54]`: `U+2262` `"≢"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ██████░░
+/// ░░░░█░░░
+/// ██████░░
+/// ░░█░░░░░
+/// ██████░░
+/// █░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[55]`: `U+2263` `"≣"`
+///
+/// ```text
+/// ██████░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[56]`: `U+2264` `"≤"`
+///
+/// ```text
+/// ░░░░██░░
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░░░██░░░
+/// ░░░░██░░
+/// ░░░░░░░░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[57]`: `U+2265` `"≥"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░░██░░░
+/// ░░░░██░░
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[58]`: `U+2266` `"≦"`
+///
+/// ```text
+/// ░░░░██░░
+/// ░░██░░░░
+/// ██░░░░░░
+/// ░░██░░░░
+/// ░░░░██░░
+/// ██████░░
+/// ░░░░░░░░
+/// ██████░░
+/// ```
+///
+/// ## `MATH_UNICODE[59]`: `U+2267` `"≧"`
+///
+/// ```text
+/// ██░░░░░░
+/// ░░██░░░░
+/// ░░░░██░░
+/// ░░██░░░░
+/// ██░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ██████░░
+/// ```
+///
+/// ## `MATH_UNICODE[60]`: `U+226A` `"≪"`
+///
+/// ```text
+/// ░░░█░░█░
+/// ░░█░░█░░
+/// ░█░░█░░░
+/// █░░█░░░░
+/// ░█░░█░░░
+/// ░░█░░█░░
+/// ░░░█░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[61]`: `U+226B` `"≫"`
+///
+/// ```text
+/// █░░█░░░░
+/// ░█░░█░░░
+/// ░░█░░█░░
+/// ░░░█░░█░
+/// ░░█░░█░░
+/// ░█░░█░░░
+/// █░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[62]`: `U+226E` `"≮"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░██░█░░
+/// ░██░█░░░
+/// ██░█░░░░
+/// ░██░░░░░
+/// ░███░░░░
+/// █░░██░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[63]`: `U+226F` `"≯"`
+///
+/// ```text
+/// ░██░░░░░
+/// ░░██░█░░
+/// ░░░██░░░
+/// ░░░███░░
+/// ░░███░░░
+/// ░███░░░░
+/// ███░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[64]`: `U+2270` `"≰"`
+///
+/// ```text
+/// ░░░░██░░
+/// ░░░██░█░
+/// ░░██░█░░
+/// ░░░██░░░
+/// ░░░███░░
+/// ░░█░░░░░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[65]`: `U+2271` `"≱"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░░██░█░
+/// ░░░░██░░
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░░█░░░░░
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[66]`: `U+2282` `"⊂"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░████░░
+/// ░██░░░░░
+/// ██░░░░░░
+/// ░██░░░░░
+/// ░░████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[67]`: `U+2283` `"⊃"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░████░░░
+/// ░░░░██░░
+/// ░░░░░██░
+/// ░░░░██░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[68]`: `U+2284` `"⊄"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░████░░
+/// ░██░█░░░
+/// ██░█░░░░
+/// ░██░░░░░
+/// █░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[69]`: `U+2285` `"⊅"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░████░░░
+/// ░░░░██░░
+/// ░░░█░██░
+/// ░░█░██░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[70]`: `U+2286` `"⊆"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░░░░
+/// ██░░░░░░
+/// ░██░░░░░
+/// ░░████░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[71]`: `U+2287` `"⊇"`
+///
+/// ```text
+/// ░████░░░
+/// ░░░░██░░
+/// ░░░░░██░
+/// ░░░░██░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[72]`: `U+2295` `"⊕"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░█░█░░
+/// █░░█░░█░
+/// ███████░
+/// █░░█░░█░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[73]`: `U+2296` `"⊖"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// ███████░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[74]`: `U+2297` `"⊗"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░█░█░█░
+/// █░░█░░█░
+/// █░█░█░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[75]`: `U+2298` `"⊘"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░██░
+/// █░░█░░█░
+/// ██░░░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[76]`: `U+2299` `"⊙"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// █░░█░░█░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[77]`: `U+22A2` `"⊢"`
+///
+/// ```text
+/// ██░░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ██████░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[78]`: `U+22A3` `"⊣"`
+///
+/// ```text
+/// ░░░░██░░
+/// ░░░░██░░
+/// ░░░░██░░
+/// ██████░░
+/// ░░░░██░░
+/// ░░░░██░░
+/// ░░░░██░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[79]`: `U+22A4` `"⊤"`
+///
+/// ```text
+/// ██████░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[80]`: `U+22A5` `"⊥"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[81]`: `U+22C5` `"⋅"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[82]`: `U+22C6` `"⋆"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ███████░
+/// ░█████░░
+/// ░██░██░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[83]`: `U+22EE` `"⋮"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `MATH_UNICODE[84]`: `U+22EF` `"⋯"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░██░██
+/// ██░██░██
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+pub const MATH_FONTS: MathFonts = MathFonts(MATH_UNICODE);
+/// Strong-typed collection wrapper for [MATH_UNICODE](./constant.MATH_UNICODE.html).
+pub struct MathFonts([FontUnicode; 85]);
+
+impl MathFonts {
+    /// Create a new collection of `MATH_UNICODE` fonts.
+    pub fn new() -> Self {
+        MathFonts(MATH_UNICODE)
+    }
+}
+
+impl fmt::Debug for MathFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(MATH_UNICODE))
+    }
+}
+
+impl PartialEq for MathFonts {
+    fn eq(&self, other: &MathFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for MathFonts {
+    fn default() -> Self {
+        MathFonts::new()
+    }
+}
+
+impl UnicodeFonts for MathFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(85), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn math_set_implements_default_trait_with_method_new() {
+        let math_set: MathFonts = Default::default();
+        assert_eq!(math_set, MathFonts::new());
+    }
+
+    #[test]
+    fn math_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(MATH_FONTS, MathFonts::new());
+    }
+
+    #[test]
+    fn math_fonts_constant_wraps_math_unicode_constant() {
+        let math = MathFonts::new();
+        assert!(math.0.len() == MATH_UNICODE.len());
+        for (idx, font) in math.0.iter().enumerate() {
+            assert_eq!(font, &MATH_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn math_set_contains_the_legacy_comparison_signs() {
+        assert_eq!(MATH_FONTS.get('≤'), Some(MISC_LEGACY[5]));
+        assert_eq!(MATH_FONTS.get('≥'), Some(MISC_LEGACY[6]));
+    }
+
+    #[test]
+    fn math_set_covers_scientific_operators() {
+        for key in "≠≈≡∞√∑∏∫∂∆∇∈∉∩∪⊂⊃∀∃∧∨".chars() {
+            assert!(!MATH_FONTS.get_font(key).unwrap().is_whitespace());
+        }
+    }
+}
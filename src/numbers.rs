@@ -0,0 +1,977 @@
+//! Number Forms. `U+2150` - `U+218F`
+//!
+//! Fractions put a tiny numerator in the top left corner and a tiny denominator in the
+//! bottom right corner, with the digits of `SCRIPT_FONTS`. Roman numerals of one
+//! letter are the `BASIC_LEGACY` letters. `¼ ½ ¾` are in `LATIN_FONTS`.
+use super::{legacy::BASIC_LEGACY, unicode::{FontUnicode, UnicodeFonts}};
+use core::fmt;
+
+/// A constant `[FontUnicode; 60]`, for Number Forms fonts (`U+2150` - `U+218F`).
+pub const NUMBER_UNICODE: [FontUnicode; 60] = [FontUnicode('\u{2150}', [0x82, 0x43, 0x02, 0xE2, 0x87, 0x48, 0x44, 0x42]),
+                                               FontUnicode('\u{2151}', [0x82, 0x43, 0x02, 0xE2, 0xA7, 0xE8, 0x84, 0x62]),
+                                               FontUnicode('\u{2152}', [0x82, 0x43, 0x22, 0x02, 0xE8, 0xA8, 0xAA, 0xE9]),
+                                               FontUnicode('\u{2153}', [0x82, 0x43, 0x02, 0x62, 0x87, 0x48, 0x84, 0x62]),
+                                               FontUnicode('\u{2154}', [0x83, 0x44, 0x02, 0x61, 0x87, 0x48, 0x84, 0x62]),
+                                               FontUnicode('\u{2155}', [0x82, 0x43, 0x02, 0xE2, 0x27, 0x68, 0x84, 0x62]),
+                                               FontUnicode('\u{2156}', [0x83, 0x44, 0x02, 0xE1, 0x27, 0x68, 0x84, 0x62]),
+                                               FontUnicode('\u{2157}', [0x83, 0x44, 0x02, 0xE4, 0x23, 0x68, 0x84, 0x62]),
+                                               FontUnicode('\u{2158}', [0x85, 0x45, 0x07, 0xE4, 0x24, 0x68, 0x84, 0x62]),
+                                               FontUnicode('\u{2159}', [0x82, 0x43, 0x02, 0xC2, 0x27, 0xE8, 0xA4, 0xE2]),
+                                               FontUnicode('\u{215A}', [0x87, 0x41, 0x03, 0xC4, 0x23, 0xE8, 0xA4, 0xE2]),
+                                               FontUnicode('\u{215B}', [0x82, 0x43, 0x02, 0xE2, 0xA7, 0xE8, 0xA4, 0xE2]),
+                                               FontUnicode('\u{215C}', [0x83, 0x44, 0x02, 0xE4, 0xA3, 0xE8, 0xA4, 0xE2]),
+                                               FontUnicode('\u{215D}', [0x87, 0x41, 0x03, 0xE4, 0xA3, 0xE8, 0xA4, 0xE2]),
+                                               FontUnicode('\u{215E}', [0x87, 0x44, 0x02, 0xE2, 0xA2, 0xE8, 0xA4, 0xE2]),
+                                               FontUnicode('\u{215F}', [0x82, 0x43, 0x02, 0x02, 0x07, 0x08, 0x04, 0x02]),
+                                               FontUnicode('\u{2160}', BASIC_LEGACY[73]),
+                                               FontUnicode('\u{2161}', [0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x00]),
+                                               FontUnicode('\u{2162}', [0xDB, 0xDB, 0xDB, 0xDB, 0xDB, 0xDB, 0xDB, 0x00]),
+                                               FontUnicode('\u{2163}', [0x45, 0x45, 0x45, 0x29, 0x29, 0x11, 0x11, 0x00]),
+                                               FontUnicode('\u{2164}', BASIC_LEGACY[86]),
+                                               FontUnicode('\u{2165}', [0x51, 0x51, 0x51, 0x4A, 0x4A, 0x44, 0x44, 0x00]),
+                                               FontUnicode('\u{2166}', [0x55, 0x55, 0x55, 0x55, 0x52, 0x52, 0x52, 0x00]),
+                                               FontUnicode('\u{2167}', [0xAD, 0xAD, 0xAD, 0xAD, 0xAA, 0xAA, 0xAA, 0x00]),
+                                               FontUnicode('\u{2168}', [0x45, 0x45, 0x29, 0x11, 0x29, 0x45, 0x45, 0x00]),
+                                               FontUnicode('\u{2169}', BASIC_LEGACY[88]),
+                                               FontUnicode('\u{216A}', [0x51, 0x51, 0x4A, 0x44, 0x4A, 0x51, 0x51, 0x00]),
+                                               FontUnicode('\u{216B}', [0x55, 0x55, 0x52, 0x52, 0x52, 0x55, 0x55, 0x00]),
+                                               FontUnicode('\u{216C}', BASIC_LEGACY[76]),
+                                               FontUnicode('\u{216D}', BASIC_LEGACY[67]),
+                                               FontUnicode('\u{216E}', BASIC_LEGACY[68]),
+                                               FontUnicode('\u{216F}', BASIC_LEGACY[77]),
+                                               FontUnicode('\u{2170}', BASIC_LEGACY[105]),
+                                               FontUnicode('\u{2171}', [0x24, 0x00, 0x24, 0x24, 0x24, 0x24, 0x24, 0x00]),
+                                               FontUnicode('\u{2172}', [0x49, 0x00, 0x49, 0x49, 0x49, 0x49, 0x49, 0x00]),
+                                               FontUnicode('\u{2173}', [0x01, 0x00, 0x45, 0x45, 0x29, 0x29, 0x11, 0x00]),
+                                               FontUnicode('\u{2174}', BASIC_LEGACY[118]),
+                                               FontUnicode('\u{2175}', [0x40, 0x00, 0x51, 0x51, 0x4A, 0x4A, 0x44, 0x00]),
+                                               FontUnicode('\u{2176}', [0x50, 0x00, 0x55, 0x55, 0x55, 0x52, 0x52, 0x00]),
+                                               FontUnicode('\u{2177}', [0xA8, 0x00, 0xAD, 0xAD, 0xAD, 0xAA, 0xAA, 0x00]),
+                                               FontUnicode('\u{2178}', [0x01, 0x00, 0x45, 0x29, 0x11, 0x29, 0x45, 0x00]),
+                                               FontUnicode('\u{2179}', BASIC_LEGACY[120]),
+                                               FontUnicode('\u{217A}', [0x40, 0x00, 0x51, 0x4A, 0x44, 0x4A, 0x51, 0x00]),
+                                               FontUnicode('\u{217B}', [0x50, 0x00, 0x55, 0x55, 0x52, 0x55, 0x55, 0x00]),
+                                               FontUnicode('\u{217C}', BASIC_LEGACY[108]),
+                                               FontUnicode('\u{217D}', BASIC_LEGACY[99]),
+                                               FontUnicode('\u{217E}', BASIC_LEGACY[100]),
+                                               FontUnicode('\u{217F}', BASIC_LEGACY[109]),
+                                               FontUnicode('\u{2180}', [0x2A, 0x49, 0x49, 0x49, 0x49, 0x49, 0x2A, 0x00]),
+                                               FontUnicode('\u{2181}', [0x0D, 0x11, 0x25, 0x25, 0x25, 0x11, 0x0D, 0x00]),
+                                               FontUnicode('\u{2182}', [0x2A, 0x55, 0x55, 0x55, 0x55, 0x55, 0x2A, 0x00]),
+                                               FontUnicode('\u{2183}', [0x1E, 0x33, 0x60, 0x60, 0x60, 0x33, 0x1E, 0x00]),
+                                               FontUnicode('\u{2184}', [0x00, 0x00, 0x3C, 0x66, 0x60, 0x66, 0x3C, 0x00]),
+                                               FontUnicode('\u{2185}', [0x1E, 0x03, 0x03, 0x1E, 0x30, 0x18, 0x0C, 0x00]),
+                                               FontUnicode('\u{2186}', [0x08, 0x08, 0x08, 0x1C, 0x2A, 0x49, 0x08, 0x00]),
+                                               FontUnicode('\u{2187}', [0x15, 0x25, 0x55, 0x55, 0x55, 0x25, 0x15, 0x00]),
+                                               FontUnicode('\u{2188}', [0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00]),
+                                               FontUnicode('\u{2189}', [0x87, 0x45, 0x05, 0x65, 0x87, 0x48, 0x84, 0x62]),
+                                               FontUnicode('\u{218A}', [0x3F, 0x33, 0x18, 0x0E, 0x03, 0x33, 0x1E, 0x00]),
+                                               FontUnicode('\u{218B}', [0x1E, 0x33, 0x03, 0x0E, 0x03, 0x33, 0x1E, 0x00])];
+
+/// A convenient constant for Number Forms fonts (`U+2150` - `U+218F`), that implements the `UnicodeFonts` trait.
+///
+/// ## `NUMBER_UNICODE[0]`: `U+2150` `"⅐"`
+///
+/// ```text
+/// ░█░░░░░█
+/// ██░░░░█░
+/// ░█░░░░░░
+/// ░█░░░███
+/// ███░░░░█
+/// ░░░█░░█░
+/// ░░█░░░█░
+/// ░█░░░░█░
+/// ```
+///
+/// ## `NUMBER_UNICODE[1]`: `U+2151` `"⅑"`
+///
+/// ```text
+/// ░█░░░░░█
+/// ██░░░░█░
+/// ░█░░░░░░
+/// ░█░░░███
+/// ███░░█░█
+/// ░░░█░███
+/// ░░█░░░░█
+/// ░█░░░██░
+/// ```
+///
+/// ## `NUMBER_UNICODE[2]`: `U+2152` `"⅒"`
+///
+/// ```text
+/// ░█░░░░░█
+/// ██░░░░█░
+/// ░█░░░█░░
+/// ░█░░░░░░
+/// ░░░█░███
+/// ░░░█░█░█
+/// ░█░█░█░█
+/// █░░█░███
+/// ```
+///
+/// ## `NUMBER_UNICODE[3]`: `U+2153` `"⅓"`
+///
+/// ```text
+/// ░█░░░░░█
+/// ██░░░░█░
+/// ░█░░░░░░
+/// ░█░░░██░
+/// ███░░░░█
+/// ░░░█░░█░
+/// ░░█░░░░█
+/// ░█░░░██░
+/// ```
+///
+/// ## `NUMBER_UNICODE[4]`: `U+2154` `"⅔"`
+///
+/// ```text
+/// ██░░░░░█
+/// ░░█░░░█░
+/// ░█░░░░░░
+/// █░░░░██░
+/// ███░░░░█
+/// ░░░█░░█░
+/// ░░█░░░░█
+/// ░█░░░██░
+/// ```
+///
+/// ## `NUMBER_UNICODE[5]`: `U+2155` `"⅕"`
+///
+/// ```text
+/// ░█░░░░░█
+/// ██░░░░█░
+/// ░█░░░░░░
+/// ░█░░░███
+/// ███░░█░░
+/// ░░░█░██░
+/// ░░█░░░░█
+/// ░█░░░██░
+/// ```
+///
+/// ## `NUMBER_UNICODE[6]`: `U+2156` `"⅖"`
+///
+/// ```text
+/// ██░░░░░█
+/// ░░█░░░█░
+/// ░█░░░░░░
+/// █░░░░███
+/// ███░░█░░
+/// ░░░█░██░
+/// ░░█░░░░█
+/// ░█░░░██░
+/// ```
+///
+/// ## `NUMBER_UNICODE[7]`: `U+2157` `"⅗"`
+///
+/// ```text
+/// ██░░░░░█
+/// ░░█░░░█░
+/// ░█░░░░░░
+/// ░░█░░███
+/// ██░░░█░░
+/// ░░░█░██░
+/// ░░█░░░░█
+/// ░█░░░██░
+/// ```
+///
+/// ## `NUMBER_UNICODE[8]`: `U+2158` `"⅘"`
+///
+/// ```text
+/// █░█░░░░█
+/// █░█░░░█░
+/// ███░░░░░
+/// ░░█░░███
+/// ░░█░░█░░
+/// ░░░█░██░
+/// ░░█░░░░█
+/// ░█░░░██░
+/// ```
+///
+/// ## `NUMBER_UNICODE[9]`: `U+2159` `"⅙"`
+///
+/// ```text
+/// ░█░░░░░█
+/// ██░░░░█░
+/// ░█░░░░░░
+/// ░█░░░░██
+/// ███░░█░░
+/// ░░░█░███
+/// ░░█░░█░█
+/// ░█░░░███
+/// ```
+///
+/// ## `NUMBER_UNICODE[10]`: `U+215A` `"⅚"`
+///
+/// ```text
+/// ███░░░░█
+/// █░░░░░█░
+/// ██░░░░░░
+/// ░░█░░░██
+/// ██░░░█░░
+/// ░░░█░███
+/// ░░█░░█░█
+/// ░█░░░███
+/// ```
+///
+/// ## `NUMBER_UNICODE[11]`: `U+215B` `"⅛"`
+///
+/// ```text
+/// ░█░░░░░█
+/// ██░░░░█░
+/// ░█░░░░░░
+/// ░█░░░███
+/// ███░░█░█
+/// ░░░█░███
+/// ░░█░░█░█
+/// ░█░░░███
+/// ```
+///
+/// ## `NUMBER_UNICODE[12]`: `U+215C` `"⅜"`
+///
+/// ```text
+/// ██░░░░░█
+/// ░░█░░░█░
+/// ░█░░░░░░
+/// ░░█░░███
+/// ██░░░█░█
+/// ░░░█░███
+/// ░░█░░█░█
+/// ░█░░░███
+/// ```
+///
+/// ## `NUMBER_UNICODE[13]`: `U+215D` `"⅝"`
+///
+/// ```text
+/// ███░░░░█
+/// █░░░░░█░
+/// ██░░░░░░
+/// ░░█░░███
+/// ██░░░█░█
+/// ░░░█░███
+/// ░░█░░█░█
+/// ░█░░░███
+/// ```
+///
+/// ## `NUMBER_UNICODE[14]`: `U+215E` `"⅞"`
+///
+/// ```text
+/// ███░░░░█
+/// ░░█░░░█░
+/// ░█░░░░░░
+/// ░█░░░███
+/// ░█░░░█░█
+/// ░░░█░███
+/// ░░█░░█░█
+/// ░█░░░███
+/// ```
+///
+/// ## `NUMBER_UNICODE[15]`: `U+215F` `"⅟"`
+///
+/// ```text
+/// ░█░░░░░█
+/// ██░░░░█░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ███░░░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[16]`: `U+2160` `"Ⅰ"`
+///
+/// ```text
+/// ░████░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[17]`: `U+2161` `"Ⅱ"`
+///
+/// ```text
+/// ░██░██░░
+/// ░██░██░░
+/// ░██░██░░
+/// ░██░██░░
+/// ░██░██░░
+/// ░██░██░░
+/// ░██░██░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[18]`: `U+2162` `"Ⅲ"`
+///
+/// ```text
+/// ██░██░██
+/// ██░██░██
+/// ██░██░██
+/// ██░██░██
+/// ██░██░██
+/// ██░██░██
+/// ██░██░██
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[19]`: `U+2163` `"Ⅳ"`
+///
+/// ```text
+/// █░█░░░█░
+/// █░█░░░█░
+/// █░█░░░█░
+/// █░░█░█░░
+/// █░░█░█░░
+/// █░░░█░░░
+/// █░░░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[20]`: `U+2164` `"Ⅴ"`
+///
+/// ```text
+/// ██░░██░░
+/// ██░░██░░
+/// ██░░██░░
+/// ██░░██░░
+/// ██░░██░░
+/// ░████░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[21]`: `U+2165` `"Ⅵ"`
+///
+/// ```text
+/// █░░░█░█░
+/// █░░░█░█░
+/// █░░░█░█░
+/// ░█░█░░█░
+/// ░█░█░░█░
+/// ░░█░░░█░
+/// ░░█░░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[22]`: `U+2166` `"Ⅶ"`
+///
+/// ```text
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// ░█░░█░█░
+/// ░█░░█░█░
+/// ░█░░█░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[23]`: `U+2167` `"Ⅷ"`
+///
+/// ```text
+/// █░██░█░█
+/// █░██░█░█
+/// █░██░█░█
+/// █░██░█░█
+/// ░█░█░█░█
+/// ░█░█░█░█
+/// ░█░█░█░█
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[24]`: `U+2168` `"Ⅸ"`
+///
+/// ```text
+/// █░█░░░█░
+/// █░█░░░█░
+/// █░░█░█░░
+/// █░░░█░░░
+/// █░░█░█░░
+/// █░█░░░█░
+/// █░█░░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[25]`: `U+2169` `"Ⅹ"`
+///
+/// ```text
+/// ██░░░██░
+/// ██░░░██░
+/// ░██░██░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░██░██░░
+/// ██░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[26]`: `U+216A` `"Ⅺ"`
+///
+/// ```text
+/// █░░░█░█░
+/// █░░░█░█░
+/// ░█░█░░█░
+/// ░░█░░░█░
+/// ░█░█░░█░
+/// █░░░█░█░
+/// █░░░█░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[27]`: `U+216B` `"Ⅻ"`
+///
+/// ```text
+/// █░█░█░█░
+/// █░█░█░█░
+/// ░█░░█░█░
+/// ░█░░█░█░
+/// ░█░░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[28]`: `U+216C` `"Ⅼ"`
+///
+/// ```text
+/// ████░░░░
+/// ░██░░░░░
+/// ░██░░░░░
+/// ░██░░░░░
+/// ░██░░░█░
+/// ░██░░██░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[29]`: `U+216D` `"Ⅽ"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░██░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ░██░░██░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[30]`: `U+216E` `"Ⅾ"`
+///
+/// ```text
+/// █████░░░
+/// ░██░██░░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░██░░
+/// █████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[31]`: `U+216F` `"Ⅿ"`
+///
+/// ```text
+/// ██░░░██░
+/// ███░███░
+/// ███████░
+/// ███████░
+/// ██░█░██░
+/// ██░░░██░
+/// ██░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[32]`: `U+2170` `"ⅰ"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░███░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[33]`: `U+2171` `"ⅱ"`
+///
+/// ```text
+/// ░░█░░█░░
+/// ░░░░░░░░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░░█░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[34]`: `U+2172` `"ⅲ"`
+///
+/// ```text
+/// █░░█░░█░
+/// ░░░░░░░░
+/// █░░█░░█░
+/// █░░█░░█░
+/// █░░█░░█░
+/// █░░█░░█░
+/// █░░█░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[35]`: `U+2173` `"ⅳ"`
+///
+/// ```text
+/// █░░░░░░░
+/// ░░░░░░░░
+/// █░█░░░█░
+/// █░█░░░█░
+/// █░░█░█░░
+/// █░░█░█░░
+/// █░░░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[36]`: `U+2174` `"ⅴ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░██░░
+/// ██░░██░░
+/// ██░░██░░
+/// ░████░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[37]`: `U+2175` `"ⅵ"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░░░░░
+/// █░░░█░█░
+/// █░░░█░█░
+/// ░█░█░░█░
+/// ░█░█░░█░
+/// ░░█░░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[38]`: `U+2176` `"ⅶ"`
+///
+/// ```text
+/// ░░░░█░█░
+/// ░░░░░░░░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// ░█░░█░█░
+/// ░█░░█░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[39]`: `U+2177` `"ⅷ"`
+///
+/// ```text
+/// ░░░█░█░█
+/// ░░░░░░░░
+/// █░██░█░█
+/// █░██░█░█
+/// █░██░█░█
+/// ░█░█░█░█
+/// ░█░█░█░█
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[40]`: `U+2178` `"ⅸ"`
+///
+/// ```text
+/// █░░░░░░░
+/// ░░░░░░░░
+/// █░█░░░█░
+/// █░░█░█░░
+/// █░░░█░░░
+/// █░░█░█░░
+/// █░█░░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[41]`: `U+2179` `"ⅹ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░██░
+/// ░██░██░░
+/// ░░███░░░
+/// ░██░██░░
+/// ██░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[42]`: `U+217A` `"ⅺ"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░░░░░
+/// █░░░█░█░
+/// ░█░█░░█░
+/// ░░█░░░█░
+/// ░█░█░░█░
+/// █░░░█░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[43]`: `U+217B` `"ⅻ"`
+///
+/// ```text
+/// ░░░░█░█░
+/// ░░░░░░░░
+/// █░█░█░█░
+/// █░█░█░█░
+/// ░█░░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[44]`: `U+217C` `"ⅼ"`
+///
+/// ```text
+/// ░███░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[45]`: `U+217D` `"ⅽ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// ██░░██░░
+/// ██░░░░░░
+/// ██░░██░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[46]`: `U+217E` `"ⅾ"`
+///
+/// ```text
+/// ░░░███░░
+/// ░░░░██░░
+/// ░░░░██░░
+/// ░█████░░
+/// ██░░██░░
+/// ██░░██░░
+/// ░███░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[47]`: `U+217F` `"ⅿ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░██░░
+/// ███████░
+/// ███████░
+/// ██░█░██░
+/// ██░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[48]`: `U+2180` `"ↀ"`
+///
+/// ```text
+/// ░█░█░█░░
+/// █░░█░░█░
+/// █░░█░░█░
+/// █░░█░░█░
+/// █░░█░░█░
+/// █░░█░░█░
+/// ░█░█░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[49]`: `U+2181` `"ↁ"`
+///
+/// ```text
+/// █░██░░░░
+/// █░░░█░░░
+/// █░█░░█░░
+/// █░█░░█░░
+/// █░█░░█░░
+/// █░░░█░░░
+/// █░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[50]`: `U+2182` `"ↂ"`
+///
+/// ```text
+/// ░█░█░█░░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// ░█░█░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[51]`: `U+2183` `"Ↄ"`
+///
+/// ```text
+/// ░████░░░
+/// ██░░██░░
+/// ░░░░░██░
+/// ░░░░░██░
+/// ░░░░░██░
+/// ██░░██░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[52]`: `U+2184` `"ↄ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░████░░
+/// ░██░░██░
+/// ░░░░░██░
+/// ░██░░██░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[53]`: `U+2185` `"ↅ"`
+///
+/// ```text
+/// ░████░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ░████░░░
+/// ░░░░██░░
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[54]`: `U+2186` `"ↆ"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█░█░█░░
+/// █░░█░░█░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[55]`: `U+2187` `"ↇ"`
+///
+/// ```text
+/// █░█░█░░░
+/// █░█░░█░░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░░█░░
+/// █░█░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[56]`: `U+2188` `"ↈ"`
+///
+/// ```text
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[57]`: `U+2189` `"↉"`
+///
+/// ```text
+/// ███░░░░█
+/// █░█░░░█░
+/// █░█░░░░░
+/// █░█░░██░
+/// ███░░░░█
+/// ░░░█░░█░
+/// ░░█░░░░█
+/// ░█░░░██░
+/// ```
+///
+/// ## `NUMBER_UNICODE[58]`: `U+218A` `"↊"`
+///
+/// ```text
+/// ██████░░
+/// ██░░██░░
+/// ░░░██░░░
+/// ░███░░░░
+/// ██░░░░░░
+/// ██░░██░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `NUMBER_UNICODE[59]`: `U+218B` `"↋"`
+///
+/// ```text
+/// ░████░░░
+/// ██░░██░░
+/// ██░░░░░░
+/// ░███░░░░
+/// ██░░░░░░
+/// ██░░██░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+pub const NUMBER_FONTS: NumberFonts = NumberFonts(NUMBER_UNICODE);
+/// Strong-typed collection wrapper for [NUMBER_UNICODE](./constant.NUMBER_UNICODE.html).
+pub struct NumberFonts([FontUnicode; 60]);
+
+impl NumberFonts {
+    /// Create a new collection of `NUMBER_UNICODE` fonts.
+    pub fn new() -> Self {
+        NumberFonts(NUMBER_UNICODE)
+    }
+}
+
+impl fmt::Debug for NumberFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(NUMBER_UNICODE))
+    }
+}
+
+impl PartialEq for NumberFonts {
+    fn eq(&self, other: &NumberFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for NumberFonts {
+    fn default() -> Self {
+        NumberFonts::new()
+    }
+}
+
+impl UnicodeFonts for NumberFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(60), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pictures::TINY_HEX;
+
+    #[test]
+    fn number_set_implements_default_trait_with_method_new() {
+        let number_set: NumberFonts = Default::default();
+        assert_eq!(number_set, NumberFonts::new());
+    }
+
+    #[test]
+    fn number_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(NUMBER_FONTS, NumberFonts::new());
+    }
+
+    #[test]
+    fn number_fonts_constant_wraps_number_unicode_constant() {
+        let number = NumberFonts::new();
+        assert!(number.0.len() == NUMBER_UNICODE.len());
+        for (idx, font) in number.0.iter().enumerate() {
+            assert_eq!(font, &NUMBER_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn number_roman_numerals_reuse_the_basic_letters() {
+        for (numeral, letter) in "ⅠⅤⅩⅬⅭⅮⅯⅰⅴⅹⅼⅽⅾⅿ".chars().zip("IVXLCDMivxlcdm".chars()) {
+            assert_eq!(NUMBER_FONTS.get(numeral), Some(BASIC_LEGACY[letter as usize]));
+        }
+    }
+
+    #[test]
+    fn number_fractions_put_the_numerator_high_and_the_denominator_low() {
+        for &(fraction, numerator, denominator) in &[('⅓', 1, 3), ('⅔', 2, 3), ('⅛', 1, 8), ('⅞', 7, 8)] {
+            let glyph = NUMBER_FONTS.get(fraction).unwrap();
+            for row in 0..5 {
+                assert_eq!(glyph[row] & 0x07, TINY_HEX[numerator][row]);
+                assert_eq!(glyph[row + 3] >> 5, TINY_HEX[denominator][row]);
+            }
+        }
+    }
+}
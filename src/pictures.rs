@@ -0,0 +1,734 @@
+//! Control Pictures. `U+2400` - `U+2426`
+//!
+//! The pictures for the C0 controls, space and delete are their abbreviations, cut
+//! down to two letters of a tiny 3x5 font and drawn on rows 1 to 5. Use
+//! [control_picture](./fn.control_picture.html) to find the picture for a control
+//! character.
+use super::unicode::{FontUnicode, UnicodeFonts};
+use core::{char, fmt};
+
+/// A constant `[FontUnicode; 39]`, for Control Pictures fonts (`U+2400` - `U+2426`).
+pub const PICTURE_UNICODE: [FontUnicode; 39] = [FontUnicode('\u{2400}', [0x00, 0x57, 0x55, 0x55, 0x55, 0x75, 0x00, 0x00]),
+                                                FontUnicode('\u{2401}', [0x00, 0x56, 0x51, 0x72, 0x54, 0x53, 0x00, 0x00]),
+                                                FontUnicode('\u{2402}', [0x00, 0x56, 0x51, 0x22, 0x54, 0x53, 0x00, 0x00]),
+                                                FontUnicode('\u{2403}', [0x00, 0x57, 0x51, 0x23, 0x51, 0x57, 0x00, 0x00]),
+                                                FontUnicode('\u{2404}', [0x00, 0x77, 0x21, 0x23, 0x21, 0x27, 0x00, 0x00]),
+                                                FontUnicode('\u{2405}', [0x00, 0x27, 0x51, 0x53, 0x31, 0x67, 0x00, 0x00]),
+                                                FontUnicode('\u{2406}', [0x00, 0x52, 0x55, 0x37, 0x55, 0x55, 0x00, 0x00]),
+                                                FontUnicode('\u{2407}', [0x00, 0x13, 0x15, 0x13, 0x15, 0x73, 0x00, 0x00]),
+                                                FontUnicode('\u{2408}', [0x00, 0x63, 0x15, 0x23, 0x45, 0x33, 0x00, 0x00]),
+                                                FontUnicode('\u{2409}', [0x00, 0x75, 0x25, 0x27, 0x25, 0x25, 0x00, 0x00]),
+                                                FontUnicode('\u{240A}', [0x00, 0x71, 0x11, 0x31, 0x11, 0x17, 0x00, 0x00]),
+                                                FontUnicode('\u{240B}', [0x00, 0x75, 0x25, 0x25, 0x25, 0x22, 0x00, 0x00]),
+                                                FontUnicode('\u{240C}', [0x00, 0x77, 0x11, 0x33, 0x11, 0x11, 0x00, 0x00]),
+                                                FontUnicode('\u{240D}', [0x00, 0x36, 0x51, 0x31, 0x51, 0x56, 0x00, 0x00]),
+                                                FontUnicode('\u{240E}', [0x00, 0x26, 0x51, 0x52, 0x54, 0x23, 0x00, 0x00]),
+                                                FontUnicode('\u{240F}', [0x00, 0x76, 0x21, 0x22, 0x24, 0x73, 0x00, 0x00]),
+                                                FontUnicode('\u{2410}', [0x00, 0x13, 0x15, 0x15, 0x15, 0x73, 0x00, 0x00]),
+                                                FontUnicode('\u{2411}', [0x00, 0x23, 0x35, 0x25, 0x25, 0x73, 0x00, 0x00]),
+                                                FontUnicode('\u{2412}', [0x00, 0x33, 0x45, 0x25, 0x15, 0x73, 0x00, 0x00]),
+                                                FontUnicode('\u{2413}', [0x00, 0x33, 0x45, 0x25, 0x45, 0x33, 0x00, 0x00]),
+                                                FontUnicode('\u{2414}', [0x00, 0x53, 0x55, 0x75, 0x45, 0x43, 0x00, 0x00]),
+                                                FontUnicode('\u{2415}', [0x00, 0x57, 0x55, 0x35, 0x55, 0x55, 0x00, 0x00]),
+                                                FontUnicode('\u{2416}', [0x00, 0x56, 0x51, 0x22, 0x24, 0x23, 0x00, 0x00]),
+                                                FontUnicode('\u{2417}', [0x00, 0x37, 0x51, 0x33, 0x51, 0x37, 0x00, 0x00]),
+                                                FontUnicode('\u{2418}', [0x00, 0x76, 0x51, 0x51, 0x51, 0x56, 0x00, 0x00]),
+                                                FontUnicode('\u{2419}', [0x00, 0x57, 0x71, 0x73, 0x51, 0x57, 0x00, 0x00]),
+                                                FontUnicode('\u{241A}', [0x00, 0x36, 0x51, 0x32, 0x54, 0x33, 0x00, 0x00]),
+                                                FontUnicode('\u{241B}', [0x00, 0x67, 0x11, 0x13, 0x11, 0x67, 0x00, 0x00]),
+                                                FontUnicode('\u{241C}', [0x00, 0x67, 0x11, 0x23, 0x41, 0x31, 0x00, 0x00]),
+                                                FontUnicode('\u{241D}', [0x00, 0x66, 0x11, 0x25, 0x45, 0x36, 0x00, 0x00]),
+                                                FontUnicode('\u{241E}', [0x00, 0x63, 0x15, 0x23, 0x45, 0x35, 0x00, 0x00]),
+                                                FontUnicode('\u{241F}', [0x00, 0x65, 0x15, 0x25, 0x45, 0x37, 0x00, 0x00]),
+                                                FontUnicode('\u{2420}', [0x00, 0x36, 0x51, 0x32, 0x14, 0x13, 0x00, 0x00]),
+                                                FontUnicode('\u{2421}', [0x00, 0x73, 0x15, 0x35, 0x15, 0x73, 0x00, 0x00]),
+                                                FontUnicode('\u{2422}', [0x07, 0x06, 0x0F, 0x3E, 0x66, 0x66, 0x3B, 0x00]),
+                                                FontUnicode('\u{2423}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x41, 0x7F, 0x00]),
+                                                FontUnicode('\u{2424}', [0x00, 0x17, 0x15, 0x15, 0x15, 0x75, 0x00, 0x00]),
+                                                FontUnicode('\u{2425}', [0x7F, 0x49, 0x25, 0x13, 0x49, 0x25, 0x7F, 0x00]),
+                                                FontUnicode('\u{2426}', [0x3C, 0x66, 0x06, 0x0C, 0x18, 0x00, 0x18, 0x00])];
+
+/// A convenient constant for Control Pictures fonts (`U+2400` - `U+2426`), that implements the `UnicodeFonts` trait.
+///
+/// ## `PICTURE_UNICODE[0]`: `U+2400` `"␀"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░█░█░
+/// █░█░███░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[1]`: `U+2401` `"␁"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░█░█░
+/// █░░░█░█░
+/// ░█░░███░
+/// ░░█░█░█░
+/// ██░░█░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[2]`: `U+2402` `"␂"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░█░█░
+/// █░░░█░█░
+/// ░█░░░█░░
+/// ░░█░█░█░
+/// ██░░█░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[3]`: `U+2403` `"␃"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░█░█░
+/// █░░░█░█░
+/// ██░░░█░░
+/// █░░░█░█░
+/// ███░█░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[4]`: `U+2404` `"␄"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░███░
+/// █░░░░█░░
+/// ██░░░█░░
+/// █░░░░█░░
+/// ███░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[5]`: `U+2405` `"␅"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░░█░░
+/// █░░░█░█░
+/// ██░░█░█░
+/// █░░░██░░
+/// ███░░██░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[6]`: `U+2406` `"␆"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░░█░█░
+/// █░█░█░█░
+/// ███░██░░
+/// █░█░█░█░
+/// █░█░█░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[7]`: `U+2407` `"␇"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░█░░░
+/// █░█░█░░░
+/// ██░░█░░░
+/// █░█░█░░░
+/// ██░░███░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[8]`: `U+2408` `"␈"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░░██░
+/// █░█░█░░░
+/// ██░░░█░░
+/// █░█░░░█░
+/// ██░░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[9]`: `U+2409` `"␉"`
+///
+/// ```text
+/// ░░░░░░░░
+/// █░█░███░
+/// █░█░░█░░
+/// ███░░█░░
+/// █░█░░█░░
+/// █░█░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[10]`: `U+240A` `"␊"`
+///
+/// ```text
+/// ░░░░░░░░
+/// █░░░███░
+/// █░░░█░░░
+/// █░░░██░░
+/// █░░░█░░░
+/// ███░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[11]`: `U+240B` `"␋"`
+///
+/// ```text
+/// ░░░░░░░░
+/// █░█░███░
+/// █░█░░█░░
+/// █░█░░█░░
+/// █░█░░█░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[12]`: `U+240C` `"␌"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░███░
+/// █░░░█░░░
+/// ██░░██░░
+/// █░░░█░░░
+/// █░░░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[13]`: `U+240D` `"␍"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░██░░
+/// █░░░█░█░
+/// █░░░██░░
+/// █░░░█░█░
+/// ░██░█░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[14]`: `U+240E` `"␎"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░░█░░
+/// █░░░█░█░
+/// ░█░░█░█░
+/// ░░█░█░█░
+/// ██░░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[15]`: `U+240F` `"␏"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░███░
+/// █░░░░█░░
+/// ░█░░░█░░
+/// ░░█░░█░░
+/// ██░░███░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[16]`: `U+2410` `"␐"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░█░░░
+/// █░█░█░░░
+/// █░█░█░░░
+/// █░█░█░░░
+/// ██░░███░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[17]`: `U+2411` `"␑"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░░█░░
+/// █░█░██░░
+/// █░█░░█░░
+/// █░█░░█░░
+/// ██░░███░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[18]`: `U+2412` `"␒"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░██░░
+/// █░█░░░█░
+/// █░█░░█░░
+/// █░█░█░░░
+/// ██░░███░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[19]`: `U+2413` `"␓"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░██░░
+/// █░█░░░█░
+/// █░█░░█░░
+/// █░█░░░█░
+/// ██░░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[20]`: `U+2414` `"␔"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░█░█░
+/// █░█░█░█░
+/// █░█░███░
+/// █░█░░░█░
+/// ██░░░░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[21]`: `U+2415` `"␕"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░█░█░
+/// █░█░█░█░
+/// █░█░██░░
+/// █░█░█░█░
+/// █░█░█░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[22]`: `U+2416` `"␖"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░█░█░
+/// █░░░█░█░
+/// ░█░░░█░░
+/// ░░█░░█░░
+/// ██░░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[23]`: `U+2417` `"␗"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░██░░
+/// █░░░█░█░
+/// ██░░██░░
+/// █░░░█░█░
+/// ███░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[24]`: `U+2418` `"␘"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░███░
+/// █░░░█░█░
+/// █░░░█░█░
+/// █░░░█░█░
+/// ░██░█░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[25]`: `U+2419` `"␙"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░█░█░
+/// █░░░███░
+/// ██░░███░
+/// █░░░█░█░
+/// ███░█░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[26]`: `U+241A` `"␚"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░██░░
+/// █░░░█░█░
+/// ░█░░██░░
+/// ░░█░█░█░
+/// ██░░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[27]`: `U+241B` `"␛"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░░██░
+/// █░░░█░░░
+/// ██░░█░░░
+/// █░░░█░░░
+/// ███░░██░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[28]`: `U+241C` `"␜"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░░██░
+/// █░░░█░░░
+/// ██░░░█░░
+/// █░░░░░█░
+/// █░░░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[29]`: `U+241D` `"␝"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░░██░
+/// █░░░█░░░
+/// █░█░░█░░
+/// █░█░░░█░
+/// ░██░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[30]`: `U+241E` `"␞"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░░██░
+/// █░█░█░░░
+/// ██░░░█░░
+/// █░█░░░█░
+/// █░█░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[31]`: `U+241F` `"␟"`
+///
+/// ```text
+/// ░░░░░░░░
+/// █░█░░██░
+/// █░█░█░░░
+/// █░█░░█░░
+/// █░█░░░█░
+/// ███░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[32]`: `U+2420` `"␠"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░██░░
+/// █░░░█░█░
+/// ░█░░██░░
+/// ░░█░█░░░
+/// ██░░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[33]`: `U+2421` `"␡"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░███░
+/// █░█░█░░░
+/// █░█░██░░
+/// █░█░█░░░
+/// ██░░███░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[34]`: `U+2422` `"␢"`
+///
+/// ```text
+/// ███░░░░░
+/// ░██░░░░░
+/// ████░░░░
+/// ░█████░░
+/// ░██░░██░
+/// ░██░░██░
+/// ██░███░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[35]`: `U+2423` `"␣"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░░░░░█░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[36]`: `U+2424` `"␤"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░█░░░
+/// █░█░█░░░
+/// █░█░█░░░
+/// █░█░█░░░
+/// █░█░███░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[37]`: `U+2425` `"␥"`
+///
+/// ```text
+/// ███████░
+/// █░░█░░█░
+/// █░█░░█░░
+/// ██░░█░░░
+/// █░░█░░█░
+/// █░█░░█░░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PICTURE_UNICODE[38]`: `U+2426` `"␦"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░██░
+/// ░██░░░░░
+/// ░░██░░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ```
+pub const PICTURE_FONTS: PictureFonts = PictureFonts(PICTURE_UNICODE);
+/// Strong-typed collection wrapper for [PICTURE_UNICODE](./constant.PICTURE_UNICODE.html).
+pub struct PictureFonts([FontUnicode; 39]);
+
+impl PictureFonts {
+    /// Create a new collection of `PICTURE_UNICODE` fonts.
+    pub fn new() -> Self {
+        PictureFonts(PICTURE_UNICODE)
+    }
+}
+
+impl fmt::Debug for PictureFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(PICTURE_UNICODE))
+    }
+}
+
+impl PartialEq for PictureFonts {
+    fn eq(&self, other: &PictureFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for PictureFonts {
+    fn default() -> Self {
+        PictureFonts::new()
+    }
+}
+
+impl UnicodeFonts for PictureFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(39), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+/// Tiny 3x5 hexadecimal digits, one byte per row, in the style of the control pictures.
+pub(crate) const TINY_HEX: [[u8; 5]; 16] = [[0x07, 0x05, 0x05, 0x05, 0x07],
+                                            [0x02, 0x03, 0x02, 0x02, 0x07],
+                                            [0x03, 0x04, 0x02, 0x01, 0x07],
+                                            [0x03, 0x04, 0x02, 0x04, 0x03],
+                                            [0x05, 0x05, 0x07, 0x04, 0x04],
+                                            [0x07, 0x01, 0x03, 0x04, 0x03],
+                                            [0x06, 0x01, 0x07, 0x05, 0x07],
+                                            [0x07, 0x04, 0x02, 0x02, 0x02],
+                                            [0x07, 0x05, 0x07, 0x05, 0x07],
+                                            [0x07, 0x05, 0x07, 0x04, 0x03],
+                                            [0x02, 0x05, 0x07, 0x05, 0x05],
+                                            [0x03, 0x05, 0x03, 0x05, 0x03],
+                                            [0x06, 0x01, 0x01, 0x01, 0x06],
+                                            [0x03, 0x05, 0x05, 0x05, 0x03],
+                                            [0x07, 0x01, 0x03, 0x01, 0x07],
+                                            [0x07, 0x01, 0x03, 0x01, 0x01]];
+
+/// Returns the Control Pictures character for a C0 control character or `DEL`, or
+/// `None` for any other character.
+pub fn control_picture(c: char) -> Option<char> {
+    match c as u32 {
+        code @ 0x00..=0x1F => char::from_u32(0x2400 + code),
+        0x7F => Some('\u{2421}'),
+        _ => None,
+    }
+}
+
+/// Returns a glyph showing `byte` as two tiny hexadecimal digits, laid out like the
+/// two-letter control pictures. This is how the C1 controls, which have no pictures of
+/// their own, are made visible.
+pub const fn hex_picture(byte: u8) -> [u8; 8] {
+    let high = TINY_HEX[(byte >> 4) as usize];
+    let low = TINY_HEX[(byte & 0x0F) as usize];
+    let mut glyph = [0u8; 8];
+    let mut row = 0;
+    while row < 5 {
+        glyph[row + 1] = high[row] | low[row] << 4;
+        row += 1;
+    }
+    glyph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picture_set_implements_default_trait_with_method_new() {
+        let picture_set: PictureFonts = Default::default();
+        assert_eq!(picture_set, PictureFonts::new());
+    }
+
+    #[test]
+    fn picture_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(PICTURE_FONTS, PictureFonts::new());
+    }
+
+    #[test]
+    fn picture_fonts_constant_wraps_picture_unicode_constant() {
+        let picture = PictureFonts::new();
+        assert!(picture.0.len() == PICTURE_UNICODE.len());
+        for (idx, font) in picture.0.iter().enumerate() {
+            assert_eq!(font, &PICTURE_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn picture_set_covers_every_c0_control_and_delete() {
+        for code in (0x00..0x20).chain(0x7F..0x80) {
+            let control = char::from_u32(code).unwrap();
+            let picture = control_picture(control).unwrap();
+            let font = PICTURE_FONTS.get(picture).unwrap();
+            assert!(font.iter().any(|&row| row != 0));
+        }
+        assert_eq!(control_picture('\t'), Some('␉'));
+        assert_eq!(control_picture('\u{1B}'), Some('␛'));
+        assert_eq!(control_picture(' '), None);
+        assert_eq!(control_picture('\u{85}'), None);
+    }
+
+    #[test]
+    fn picture_hex_digits_share_the_tiny_letters() {
+        // `␌` is drawn "FF", just like the byte `0xFF`
+        assert_eq!(PICTURE_FONTS.get('␌'), Some(hex_picture(0xFF)));
+        assert_eq!(hex_picture(0x9B), [0x00, 0x37, 0x55, 0x37, 0x54, 0x33, 0x00, 0x00]);
+        for a in 0x80..0xA0 {
+            for b in (a + 1)..0xA0 {
+                assert_ne!(hex_picture(a), hex_picture(b));
+            }
+        }
+    }
+}
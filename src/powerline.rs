@@ -0,0 +1,863 @@
+//! Powerline and Nerd Font symbols with private unicode points. `U+E0A0` - `U+E0D4`
+//!
+//! Solid separators fill the whole height of the cell, and touch its left or right
+//! edge on every row, so that they join a `BLOCK_LEGACY` full block of the same colour
+//! without a gap. A few common Nerd Font icons, for version control, locks, folders and
+//! batteries, are included from `U+E700` and `U+F000` onwards.
+use super::unicode::{FontUnicode, UnicodeFonts};
+use core::fmt;
+
+/// A constant `[FontUnicode; 51]`, for Powerline and Nerd Font fonts (`U+E0A0` - `U+F418`).
+pub const POWERLINE_UNICODE: [FontUnicode; 51] = [FontUnicode('\u{E0A0}', [0x22, 0x22, 0x12, 0x0A, 0x06, 0x02, 0x02, 0x02]),
+                                                  FontUnicode('\u{E0A1}', [0x01, 0x01, 0x01, 0x0F, 0x90, 0xB0, 0xD0, 0x90]),
+                                                  FontUnicode('\u{E0A2}', [0x1C, 0x22, 0x22, 0x7F, 0x77, 0x77, 0x7F, 0x00]),
+                                                  FontUnicode('\u{E0A3}', [0x06, 0x01, 0x01, 0x06, 0x90, 0xB0, 0xD0, 0x90]),
+                                                  FontUnicode('\u{E0B0}', [0x03, 0x0F, 0x3F, 0xFF, 0xFF, 0x3F, 0x0F, 0x03]),
+                                                  FontUnicode('\u{E0B1}', [0x03, 0x0C, 0x30, 0xC0, 0xC0, 0x30, 0x0C, 0x03]),
+                                                  FontUnicode('\u{E0B2}', [0xC0, 0xF0, 0xFC, 0xFF, 0xFF, 0xFC, 0xF0, 0xC0]),
+                                                  FontUnicode('\u{E0B3}', [0xC0, 0x30, 0x0C, 0x03, 0x03, 0x0C, 0x30, 0xC0]),
+                                                  FontUnicode('\u{E0B4}', [0x0F, 0x3F, 0x7F, 0xFF, 0xFF, 0x7F, 0x3F, 0x0F]),
+                                                  FontUnicode('\u{E0B5}', [0x0F, 0x30, 0x40, 0x80, 0x80, 0x40, 0x30, 0x0F]),
+                                                  FontUnicode('\u{E0B6}', [0xF0, 0xFC, 0xFE, 0xFF, 0xFF, 0xFE, 0xFC, 0xF0]),
+                                                  FontUnicode('\u{E0B7}', [0xF0, 0x0C, 0x02, 0x01, 0x01, 0x02, 0x0C, 0xF0]),
+                                                  FontUnicode('\u{E0B8}', [0x01, 0x03, 0x07, 0x0F, 0x1F, 0x3F, 0x7F, 0xFF]),
+                                                  FontUnicode('\u{E0B9}', [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80]),
+                                                  FontUnicode('\u{E0BA}', [0x80, 0xC0, 0xE0, 0xF0, 0xF8, 0xFC, 0xFE, 0xFF]),
+                                                  FontUnicode('\u{E0BB}', [0x80, 0x40, 0x20, 0x10, 0x08, 0x04, 0x02, 0x01]),
+                                                  FontUnicode('\u{E0BC}', [0xFF, 0x7F, 0x3F, 0x1F, 0x0F, 0x07, 0x03, 0x01]),
+                                                  FontUnicode('\u{E0BD}', [0x80, 0x40, 0x20, 0x10, 0x08, 0x04, 0x02, 0x01]),
+                                                  FontUnicode('\u{E0BE}', [0xFF, 0xFE, 0xFC, 0xF8, 0xF0, 0xE0, 0xC0, 0x80]),
+                                                  FontUnicode('\u{E0BF}', [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80]),
+                                                  FontUnicode('\u{E0C0}', [0x07, 0x1F, 0x0F, 0x7F, 0x3F, 0xFF, 0x7F, 0x1F]),
+                                                  FontUnicode('\u{E0C1}', [0x04, 0x18, 0x08, 0x70, 0x20, 0xC0, 0x60, 0x10]),
+                                                  FontUnicode('\u{E0C2}', [0xE0, 0xF8, 0xF0, 0xFE, 0xFC, 0xFF, 0xFE, 0xF8]),
+                                                  FontUnicode('\u{E0C3}', [0x20, 0x18, 0x10, 0x0E, 0x04, 0x03, 0x06, 0x08]),
+                                                  FontUnicode('\u{E0C4}', [0x5F, 0xBF, 0x5F, 0xBF, 0x5F, 0xBF, 0x5F, 0xBF]),
+                                                  FontUnicode('\u{E0C5}', [0xFA, 0xFD, 0xFA, 0xFD, 0xFA, 0xFD, 0xFA, 0xFD]),
+                                                  FontUnicode('\u{E0C6}', [0xCF, 0xCF, 0x3F, 0x3F, 0xCF, 0xCF, 0x3F, 0x3F]),
+                                                  FontUnicode('\u{E0C7}', [0xF3, 0xF3, 0xFC, 0xFC, 0xF3, 0xF3, 0xFC, 0xFC]),
+                                                  FontUnicode('\u{E0C8}', [0xFF, 0x0F, 0x3F, 0x03, 0x7F, 0x1F, 0xFF, 0x07]),
+                                                  FontUnicode('\u{E0CA}', [0xFF, 0xF0, 0xFC, 0xC0, 0xFE, 0xF8, 0xFF, 0xE0]),
+                                                  FontUnicode('\u{E0CC}', [0x55, 0xEE, 0x55, 0xBB, 0x55, 0xEE, 0x55, 0xBB]),
+                                                  FontUnicode('\u{E0CD}', [0xAA, 0x11, 0xAA, 0x44, 0xAA, 0x11, 0xAA, 0x44]),
+                                                  FontUnicode('\u{E0CE}', [0x3F, 0x3F, 0x7F, 0x7F, 0x3F, 0x3F, 0x7F, 0x7F]),
+                                                  FontUnicode('\u{E0CF}', [0x20, 0x20, 0x60, 0x60, 0x20, 0x20, 0x60, 0x60]),
+                                                  FontUnicode('\u{E0D0}', [0x66, 0x66, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]),
+                                                  FontUnicode('\u{E0D1}', [0x3F, 0x3F, 0xFF, 0xFF, 0x3F, 0x3F, 0xFF, 0xFF]),
+                                                  FontUnicode('\u{E0D2}', [0x0F, 0x3F, 0xFF, 0xFF, 0xFF, 0xFF, 0x3F, 0x0F]),
+                                                  FontUnicode('\u{E0D4}', [0xF0, 0xFC, 0xFF, 0xFF, 0xFF, 0xFF, 0xFC, 0xF0]),
+                                                  FontUnicode('\u{E725}', [0x22, 0x22, 0x12, 0x0A, 0x06, 0x02, 0x02, 0x02]),
+                                                  FontUnicode('\u{F015}', [0x18, 0x3C, 0x7E, 0xFF, 0x66, 0x66, 0x66, 0x00]),
+                                                  FontUnicode('\u{F023}', [0x1C, 0x22, 0x22, 0x7F, 0x77, 0x77, 0x7F, 0x00]),
+                                                  FontUnicode('\u{F07B}', [0x00, 0x07, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x00]),
+                                                  FontUnicode('\u{F07C}', [0x00, 0x07, 0x79, 0x01, 0xFE, 0x7E, 0xFC, 0x00]),
+                                                  FontUnicode('\u{F120}', [0xFF, 0x81, 0x85, 0x89, 0x85, 0xE1, 0x81, 0xFF]),
+                                                  FontUnicode('\u{F240}', [0x00, 0x7F, 0x7F, 0xFF, 0xFF, 0x7F, 0x7F, 0x00]),
+                                                  FontUnicode('\u{F241}', [0x00, 0x7F, 0x5F, 0xDF, 0xDF, 0x5F, 0x7F, 0x00]),
+                                                  FontUnicode('\u{F242}', [0x00, 0x7F, 0x4F, 0xCF, 0xCF, 0x4F, 0x7F, 0x00]),
+                                                  FontUnicode('\u{F243}', [0x00, 0x7F, 0x43, 0xC3, 0xC3, 0x43, 0x7F, 0x00]),
+                                                  FontUnicode('\u{F244}', [0x00, 0x7F, 0x41, 0xC1, 0xC1, 0x41, 0x7F, 0x00]),
+                                                  FontUnicode('\u{F417}', [0x00, 0x00, 0x1C, 0xE3, 0x1C, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{F418}', [0x22, 0x22, 0x12, 0x0A, 0x06, 0x02, 0x02, 0x02])];
+
+/// A convenient constant for Powerline and Nerd Font fonts (`U+E0A0` - `U+F418`), that implements the `UnicodeFonts` trait.
+///
+/// ## `POWERLINE_UNICODE[0]`: `U+E0A0` `"\u{e0a0}"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░█░░░
+/// ░█░█░░░░
+/// ░██░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[1]`: `U+E0A1` `"\u{e0a1}"`
+///
+/// ```text
+/// █░░░░░░░
+/// █░░░░░░░
+/// █░░░░░░░
+/// ████░░░░
+/// ░░░░█░░█
+/// ░░░░██░█
+/// ░░░░█░██
+/// ░░░░█░░█
+/// ```
+///
+/// ## `POWERLINE_UNICODE[2]`: `U+E0A2` `"\u{e0a2}"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ███████░
+/// ███░███░
+/// ███░███░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[3]`: `U+E0A3` `"\u{e0a3}"`
+///
+/// ```text
+/// ░██░░░░░
+/// █░░░░░░░
+/// █░░░░░░░
+/// ░██░░░░░
+/// ░░░░█░░█
+/// ░░░░██░█
+/// ░░░░█░██
+/// ░░░░█░░█
+/// ```
+///
+/// ## `POWERLINE_UNICODE[4]`: `U+E0B0` `"\u{e0b0}"`
+///
+/// ```text
+/// ██░░░░░░
+/// ████░░░░
+/// ██████░░
+/// ████████
+/// ████████
+/// ██████░░
+/// ████░░░░
+/// ██░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[5]`: `U+E0B1` `"\u{e0b1}"`
+///
+/// ```text
+/// ██░░░░░░
+/// ░░██░░░░
+/// ░░░░██░░
+/// ░░░░░░██
+/// ░░░░░░██
+/// ░░░░██░░
+/// ░░██░░░░
+/// ██░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[6]`: `U+E0B2` `"\u{e0b2}"`
+///
+/// ```text
+/// ░░░░░░██
+/// ░░░░████
+/// ░░██████
+/// ████████
+/// ████████
+/// ░░██████
+/// ░░░░████
+/// ░░░░░░██
+/// ```
+///
+/// ## `POWERLINE_UNICODE[7]`: `U+E0B3` `"\u{e0b3}"`
+///
+/// ```text
+/// ░░░░░░██
+/// ░░░░██░░
+/// ░░██░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ░░██░░░░
+/// ░░░░██░░
+/// ░░░░░░██
+/// ```
+///
+/// ## `POWERLINE_UNICODE[8]`: `U+E0B4` `"\u{e0b4}"`
+///
+/// ```text
+/// ████░░░░
+/// ██████░░
+/// ███████░
+/// ████████
+/// ████████
+/// ███████░
+/// ██████░░
+/// ████░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[9]`: `U+E0B5` `"\u{e0b5}"`
+///
+/// ```text
+/// ████░░░░
+/// ░░░░██░░
+/// ░░░░░░█░
+/// ░░░░░░░█
+/// ░░░░░░░█
+/// ░░░░░░█░
+/// ░░░░██░░
+/// ████░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[10]`: `U+E0B6` `"\u{e0b6}"`
+///
+/// ```text
+/// ░░░░████
+/// ░░██████
+/// ░███████
+/// ████████
+/// ████████
+/// ░███████
+/// ░░██████
+/// ░░░░████
+/// ```
+///
+/// ## `POWERLINE_UNICODE[11]`: `U+E0B7` `"\u{e0b7}"`
+///
+/// ```text
+/// ░░░░████
+/// ░░██░░░░
+/// ░█░░░░░░
+/// █░░░░░░░
+/// █░░░░░░░
+/// ░█░░░░░░
+/// ░░██░░░░
+/// ░░░░████
+/// ```
+///
+/// ## `POWERLINE_UNICODE[12]`: `U+E0B8` `"\u{e0b8}"`
+///
+/// ```text
+/// █░░░░░░░
+/// ██░░░░░░
+/// ███░░░░░
+/// ████░░░░
+/// █████░░░
+/// ██████░░
+/// ███████░
+/// ████████
+/// ```
+///
+/// ## `POWERLINE_UNICODE[13]`: `U+E0B9` `"\u{e0b9}"`
+///
+/// ```text
+/// █░░░░░░░
+/// ░█░░░░░░
+/// ░░█░░░░░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░░░░█░░
+/// ░░░░░░█░
+/// ░░░░░░░█
+/// ```
+///
+/// ## `POWERLINE_UNICODE[14]`: `U+E0BA` `"\u{e0ba}"`
+///
+/// ```text
+/// ░░░░░░░█
+/// ░░░░░░██
+/// ░░░░░███
+/// ░░░░████
+/// ░░░█████
+/// ░░██████
+/// ░███████
+/// ████████
+/// ```
+///
+/// ## `POWERLINE_UNICODE[15]`: `U+E0BB` `"\u{e0bb}"`
+///
+/// ```text
+/// ░░░░░░░█
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// █░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[16]`: `U+E0BC` `"\u{e0bc}"`
+///
+/// ```text
+/// ████████
+/// ███████░
+/// ██████░░
+/// █████░░░
+/// ████░░░░
+/// ███░░░░░
+/// ██░░░░░░
+/// █░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[17]`: `U+E0BD` `"\u{e0bd}"`
+///
+/// ```text
+/// ░░░░░░░█
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// █░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[18]`: `U+E0BE` `"\u{e0be}"`
+///
+/// ```text
+/// ████████
+/// ░███████
+/// ░░██████
+/// ░░░█████
+/// ░░░░████
+/// ░░░░░███
+/// ░░░░░░██
+/// ░░░░░░░█
+/// ```
+///
+/// ## `POWERLINE_UNICODE[19]`: `U+E0BF` `"\u{e0bf}"`
+///
+/// ```text
+/// █░░░░░░░
+/// ░█░░░░░░
+/// ░░█░░░░░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░░░░█░░
+/// ░░░░░░█░
+/// ░░░░░░░█
+/// ```
+///
+/// ## `POWERLINE_UNICODE[20]`: `U+E0C0` `"\u{e0c0}"`
+///
+/// ```text
+/// ███░░░░░
+/// █████░░░
+/// ████░░░░
+/// ███████░
+/// ██████░░
+/// ████████
+/// ███████░
+/// █████░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[21]`: `U+E0C1` `"\u{e0c1}"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░░░██░░░
+/// ░░░█░░░░
+/// ░░░░███░
+/// ░░░░░█░░
+/// ░░░░░░██
+/// ░░░░░██░
+/// ░░░░█░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[22]`: `U+E0C2` `"\u{e0c2}"`
+///
+/// ```text
+/// ░░░░░███
+/// ░░░█████
+/// ░░░░████
+/// ░███████
+/// ░░██████
+/// ████████
+/// ░███████
+/// ░░░█████
+/// ```
+///
+/// ## `POWERLINE_UNICODE[23]`: `U+E0C3` `"\u{e0c3}"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░██░░░
+/// ░░░░█░░░
+/// ░███░░░░
+/// ░░█░░░░░
+/// ██░░░░░░
+/// ░██░░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[24]`: `U+E0C4` `"\u{e0c4}"`
+///
+/// ```text
+/// █████░█░
+/// ██████░█
+/// █████░█░
+/// ██████░█
+/// █████░█░
+/// ██████░█
+/// █████░█░
+/// ██████░█
+/// ```
+///
+/// ## `POWERLINE_UNICODE[25]`: `U+E0C5` `"\u{e0c5}"`
+///
+/// ```text
+/// ░█░█████
+/// █░██████
+/// ░█░█████
+/// █░██████
+/// ░█░█████
+/// █░██████
+/// ░█░█████
+/// █░██████
+/// ```
+///
+/// ## `POWERLINE_UNICODE[26]`: `U+E0C6` `"\u{e0c6}"`
+///
+/// ```text
+/// ████░░██
+/// ████░░██
+/// ██████░░
+/// ██████░░
+/// ████░░██
+/// ████░░██
+/// ██████░░
+/// ██████░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[27]`: `U+E0C7` `"\u{e0c7}"`
+///
+/// ```text
+/// ██░░████
+/// ██░░████
+/// ░░██████
+/// ░░██████
+/// ██░░████
+/// ██░░████
+/// ░░██████
+/// ░░██████
+/// ```
+///
+/// ## `POWERLINE_UNICODE[28]`: `U+E0C8` `"\u{e0c8}"`
+///
+/// ```text
+/// ████████
+/// ████░░░░
+/// ██████░░
+/// ██░░░░░░
+/// ███████░
+/// █████░░░
+/// ████████
+/// ███░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[29]`: `U+E0CA` `"\u{e0ca}"`
+///
+/// ```text
+/// ████████
+/// ░░░░████
+/// ░░██████
+/// ░░░░░░██
+/// ░███████
+/// ░░░█████
+/// ████████
+/// ░░░░░███
+/// ```
+///
+/// ## `POWERLINE_UNICODE[30]`: `U+E0CC` `"\u{e0cc}"`
+///
+/// ```text
+/// █░█░█░█░
+/// ░███░███
+/// █░█░█░█░
+/// ██░███░█
+/// █░█░█░█░
+/// ░███░███
+/// █░█░█░█░
+/// ██░███░█
+/// ```
+///
+/// ## `POWERLINE_UNICODE[31]`: `U+E0CD` `"\u{e0cd}"`
+///
+/// ```text
+/// ░█░█░█░█
+/// █░░░█░░░
+/// ░█░█░█░█
+/// ░░█░░░█░
+/// ░█░█░█░█
+/// █░░░█░░░
+/// ░█░█░█░█
+/// ░░█░░░█░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[32]`: `U+E0CE` `"\u{e0ce}"`
+///
+/// ```text
+/// ██████░░
+/// ██████░░
+/// ███████░
+/// ███████░
+/// ██████░░
+/// ██████░░
+/// ███████░
+/// ███████░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[33]`: `U+E0CF` `"\u{e0cf}"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ░░░░░██░
+/// ░░░░░██░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ░░░░░██░
+/// ░░░░░██░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[34]`: `U+E0D0` `"\u{e0d0}"`
+///
+/// ```text
+/// ░██░░██░
+/// ░██░░██░
+/// ████████
+/// ████████
+/// ████████
+/// ████████
+/// ████████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[35]`: `U+E0D1` `"\u{e0d1}"`
+///
+/// ```text
+/// ██████░░
+/// ██████░░
+/// ████████
+/// ████████
+/// ██████░░
+/// ██████░░
+/// ████████
+/// ████████
+/// ```
+///
+/// ## `POWERLINE_UNICODE[36]`: `U+E0D2` `"\u{e0d2}"`
+///
+/// ```text
+/// ████░░░░
+/// ██████░░
+/// ████████
+/// ████████
+/// ████████
+/// ████████
+/// ██████░░
+/// ████░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[37]`: `U+E0D4` `"\u{e0d4}"`
+///
+/// ```text
+/// ░░░░████
+/// ░░██████
+/// ████████
+/// ████████
+/// ████████
+/// ████████
+/// ░░██████
+/// ░░░░████
+/// ```
+///
+/// ## `POWERLINE_UNICODE[38]`: `U+E725` `"\u{e725}"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░█░░░
+/// ░█░█░░░░
+/// ░██░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[39]`: `U+F015` `"\u{f015}"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░████░░
+/// ░██████░
+/// ████████
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[40]`: `U+F023` `"\u{f023}"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ███████░
+/// ███░███░
+/// ███░███░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[41]`: `U+F07B` `"\u{f07b}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░░░░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[42]`: `U+F07C` `"\u{f07c}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░░░░░
+/// █░░████░
+/// █░░░░░░░
+/// ░███████
+/// ░██████░
+/// ░░██████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[43]`: `U+F120` `"\u{f120}"`
+///
+/// ```text
+/// ████████
+/// █░░░░░░█
+/// █░█░░░░█
+/// █░░█░░░█
+/// █░█░░░░█
+/// █░░░░███
+/// █░░░░░░█
+/// ████████
+/// ```
+///
+/// ## `POWERLINE_UNICODE[44]`: `U+F240` `"\u{f240}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███████░
+/// ███████░
+/// ████████
+/// ████████
+/// ███████░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[45]`: `U+F241` `"\u{f241}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███████░
+/// █████░█░
+/// █████░██
+/// █████░██
+/// █████░█░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[46]`: `U+F242` `"\u{f242}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███████░
+/// ████░░█░
+/// ████░░██
+/// ████░░██
+/// ████░░█░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[47]`: `U+F243` `"\u{f243}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███████░
+/// ██░░░░█░
+/// ██░░░░██
+/// ██░░░░██
+/// ██░░░░█░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[48]`: `U+F244` `"\u{f244}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███████░
+/// █░░░░░█░
+/// █░░░░░██
+/// █░░░░░██
+/// █░░░░░█░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[49]`: `U+F417` `"\u{f417}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ██░░░███
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `POWERLINE_UNICODE[50]`: `U+F418` `"\u{f418}"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░█░░░
+/// ░█░█░░░░
+/// ░██░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ```
+pub const POWERLINE_FONTS: PowerlineFonts = PowerlineFonts(POWERLINE_UNICODE);
+/// Strong-typed collection wrapper for [POWERLINE_UNICODE](./constant.POWERLINE_UNICODE.html).
+pub struct PowerlineFonts([FontUnicode; 51]);
+
+impl PowerlineFonts {
+    /// Create a new collection of `POWERLINE_UNICODE` fonts.
+    pub fn new() -> Self {
+        PowerlineFonts(POWERLINE_UNICODE)
+    }
+}
+
+impl fmt::Debug for PowerlineFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(POWERLINE_UNICODE))
+    }
+}
+
+impl PartialEq for PowerlineFonts {
+    fn eq(&self, other: &PowerlineFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for PowerlineFonts {
+    fn default() -> Self {
+        PowerlineFonts::new()
+    }
+}
+
+impl UnicodeFonts for PowerlineFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(51), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use legacy::BLOCK_LEGACY;
+
+    #[test]
+    fn powerline_set_implements_default_trait_with_method_new() {
+        let powerline_set: PowerlineFonts = Default::default();
+        assert_eq!(powerline_set, PowerlineFonts::new());
+    }
+
+    #[test]
+    fn powerline_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(POWERLINE_FONTS, PowerlineFonts::new());
+    }
+
+    #[test]
+    fn powerline_fonts_constant_wraps_powerline_unicode_constant() {
+        let powerline = PowerlineFonts::new();
+        assert!(powerline.0.len() == POWERLINE_UNICODE.len());
+        for (idx, font) in powerline.0.iter().enumerate() {
+            assert_eq!(font, &POWERLINE_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn powerline_solid_separators_tile_with_the_full_block() {
+        let full = BLOCK_LEGACY[8];
+        for &(right, left) in &[('\u{E0B0}', '\u{E0B2}'),
+                                ('\u{E0B4}', '\u{E0B6}'),
+                                ('\u{E0B8}', '\u{E0BA}'),
+                                ('\u{E0BC}', '\u{E0BE}'),
+                                ('\u{E0C0}', '\u{E0C2}'),
+                                ('\u{E0D2}', '\u{E0D4}')]
+        {
+            let right = POWERLINE_FONTS.get(right).unwrap();
+            let left = POWERLINE_FONTS.get(left).unwrap();
+            for row in 0..8 {
+                assert_eq!(right[row] & 0x01, full[row] & 0x01);
+                assert_eq!(right[row] & (right[row] >> 1), right[row] >> 1);
+                assert_eq!(left[row], right[row].reverse_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn powerline_triangles_fill_the_cell_at_their_tip() {
+        for key in "\u{E0B0}\u{E0B2}\u{E0B4}\u{E0B6}".chars() {
+            let glyph = POWERLINE_FONTS.get(key).unwrap();
+            assert_eq!(glyph[3], BLOCK_LEGACY[8][3]);
+            assert_eq!(glyph[4], BLOCK_LEGACY[8][4]);
+        }
+    }
+}
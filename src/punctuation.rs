@@ -0,0 +1,1230 @@
+//! General Punctuation. `U+2000` - `U+206F`
+//!
+//! Spaces render as blank glyphs. Zero-width spaces, joiners, and the bidi and invisible
+//! formatting characters render blank as well, and are reported as non-spacing by
+//! [is_zero_width](./fn.is_zero_width.html), so that renderers can skip their cell.
+use super::unicode::{FontUnicode, UnicodeFonts};
+use core::fmt;
+
+/// A constant `[FontUnicode; 111]`, for General Punctuation fonts (`U+2000` - `U+206F`).
+pub const PUNCTUATION_UNICODE: [FontUnicode; 111] = [FontUnicode('\u{2000}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2001}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2002}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2003}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2004}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2005}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2006}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2007}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2008}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2009}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{200A}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{200B}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{200C}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{200D}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{200E}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{200F}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2010}', [0x00, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2011}', [0x00, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2012}', [0x00, 0x00, 0x00, 0x7F, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2013}', [0x00, 0x00, 0x00, 0x7E, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2014}', [0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2015}', [0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2016}', [0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x00]),
+                                                     FontUnicode('\u{2017}', [0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 0xFF]),
+                                                     FontUnicode('\u{2018}', [0x0C, 0x06, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2019}', [0x06, 0x06, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{201A}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x06]),
+                                                     FontUnicode('\u{201B}', [0x06, 0x06, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{201C}', [0x6C, 0x36, 0x36, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{201D}', [0x36, 0x36, 0x1B, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{201E}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x36, 0x36, 0x1B]),
+                                                     FontUnicode('\u{201F}', [0x36, 0x36, 0x6C, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2020}', [0x0C, 0x0C, 0x3F, 0x0C, 0x0C, 0x0C, 0x0C, 0x00]),
+                                                     FontUnicode('\u{2021}', [0x0C, 0x3F, 0x0C, 0x0C, 0x0C, 0x3F, 0x0C, 0x00]),
+                                                     FontUnicode('\u{2022}', [0x00, 0x00, 0x0C, 0x1E, 0x1E, 0x0C, 0x00, 0x00]),
+                                                     FontUnicode('\u{2023}', [0x00, 0x02, 0x06, 0x0E, 0x06, 0x02, 0x00, 0x00]),
+                                                     FontUnicode('\u{2024}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x00]),
+                                                     FontUnicode('\u{2025}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x33, 0x33, 0x00]),
+                                                     FontUnicode('\u{2026}', [0x00, 0x00, 0x00, 0x00, 0x00, 0xDB, 0xDB, 0x00]),
+                                                     FontUnicode('\u{2027}', [0x00, 0x00, 0x00, 0x0C, 0x0C, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2028}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2029}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{202A}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{202B}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{202C}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{202D}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{202E}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{202F}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2030}', [0x23, 0x13, 0x08, 0x04, 0x02, 0x6D, 0x6C, 0x00]),
+                                                     FontUnicode('\u{2031}', [0x23, 0x13, 0x08, 0x04, 0x02, 0x55, 0x54, 0x00]),
+                                                     FontUnicode('\u{2032}', [0x18, 0x0C, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2033}', [0x6C, 0x36, 0x1B, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2034}', [0x54, 0x2A, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2035}', [0x06, 0x0C, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2036}', [0x1B, 0x36, 0x6C, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2037}', [0x15, 0x2A, 0x54, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2038}', [0x00, 0x00, 0x00, 0x00, 0x08, 0x1C, 0x36, 0x63]),
+                                                     FontUnicode('\u{2039}', [0x00, 0x18, 0x0C, 0x06, 0x0C, 0x18, 0x00, 0x00]),
+                                                     FontUnicode('\u{203A}', [0x00, 0x06, 0x0C, 0x18, 0x0C, 0x06, 0x00, 0x00]),
+                                                     FontUnicode('\u{203B}', [0x49, 0x22, 0x14, 0x49, 0x14, 0x22, 0x49, 0x00]),
+                                                     FontUnicode('\u{203C}', [0x66, 0x66, 0x66, 0x66, 0x66, 0x00, 0x66, 0x00]),
+                                                     FontUnicode('\u{203D}', [0x1E, 0x3F, 0x3C, 0x1C, 0x0C, 0x00, 0x0C, 0x00]),
+                                                     FontUnicode('\u{203E}', [0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{203F}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC3, 0x7E]),
+                                                     FontUnicode('\u{2040}', [0x7E, 0xC3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2041}', [0x00, 0x00, 0x00, 0x30, 0x18, 0x3C, 0x66, 0xC3]),
+                                                     FontUnicode('\u{2042}', [0x14, 0x08, 0x14, 0x00, 0x55, 0x22, 0x55, 0x00]),
+                                                     FontUnicode('\u{2043}', [0x00, 0x00, 0x00, 0x1E, 0x1E, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2044}', [0x40, 0x20, 0x10, 0x08, 0x04, 0x02, 0x01, 0x00]),
+                                                     FontUnicode('\u{2045}', [0x1E, 0x06, 0x06, 0x3E, 0x06, 0x06, 0x1E, 0x00]),
+                                                     FontUnicode('\u{2046}', [0x1E, 0x18, 0x18, 0x3E, 0x18, 0x18, 0x1E, 0x00]),
+                                                     FontUnicode('\u{2047}', [0x66, 0x99, 0x44, 0x22, 0x22, 0x00, 0x22, 0x00]),
+                                                     FontUnicode('\u{2048}', [0x46, 0x49, 0x44, 0x42, 0x42, 0x00, 0x42, 0x00]),
+                                                     FontUnicode('\u{2049}', [0x32, 0x4A, 0x22, 0x12, 0x12, 0x00, 0x12, 0x00]),
+                                                     FontUnicode('\u{204A}', [0x00, 0x00, 0x3F, 0x30, 0x18, 0x18, 0x0C, 0x00]),
+                                                     FontUnicode('\u{204B}', [0x7F, 0xDB, 0xDB, 0x7B, 0x1B, 0x1B, 0x1B, 0x00]),
+                                                     FontUnicode('\u{204C}', [0x00, 0x3C, 0x3E, 0x3F, 0x3F, 0x3E, 0x3C, 0x00]),
+                                                     FontUnicode('\u{204D}', [0x00, 0x3C, 0x7C, 0xFC, 0xFC, 0x7C, 0x3C, 0x00]),
+                                                     FontUnicode('\u{204E}', [0x00, 0x00, 0x00, 0x66, 0x3C, 0xFF, 0x3C, 0x66]),
+                                                     FontUnicode('\u{204F}', [0x00, 0x0C, 0x0C, 0x00, 0x00, 0x0C, 0x0C, 0x18]),
+                                                     FontUnicode('\u{2050}', [0x81, 0x7E, 0x00, 0x00, 0x00, 0x00, 0x7E, 0x81]),
+                                                     FontUnicode('\u{2051}', [0x14, 0x08, 0x14, 0x00, 0x14, 0x08, 0x14, 0x00]),
+                                                     FontUnicode('\u{2052}', [0x43, 0x23, 0x10, 0x08, 0x04, 0x62, 0x61, 0x00]),
+                                                     FontUnicode('\u{2053}', [0x00, 0x00, 0x00, 0x8E, 0x71, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2054}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7E, 0xC3]),
+                                                     FontUnicode('\u{2055}', [0x08, 0x2A, 0x1C, 0x7F, 0x1C, 0x2A, 0x08, 0x00]),
+                                                     FontUnicode('\u{2056}', [0x00, 0x18, 0x18, 0x00, 0x00, 0x66, 0x66, 0x00]),
+                                                     FontUnicode('\u{2057}', [0xAA, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2058}', [0x18, 0x18, 0x00, 0x66, 0x66, 0x00, 0x18, 0x18]),
+                                                     FontUnicode('\u{2059}', [0xC3, 0xC3, 0x00, 0x18, 0x18, 0x00, 0xC3, 0xC3]),
+                                                     FontUnicode('\u{205A}', [0x0C, 0x0C, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x00]),
+                                                     FontUnicode('\u{205B}', [0x00, 0x66, 0x66, 0x00, 0x00, 0x66, 0x66, 0x00]),
+                                                     FontUnicode('\u{205C}', [0x18, 0x18, 0x00, 0xDB, 0xDB, 0x00, 0x18, 0x18]),
+                                                     FontUnicode('\u{205D}', [0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C]),
+                                                     FontUnicode('\u{205E}', [0x0C, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x0C, 0x00]),
+                                                     FontUnicode('\u{205F}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2060}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2061}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2062}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2063}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2064}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2066}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2067}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2068}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{2069}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{206A}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{206B}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{206C}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{206D}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{206E}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                     FontUnicode('\u{206F}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00])];
+
+/// A convenient constant for General Punctuation fonts (`U+2000` - `U+206F`), that implements the `UnicodeFonts` trait.
+///
+/// ## `PUNCTUATION_UNICODE[0]`: `U+2000` " "
+/// ## `PUNCTUATION_UNICODE[1]`: `U+2001` " "
+/// ## `PUNCTUATION_UNICODE[2]`: `U+2002` " "
+/// ## `PUNCTUATION_UNICODE[3]`: `U+2003` " "
+/// ## `PUNCTUATION_UNICODE[4]`: `U+2004` " "
+/// ## `PUNCTUATION_UNICODE[5]`: `U+2005` " "
+/// ## `PUNCTUATION_UNICODE[6]`: `U+2006` " "
+/// ## `PUNCTUATION_UNICODE[7]`: `U+2007` " "
+/// ## `PUNCTUATION_UNICODE[8]`: `U+2008` " "
+/// ## `PUNCTUATION_UNICODE[9]`: `U+2009` " "
+/// ## `PUNCTUATION_UNICODE[10]`: `U+200A` " "
+/// ## `PUNCTUATION_UNICODE[11]`: `U+200B` " "
+/// ## `PUNCTUATION_UNICODE[12]`: `U+200C` " "
+/// ## `PUNCTUATION_UNICODE[13]`: `U+200D` " "
+/// ## `PUNCTUATION_UNICODE[14]`: `U+200E` " "
+/// ## `PUNCTUATION_UNICODE[15]`: `U+200F` " "
+/// ## `PUNCTUATION_UNICODE[16]`: `U+2010` `"‐"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[17]`: `U+2011` `"‑"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[18]`: `U+2012` `"‒"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ███████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[19]`: `U+2013` `"–"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[20]`: `U+2014` `"—"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[21]`: `U+2015` `"―"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[22]`: `U+2016` `"‖"`
+///
+/// ```text
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[23]`: `U+2017` `"‗"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ████████
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[24]`: `U+2018` `"‘"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░██░░░░░
+/// ░██░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[25]`: `U+2019` `"’"`
+///
+/// ```text
+/// ░██░░░░░
+/// ░██░░░░░
+/// ██░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[26]`: `U+201A` `"‚"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░██░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[27]`: `U+201B` `"‛"`
+///
+/// ```text
+/// ░██░░░░░
+/// ░██░░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[28]`: `U+201C` `"“"`
+///
+/// ```text
+/// ░░██░██░
+/// ░██░██░░
+/// ░██░██░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[29]`: `U+201D` `"”"`
+///
+/// ```text
+/// ░██░██░░
+/// ░██░██░░
+/// ██░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[30]`: `U+201E` `"„"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██░██░░
+/// ░██░██░░
+/// ██░██░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[31]`: `U+201F` `"‟"`
+///
+/// ```text
+/// ░██░██░░
+/// ░██░██░░
+/// ░░██░██░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[32]`: `U+2020` `"†"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░██░░░░
+/// ██████░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[33]`: `U+2021` `"‡"`
+///
+/// ```text
+/// ░░██░░░░
+/// ██████░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ██████░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[34]`: `U+2022` `"•"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░████░░░
+/// ░████░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[35]`: `U+2023` `"‣"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░░░░░░
+/// ░██░░░░░
+/// ░███░░░░
+/// ░██░░░░░
+/// ░█░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[36]`: `U+2024` `"․"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[37]`: `U+2025` `"‥"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░██░░
+/// ██░░██░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[38]`: `U+2026` `"…"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░██░██
+/// ██░██░██
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[39]`: `U+2027` `"‧"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[40]`: `U+2028` " "
+/// ## `PUNCTUATION_UNICODE[41]`: `U+2029` " "
+/// ## `PUNCTUATION_UNICODE[42]`: `U+202A` " "
+/// ## `PUNCTUATION_UNICODE[43]`: `U+202B` " "
+/// ## `PUNCTUATION_UNICODE[44]`: `U+202C` " "
+/// ## `PUNCTUATION_UNICODE[45]`: `U+202D` " "
+/// ## `PUNCTUATION_UNICODE[46]`: `U+202E` " "
+/// ## `PUNCTUATION_UNICODE[47]`: `U+202F` " "
+/// ## `PUNCTUATION_UNICODE[48]`: `U+2030` `"‰"`
+///
+/// ```text
+/// ██░░░█░░
+/// ██░░█░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// █░██░██░
+/// ░░██░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[49]`: `U+2031` `"‱"`
+///
+/// ```text
+/// ██░░░█░░
+/// ██░░█░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// █░█░█░█░
+/// ░░█░█░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[50]`: `U+2032` `"′"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░██░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[51]`: `U+2033` `"″"`
+///
+/// ```text
+/// ░░██░██░
+/// ░██░██░░
+/// ██░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[52]`: `U+2034` `"‴"`
+///
+/// ```text
+/// ░░█░█░█░
+/// ░█░█░█░░
+/// █░█░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[53]`: `U+2035` `"‵"`
+///
+/// ```text
+/// ░██░░░░░
+/// ░░██░░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[54]`: `U+2036` `"‶"`
+///
+/// ```text
+/// ██░██░░░
+/// ░██░██░░
+/// ░░██░██░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[55]`: `U+2037` `"‷"`
+///
+/// ```text
+/// █░█░█░░░
+/// ░█░█░█░░
+/// ░░█░█░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[56]`: `U+2038` `"‸"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░██░██░░
+/// ██░░░██░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[57]`: `U+2039` `"‹"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░██░░░░░
+/// ░░██░░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[58]`: `U+203A` `"›"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░░░░░
+/// ░░██░░░░
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░██░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[59]`: `U+203B` `"※"`
+///
+/// ```text
+/// █░░█░░█░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// █░░█░░█░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// █░░█░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[60]`: `U+203C` `"‼"`
+///
+/// ```text
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[61]`: `U+203D` `"‽"`
+///
+/// ```text
+/// ░████░░░
+/// ██████░░
+/// ░░████░░
+/// ░░███░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[62]`: `U+203E` `"‾"`
+///
+/// ```text
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[63]`: `U+203F` `"‿"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░░██
+/// ░██████░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[64]`: `U+2040` `"⁀"`
+///
+/// ```text
+/// ░██████░
+/// ██░░░░██
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[65]`: `U+2041` `"⁁"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░██░░
+/// ░░░██░░░
+/// ░░████░░
+/// ░██░░██░
+/// ██░░░░██
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[66]`: `U+2042` `"⁂"`
+///
+/// ```text
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// █░█░█░█░
+/// ░█░░░█░░
+/// █░█░█░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[67]`: `U+2043` `"⁃"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[68]`: `U+2044` `"⁄"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// █░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[69]`: `U+2045` `"⁅"`
+///
+/// ```text
+/// ░████░░░
+/// ░██░░░░░
+/// ░██░░░░░
+/// ░█████░░
+/// ░██░░░░░
+/// ░██░░░░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[70]`: `U+2046` `"⁆"`
+///
+/// ```text
+/// ░████░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░█████░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[71]`: `U+2047` `"⁇"`
+///
+/// ```text
+/// ░██░░██░
+/// █░░██░░█
+/// ░░█░░░█░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[72]`: `U+2048` `"⁈"`
+///
+/// ```text
+/// ░██░░░█░
+/// █░░█░░█░
+/// ░░█░░░█░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ░░░░░░░░
+/// ░█░░░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[73]`: `U+2049` `"⁉"`
+///
+/// ```text
+/// ░█░░██░░
+/// ░█░█░░█░
+/// ░█░░░█░░
+/// ░█░░█░░░
+/// ░█░░█░░░
+/// ░░░░░░░░
+/// ░█░░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[74]`: `U+204A` `"⁊"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██████░░
+/// ░░░░██░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[75]`: `U+204B` `"⁋"`
+///
+/// ```text
+/// ███████░
+/// ██░██░██
+/// ██░██░██
+/// ██░████░
+/// ██░██░░░
+/// ██░██░░░
+/// ██░██░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[76]`: `U+204C` `"⁌"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░████░░
+/// ░█████░░
+/// ██████░░
+/// ██████░░
+/// ░█████░░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[77]`: `U+204D` `"⁍"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░████░░
+/// ░░█████░
+/// ░░██████
+/// ░░██████
+/// ░░█████░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[78]`: `U+204E` `"⁎"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██░░██░
+/// ░░████░░
+/// ████████
+/// ░░████░░
+/// ░██░░██░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[79]`: `U+204F` `"⁏"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[80]`: `U+2050` `"⁐"`
+///
+/// ```text
+/// █░░░░░░█
+/// ░██████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██████░
+/// █░░░░░░█
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[81]`: `U+2051` `"⁑"`
+///
+/// ```text
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[82]`: `U+2052` `"⁒"`
+///
+/// ```text
+/// ██░░░░█░
+/// ██░░░█░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░█░░░██░
+/// █░░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[83]`: `U+2053` `"⁓"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░███░░░█
+/// █░░░███░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[84]`: `U+2054` `"⁔"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██████░
+/// ██░░░░██
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[85]`: `U+2055` `"⁕"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ███████░
+/// ░░███░░░
+/// ░█░█░█░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[86]`: `U+2056` `"⁖"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██░░██░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[87]`: `U+2057` `"⁗"`
+///
+/// ```text
+/// ░█░█░█░█
+/// █░█░█░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[88]`: `U+2058` `"⁘"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ░██░░██░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[89]`: `U+2059` `"⁙"`
+///
+/// ```text
+/// ██░░░░██
+/// ██░░░░██
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ██░░░░██
+/// ██░░░░██
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[90]`: `U+205A` `"⁚"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[91]`: `U+205B` `"⁛"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░░██░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██░░██░
+/// ░██░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[92]`: `U+205C` `"⁜"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ██░██░██
+/// ██░██░██
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[93]`: `U+205D` `"⁝"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[94]`: `U+205E` `"⁞"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `PUNCTUATION_UNICODE[95]`: `U+205F` " "
+/// ## `PUNCTUATION_UNICODE[96]`: `U+2060` " "
+/// ## `PUNCTUATION_UNICODE[97]`: `U+2061` " "
+/// ## `PUNCTUATION_UNICODE[98]`: `U+2062` " "
+/// ## `PUNCTUATION_UNICODE[99]`: `U+2063` " "
+/// ## `PUNCTUATION_UNICODE[100]`: `U+2064` " "
+/// ## `PUNCTUATION_UNICODE[101]`: `U+2066` " "
+/// ## `PUNCTUATION_UNICODE[102]`: `U+2067` " "
+/// ## `PUNCTUATION_UNICODE[103]`: `U+2068` " "
+/// ## `PUNCTUATION_UNICODE[104]`: `U+2069` " "
+/// ## `PUNCTUATION_UNICODE[105]`: `U+206A` " "
+/// ## `PUNCTUATION_UNICODE[106]`: `U+206B` " "
+/// ## `PUNCTUATION_UNICODE[107]`: `U+206C` " "
+/// ## `PUNCTUATION_UNICODE[108]`: `U+206D` " "
+/// ## `PUNCTUATION_UNICODE[109]`: `U+206E` " "
+/// ## `PUNCTUATION_UNICODE[110]`: `U+206F` " "
+pub const PUNCTUATION_FONTS: PunctuationFonts = PunctuationFonts(PUNCTUATION_UNICODE);
+/// Strong-typed collection wrapper for [PUNCTUATION_UNICODE](./constant.PUNCTUATION_UNICODE.html).
+pub struct PunctuationFonts([FontUnicode; 111]);
+
+impl PunctuationFonts {
+    /// Create a new collection of `PUNCTUATION_UNICODE` fonts.
+    pub fn new() -> Self {
+        PunctuationFonts(PUNCTUATION_UNICODE)
+    }
+}
+
+impl fmt::Debug for PunctuationFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(PUNCTUATION_UNICODE))
+    }
+}
+
+impl PartialEq for PunctuationFonts {
+    fn eq(&self, other: &PunctuationFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for PunctuationFonts {
+    fn default() -> Self {
+        PunctuationFonts::new()
+    }
+}
+
+impl UnicodeFonts for PunctuationFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(111), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+/// Returns `true` for the General Punctuation characters that take no cell of their own:
+/// zero-width spaces and joiners, bidi marks, and invisible operators.
+pub fn is_zero_width(c: char) -> bool {
+    matches!(c,
+             '\u{200B}'..='\u{200F}'
+             | '\u{202A}'..='\u{202E}'
+             | '\u{2060}'..='\u{2064}'
+             | '\u{2066}'..='\u{206F}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn punctuation_set_implements_default_trait_with_method_new() {
+        let punctuation_set: PunctuationFonts = Default::default();
+        assert_eq!(punctuation_set, PunctuationFonts::new());
+    }
+
+    #[test]
+    fn punctuation_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(PUNCTUATION_FONTS, PunctuationFonts::new());
+    }
+
+    #[test]
+    fn punctuation_fonts_constant_wraps_punctuation_unicode_constant() {
+        let punctuation = PunctuationFonts::new();
+        assert!(punctuation.0.len() == PUNCTUATION_UNICODE.len());
+        for (idx, font) in punctuation.0.iter().enumerate() {
+            assert_eq!(font, &PUNCTUATION_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn punctuation_spaces_render_as_blank_glyphs() {
+        for code in (0x2000..=0x200A).chain([0x202F, 0x205F].iter().cloned()) {
+            let key = char::from_u32(code).unwrap();
+            let font = PUNCTUATION_FONTS.get_font(key).unwrap();
+            assert!(font.is_whitespace());
+            assert!(!is_zero_width(key));
+        }
+    }
+
+    #[test]
+    fn punctuation_zero_width_characters_are_blank_and_non_spacing() {
+        for key in &['\u{200B}', '\u{200C}', '\u{200D}', '\u{2060}', '\u{2069}'] {
+            assert!(is_zero_width(*key));
+            assert_eq!(PUNCTUATION_FONTS.get(*key), Some([0; 8]));
+        }
+        assert!(!is_zero_width('\u{2026}'));
+        assert!(!is_zero_width(' '));
+    }
+
+    #[test]
+    fn punctuation_quotes_and_dashes_are_drawn() {
+        for key in "‘’“”–—…•‰′″‹›".chars() {
+            assert!(!PUNCTUATION_FONTS.get_font(key).unwrap().is_whitespace());
+        }
+    }
+}
@@ -0,0 +1,454 @@
+//! Rendering text with all of the font sets.
+//!
+//! [Renderer](./struct.Renderer.html) looks every character up in each set in turn, and
+//! takes the glyph from the first set that has one.
+use super::alternates::AlternateFonts;
+use super::combining::marks_len;
+use super::emoji::sequence_tail;
+use super::fullwidth::{is_wide, stretch};
+use super::hiragana::{compose_voicing, voicing_len};
+use super::punctuation::is_zero_width;
+use super::thai::{is_thai_combining, thai_marks_len};
+use super::transliteration::transliterate;
+use super::{ARABIC_FONTS, ARROW_FONTS, BASIC_FONTS, BLOCK_FONTS, BOX_FONTS, BRAILLE_FONTS,
+            COMBINING_FONTS, COMPUTING_FONTS, CONTROL_FONTS, CURRENCY_FONTS, DECOMPOSED_FONTS,
+            DINGBAT_FONTS, EMOJI_FONTS, ENCLOSED_FONTS, FULLWIDTH_FONTS, GEOMETRIC_FONTS,
+            GREEK_FONTS, HANGUL_FONTS, HIRAGANA_FONTS, LATIN_FONTS, MATH_FONTS, MISC_FONTS,
+            NUMBER_FONTS, PICTURE_FONTS, POWERLINE_FONTS, PUNCTUATION_FONTS, SCRIPT_FONTS,
+            SGA_FONTS, SYMBOL_FONTS, TECHNICAL_FONTS, THAI_FONTS};
+use super::pictures::{control_picture, hex_picture};
+use super::unicode::UnicodeFonts;
+use core::str::Chars;
+
+/// Every font set, in lookup order.
+const SETS: [&dyn UnicodeFonts; 31] = [&BASIC_FONTS,
+                                       &CONTROL_FONTS,
+                                       &LATIN_FONTS,
+                                       &COMBINING_FONTS,
+                                       &GREEK_FONTS,
+                                       &PUNCTUATION_FONTS,
+                                       &SCRIPT_FONTS,
+                                       &CURRENCY_FONTS,
+                                       &NUMBER_FONTS,
+                                       &ENCLOSED_FONTS,
+                                       &ARROW_FONTS,
+                                       &MATH_FONTS,
+                                       &TECHNICAL_FONTS,
+                                       &BOX_FONTS,
+                                       &BLOCK_FONTS,
+                                       &GEOMETRIC_FONTS,
+                                       &SYMBOL_FONTS,
+                                       &DINGBAT_FONTS,
+                                       &EMOJI_FONTS,
+                                       &PICTURE_FONTS,
+                                       &BRAILLE_FONTS,
+                                       &COMPUTING_FONTS,
+                                       &MISC_FONTS,
+                                       &THAI_FONTS,
+                                       &ARABIC_FONTS,
+                                       &HIRAGANA_FONTS,
+                                       &HANGUL_FONTS,
+                                       &FULLWIDTH_FONTS,
+                                       &SGA_FONTS,
+                                       &POWERLINE_FONTS,
+                                       &DECOMPOSED_FONTS];
+
+/// What a [Renderer](./struct.Renderer.html) draws for the characters that no set has a
+/// glyph for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Fallback {
+    /// The character is skipped, and takes no cell.
+    Skip,
+    /// The character is drawn as the given glyph.
+    Replacement([u8; 8]),
+    /// The character is drawn as its [ASCII transliteration](./unicode/fn.transliterate.html),
+    /// one cell per letter, or skipped if it has none. The letters are drawn with the
+    /// renderer's alternates.
+    Transliterate,
+}
+
+// Deriving it would take `#[default]`, which needs Rust 1.62.
+#[allow(clippy::derivable_impls)]
+impl Default for Fallback {
+    fn default() -> Self {
+        Fallback::Skip
+    }
+}
+
+/// Looks characters up across all of the font sets.
+///
+/// By default, control characters render as blank glyphs, like they do in
+/// `BASIC_FONTS` and `CONTROL_FONTS`.
+///
+/// ```
+/// use font8x8::{Renderer, UnicodeFonts, BASIC_FONTS, PICTURE_FONTS};
+///
+/// let renderer = Renderer::new();
+/// assert_eq!(renderer.get('A'), BASIC_FONTS.get('A'));
+/// assert_eq!(renderer.get('\r'), Some([0; 8]));
+///
+/// let renderer = Renderer::new().visible_controls(true);
+/// assert_eq!(renderer.get('\r'), PICTURE_FONTS.get('␍'));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Renderer {
+    visible_controls: bool,
+    double_width: bool,
+    fallback: Fallback,
+    alternates: AlternateFonts,
+    vertical: bool,
+}
+
+impl Renderer {
+    /// Create a new renderer, with control characters rendered blank.
+    pub fn new() -> Self {
+        Renderer { visible_controls: false,
+                   double_width: false,
+                   fallback: Fallback::Skip,
+                   alternates: AlternateFonts::new(),
+                   vertical: false }
+    }
+
+    /// Sets whether control characters are drawn visibly instead of blank: the C0
+    /// controls and `DEL` as their [control pictures](./unicode/fn.control_picture.html),
+    /// and the C1 controls as their code in [tiny hex digits](./unicode/fn.hex_picture.html).
+    pub fn visible_controls(mut self, visible: bool) -> Self {
+        self.visible_controls = visible;
+        self
+    }
+
+    /// Sets whether [wide](./unicode/fn.is_wide.html) characters take two cells when
+    /// rendering: the left and right halves of their 16x8 glyph for the fullwidth forms
+    /// and Hangul syllables, and their 8x8 glyph followed by a blank cell otherwise.
+    /// Fullwidth forms and Hangul syllables with marks drawn onto them are stretched over
+    /// both cells from their 8x8 glyph, marks included.
+    pub fn double_width(mut self, double: bool) -> Self {
+        self.double_width = double;
+        self
+    }
+
+    /// Sets what is drawn for the characters that no set has a glyph for, when rendering
+    /// text. They are skipped by default.
+    ///
+    /// ```
+    /// use font8x8::{Fallback, Renderer, UnicodeFonts, BASIC_FONTS};
+    ///
+    /// let glyphs: Vec<[u8; 8]> = Renderer::new().fallback(Fallback::Transliterate)
+    ///                                           .render("Жук")
+    ///                                           .collect();
+    /// let expected: Vec<[u8; 8]> = "Zhuk".chars().filter_map(|c| BASIC_FONTS.get(c)).collect();
+    /// assert_eq!(glyphs, expected);
+    /// ```
+    pub fn fallback(mut self, fallback: Fallback) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Sets whether glyphs are drawn for vertical text, with
+    /// [vertical forms](./unicode/struct.HiraganaFonts.html#method.get_vertical) for the
+    /// small kana and the iteration marks.
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+        self
+    }
+
+    /// Sets the [stylistic alternates](./unicode/enum.Alternate.html) drawn instead of
+    /// the glyphs of `BASIC_FONTS`.
+    ///
+    /// ```
+    /// use font8x8::unicode::{Alternate, AlternateFonts};
+    /// use font8x8::Renderer;
+    ///
+    /// let alternates = AlternateFonts::new().with(Alternate::DottedZero);
+    /// let renderer = Renderer::new().alternates(alternates);
+    /// assert_eq!(renderer.get('0'), Some(Alternate::DottedZero.glyph()));
+    /// ```
+    pub fn alternates(mut self, alternates: AlternateFonts) -> Self {
+        self.alternates = alternates;
+        self
+    }
+
+    /// Returns the glyph for `key` from the first font set that has it, or its chosen
+    /// alternate, or its vertical form when drawing vertical text.
+    pub fn get(&self, key: char) -> Option<[u8; 8]> {
+        if self.visible_controls && key.is_control() {
+            return match control_picture(key) {
+                Some(picture) => PICTURE_FONTS.get(picture),
+                None => Some(hex_picture(key as u8)),
+            };
+        }
+        if self.vertical {
+            if let Some(glyph) = HIRAGANA_FONTS.get_vertical(key) {
+                return Some(glyph);
+            }
+        }
+        self.alternates.get(key)
+                       .or_else(|| SETS.iter().filter_map(|set| set.get(key)).next())
+    }
+
+    /// Returns an iterator over the glyphs for `text`, one per cell. Non-spacing
+    /// characters are skipped, and characters that no set has a glyph for are drawn as
+    /// set by [fallback](#method.fallback). Combining
+    /// diacritical marks are drawn onto the glyph before them, as split by
+    /// [graphemes](./unicode/fn.graphemes.html), kana voicing marks are composed with the
+    /// kana before them, and emoji sequences are drawn with the
+    /// glyph of their base, as split by [emoji_sequences](./unicode/fn.emoji_sequences.html).
+    pub fn render<'a>(&self, text: &'a str) -> Rendered<'a> {
+        Rendered { renderer: *self,
+                   chars: text.chars(),
+                   pending: None,
+                   transliteration: "" }
+    }
+}
+
+/// Iterator over the glyphs of a text, created by
+/// [Renderer::render](./struct.Renderer.html#method.render).
+#[derive(Clone, Debug)]
+pub struct Rendered<'a> {
+    renderer: Renderer,
+    chars: Chars<'a>,
+    pending: Option<[u8; 8]>,
+    transliteration: &'static str,
+}
+
+impl<'a> Iterator for Rendered<'a> {
+    type Item = [u8; 8];
+
+    fn next(&mut self) -> Option<[u8; 8]> {
+        if let Some(glyph) = self.pending.take() {
+            return Some(glyph);
+        }
+        if let Some(&c) = self.transliteration.as_bytes().first() {
+            self.transliteration = &self.transliteration[1..];
+            return self.renderer.get(char::from(c));
+        }
+        let renderer = self.renderer;
+        let (key, glyph, overlaid) = loop {
+            let key = self.chars.next()?;
+            let rest = self.chars.as_str();
+            let rest = &rest[sequence_tail(key, rest)..];
+            let (marks, rest) = rest.split_at(marks_len(rest));
+            let (voicing, rest) = rest.split_at(voicing_len(rest));
+            let (thai_marks, rest) = rest.split_at(thai_marks_len(rest));
+            self.chars = rest.chars();
+            if is_zero_width(key) || is_thai_combining(key) {
+                continue;
+            }
+            let (key, voicing) = compose_voicing(key, voicing);
+            if let Some(base) = renderer.get(key) {
+                let glyph = COMBINING_FONTS.overlay(base, marks);
+                let glyph = HIRAGANA_FONTS.overlay(glyph, voicing);
+                let glyph = THAI_FONTS.overlay(glyph, thai_marks);
+                break (key, glyph, glyph != base);
+            }
+            match (renderer.fallback, transliterate(key)) {
+                (Fallback::Replacement(glyph), _) => break (key, glyph, false),
+                (Fallback::Transliterate, Some(transliteration)) => {
+                    let c = transliteration.as_bytes()[0];
+                    self.transliteration = &transliteration[1..];
+                    break (key, renderer.get(char::from(c)).unwrap_or_default(), false);
+                }
+                _ => continue,
+            }
+        };
+        if !renderer.double_width || !is_wide(key) || !self.transliteration.is_empty() {
+            return Some(glyph);
+        }
+        let [left, right] = match HANGUL_FONTS.get_wide(key) {
+            Some(wide) if !overlaid => wide,
+            _ if FULLWIDTH_FONTS.get(key).is_some() || HANGUL_FONTS.get(key).is_some() => {
+                stretch(glyph)
+            }
+            _ => [glyph, [0; 8]],
+        };
+        self.pending = Some(right);
+        Some(left)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::alternates::Alternate;
+    use super::super::arabic::shape_arabic;
+    use super::*;
+
+    #[test]
+    fn renderer_implements_default_trait_with_method_new() {
+        let renderer: Renderer = Default::default();
+        assert_eq!(renderer, Renderer::new());
+    }
+
+    #[test]
+    fn renderer_draws_the_chosen_alternates() {
+        let alternates = AlternateFonts::new().with(Alternate::SingleStoreyA)
+                                              .with(Alternate::SlashedZero);
+        let mut glyphs = Renderer::new().alternates(alternates).render("a0O\u{0301}");
+        assert_eq!(glyphs.next(), Some(Alternate::SingleStoreyA.glyph()));
+        assert_eq!(glyphs.next(), Some(Alternate::SlashedZero.glyph()));
+        assert_eq!(glyphs.next(), Some(COMBINING_FONTS.overlay(BASIC_FONTS.get('O').unwrap(), "\u{0301}")));
+        assert_eq!(glyphs.next(), None);
+    }
+
+    #[test]
+    fn renderer_draws_missing_characters_as_set_by_its_fallback() {
+        let text = "\u{0142}\u{E000}\u{0416}!";
+        let mut glyphs = Renderer::new().render(text);
+        assert_eq!(glyphs.next(), BASIC_FONTS.get('!'));
+        assert_eq!(glyphs.next(), None);
+
+        let question = BASIC_FONTS.get('?').unwrap();
+        let glyphs: Vec<[u8; 8]> = Renderer::new().fallback(Fallback::Replacement(question))
+                                                  .render(text)
+                                                  .collect();
+        let expected: Vec<[u8; 8]> = "???!".chars().filter_map(|c| BASIC_FONTS.get(c)).collect();
+        assert_eq!(glyphs, expected);
+
+        let glyphs: Vec<[u8; 8]> = Renderer::new().fallback(Fallback::Transliterate)
+                                                  .render(text)
+                                                  .collect();
+        let expected: Vec<[u8; 8]> = "lZh!".chars().filter_map(|c| BASIC_FONTS.get(c)).collect();
+        assert_eq!(glyphs, expected);
+    }
+
+    #[test]
+    fn renderer_transliterates_with_its_alternates() {
+        let alternates = AlternateFonts::new().with(Alternate::SingleStoreyA);
+        let glyphs: Vec<[u8; 8]> = Renderer::new().fallback(Fallback::Transliterate)
+                                                  .alternates(alternates)
+                                                  .render("\u{042F}\u{0430}")
+                                                  .collect();
+        let a = Alternate::SingleStoreyA.glyph();
+        assert_eq!(glyphs, [BASIC_FONTS.get('Y').unwrap(), a, a]);
+    }
+
+    #[test]
+    fn renderer_takes_glyphs_from_every_set() {
+        let renderer = Renderer::new();
+        assert_eq!(renderer.get('a'), BASIC_FONTS.get('a'));
+        assert_eq!(renderer.get('é'), LATIN_FONTS.get('é'));
+        assert_eq!(renderer.get('λ'), GREEK_FONTS.get('λ'));
+        assert_eq!(renderer.get('┼'), BOX_FONTS.get('┼'));
+        assert_eq!(renderer.get('한'), HANGUL_FONTS.get('한'));
+        assert_eq!(renderer.get('\u{E000}'), None);
+    }
+
+    #[test]
+    fn renderer_draws_controls_blank_by_default() {
+        let renderer = Renderer::new();
+        for c in ['\0', '\n', '\u{1B}', '\u{7F}', '\u{85}', '\u{9F}'].iter() {
+            assert_eq!(renderer.get(*c), Some([0; 8]));
+        }
+    }
+
+    #[test]
+    fn renderer_draws_controls_visibly_when_asked() {
+        let renderer = Renderer::new().visible_controls(true);
+        assert_eq!(renderer.get('\n'), PICTURE_FONTS.get('␊'));
+        assert_eq!(renderer.get('\u{1B}'), PICTURE_FONTS.get('␛'));
+        assert_eq!(renderer.get('\u{7F}'), PICTURE_FONTS.get('␡'));
+        assert_eq!(renderer.get('\u{9B}'), Some(hex_picture(0x9B)));
+        assert_eq!(renderer.get(' '), BASIC_FONTS.get(' '));
+        assert_eq!(renderer.get('A'), BASIC_FONTS.get('A'));
+    }
+
+    #[test]
+    fn renderer_skips_non_spacing_and_missing_characters() {
+        let renderer = Renderer::new().visible_controls(true);
+        let mut glyphs = renderer.render("a\u{200B}\u{E000}\r\n");
+        assert_eq!(glyphs.next(), BASIC_FONTS.get('a'));
+        assert_eq!(glyphs.next(), PICTURE_FONTS.get('␍'));
+        assert_eq!(glyphs.next(), PICTURE_FONTS.get('␊'));
+        assert_eq!(glyphs.next(), None);
+    }
+
+    #[test]
+    fn renderer_draws_shaped_arabic_with_its_harakat() {
+        let word = "\u{0643}\u{064E}\u{062A}\u{064E}\u{0628}\u{0650}";
+        let text: String = shape_arabic(word).rev().collect();
+        let cells = "\u{FE7A}\u{FE90}\u{FE77}\u{FE98}\u{FE77}\u{FEDB}";
+        let expected: Vec<[u8; 8]> = cells.chars().filter_map(|c| ARABIC_FONTS.get(c)).collect();
+        assert_eq!(expected.len(), 6);
+        assert_eq!(Renderer::new().render(&text).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn renderer_draws_thai_marks_onto_their_base() {
+        let ko_kai = THAI_FONTS.get('\u{0E01}').unwrap();
+        let glyphs: Vec<[u8; 8]> = Renderer::new().render("\u{0E01}\u{0E49}").collect();
+        assert_eq!(glyphs, [THAI_FONTS.get_cluster("\u{0E01}\u{0E49}").unwrap()]);
+        assert_ne!(glyphs[0], ko_kai);
+    }
+
+    #[test]
+    fn renderer_draws_wide_characters_in_two_cells_when_asked() {
+        let mut glyphs = Renderer::new().render("Ａあ");
+        assert_eq!(glyphs.next(), BASIC_FONTS.get('A'));
+        assert_eq!(glyphs.next(), HIRAGANA_FONTS.get('あ'));
+        assert_eq!(glyphs.next(), None);
+
+        let [left, right] = FULLWIDTH_FONTS.get_wide('Ａ').unwrap();
+        let [hangul_left, hangul_right] = HANGUL_FONTS.get_wide('한').unwrap();
+        let mut glyphs = Renderer::new().double_width(true).render("Ａあ한a");
+        assert_eq!(glyphs.next(), Some(left));
+        assert_eq!(glyphs.next(), Some(right));
+        assert_eq!(glyphs.next(), HIRAGANA_FONTS.get('あ'));
+        assert_eq!(glyphs.next(), Some([0; 8]));
+        assert_eq!(glyphs.next(), Some(hangul_left));
+        assert_eq!(glyphs.next(), Some(hangul_right));
+        assert_eq!(glyphs.next(), BASIC_FONTS.get('a'));
+        assert_eq!(glyphs.next(), None);
+    }
+
+    #[test]
+    fn renderer_draws_marks_onto_wide_characters() {
+        let acute = COMBINING_FONTS.overlay(FULLWIDTH_FONTS.get('ａ').unwrap(), "\u{0301}");
+        let [left, right] = stretch(acute);
+        let [han_left, han_right] = stretch(COMBINING_FONTS.overlay(HANGUL_FONTS.get('한').unwrap(),
+                                                                    "\u{0301}"));
+        let glyphs: Vec<[u8; 8]> = Renderer::new().double_width(true)
+                                                  .render("ａ\u{0301}か\u{3099}한\u{0301}")
+                                                  .collect();
+        assert_eq!(glyphs,
+                   [left, right, HIRAGANA_FONTS.get('が').unwrap(), [0; 8], han_left, han_right]);
+        assert_ne!(left, FULLWIDTH_FONTS.get_wide('ａ').unwrap()[0]);
+    }
+
+    #[test]
+    fn renderer_draws_emoji_sequences_with_their_base() {
+        let text = "\u{1F44D}\u{1F3FD}\u{1F636}\u{200D}\u{1F32B}\u{FE0F}\u{1F9D1}\u{200D}\u{1F680}a\u{FE0E}";
+        let mut glyphs = Renderer::new().render(text);
+        assert_eq!(glyphs.next(), EMOJI_FONTS.get('\u{1F44D}'));
+        assert_eq!(glyphs.next(), EMOJI_FONTS.get('\u{1F636}'));
+        assert_eq!(glyphs.next(), BASIC_FONTS.get('a'));
+        assert_eq!(glyphs.next(), None);
+    }
+
+    #[test]
+    fn renderer_draws_combining_marks_onto_their_base() {
+        let e = BASIC_FONTS.get('e').unwrap();
+        let mut glyphs = Renderer::new().render("\u{0301}e\u{0301}\u{0323}\u{E000}\u{0301}x");
+        assert_eq!(glyphs.next(), COMBINING_FONTS.get('\u{0301}'));
+        assert_eq!(glyphs.next(), Some(COMBINING_FONTS.overlay(e, "\u{0301}\u{0323}")));
+        assert_eq!(glyphs.next(), BASIC_FONTS.get('x'));
+        assert_eq!(glyphs.next(), None);
+    }
+
+    #[test]
+    fn renderer_composes_kana_with_their_voicing_marks() {
+        let a = HIRAGANA_FONTS.get('あ').unwrap();
+        let mut glyphs = Renderer::new().render("か\u{3099}は\u{309A}あ\u{3099}");
+        assert_eq!(glyphs.next(), HIRAGANA_FONTS.get('が'));
+        assert_eq!(glyphs.next(), HIRAGANA_FONTS.get('ぱ'));
+        assert_eq!(glyphs.next(), Some(HIRAGANA_FONTS.overlay(a, "\u{3099}")));
+        assert_eq!(glyphs.next(), None);
+    }
+
+    #[test]
+    fn renderer_draws_vertical_forms_when_asked() {
+        let mut glyphs = Renderer::new().vertical(true).render("きっ");
+        assert_eq!(glyphs.next(), HIRAGANA_FONTS.get('き'));
+        assert_eq!(glyphs.next(), HIRAGANA_FONTS.get_vertical('っ'));
+        assert_eq!(glyphs.next(), None);
+        assert_ne!(HIRAGANA_FONTS.get_vertical('っ'), HIRAGANA_FONTS.get('っ'));
+    }
+}
@@ -0,0 +1,323 @@
+//! Romaji to hiragana conversion, for text entry with a Latin keyboard.
+//!
+//! Romaji is converted as it is typed, like in an input method: `"kya"` is `"きゃ"`,
+//! with Hepburn spellings like `"shi"`, `"tsu"` and `"fu"`, and the common conventions
+//! for the letters that have none of their own. Every character of the output is in
+//! `HIRAGANA_FONTS`.
+//!
+//! * `n` is `"ん"` before a consonant other than `y`, or when doubled as `"nn"`, or when
+//!   followed by `'`, as in `"kin'en"`.
+//! * A doubled consonant starts with `"っ"`, as in `"kitte"`, and so does `"tch"`, as in
+//!   `"matcha"`.
+//! * `x` or `l` spell small kana, like `"xtu"` or `"ltsu"` for `"っ"` and `"lya"` for `"ゃ"`.
+use core::fmt;
+use core::str::{self, Chars};
+
+/// Romaji syllables and their hiragana, sorted by syllable. No syllable starts with
+/// another one, so that a syllable is converted as soon as it is typed.
+const ROMAJI: [(&str, &str); 169] = [("a", "あ"), ("ba", "ば"), ("be", "べ"), ("bi", "び"),
+                                     ("bo", "ぼ"), ("bu", "ぶ"), ("bya", "びゃ"), ("byo", "びょ"),
+                                     ("byu", "びゅ"), ("cha", "ちゃ"), ("che", "ちぇ"),
+                                     ("chi", "ち"), ("cho", "ちょ"), ("chu", "ちゅ"),
+                                     ("cya", "ちゃ"), ("cyo", "ちょ"), ("cyu", "ちゅ"),
+                                     ("da", "だ"), ("de", "で"), ("di", "ぢ"), ("do", "ど"),
+                                     ("du", "づ"), ("dya", "ぢゃ"), ("dyo", "ぢょ"),
+                                     ("dyu", "ぢゅ"), ("e", "え"), ("fa", "ふぁ"), ("fe", "ふぇ"),
+                                     ("fi", "ふぃ"), ("fo", "ふぉ"), ("fu", "ふ"), ("ga", "が"),
+                                     ("ge", "げ"), ("gi", "ぎ"), ("go", "ご"), ("gu", "ぐ"),
+                                     ("gya", "ぎゃ"), ("gyo", "ぎょ"), ("gyu", "ぎゅ"),
+                                     ("ha", "は"), ("he", "へ"), ("hi", "ひ"), ("ho", "ほ"),
+                                     ("hu", "ふ"), ("hya", "ひゃ"), ("hyo", "ひょ"),
+                                     ("hyu", "ひゅ"), ("i", "い"), ("ja", "じゃ"), ("je", "じぇ"),
+                                     ("ji", "じ"), ("jo", "じょ"), ("ju", "じゅ"), ("jya", "じゃ"),
+                                     ("jyo", "じょ"), ("jyu", "じゅ"), ("ka", "か"), ("ke", "け"),
+                                     ("ki", "き"), ("ko", "こ"), ("ku", "く"), ("kya", "きゃ"),
+                                     ("kyo", "きょ"), ("kyu", "きゅ"), ("la", "ぁ"), ("le", "ぇ"),
+                                     ("li", "ぃ"), ("lo", "ぉ"), ("ltsu", "っ"), ("ltu", "っ"),
+                                     ("lu", "ぅ"), ("lwa", "ゎ"), ("lya", "ゃ"), ("lyo", "ょ"),
+                                     ("lyu", "ゅ"), ("ma", "ま"), ("me", "め"), ("mi", "み"),
+                                     ("mo", "も"), ("mu", "む"), ("mya", "みゃ"), ("myo", "みょ"),
+                                     ("myu", "みゅ"), ("na", "な"), ("ne", "ね"), ("ni", "に"),
+                                     ("no", "の"), ("nu", "ぬ"), ("nya", "にゃ"), ("nyo", "にょ"),
+                                     ("nyu", "にゅ"), ("o", "お"), ("pa", "ぱ"), ("pe", "ぺ"),
+                                     ("pi", "ぴ"), ("po", "ぽ"), ("pu", "ぷ"), ("pya", "ぴゃ"),
+                                     ("pyo", "ぴょ"), ("pyu", "ぴゅ"), ("ra", "ら"), ("re", "れ"),
+                                     ("ri", "り"), ("ro", "ろ"), ("ru", "る"), ("rya", "りゃ"),
+                                     ("ryo", "りょ"), ("ryu", "りゅ"), ("sa", "さ"), ("se", "せ"),
+                                     ("sha", "しゃ"), ("she", "しぇ"), ("shi", "し"),
+                                     ("sho", "しょ"), ("shu", "しゅ"), ("si", "し"), ("so", "そ"),
+                                     ("su", "す"), ("sya", "しゃ"), ("syo", "しょ"),
+                                     ("syu", "しゅ"), ("ta", "た"), ("te", "て"), ("ti", "ち"),
+                                     ("to", "と"), ("tsa", "つぁ"), ("tse", "つぇ"),
+                                     ("tsi", "つぃ"), ("tso", "つぉ"), ("tsu", "つ"), ("tu", "つ"),
+                                     ("tya", "ちゃ"), ("tyo", "ちょ"), ("tyu", "ちゅ"), ("u", "う"),
+                                     ("va", "ゔぁ"), ("ve", "ゔぇ"), ("vi", "ゔぃ"), ("vo", "ゔぉ"),
+                                     ("vu", "ゔ"), ("wa", "わ"), ("we", "うぇ"), ("wi", "うぃ"),
+                                     ("wo", "を"), ("wye", "ゑ"), ("wyi", "ゐ"), ("xa", "ぁ"),
+                                     ("xe", "ぇ"), ("xi", "ぃ"), ("xo", "ぉ"), ("xtsu", "っ"),
+                                     ("xtu", "っ"), ("xu", "ぅ"), ("xwa", "ゎ"), ("xya", "ゃ"),
+                                     ("xyo", "ょ"), ("xyu", "ゅ"), ("ya", "や"), ("ye", "いぇ"),
+                                     ("yo", "よ"), ("yu", "ゆ"), ("za", "ざ"), ("ze", "ぜ"),
+                                     ("zi", "じ"), ("zo", "ぞ"), ("zu", "ず"), ("zya", "じゃ"),
+                                     ("zyo", "じょ"), ("zyu", "じゅ")];
+
+/// Longest syllable of `ROMAJI`, in letters.
+const SYLLABLE_LEN: usize = 4;
+
+/// The hiragana completed by a letter typed into a
+/// [RomajiConverter](./struct.RomajiConverter.html), as an iterator over its characters.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConvertedKana {
+    chars: [char; 4],
+    start: usize,
+    end: usize,
+}
+
+impl ConvertedKana {
+    /// Appends the characters of `kana`.
+    fn push_str(&mut self, kana: &str) {
+        for c in kana.chars() {
+            self.chars[self.end] = c;
+            self.end += 1;
+        }
+    }
+}
+
+impl Iterator for ConvertedKana {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        Some(self.chars[self.start - 1])
+    }
+}
+
+/// Converts romaji to hiragana one typed letter at a time. The letters of a syllable
+/// that is not complete yet stay [pending](#method.pending).
+///
+/// ```
+/// use font8x8::unicode::RomajiConverter;
+///
+/// let mut converter = RomajiConverter::new();
+/// assert_eq!(converter.push('k').next(), None);
+/// assert_eq!(converter.push('y').next(), None);
+/// assert_eq!(converter.pending(), "ky");
+/// let kana: String = converter.push('o').collect();
+/// assert_eq!(kana, "きょ");
+/// assert_eq!(converter.pending(), "");
+/// ```
+#[derive(Clone, Default)]
+pub struct RomajiConverter {
+    pending: [u8; SYLLABLE_LEN],
+    len: usize,
+}
+
+impl RomajiConverter {
+    /// Create a new converter, with nothing pending.
+    pub fn new() -> Self {
+        RomajiConverter { pending: [0; SYLLABLE_LEN],
+                          len: 0 }
+    }
+
+    /// Returns the letters typed for the syllable that is not complete yet.
+    pub fn pending(&self) -> &str {
+        str::from_utf8(&self.pending[..self.len]).unwrap_or_default()
+    }
+
+    /// Types `c`, and returns the hiragana that it completes. Letters are converted in
+    /// either case. Any other character ends the pending syllable, like
+    /// [flush](#method.flush), and is otherwise ignored.
+    pub fn push(&mut self, c: char) -> ConvertedKana {
+        let mut kana = ConvertedKana::default();
+        if !c.is_ascii_alphabetic() && c != '\'' {
+            return self.flush();
+        }
+        self.pending[self.len] = c.to_ascii_lowercase() as u8;
+        self.len += 1;
+        while self.len > 0 {
+            let pending = self.pending();
+            if let Some(syllable) = syllable(pending) {
+                kana.push_str(syllable);
+                self.len = 0;
+                break;
+            }
+            if ROMAJI.iter().any(|&(romaji, _)| romaji.starts_with(pending)) {
+                break;
+            }
+            let bytes = pending.as_bytes();
+            let skip = match (bytes[0], bytes.get(1)) {
+                (b'n', Some(b'n')) | (b'n', Some(b'\'')) => {
+                    kana.push_str("ん");
+                    2
+                }
+                (b'n', _) => {
+                    kana.push_str("ん");
+                    1
+                }
+                (first, Some(&second)) if is_doubled(first, second) => {
+                    kana.push_str("っ");
+                    1
+                }
+                _ => 1,
+            };
+            self.pending.copy_within(skip..self.len, 0);
+            self.len -= skip;
+        }
+        kana
+    }
+
+    /// Removes the last pending letter, and returns it.
+    pub fn pop(&mut self) -> Option<char> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.pending[self.len] as char)
+    }
+
+    /// Ends the pending syllable at the end of the input, and returns its hiragana: a
+    /// pending `n` is `"ん"`, and the letters of any other incomplete syllable are dropped.
+    pub fn flush(&mut self) -> ConvertedKana {
+        let mut kana = ConvertedKana::default();
+        if self.pending() == "n" {
+            kana.push_str("ん");
+        }
+        self.len = 0;
+        kana
+    }
+}
+
+impl fmt::Debug for RomajiConverter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RomajiConverter({:?})", self.pending())
+    }
+}
+
+impl PartialEq for RomajiConverter {
+    fn eq(&self, other: &RomajiConverter) -> bool {
+        self.pending() == other.pending()
+    }
+}
+
+/// Returns the hiragana for the romaji `syllable`, if it is one.
+fn syllable(syllable: &str) -> Option<&'static str> {
+    ROMAJI.binary_search_by_key(&syllable, |&(romaji, _)| romaji)
+          .ok()
+          .map(|idx| ROMAJI[idx].1)
+}
+
+/// Returns `true` if the letters `first` and `second` spell a double consonant, which
+/// starts with `"っ"`: the same consonant twice, other than `n`, or `"tc"`.
+fn is_doubled(first: u8, second: u8) -> bool {
+    let consonant = first.is_ascii_lowercase() && !matches!(first, b'a' | b'e' | b'i' | b'o' | b'u' | b'n');
+    consonant && (first == second || (first, second) == (b't', b'c'))
+}
+
+/// Iterator over the hiragana of a romaji string, returned by
+/// [romaji_to_hiragana](./fn.romaji_to_hiragana.html).
+#[derive(Clone, Debug)]
+pub struct RomajiHiragana<'a> {
+    chars: Chars<'a>,
+    converter: RomajiConverter,
+    kana: ConvertedKana,
+}
+
+impl<'a> Iterator for RomajiHiragana<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.kana.next() {
+                return Some(c);
+            }
+            self.kana = match self.chars.next() {
+                Some(c) => self.converter.push(c),
+                None if self.converter.len > 0 => self.converter.flush(),
+                None => return None,
+            };
+        }
+    }
+}
+
+/// Converts the romaji `text` to hiragana, as if it was typed into a
+/// [RomajiConverter](./struct.RomajiConverter.html) and flushed at the end.
+///
+/// ```
+/// use font8x8::unicode::romaji_to_hiragana;
+///
+/// let kana: String = romaji_to_hiragana("Shinbun").collect();
+/// assert_eq!(kana, "しんぶん");
+/// ```
+pub fn romaji_to_hiragana<'a>(text: &'a str) -> RomajiHiragana<'a> {
+    RomajiHiragana { chars: text.chars(),
+                     converter: RomajiConverter::new(),
+                     kana: ConvertedKana::default() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{UnicodeFonts, HIRAGANA_FONTS};
+
+    fn hiragana(romaji: &str) -> String {
+        romaji_to_hiragana(romaji).collect()
+    }
+
+    #[test]
+    fn romaji_syllables_are_sorted_and_do_not_start_with_one_another() {
+        for pair in ROMAJI.windows(2) {
+            assert!(pair[0].0 < pair[1].0, "{:?}", pair[1]);
+        }
+        for &(romaji, _) in ROMAJI.iter() {
+            assert!(romaji.len() <= SYLLABLE_LEN, "{:?}", romaji);
+            for &(other, _) in ROMAJI.iter() {
+                assert!(romaji == other || !other.starts_with(romaji), "{:?} {:?}", romaji, other);
+            }
+        }
+    }
+
+    #[test]
+    fn romaji_converts_to_hiragana_in_the_hiragana_fonts() {
+        for &(_, kana) in ROMAJI.iter().chain([("", "ん"), ("", "っ")].iter()) {
+            for c in kana.chars() {
+                assert_ne!(HIRAGANA_FONTS.get(c).unwrap_or_default(), [0; 8], "{:?}", c);
+            }
+        }
+    }
+
+    #[test]
+    fn romaji_follows_hepburn_and_input_method_conventions() {
+        assert_eq!(hiragana("konnnichiha"), "こんにちは");
+        assert_eq!(hiragana("kon'nichiha"), "こんにちは");
+        assert_eq!(hiragana("kin'en"), "きんえん");
+        assert_eq!(hiragana("tsukue"), "つくえ");
+        assert_eq!(hiragana("kitte"), "きって");
+        assert_eq!(hiragana("matcha"), "まっちゃ");
+        assert_eq!(hiragana("zasshi"), "ざっし");
+        assert_eq!(hiragana("xtulyaxtsu"), "っゃっ");
+        assert_eq!(hiragana("Kyouto"), "きょうと");
+        assert_eq!(hiragana("fujisan"), "ふじさん");
+        assert_eq!(hiragana("qa1bo"), "あぼ");
+    }
+
+    #[test]
+    fn romaji_converter_keeps_incomplete_syllables_pending() {
+        let mut converter = RomajiConverter::new();
+        assert_eq!(converter.push('t').next(), None);
+        assert_eq!(converter.push('s').next(), None);
+        assert_eq!(converter.pending(), "ts");
+        assert_eq!(converter.pop(), Some('s'));
+        assert_eq!(converter.push('t').collect::<String>(), "っ");
+        assert_eq!(converter.pending(), "t");
+        assert_eq!(converter.push('a').collect::<String>(), "た");
+        assert_eq!(converter.push('n').next(), None);
+        assert_eq!(converter.pending(), "n");
+        assert_eq!(converter.push('k').collect::<String>(), "ん");
+        assert_eq!(converter.pending(), "k");
+        assert_eq!(converter.flush().next(), None);
+        assert_eq!(converter, RomajiConverter::new());
+    }
+}
@@ -0,0 +1,738 @@
+//! Superscripts and Subscripts. `U+2070` - `U+209F`
+//!
+//! Digits are the tiny 3x5 digits of the control pictures, drawn on rows 0 to 4 for
+//! superscripts and on rows 3 to 7 for subscripts, so that they stay readable next to
+//! full-size letters. Superscript `¹ ² ³` are in `LATIN_FONTS`.
+use super::unicode::{FontUnicode, UnicodeFonts};
+use core::fmt;
+
+/// A constant `[FontUnicode; 42]`, for Superscripts and Subscripts fonts (`U+2070` - `U+209F`).
+pub const SCRIPT_UNICODE: [FontUnicode; 42] = [FontUnicode('\u{2070}', [0x1C, 0x14, 0x14, 0x14, 0x1C, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{2071}', [0x08, 0x00, 0x0C, 0x08, 0x1C, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{2074}', [0x14, 0x14, 0x1C, 0x10, 0x10, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{2075}', [0x1C, 0x04, 0x0C, 0x10, 0x0C, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{2076}', [0x18, 0x04, 0x1C, 0x14, 0x1C, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{2077}', [0x1C, 0x10, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{2078}', [0x1C, 0x14, 0x1C, 0x14, 0x1C, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{2079}', [0x1C, 0x14, 0x1C, 0x10, 0x0C, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{207A}', [0x00, 0x08, 0x1C, 0x08, 0x00, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{207B}', [0x00, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{207C}', [0x00, 0x1C, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{207D}', [0x08, 0x04, 0x04, 0x04, 0x08, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{207E}', [0x04, 0x08, 0x08, 0x08, 0x04, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{207F}', [0x00, 0x0C, 0x14, 0x14, 0x14, 0x00, 0x00, 0x00]),
+                                               FontUnicode('\u{2080}', [0x00, 0x00, 0x00, 0x1C, 0x14, 0x14, 0x14, 0x1C]),
+                                               FontUnicode('\u{2081}', [0x00, 0x00, 0x00, 0x08, 0x0C, 0x08, 0x08, 0x1C]),
+                                               FontUnicode('\u{2082}', [0x00, 0x00, 0x00, 0x0C, 0x10, 0x08, 0x04, 0x1C]),
+                                               FontUnicode('\u{2083}', [0x00, 0x00, 0x00, 0x0C, 0x10, 0x08, 0x10, 0x0C]),
+                                               FontUnicode('\u{2084}', [0x00, 0x00, 0x00, 0x14, 0x14, 0x1C, 0x10, 0x10]),
+                                               FontUnicode('\u{2085}', [0x00, 0x00, 0x00, 0x1C, 0x04, 0x0C, 0x10, 0x0C]),
+                                               FontUnicode('\u{2086}', [0x00, 0x00, 0x00, 0x18, 0x04, 0x1C, 0x14, 0x1C]),
+                                               FontUnicode('\u{2087}', [0x00, 0x00, 0x00, 0x1C, 0x10, 0x08, 0x08, 0x08]),
+                                               FontUnicode('\u{2088}', [0x00, 0x00, 0x00, 0x1C, 0x14, 0x1C, 0x14, 0x1C]),
+                                               FontUnicode('\u{2089}', [0x00, 0x00, 0x00, 0x1C, 0x14, 0x1C, 0x10, 0x0C]),
+                                               FontUnicode('\u{208A}', [0x00, 0x00, 0x00, 0x00, 0x08, 0x1C, 0x08, 0x00]),
+                                               FontUnicode('\u{208B}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x00, 0x00]),
+                                               FontUnicode('\u{208C}', [0x00, 0x00, 0x00, 0x00, 0x1C, 0x00, 0x1C, 0x00]),
+                                               FontUnicode('\u{208D}', [0x00, 0x00, 0x00, 0x08, 0x04, 0x04, 0x04, 0x08]),
+                                               FontUnicode('\u{208E}', [0x00, 0x00, 0x00, 0x04, 0x08, 0x08, 0x08, 0x04]),
+                                               FontUnicode('\u{2090}', [0x00, 0x00, 0x00, 0x00, 0x0C, 0x18, 0x14, 0x18]),
+                                               FontUnicode('\u{2091}', [0x00, 0x00, 0x00, 0x00, 0x08, 0x1C, 0x04, 0x18]),
+                                               FontUnicode('\u{2092}', [0x00, 0x00, 0x00, 0x00, 0x08, 0x14, 0x14, 0x08]),
+                                               FontUnicode('\u{2093}', [0x00, 0x00, 0x00, 0x00, 0x14, 0x08, 0x08, 0x14]),
+                                               FontUnicode('\u{2094}', [0x00, 0x00, 0x00, 0x00, 0x0C, 0x10, 0x1C, 0x08]),
+                                               FontUnicode('\u{2095}', [0x00, 0x00, 0x00, 0x04, 0x04, 0x0C, 0x14, 0x14]),
+                                               FontUnicode('\u{2096}', [0x00, 0x00, 0x00, 0x04, 0x14, 0x0C, 0x0C, 0x14]),
+                                               FontUnicode('\u{2097}', [0x00, 0x00, 0x00, 0x0C, 0x08, 0x08, 0x08, 0x1C]),
+                                               FontUnicode('\u{2098}', [0x00, 0x00, 0x00, 0x00, 0x0C, 0x1C, 0x14, 0x14]),
+                                               FontUnicode('\u{2099}', [0x00, 0x00, 0x00, 0x00, 0x0C, 0x14, 0x14, 0x14]),
+                                               FontUnicode('\u{209A}', [0x00, 0x00, 0x00, 0x00, 0x0C, 0x14, 0x0C, 0x04]),
+                                               FontUnicode('\u{209B}', [0x00, 0x00, 0x00, 0x00, 0x18, 0x08, 0x10, 0x0C]),
+                                               FontUnicode('\u{209C}', [0x00, 0x00, 0x00, 0x08, 0x1C, 0x08, 0x08, 0x18])];
+
+/// A convenient constant for Superscripts and Subscripts fonts (`U+2070` - `U+209F`), that implements the `UnicodeFonts` trait.
+///
+/// ## `SCRIPT_UNICODE[0]`: `U+2070` `"⁰"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[1]`: `U+2071` `"ⁱ"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[2]`: `U+2074` `"⁴"`
+///
+/// ```text
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[3]`: `U+2075` `"⁵"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░█░░░░░
+/// ░░██░░░░
+/// ░░░░█░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[4]`: `U+2076` `"⁶"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░█░░░░░
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[5]`: `U+2077` `"⁷"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[6]`: `U+2078` `"⁸"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[7]`: `U+2079` `"⁹"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ░░░░█░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[8]`: `U+207A` `"⁺"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[9]`: `U+207B` `"⁻"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[10]`: `U+207C` `"⁼"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[11]`: `U+207D` `"⁽"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[12]`: `U+207E` `"⁾"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[13]`: `U+207F` `"ⁿ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[14]`: `U+2080` `"₀"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[15]`: `U+2081` `"₁"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░██░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[16]`: `U+2082` `"₂"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[17]`: `U+2083` `"₃"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[18]`: `U+2084` `"₄"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[19]`: `U+2085` `"₅"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░█░░░░░
+/// ░░██░░░░
+/// ░░░░█░░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[20]`: `U+2086` `"₆"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░█░░░░░
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[21]`: `U+2087` `"₇"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[22]`: `U+2088` `"₈"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[23]`: `U+2089` `"₉"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ░░░░█░░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[24]`: `U+208A` `"₊"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[25]`: `U+208B` `"₋"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[26]`: `U+208C` `"₌"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[27]`: `U+208D` `"₍"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[28]`: `U+208E` `"₎"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[29]`: `U+2090` `"ₐ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░░██░░░
+/// ░░█░█░░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[30]`: `U+2091` `"ₑ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░░█░░░░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[31]`: `U+2092` `"ₒ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[32]`: `U+2093` `"ₓ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[33]`: `U+2094` `"ₔ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░░░█░░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[34]`: `U+2095` `"ₕ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░██░░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[35]`: `U+2096` `"ₖ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░░█░█░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░█░█░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[36]`: `U+2097` `"ₗ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[37]`: `U+2098` `"ₘ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[38]`: `U+2099` `"ₙ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[39]`: `U+209A` `"ₚ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░░█░█░░░
+/// ░░██░░░░
+/// ░░█░░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[40]`: `U+209B` `"ₛ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░██░░░░
+/// ```
+///
+/// ## `SCRIPT_UNICODE[41]`: `U+209C` `"ₜ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░██░░░
+/// ```
+pub const SCRIPT_FONTS: ScriptFonts = ScriptFonts(SCRIPT_UNICODE);
+/// Strong-typed collection wrapper for [SCRIPT_UNICODE](./constant.SCRIPT_UNICODE.html).
+pub struct ScriptFonts([FontUnicode; 42]);
+
+impl ScriptFonts {
+    /// Create a new collection of `SCRIPT_UNICODE` fonts.
+    pub fn new() -> Self {
+        ScriptFonts(SCRIPT_UNICODE)
+    }
+}
+
+impl fmt::Debug for ScriptFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(SCRIPT_UNICODE))
+    }
+}
+
+impl PartialEq for ScriptFonts {
+    fn eq(&self, other: &ScriptFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for ScriptFonts {
+    fn default() -> Self {
+        ScriptFonts::new()
+    }
+}
+
+impl UnicodeFonts for ScriptFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(42), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pictures::TINY_HEX;
+
+    #[test]
+    fn script_set_implements_default_trait_with_method_new() {
+        let script_set: ScriptFonts = Default::default();
+        assert_eq!(script_set, ScriptFonts::new());
+    }
+
+    #[test]
+    fn script_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(SCRIPT_FONTS, ScriptFonts::new());
+    }
+
+    #[test]
+    fn script_fonts_constant_wraps_script_unicode_constant() {
+        let script = ScriptFonts::new();
+        assert!(script.0.len() == SCRIPT_UNICODE.len());
+        for (idx, font) in script.0.iter().enumerate() {
+            assert_eq!(font, &SCRIPT_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn script_digits_are_the_tiny_control_picture_digits() {
+        for digit in 0..10 {
+            let tiny = TINY_HEX[digit as usize];
+            let sub = SCRIPT_FONTS.get(::core::char::from_u32(0x2080 + digit).unwrap()).unwrap();
+            for row in 0..5 {
+                assert_eq!(sub[row + 3], tiny[row] << 2);
+            }
+            if (1..4).contains(&digit) {
+                continue;
+            }
+            let code = if digit == 0 { 0x2070 } else { 0x2070 + digit };
+            let sup = SCRIPT_FONTS.get(::core::char::from_u32(code).unwrap()).unwrap();
+            for row in 0..5 {
+                assert_eq!(sup[row], tiny[row] << 2);
+            }
+        }
+    }
+
+    #[test]
+    fn script_superscripts_sit_high_and_subscripts_sit_low() {
+        for font in SCRIPT_UNICODE.iter() {
+            let glyph = font.byte_array();
+            if font.char() < '\u{2080}' {
+                assert_eq!(glyph[5] | glyph[6] | glyph[7], 0);
+            } else {
+                assert_eq!(glyph[0] | glyph[1] | glyph[2], 0);
+            }
+        }
+    }
+}
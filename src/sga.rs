@@ -1,10 +1,27 @@
 //! Special characters with private unicode points.
+//!
+//! The glyphs are the letters of the Standard Galactic Alphabet, in the order of the
+//! Latin letters they stand for, so that the SGA letter for the Latin letter `L` is
+//! `U+E541 + (L - 'A')`. [to_sga](./fn.to_sga.html) and [from_sga](./fn.from_sga.html)
+//! map between them.
+//!
+//! | Latin | SGA      | Latin | SGA      | Latin | SGA      |
+//! |-------|----------|-------|----------|-------|----------|
+//! | `A`   | `U+E541` | `J`   | `U+E54A` | `S`   | `U+E553` |
+//! | `B`   | `U+E542` | `K`   | `U+E54B` | `T`   | `U+E554` |
+//! | `C`   | `U+E543` | `L`   | `U+E54C` | `U`   | `U+E555` |
+//! | `D`   | `U+E544` | `M`   | `U+E54D` | `V`   | `U+E556` |
+//! | `E`   | `U+E545` | `N`   | `U+E54E` | `W`   | `U+E557` |
+//! | `F`   | `U+E546` | `O`   | `U+E54F` | `X`   | `U+E558` |
+//! | `G`   | `U+E547` | `P`   | `U+E550` | `Y`   | `U+E559` |
+//! | `H`   | `U+E548` | `Q`   | `U+E551` | `Z`   | `U+E55A` |
+//! | `I`   | `U+E549` | `R`   | `U+E552` |       |          |
 use super::{
     legacy::SGA_LEGACY, unicode::{FontUnicode, UnicodeFonts},
 };
-use core::fmt;
+use core::{char, fmt, str::Chars};
 
-/// A constant `[FontUnicode; 26]`, for special SGA fonts (`U+E543` - `U+E55A`).
+/// A constant `[FontUnicode; 26]`, for special SGA fonts (`U+E541` - `U+E55A`).
 pub const SGA_UNICODE: [FontUnicode; 26] = [FontUnicode('\u{E541}', SGA_LEGACY[0]),
                                             FontUnicode('\u{E542}', SGA_LEGACY[1]),
                                             FontUnicode('\u{E543}', SGA_LEGACY[2]),
@@ -436,6 +453,79 @@ impl UnicodeFonts for SgaFonts {
     }
 }
 
+/// The private code point of SGA `A`.
+const SGA_FIRST: u32 = 0xE541;
+
+/// Returns the SGA letter for the ASCII letter `c`, in either case, or `None` if `c` is
+/// not an ASCII letter.
+///
+/// ```
+/// use font8x8::unicode::to_sga;
+///
+/// assert_eq!(to_sga('A'), Some('\u{E541}'));
+/// assert_eq!(to_sga('z'), Some('\u{E55A}'));
+/// assert_eq!(to_sga('1'), None);
+/// ```
+pub fn to_sga(c: char) -> Option<char> {
+    if !c.is_ascii_alphabetic() {
+        return None;
+    }
+    char::from_u32(SGA_FIRST + (c.to_ascii_uppercase() as u32 - 'A' as u32))
+}
+
+/// Returns the uppercase ASCII letter for the SGA letter `c`, or `None` if `c` is not
+/// an SGA letter.
+///
+/// ```
+/// use font8x8::unicode::from_sga;
+///
+/// assert_eq!(from_sga('\u{E541}'), Some('A'));
+/// assert_eq!(from_sga('\u{E55A}'), Some('Z'));
+/// assert_eq!(from_sga('A'), None);
+/// ```
+pub fn from_sga(c: char) -> Option<char> {
+    let idx = (c as u32).wrapping_sub(SGA_FIRST);
+    if idx < SGA_UNICODE.len() as u32 {
+        char::from_u32('A' as u32 + idx)
+    } else {
+        None
+    }
+}
+
+/// Iterator over the characters of a string in SGA, returned by
+/// [sga_chars](./fn.sga_chars.html).
+#[derive(Clone, Debug)]
+pub struct SgaChars<'a> {
+    chars: Chars<'a>,
+}
+
+impl<'a> Iterator for SgaChars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        Some(to_sga(c).unwrap_or(c))
+    }
+}
+
+/// Returns the characters of `text` with its ASCII letters replaced by their SGA
+/// letters, and every other character as it is.
+///
+/// ```
+/// use font8x8::unicode::{from_sga, sga_chars};
+/// use font8x8::{Renderer, UnicodeFonts, SGA_FONTS};
+///
+/// let renderer = Renderer::new();
+/// let glyphs: Vec<[u8; 8]> = sga_chars("Hi!").filter_map(|c| renderer.get(c)).collect();
+/// assert_eq!(glyphs[0], SGA_FONTS.get('\u{E548}').unwrap());
+///
+/// let latin: String = sga_chars("Hi!").map(|c| from_sga(c).unwrap_or(c)).collect();
+/// assert_eq!(latin, "HI!");
+/// ```
+pub fn sga_chars<'a>(text: &'a str) -> SgaChars<'a> {
+    SgaChars { chars: text.chars() }
+}
+
 #[cfg(feature = "std")]
 fn print_set(idx: usize, font: &FontUnicode) {
     if font.is_whitespace() {
@@ -484,4 +574,24 @@ mod tests {
             assert_eq!(font, &SGA_UNICODE[idx]);
         }
     }
+
+    #[test]
+    fn sga_letters_map_to_and_from_ascii_letters() {
+        for (idx, (upper, lower)) in ('A'..='Z').zip('a'..='z').enumerate() {
+            let sga = SGA_UNICODE[idx].char();
+            assert_eq!(to_sga(upper), Some(sga));
+            assert_eq!(to_sga(lower), Some(sga));
+            assert_eq!(from_sga(sga), Some(upper));
+        }
+        assert_eq!(to_sga('@'), None);
+        assert_eq!(to_sga('['), None);
+        assert_eq!(from_sga('\u{E540}'), None);
+        assert_eq!(from_sga('\u{E55B}'), None);
+    }
+
+    #[test]
+    fn sga_chars_only_replaces_ascii_letters() {
+        let sga: Vec<char> = sga_chars("aZ 1é").collect();
+        assert_eq!(sga, ['\u{E541}', '\u{E55A}', ' ', '1', 'é']);
+    }
 }
@@ -0,0 +1,1284 @@
+//! Miscellaneous Symbols. `U+2600` - `U+26FF`
+//!
+//! The faces, card suits, gender signs, notes and sun come from the CP437 glyphs of the
+//! original VGA font. The white suits and the white chess pieces are outlines of the
+//! black ones.
+use super::unicode::{FontUnicode, UnicodeFonts};
+use core::fmt;
+
+/// A constant `[FontUnicode; 82]`, for Miscellaneous Symbols fonts (`U+2600` - `U+26FF`).
+pub const SYMBOL_UNICODE: [FontUnicode; 82] = [FontUnicode('\u{2600}', [0x49, 0x22, 0x1C, 0xEF, 0x1C, 0x22, 0x49, 0x00]),
+                                               FontUnicode('\u{2601}', [0x00, 0x00, 0x0C, 0x32, 0x41, 0x81, 0x7E, 0x00]),
+                                               FontUnicode('\u{2602}', [0x08, 0x3E, 0x7F, 0x08, 0x08, 0x08, 0x0A, 0x04]),
+                                               FontUnicode('\u{2603}', [0x1C, 0x14, 0x1C, 0x22, 0x49, 0x41, 0x3E, 0x00]),
+                                               FontUnicode('\u{2604}', [0x01, 0x0A, 0x64, 0xF2, 0xF8, 0x70, 0x40, 0x00]),
+                                               FontUnicode('\u{2605}', [0x08, 0x08, 0x7F, 0x3E, 0x1C, 0x36, 0x63, 0x00]),
+                                               FontUnicode('\u{2606}', [0x08, 0x14, 0x63, 0x22, 0x2A, 0x36, 0x63, 0x00]),
+                                               FontUnicode('\u{2607}', [0x18, 0x0C, 0x06, 0x3F, 0x18, 0x2C, 0x36, 0x38]),
+                                               FontUnicode('\u{2608}', [0x7F, 0x40, 0x20, 0x10, 0x78, 0x20, 0x10, 0x08]),
+                                               FontUnicode('\u{2609}', [0x1C, 0x22, 0x41, 0x49, 0x41, 0x22, 0x1C, 0x00]),
+                                               FontUnicode('\u{260E}', [0x00, 0x3E, 0x63, 0x08, 0x3E, 0x63, 0x7F, 0x00]),
+                                               FontUnicode('\u{260F}', [0x00, 0x3E, 0x41, 0x08, 0x3E, 0x41, 0x7F, 0x00]),
+                                               FontUnicode('\u{2610}', [0x7F, 0x41, 0x41, 0x41, 0x41, 0x41, 0x7F, 0x00]),
+                                               FontUnicode('\u{2611}', [0x7F, 0x41, 0x61, 0x51, 0x4B, 0x45, 0x7F, 0x00]),
+                                               FontUnicode('\u{2612}', [0x7F, 0x63, 0x55, 0x49, 0x55, 0x63, 0x7F, 0x00]),
+                                               FontUnicode('\u{2614}', [0x08, 0x3E, 0x7F, 0x08, 0x2A, 0x08, 0x4D, 0x04]),
+                                               FontUnicode('\u{2615}', [0x14, 0x0A, 0x00, 0x3F, 0xE1, 0xA1, 0xDE, 0x40]),
+                                               FontUnicode('\u{2618}', [0x36, 0x6F, 0x36, 0x2D, 0x36, 0x08, 0x08, 0x04]),
+                                               FontUnicode('\u{261C}', [0x00, 0x3F, 0x01, 0x0F, 0x09, 0x09, 0x0E, 0x00]),
+                                               FontUnicode('\u{261D}', [0x08, 0x08, 0x08, 0x3A, 0x3F, 0x21, 0x1E, 0x00]),
+                                               FontUnicode('\u{261E}', [0x00, 0x7E, 0x40, 0x78, 0x48, 0x48, 0x38, 0x00]),
+                                               FontUnicode('\u{261F}', [0x00, 0x1E, 0x21, 0x3F, 0x3A, 0x08, 0x08, 0x08]),
+                                               FontUnicode('\u{2620}', [0x3E, 0x5D, 0x49, 0x3E, 0x14, 0x41, 0x22, 0x41]),
+                                               FontUnicode('\u{2622}', [0x00, 0x63, 0x77, 0x08, 0x00, 0x1C, 0x1C, 0x00]),
+                                               FontUnicode('\u{2623}', [0x08, 0x14, 0x08, 0x36, 0x49, 0x55, 0x22, 0x00]),
+                                               FontUnicode('\u{262E}', [0x1C, 0x2A, 0x49, 0x5D, 0x6B, 0x2A, 0x1C, 0x00]),
+                                               FontUnicode('\u{262F}', [0x1C, 0x3A, 0x79, 0x7D, 0x47, 0x36, 0x1C, 0x00]),
+                                               FontUnicode('\u{2639}', [0x7E, 0x81, 0xA5, 0x81, 0x99, 0xA5, 0x81, 0x7E]),
+                                               FontUnicode('\u{263A}', [0x7E, 0x81, 0xA5, 0x81, 0xBD, 0x99, 0x81, 0x7E]),
+                                               FontUnicode('\u{263B}', [0x7E, 0xFF, 0xDB, 0xFF, 0xC3, 0xE7, 0xFF, 0x7E]),
+                                               FontUnicode('\u{263C}', [0x99, 0x5A, 0x3C, 0xE7, 0xE7, 0x3C, 0x5A, 0x99]),
+                                               FontUnicode('\u{263D}', [0x0C, 0x10, 0x20, 0x20, 0x20, 0x10, 0x0C, 0x00]),
+                                               FontUnicode('\u{263E}', [0x18, 0x04, 0x02, 0x02, 0x02, 0x04, 0x18, 0x00]),
+                                               FontUnicode('\u{2640}', [0x3C, 0x66, 0x66, 0x66, 0x3C, 0x18, 0x7E, 0x18]),
+                                               FontUnicode('\u{2642}', [0xF0, 0xE0, 0xF0, 0xBE, 0x33, 0x33, 0x33, 0x1E]),
+                                               FontUnicode('\u{2654}', [0x08, 0x1C, 0x08, 0x36, 0x41, 0x22, 0x14, 0x3E]),
+                                               FontUnicode('\u{2655}', [0x49, 0x6B, 0x41, 0x22, 0x14, 0x14, 0x22, 0x7F]),
+                                               FontUnicode('\u{2656}', [0x55, 0x7F, 0x22, 0x22, 0x22, 0x22, 0x41, 0x7F]),
+                                               FontUnicode('\u{2657}', [0x08, 0x14, 0x22, 0x2A, 0x14, 0x14, 0x22, 0x7F]),
+                                               FontUnicode('\u{2658}', [0x04, 0x12, 0x51, 0x45, 0x48, 0x44, 0x42, 0x7F]),
+                                               FontUnicode('\u{2659}', [0x00, 0x1C, 0x14, 0x08, 0x14, 0x14, 0x22, 0x7F]),
+                                               FontUnicode('\u{265A}', [0x08, 0x1C, 0x08, 0x36, 0x7F, 0x3E, 0x1C, 0x3E]),
+                                               FontUnicode('\u{265B}', [0x49, 0x6B, 0x7F, 0x3E, 0x1C, 0x1C, 0x3E, 0x7F]),
+                                               FontUnicode('\u{265C}', [0x55, 0x7F, 0x3E, 0x3E, 0x3E, 0x3E, 0x7F, 0x7F]),
+                                               FontUnicode('\u{265D}', [0x08, 0x14, 0x36, 0x3E, 0x1C, 0x1C, 0x3E, 0x7F]),
+                                               FontUnicode('\u{265E}', [0x04, 0x1E, 0x7F, 0x7B, 0x78, 0x3C, 0x3E, 0x7F]),
+                                               FontUnicode('\u{265F}', [0x00, 0x1C, 0x1C, 0x08, 0x1C, 0x1C, 0x3E, 0x7F]),
+                                               FontUnicode('\u{2660}', [0x08, 0x08, 0x1C, 0x3E, 0x7F, 0x3E, 0x08, 0x1C]),
+                                               FontUnicode('\u{2661}', [0x36, 0x49, 0x41, 0x41, 0x22, 0x14, 0x08, 0x00]),
+                                               FontUnicode('\u{2662}', [0x08, 0x14, 0x22, 0x41, 0x22, 0x14, 0x08, 0x00]),
+                                               FontUnicode('\u{2663}', [0x1C, 0x3E, 0x1C, 0x7F, 0x7F, 0x6B, 0x08, 0x1C]),
+                                               FontUnicode('\u{2664}', [0x08, 0x08, 0x14, 0x22, 0x41, 0x36, 0x08, 0x1C]),
+                                               FontUnicode('\u{2665}', [0x36, 0x7F, 0x7F, 0x7F, 0x3E, 0x1C, 0x08, 0x00]),
+                                               FontUnicode('\u{2666}', [0x08, 0x1C, 0x3E, 0x7F, 0x3E, 0x1C, 0x08, 0x00]),
+                                               FontUnicode('\u{2667}', [0x1C, 0x22, 0x14, 0x63, 0x55, 0x6B, 0x08, 0x1C]),
+                                               FontUnicode('\u{2669}', [0x08, 0x08, 0x08, 0x08, 0x0E, 0x0F, 0x06, 0x00]),
+                                               FontUnicode('\u{266A}', [0xFC, 0xCC, 0xFC, 0x0C, 0x0C, 0x0E, 0x0F, 0x07]),
+                                               FontUnicode('\u{266B}', [0xFE, 0xC6, 0xFE, 0xC6, 0xC6, 0xE6, 0x67, 0x03]),
+                                               FontUnicode('\u{266C}', [0x7E, 0x42, 0x7E, 0x42, 0x42, 0x63, 0x63, 0x00]),
+                                               FontUnicode('\u{266D}', [0x02, 0x02, 0x02, 0x1E, 0x12, 0x0A, 0x06, 0x00]),
+                                               FontUnicode('\u{266E}', [0x02, 0x02, 0x22, 0x3E, 0x22, 0x30, 0x20, 0x00]),
+                                               FontUnicode('\u{266F}', [0x14, 0x34, 0x2E, 0x14, 0x74, 0x16, 0x14, 0x00]),
+                                               FontUnicode('\u{267B}', [0x18, 0x24, 0x42, 0xE3, 0x01, 0x42, 0x3C, 0x00]),
+                                               FontUnicode('\u{267F}', [0x0C, 0x0C, 0x08, 0x78, 0x0A, 0x71, 0x51, 0x4E]),
+                                               FontUnicode('\u{2690}', [0x7F, 0x41, 0x39, 0x0F, 0x01, 0x01, 0x01, 0x00]),
+                                               FontUnicode('\u{2691}', [0x7F, 0x7F, 0x7F, 0x0F, 0x01, 0x01, 0x01, 0x00]),
+                                               FontUnicode('\u{2693}', [0x08, 0x14, 0x08, 0x3E, 0x08, 0x49, 0x2A, 0x1C]),
+                                               FontUnicode('\u{2694}', [0x41, 0x22, 0x14, 0x08, 0x14, 0x63, 0x63, 0x00]),
+                                               FontUnicode('\u{2699}', [0x08, 0x3E, 0x36, 0x77, 0x36, 0x3E, 0x08, 0x00]),
+                                               FontUnicode('\u{26A0}', [0x08, 0x14, 0x14, 0x2A, 0x2A, 0x41, 0x49, 0x7F]),
+                                               FontUnicode('\u{26A1}', [0x70, 0x38, 0x1C, 0x7E, 0x38, 0x1C, 0x06, 0x01]),
+                                               FontUnicode('\u{26AA}', [0x1C, 0x22, 0x41, 0x41, 0x41, 0x22, 0x1C, 0x00]),
+                                               FontUnicode('\u{26AB}', [0x1C, 0x3E, 0x7F, 0x7F, 0x7F, 0x3E, 0x1C, 0x00]),
+                                               FontUnicode('\u{26BD}', [0x1C, 0x2A, 0x5D, 0x77, 0x5D, 0x22, 0x1C, 0x00]),
+                                               FontUnicode('\u{26C4}', [0x1C, 0x3E, 0x14, 0x22, 0x49, 0x41, 0x3E, 0x00]),
+                                               FontUnicode('\u{26C5}', [0x00, 0x05, 0x0E, 0xFB, 0x82, 0x81, 0xFE, 0x00]),
+                                               FontUnicode('\u{26D4}', [0x1C, 0x3E, 0x7F, 0x41, 0x7F, 0x3E, 0x1C, 0x00]),
+                                               FontUnicode('\u{26EA}', [0x08, 0x1C, 0x08, 0x1C, 0x3E, 0x63, 0x6B, 0x6B]),
+                                               FontUnicode('\u{26F3}', [0x0C, 0x3C, 0x7C, 0x04, 0x04, 0x04, 0x0E, 0x1F]),
+                                               FontUnicode('\u{26F5}', [0x08, 0x18, 0x38, 0x78, 0x08, 0x7F, 0x3E, 0x00]),
+                                               FontUnicode('\u{26FA}', [0x08, 0x1C, 0x36, 0x36, 0x63, 0x63, 0x7F, 0x00]),
+                                               FontUnicode('\u{26FD}', [0x0F, 0x29, 0x49, 0x4F, 0x4F, 0x6F, 0x0F, 0x1F])];
+
+/// A convenient constant for Miscellaneous Symbols fonts (`U+2600` - `U+26FF`), that implements the `UnicodeFonts` trait.
+///
+/// ## `SYMBOL_UNICODE[0]`: `U+2600` `"☀"`
+///
+/// ```text
+/// █░░█░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ████░███
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░█░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[1]`: `U+2601` `"☁"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░█░░██░░
+/// █░░░░░█░
+/// █░░░░░░█
+/// ░██████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[2]`: `U+2602` `"☂"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░█████░░
+/// ███████░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░█░█░░░░
+/// ░░█░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[3]`: `U+2603` `"☃"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░█░░█░
+/// █░░░░░█░
+/// ░█████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[4]`: `U+2604` `"☄"`
+///
+/// ```text
+/// █░░░░░░░
+/// ░█░█░░░░
+/// ░░█░░██░
+/// ░█░░████
+/// ░░░█████
+/// ░░░░███░
+/// ░░░░░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[5]`: `U+2605` `"★"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░██░██░░
+/// ██░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[6]`: `U+2606` `"☆"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ██░░░██░
+/// ░█░░░█░░
+/// ░█░█░█░░
+/// ░██░██░░
+/// ██░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[7]`: `U+2607` `"☇"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░██░░░░
+/// ░██░░░░░
+/// ██████░░
+/// ░░░██░░░
+/// ░░██░█░░
+/// ░██░██░░
+/// ░░░███░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[8]`: `U+2608` `"☈"`
+///
+/// ```text
+/// ███████░
+/// ░░░░░░█░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ░░░████░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[9]`: `U+2609` `"☉"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// █░░█░░█░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[10]`: `U+260E` `"☎"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█████░░
+/// ██░░░██░
+/// ░░░█░░░░
+/// ░█████░░
+/// ██░░░██░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[11]`: `U+260F` `"☏"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█████░░
+/// █░░░░░█░
+/// ░░░█░░░░
+/// ░█████░░
+/// █░░░░░█░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[12]`: `U+2610` `"☐"`
+///
+/// ```text
+/// ███████░
+/// █░░░░░█░
+/// █░░░░░█░
+/// █░░░░░█░
+/// █░░░░░█░
+/// █░░░░░█░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[13]`: `U+2611` `"☑"`
+///
+/// ```text
+/// ███████░
+/// █░░░░░█░
+/// █░░░░██░
+/// █░░░█░█░
+/// ██░█░░█░
+/// █░█░░░█░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[14]`: `U+2612` `"☒"`
+///
+/// ```text
+/// ███████░
+/// ██░░░██░
+/// █░█░█░█░
+/// █░░█░░█░
+/// █░█░█░█░
+/// ██░░░██░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[15]`: `U+2614` `"☔"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░█████░░
+/// ███████░
+/// ░░░█░░░░
+/// ░█░█░█░░
+/// ░░░█░░░░
+/// █░██░░█░
+/// ░░█░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[16]`: `U+2615` `"☕"`
+///
+/// ```text
+/// ░░█░█░░░
+/// ░█░█░░░░
+/// ░░░░░░░░
+/// ██████░░
+/// █░░░░███
+/// █░░░░█░█
+/// ░████░██
+/// ░░░░░░█░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[17]`: `U+2618` `"☘"`
+///
+/// ```text
+/// ░██░██░░
+/// ████░██░
+/// ░██░██░░
+/// █░██░█░░
+/// ░██░██░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[18]`: `U+261C` `"☜"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██████░░
+/// █░░░░░░░
+/// ████░░░░
+/// █░░█░░░░
+/// █░░█░░░░
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[19]`: `U+261D` `"☝"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░█░███░░
+/// ██████░░
+/// █░░░░█░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[20]`: `U+261E` `"☞"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██████░
+/// ░░░░░░█░
+/// ░░░████░
+/// ░░░█░░█░
+/// ░░░█░░█░
+/// ░░░███░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[21]`: `U+261F` `"☟"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░████░░░
+/// █░░░░█░░
+/// ██████░░
+/// ░█░███░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[22]`: `U+2620` `"☠"`
+///
+/// ```text
+/// ░█████░░
+/// █░███░█░
+/// █░░█░░█░
+/// ░█████░░
+/// ░░█░█░░░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[23]`: `U+2622` `"☢"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██░░░██░
+/// ███░███░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[24]`: `U+2623` `"☣"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░██░██░░
+/// █░░█░░█░
+/// █░█░█░█░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[25]`: `U+262E` `"☮"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░█░█░░
+/// █░░█░░█░
+/// █░███░█░
+/// ██░█░██░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[26]`: `U+262F` `"☯"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░███░░
+/// █░░████░
+/// █░█████░
+/// ███░░░█░
+/// ░██░██░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[27]`: `U+2639` `"☹"`
+///
+/// ```text
+/// ░██████░
+/// █░░░░░░█
+/// █░█░░█░█
+/// █░░░░░░█
+/// █░░██░░█
+/// █░█░░█░█
+/// █░░░░░░█
+/// ░██████░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[28]`: `U+263A` `"☺"`
+///
+/// ```text
+/// ░██████░
+/// █░░░░░░█
+/// █░█░░█░█
+/// █░░░░░░█
+/// █░████░█
+/// █░░██░░█
+/// █░░░░░░█
+/// ░██████░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[29]`: `U+263B` `"☻"`
+///
+/// ```text
+/// ░██████░
+/// ████████
+/// ██░██░██
+/// ████████
+/// ██░░░░██
+/// ███░░███
+/// ████████
+/// ░██████░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[30]`: `U+263C` `"☼"`
+///
+/// ```text
+/// █░░██░░█
+/// ░█░██░█░
+/// ░░████░░
+/// ███░░███
+/// ███░░███
+/// ░░████░░
+/// ░█░██░█░
+/// █░░██░░█
+/// ```
+///
+/// ## `SYMBOL_UNICODE[31]`: `U+263D` `"☽"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░░░█░░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[32]`: `U+263E` `"☾"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ░░█░░░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[33]`: `U+2640` `"♀"`
+///
+/// ```text
+/// ░░████░░
+/// ░██░░██░
+/// ░██░░██░
+/// ░██░░██░
+/// ░░████░░
+/// ░░░██░░░
+/// ░██████░
+/// ░░░██░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[34]`: `U+2642` `"♂"`
+///
+/// ```text
+/// ░░░░████
+/// ░░░░░███
+/// ░░░░████
+/// ░█████░█
+/// ██░░██░░
+/// ██░░██░░
+/// ██░░██░░
+/// ░████░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[35]`: `U+2654` `"♔"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░██░██░░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░█████░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[36]`: `U+2655` `"♕"`
+///
+/// ```text
+/// █░░█░░█░
+/// ██░█░██░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ███████░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[37]`: `U+2656` `"♖"`
+///
+/// ```text
+/// █░█░█░█░
+/// ███████░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// ███████░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[38]`: `U+2657` `"♗"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ░█░█░█░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ███████░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[39]`: `U+2658` `"♘"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░█░░█░░░
+/// █░░░█░█░
+/// █░█░░░█░
+/// ░░░█░░█░
+/// ░░█░░░█░
+/// ░█░░░░█░
+/// ███████░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[40]`: `U+2659` `"♙"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ███████░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[41]`: `U+265A` `"♚"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░██░██░░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░█████░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[42]`: `U+265B` `"♛"`
+///
+/// ```text
+/// █░░█░░█░
+/// ██░█░██░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[43]`: `U+265C` `"♜"`
+///
+/// ```text
+/// █░█░█░█░
+/// ███████░
+/// ░█████░░
+/// ░█████░░
+/// ░█████░░
+/// ░█████░░
+/// ███████░
+/// ███████░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[44]`: `U+265D` `"♝"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░██░██░░
+/// ░█████░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[45]`: `U+265E` `"♞"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░████░░░
+/// ███████░
+/// ██░████░
+/// ░░░████░
+/// ░░████░░
+/// ░█████░░
+/// ███████░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[46]`: `U+265F` `"♟"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[47]`: `U+2660` `"♠"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// ░█████░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[48]`: `U+2661` `"♡"`
+///
+/// ```text
+/// ░██░██░░
+/// █░░█░░█░
+/// █░░░░░█░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[49]`: `U+2662` `"♢"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[50]`: `U+2663` `"♣"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█████░░
+/// ░░███░░░
+/// ███████░
+/// ███████░
+/// ██░█░██░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[51]`: `U+2664` `"♤"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// ░██░██░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[52]`: `U+2665` `"♥"`
+///
+/// ```text
+/// ░██░██░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[53]`: `U+2666` `"♦"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[54]`: `U+2667` `"♧"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ██░░░██░
+/// █░█░█░█░
+/// ██░█░██░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[55]`: `U+2669` `"♩"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░███░░░░
+/// ████░░░░
+/// ░██░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[56]`: `U+266A` `"♪"`
+///
+/// ```text
+/// ░░██████
+/// ░░██░░██
+/// ░░██████
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░███░░░░
+/// ████░░░░
+/// ███░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[57]`: `U+266B` `"♫"`
+///
+/// ```text
+/// ░███████
+/// ░██░░░██
+/// ░███████
+/// ░██░░░██
+/// ░██░░░██
+/// ░██░░███
+/// ███░░██░
+/// ██░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[58]`: `U+266C` `"♬"`
+///
+/// ```text
+/// ░██████░
+/// ░█░░░░█░
+/// ░██████░
+/// ░█░░░░█░
+/// ░█░░░░█░
+/// ██░░░██░
+/// ██░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[59]`: `U+266D` `"♭"`
+///
+/// ```text
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ░████░░░
+/// ░█░░█░░░
+/// ░█░█░░░░
+/// ░██░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[60]`: `U+266E` `"♮"`
+///
+/// ```text
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ░█░░░█░░
+/// ░█████░░
+/// ░█░░░█░░
+/// ░░░░██░░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[61]`: `U+266F` `"♯"`
+///
+/// ```text
+/// ░░█░█░░░
+/// ░░█░██░░
+/// ░███░█░░
+/// ░░█░█░░░
+/// ░░█░███░
+/// ░██░█░░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[62]`: `U+267B` `"♻"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░█░░█░░
+/// ░█░░░░█░
+/// ██░░░███
+/// █░░░░░░░
+/// ░█░░░░█░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[63]`: `U+267F` `"♿"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░█░░░░
+/// ░░░████░
+/// ░█░█░░░░
+/// █░░░███░
+/// █░░░█░█░
+/// ░███░░█░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[64]`: `U+2690` `"⚐"`
+///
+/// ```text
+/// ███████░
+/// █░░░░░█░
+/// █░░███░░
+/// ████░░░░
+/// █░░░░░░░
+/// █░░░░░░░
+/// █░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[65]`: `U+2691` `"⚑"`
+///
+/// ```text
+/// ███████░
+/// ███████░
+/// ███████░
+/// ████░░░░
+/// █░░░░░░░
+/// █░░░░░░░
+/// █░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[66]`: `U+2693` `"⚓"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░█████░░
+/// ░░░█░░░░
+/// █░░█░░█░
+/// ░█░█░█░░
+/// ░░███░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[67]`: `U+2694` `"⚔"`
+///
+/// ```text
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ██░░░██░
+/// ██░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[68]`: `U+2699` `"⚙"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░█████░░
+/// ░██░██░░
+/// ███░███░
+/// ░██░██░░
+/// ░█████░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[69]`: `U+26A0` `"⚠"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░░█░█░░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// █░░░░░█░
+/// █░░█░░█░
+/// ███████░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[70]`: `U+26A1` `"⚡"`
+///
+/// ```text
+/// ░░░░███░
+/// ░░░███░░
+/// ░░███░░░
+/// ░██████░
+/// ░░░███░░
+/// ░░███░░░
+/// ░██░░░░░
+/// █░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[71]`: `U+26AA` `"⚪"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// █░░░░░█░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[72]`: `U+26AB` `"⚫"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// ███████░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[73]`: `U+26BD` `"⚽"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░█░█░░
+/// █░███░█░
+/// ███░███░
+/// █░███░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[74]`: `U+26C4` `"⛄"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█████░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// █░░█░░█░
+/// █░░░░░█░
+/// ░█████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[75]`: `U+26C5` `"⛅"`
+///
+/// ```text
+/// ░░░░░░░░
+/// █░█░░░░░
+/// ░███░░░░
+/// ██░█████
+/// ░█░░░░░█
+/// █░░░░░░█
+/// ░███████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[76]`: `U+26D4` `"⛔"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// █░░░░░█░
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[77]`: `U+26EA` `"⛪"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█████░░
+/// ██░░░██░
+/// ██░█░██░
+/// ██░█░██░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[78]`: `U+26F3` `"⛳"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░████░░
+/// ░░█████░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░░█░░░░░
+/// ░███░░░░
+/// █████░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[79]`: `U+26F5` `"⛵"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░██░░░
+/// ░░░███░░
+/// ░░░████░
+/// ░░░█░░░░
+/// ███████░
+/// ░█████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[80]`: `U+26FA` `"⛺"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░██░██░░
+/// ░██░██░░
+/// ██░░░██░
+/// ██░░░██░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `SYMBOL_UNICODE[81]`: `U+26FD` `"⛽"`
+///
+/// ```text
+/// ████░░░░
+/// █░░█░█░░
+/// █░░█░░█░
+/// ████░░█░
+/// ████░░█░
+/// ████░██░
+/// ████░░░░
+/// █████░░░
+/// ```
+pub const SYMBOL_FONTS: SymbolFonts = SymbolFonts(SYMBOL_UNICODE);
+/// Strong-typed collection wrapper for [SYMBOL_UNICODE](./constant.SYMBOL_UNICODE.html).
+pub struct SymbolFonts([FontUnicode; 82]);
+
+impl SymbolFonts {
+    /// Create a new collection of `SYMBOL_UNICODE` fonts.
+    pub fn new() -> Self {
+        SymbolFonts(SYMBOL_UNICODE)
+    }
+}
+
+impl fmt::Debug for SymbolFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(SYMBOL_UNICODE))
+    }
+}
+
+impl PartialEq for SymbolFonts {
+    fn eq(&self, other: &SymbolFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for SymbolFonts {
+    fn default() -> Self {
+        SymbolFonts::new()
+    }
+}
+
+impl UnicodeFonts for SymbolFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(82), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symbol_set_implements_default_trait_with_method_new() {
+        let symbol_set: SymbolFonts = Default::default();
+        assert_eq!(symbol_set, SymbolFonts::new());
+    }
+
+    #[test]
+    fn symbol_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(SYMBOL_FONTS, SymbolFonts::new());
+    }
+
+    #[test]
+    fn symbol_fonts_constant_wraps_symbol_unicode_constant() {
+        let symbol = SymbolFonts::new();
+        assert!(symbol.0.len() == SYMBOL_UNICODE.len());
+        for (idx, font) in symbol.0.iter().enumerate() {
+            assert_eq!(font, &SYMBOL_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn symbol_set_covers_the_common_symbols() {
+        for c in "☀☁☂☃★☆☎☐☑☒♠♣♥♦♪♫⚠⚡".chars() {
+            let font = SYMBOL_FONTS.get(c).unwrap();
+            assert!(font.iter().any(|&row| row != 0));
+        }
+    }
+
+    #[test]
+    fn symbol_white_chess_pieces_differ_from_the_black_ones() {
+        for offset in 0..6 {
+            let white = ::core::char::from_u32(0x2654 + offset).unwrap();
+            let black = ::core::char::from_u32(0x265A + offset).unwrap();
+            assert_ne!(SYMBOL_FONTS.get(white).unwrap(),
+                       SYMBOL_FONTS.get(black).unwrap());
+        }
+    }
+}
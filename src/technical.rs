@@ -0,0 +1,970 @@
+//! Miscellaneous Technical. `U+2300` - `U+23FF`
+//!
+//! Keyboard, media control and power symbols, ceilings and floors, and the scan lines
+//! of terminal line drawing. `⌐` is the `MISC_LEGACY` glyph, and `⇧` is in
+//! `ARROW_FONTS`.
+use super::{legacy::MISC_LEGACY, unicode::{FontUnicode, UnicodeFonts}};
+use core::fmt;
+
+/// A constant `[FontUnicode; 60]`, for Miscellaneous Technical fonts (`U+2300` - `U+23FF`).
+pub const TECHNICAL_UNICODE: [FontUnicode; 60] = [FontUnicode('\u{2300}', [0x20, 0x3C, 0x32, 0x2A, 0x26, 0x1E, 0x02, 0x00]),
+                                                  FontUnicode('\u{2302}', [0x08, 0x14, 0x22, 0x41, 0x41, 0x41, 0x7F, 0x00]),
+                                                  FontUnicode('\u{2303}', [0x08, 0x14, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{2304}', [0x22, 0x14, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{2305}', [0x7F, 0x00, 0x08, 0x14, 0x22, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{2306}', [0x7F, 0x00, 0x7F, 0x00, 0x08, 0x14, 0x22, 0x00]),
+                                                  FontUnicode('\u{2308}', [0x3C, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x00]),
+                                                  FontUnicode('\u{2309}', [0x1E, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x00]),
+                                                  FontUnicode('\u{230A}', [0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x3C, 0x00]),
+                                                  FontUnicode('\u{230B}', [0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x1E, 0x00]),
+                                                  FontUnicode('\u{2310}', MISC_LEGACY[4]),
+                                                  FontUnicode('\u{2312}', [0x00, 0x00, 0x1C, 0x22, 0x41, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{2313}', [0x00, 0x00, 0x1C, 0x22, 0x41, 0x7F, 0x00, 0x00]),
+                                                  FontUnicode('\u{2318}', [0x36, 0x49, 0x3E, 0x14, 0x3E, 0x49, 0x36, 0x00]),
+                                                  FontUnicode('\u{2319}', [0x00, 0x00, 0x00, 0x03, 0x03, 0x7F, 0x00, 0x00]),
+                                                  FontUnicode('\u{231A}', [0x1C, 0x1C, 0x22, 0x2A, 0x32, 0x22, 0x1C, 0x1C]),
+                                                  FontUnicode('\u{231B}', [0x7F, 0x22, 0x14, 0x08, 0x14, 0x2A, 0x7F, 0x00]),
+                                                  FontUnicode('\u{231C}', [0x0F, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{231D}', [0x78, 0x40, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{231E}', [0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x0F, 0x00]),
+                                                  FontUnicode('\u{231F}', [0x00, 0x00, 0x00, 0x00, 0x40, 0x40, 0x78, 0x00]),
+                                                  FontUnicode('\u{2320}', [0x30, 0xD8, 0xD8, 0x18, 0x18, 0x18, 0x18, 0x18]),
+                                                  FontUnicode('\u{2321}', [0x18, 0x18, 0x18, 0x18, 0x1B, 0x1B, 0x0E, 0x00]),
+                                                  FontUnicode('\u{2325}', [0x00, 0xE7, 0x08, 0x08, 0x10, 0x70, 0x00, 0x00]),
+                                                  FontUnicode('\u{2326}', [0x1F, 0x31, 0x55, 0x89, 0x55, 0x31, 0x1F, 0x00]),
+                                                  FontUnicode('\u{2327}', [0x7F, 0x63, 0x55, 0x49, 0x55, 0x63, 0x7F, 0x00]),
+                                                  FontUnicode('\u{2328}', [0x00, 0xFF, 0xD5, 0xAB, 0xD5, 0x81, 0xFF, 0x00]),
+                                                  FontUnicode('\u{2329}', [0x10, 0x08, 0x04, 0x02, 0x04, 0x08, 0x10, 0x00]),
+                                                  FontUnicode('\u{232A}', [0x04, 0x08, 0x10, 0x20, 0x10, 0x08, 0x04, 0x00]),
+                                                  FontUnicode('\u{232B}', [0xF8, 0x8C, 0xAA, 0x91, 0xAA, 0x8C, 0xF8, 0x00]),
+                                                  FontUnicode('\u{2387}', [0x00, 0xE7, 0x08, 0x08, 0x10, 0xF0, 0x00, 0x00]),
+                                                  FontUnicode('\u{238B}', [0x0F, 0x03, 0x05, 0x89, 0x90, 0xA0, 0x38, 0x00]),
+                                                  FontUnicode('\u{23BA}', [0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{23BB}', [0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{23BC}', [0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00]),
+                                                  FontUnicode('\u{23BD}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF]),
+                                                  FontUnicode('\u{23CE}', [0x40, 0x40, 0x44, 0x46, 0x7F, 0x06, 0x04, 0x00]),
+                                                  FontUnicode('\u{23CF}', [0x08, 0x1C, 0x3E, 0x7F, 0x00, 0x7F, 0x7F, 0x00]),
+                                                  FontUnicode('\u{23E9}', [0x11, 0x33, 0x77, 0xFF, 0x77, 0x33, 0x11, 0x00]),
+                                                  FontUnicode('\u{23EA}', [0x88, 0xCC, 0xEE, 0xFF, 0xEE, 0xCC, 0x88, 0x00]),
+                                                  FontUnicode('\u{23EB}', [0x08, 0x1C, 0x3E, 0x08, 0x1C, 0x3E, 0x7F, 0x00]),
+                                                  FontUnicode('\u{23EC}', [0x7F, 0x3E, 0x1C, 0x08, 0x3E, 0x1C, 0x08, 0x00]),
+                                                  FontUnicode('\u{23ED}', [0x51, 0xB3, 0xF7, 0xFF, 0xF7, 0xB3, 0x51, 0x00]),
+                                                  FontUnicode('\u{23EE}', [0x8A, 0xCD, 0xEF, 0xFF, 0xEF, 0xCD, 0x8A, 0x00]),
+                                                  FontUnicode('\u{23EF}', [0x51, 0x53, 0x57, 0xAF, 0x57, 0x53, 0x51, 0x00]),
+                                                  FontUnicode('\u{23F0}', [0x63, 0x5D, 0x22, 0x2A, 0x32, 0x22, 0x1C, 0x22]),
+                                                  FontUnicode('\u{23F1}', [0x1C, 0x08, 0x3E, 0x49, 0x59, 0x41, 0x3E, 0x00]),
+                                                  FontUnicode('\u{23F2}', [0x1C, 0x22, 0x41, 0x49, 0x59, 0x41, 0x3E, 0x00]),
+                                                  FontUnicode('\u{23F3}', [0x7F, 0x22, 0x1C, 0x08, 0x14, 0x3E, 0x7F, 0x00]),
+                                                  FontUnicode('\u{23F4}', [0x00, 0x10, 0x18, 0x1C, 0x18, 0x10, 0x00, 0x00]),
+                                                  FontUnicode('\u{23F5}', [0x00, 0x04, 0x0C, 0x1C, 0x0C, 0x04, 0x00, 0x00]),
+                                                  FontUnicode('\u{23F6}', [0x00, 0x00, 0x08, 0x1C, 0x3E, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{23F7}', [0x00, 0x00, 0x3E, 0x1C, 0x08, 0x00, 0x00, 0x00]),
+                                                  FontUnicode('\u{23F8}', [0x00, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x00]),
+                                                  FontUnicode('\u{23F9}', [0x00, 0x3F, 0x3F, 0x3F, 0x3F, 0x3F, 0x3F, 0x00]),
+                                                  FontUnicode('\u{23FA}', [0x00, 0x0C, 0x1E, 0x3F, 0x3F, 0x1E, 0x0C, 0x00]),
+                                                  FontUnicode('\u{23FB}', [0x08, 0x2A, 0x49, 0x49, 0x41, 0x22, 0x1C, 0x00]),
+                                                  FontUnicode('\u{23FC}', [0x08, 0x3E, 0x6B, 0x49, 0x41, 0x63, 0x3E, 0x00]),
+                                                  FontUnicode('\u{23FD}', [0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00]),
+                                                  FontUnicode('\u{23FE}', [0x1C, 0x06, 0x03, 0x03, 0x03, 0x06, 0x1C, 0x00])];
+
+/// A convenient constant for Miscellaneous Technical fonts (`U+2300` - `U+23FF`), that implements the `UnicodeFonts` trait.
+///
+/// ## `TECHNICAL_UNICODE[0]`: `U+2300` `"⌀"`
+///
+/// ```text
+/// ░░░░░█░░
+/// ░░████░░
+/// ░█░░██░░
+/// ░█░█░█░░
+/// ░██░░█░░
+/// ░████░░░
+/// ░█░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[1]`: `U+2302` `"⌂"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// █░░░░░█░
+/// █░░░░░█░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[2]`: `U+2303` `"⌃"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[3]`: `U+2304` `"⌄"`
+///
+/// ```text
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[4]`: `U+2305` `"⌅"`
+///
+/// ```text
+/// ███████░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[5]`: `U+2306` `"⌆"`
+///
+/// ```text
+/// ███████░
+/// ░░░░░░░░
+/// ███████░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[6]`: `U+2308` `"⌈"`
+///
+/// ```text
+/// ░░████░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[7]`: `U+2309` `"⌉"`
+///
+/// ```text
+/// ░████░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[8]`: `U+230A` `"⌊"`
+///
+/// ```text
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░██░░░░
+/// ░░████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[9]`: `U+230B` `"⌋"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[10]`: `U+2310` `"⌐"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██████░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[11]`: `U+2312` `"⌒"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[12]`: `U+2313` `"⌓"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// ███████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[13]`: `U+2318` `"⌘"`
+///
+/// ```text
+/// ░██░██░░
+/// █░░█░░█░
+/// ░█████░░
+/// ░░█░█░░░
+/// ░█████░░
+/// █░░█░░█░
+/// ░██░██░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[14]`: `U+2319` `"⌙"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ███████░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[15]`: `U+231A` `"⌚"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░███░░░
+/// ░█░░░█░░
+/// ░█░█░█░░
+/// ░█░░██░░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░███░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[16]`: `U+231B` `"⌛"`
+///
+/// ```text
+/// ███████░
+/// ░█░░░█░░
+/// ░░█░█░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█░█░█░░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[17]`: `U+231C` `"⌜"`
+///
+/// ```text
+/// ████░░░░
+/// █░░░░░░░
+/// █░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[18]`: `U+231D` `"⌝"`
+///
+/// ```text
+/// ░░░████░
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[19]`: `U+231E` `"⌞"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░░░░░░░
+/// █░░░░░░░
+/// ████░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[20]`: `U+231F` `"⌟"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░░████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[21]`: `U+2320` `"⌠"`
+///
+/// ```text
+/// ░░░░██░░
+/// ░░░██░██
+/// ░░░██░██
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[22]`: `U+2321` `"⌡"`
+///
+/// ```text
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ░░░██░░░
+/// ██░██░░░
+/// ██░██░░░
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[23]`: `U+2325` `"⌥"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░░███
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░░░███░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[24]`: `U+2326` `"⌦"`
+///
+/// ```text
+/// █████░░░
+/// █░░░██░░
+/// █░█░█░█░
+/// █░░█░░░█
+/// █░█░█░█░
+/// █░░░██░░
+/// █████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[25]`: `U+2327` `"⌧"`
+///
+/// ```text
+/// ███████░
+/// ██░░░██░
+/// █░█░█░█░
+/// █░░█░░█░
+/// █░█░█░█░
+/// ██░░░██░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[26]`: `U+2328` `"⌨"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ████████
+/// █░█░█░██
+/// ██░█░█░█
+/// █░█░█░██
+/// █░░░░░░█
+/// ████████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[27]`: `U+2329` `"〈"`
+///
+/// ```text
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░█░░░░░░
+/// ░░█░░░░░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[28]`: `U+232A` `"〉"`
+///
+/// ```text
+/// ░░█░░░░░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░░░░█░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[29]`: `U+232B` `"⌫"`
+///
+/// ```text
+/// ░░░█████
+/// ░░██░░░█
+/// ░█░█░█░█
+/// █░░░█░░█
+/// ░█░█░█░█
+/// ░░██░░░█
+/// ░░░█████
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[30]`: `U+2387` `"⎇"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ███░░███
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░░█░░░
+/// ░░░░████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[31]`: `U+238B` `"⎋"`
+///
+/// ```text
+/// ████░░░░
+/// ██░░░░░░
+/// █░█░░░░░
+/// █░░█░░░█
+/// ░░░░█░░█
+/// ░░░░░█░█
+/// ░░░███░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[32]`: `U+23BA` `"⎺"`
+///
+/// ```text
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[33]`: `U+23BB` `"⎻"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[34]`: `U+23BC` `"⎼"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[35]`: `U+23BD` `"⎽"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ████████
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[36]`: `U+23CE` `"⏎"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░█░░░█░
+/// ░██░░░█░
+/// ███████░
+/// ░██░░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[37]`: `U+23CF` `"⏏"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// ░░░░░░░░
+/// ███████░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[38]`: `U+23E9` `"⏩"`
+///
+/// ```text
+/// █░░░█░░░
+/// ██░░██░░
+/// ███░███░
+/// ████████
+/// ███░███░
+/// ██░░██░░
+/// █░░░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[39]`: `U+23EA` `"⏪"`
+///
+/// ```text
+/// ░░░█░░░█
+/// ░░██░░██
+/// ░███░███
+/// ████████
+/// ░███░███
+/// ░░██░░██
+/// ░░░█░░░█
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[40]`: `U+23EB` `"⏫"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█████░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█████░░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[41]`: `U+23EC` `"⏬"`
+///
+/// ```text
+/// ███████░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[42]`: `U+23ED` `"⏭"`
+///
+/// ```text
+/// █░░░█░█░
+/// ██░░██░█
+/// ███░████
+/// ████████
+/// ███░████
+/// ██░░██░█
+/// █░░░█░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[43]`: `U+23EE` `"⏮"`
+///
+/// ```text
+/// ░█░█░░░█
+/// █░██░░██
+/// ████░███
+/// ████████
+/// ████░███
+/// █░██░░██
+/// ░█░█░░░█
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[44]`: `U+23EF` `"⏯"`
+///
+/// ```text
+/// █░░░█░█░
+/// ██░░█░█░
+/// ███░█░█░
+/// ████░█░█
+/// ███░█░█░
+/// ██░░█░█░
+/// █░░░█░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[45]`: `U+23F0` `"⏰"`
+///
+/// ```text
+/// ██░░░██░
+/// █░███░█░
+/// ░█░░░█░░
+/// ░█░█░█░░
+/// ░█░░██░░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░█░░░█░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[46]`: `U+23F1` `"⏱"`
+///
+/// ```text
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░█████░░
+/// █░░█░░█░
+/// █░░██░█░
+/// █░░░░░█░
+/// ░█████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[47]`: `U+23F2` `"⏲"`
+///
+/// ```text
+/// ░░███░░░
+/// ░█░░░█░░
+/// █░░░░░█░
+/// █░░█░░█░
+/// █░░██░█░
+/// █░░░░░█░
+/// ░█████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[48]`: `U+23F3` `"⏳"`
+///
+/// ```text
+/// ███████░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░█░█░░░
+/// ░█████░░
+/// ███████░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[49]`: `U+23F4` `"⏴"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░█░░░
+/// ░░░██░░░
+/// ░░███░░░
+/// ░░░██░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[50]`: `U+23F5` `"⏵"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░█░░░░░
+/// ░░██░░░░
+/// ░░███░░░
+/// ░░██░░░░
+/// ░░█░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[51]`: `U+23F6` `"⏶"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ░░███░░░
+/// ░█████░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[52]`: `U+23F7` `"⏷"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█████░░
+/// ░░███░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[53]`: `U+23F8` `"⏸"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░██░██░░
+/// ░██░██░░
+/// ░██░██░░
+/// ░██░██░░
+/// ░██░██░░
+/// ░██░██░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[54]`: `U+23F9` `"⏹"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ██████░░
+/// ██████░░
+/// ██████░░
+/// ██████░░
+/// ██████░░
+/// ██████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[55]`: `U+23FA` `"⏺"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░██░░░░
+/// ░████░░░
+/// ██████░░
+/// ██████░░
+/// ░████░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[56]`: `U+23FB` `"⏻"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░█░█░█░░
+/// █░░█░░█░
+/// █░░█░░█░
+/// █░░░░░█░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[57]`: `U+23FC` `"⏼"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░█████░░
+/// ██░█░██░
+/// █░░█░░█░
+/// █░░░░░█░
+/// ██░░░██░
+/// ░█████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[58]`: `U+23FD` `"⏽"`
+///
+/// ```text
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `TECHNICAL_UNICODE[59]`: `U+23FE` `"⏾"`
+///
+/// ```text
+/// ░░███░░░
+/// ░██░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ░██░░░░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+pub const TECHNICAL_FONTS: TechnicalFonts = TechnicalFonts(TECHNICAL_UNICODE);
+/// Strong-typed collection wrapper for [TECHNICAL_UNICODE](./constant.TECHNICAL_UNICODE.html).
+pub struct TechnicalFonts([FontUnicode; 60]);
+
+impl TechnicalFonts {
+    /// Create a new collection of `TECHNICAL_UNICODE` fonts.
+    pub fn new() -> Self {
+        TechnicalFonts(TECHNICAL_UNICODE)
+    }
+}
+
+impl fmt::Debug for TechnicalFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(TECHNICAL_UNICODE))
+    }
+}
+
+impl PartialEq for TechnicalFonts {
+    fn eq(&self, other: &TechnicalFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for TechnicalFonts {
+    fn default() -> Self {
+        TechnicalFonts::new()
+    }
+}
+
+impl UnicodeFonts for TechnicalFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(60), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn technical_set_implements_default_trait_with_method_new() {
+        let technical_set: TechnicalFonts = Default::default();
+        assert_eq!(technical_set, TechnicalFonts::new());
+    }
+
+    #[test]
+    fn technical_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(TECHNICAL_FONTS, TechnicalFonts::new());
+    }
+
+    #[test]
+    fn technical_fonts_constant_wraps_technical_unicode_constant() {
+        let technical = TechnicalFonts::new();
+        assert!(technical.0.len() == TECHNICAL_UNICODE.len());
+        for (idx, font) in technical.0.iter().enumerate() {
+            assert_eq!(font, &TECHNICAL_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn technical_set_covers_the_keyboard_hints() {
+        for key in "⌘⌥⌫⌦⏎⎋⏏⏻⏸⏯⏹⏺⌚⌛⏰".chars() {
+            assert!(TECHNICAL_FONTS.get(key).is_some(), "{:?}", key);
+        }
+    }
+
+    #[test]
+    fn technical_set_keeps_the_legacy_reversed_not_sign() {
+        assert_eq!(TECHNICAL_FONTS.get('⌐'), Some(MISC_LEGACY[4]));
+    }
+}
@@ -0,0 +1,1517 @@
+//! Thai. `U+0E00` - `U+0E7F`
+//!
+//! The bodies of consonants and spacing vowels are drawn in rows 2 to 6 of the cell, with
+//! the ascenders of `ป ฝ ฟ ฬ` reaching into row 1, and the descenders of `ญ ฎ ฏ ฐ` into
+//! row 7. Combining vowels and tone marks are drawn in place, above (rows 0 and 1) or
+//! below (row 7) the body. Vowels above stay on the left of the cell, and tone marks on
+//! the right, so that both can be stacked over the same consonant.
+//!
+//! [ThaiFonts::overlay](./struct.ThaiFonts.html#method.overlay) draws the marks of a
+//! cluster onto its base, moving marks above to the left of an ascender, and the base up
+//! over marks below that would run into a descender.
+//!
+//! Use [thai_clusters](./fn.thai_clusters.html) to split text into clusters, and
+//! [ThaiFonts::get_cluster](./struct.ThaiFonts.html#method.get_cluster) to render them.
+use super::combining::{ink_columns, ink_rows, moved};
+use super::{FontUnicode, UnicodeFonts};
+use core::fmt;
+
+/// A constant `[FontUnicode; 87]`, for Thai fonts (`U+0E01` - `U+0E5B`).
+pub const THAI_UNICODE: [FontUnicode; 87] = [FontUnicode('\u{0E01}', [0x00, 0x00, 0x1E, 0x21, 0x22, 0x22, 0x22, 0x00]),
+                                             FontUnicode('\u{0E02}', [0x00, 0x00, 0x23, 0x32, 0x22, 0x22, 0x1C, 0x00]),
+                                             FontUnicode('\u{0E03}', [0x00, 0x00, 0x25, 0x33, 0x22, 0x22, 0x1C, 0x00]),
+                                             FontUnicode('\u{0E04}', [0x00, 0x00, 0x1E, 0x23, 0x23, 0x22, 0x22, 0x00]),
+                                             FontUnicode('\u{0E05}', [0x00, 0x00, 0x1A, 0x25, 0x23, 0x22, 0x22, 0x00]),
+                                             FontUnicode('\u{0E06}', [0x00, 0x00, 0x23, 0x22, 0x2A, 0x36, 0x62, 0x00]),
+                                             FontUnicode('\u{0E07}', [0x00, 0x00, 0x20, 0x20, 0x23, 0x12, 0x0C, 0x00]),
+                                             FontUnicode('\u{0E08}', [0x00, 0x00, 0x1E, 0x20, 0x2C, 0x24, 0x38, 0x00]),
+                                             FontUnicode('\u{0E09}', [0x00, 0x00, 0x1E, 0x21, 0x23, 0x2A, 0x34, 0x00]),
+                                             FontUnicode('\u{0E0A}', [0x00, 0x00, 0x63, 0x22, 0x22, 0x22, 0x1C, 0x00]),
+                                             FontUnicode('\u{0E0B}', [0x00, 0x00, 0x65, 0x23, 0x22, 0x22, 0x1C, 0x00]),
+                                             FontUnicode('\u{0E0C}', [0x00, 0x00, 0x4B, 0x4A, 0x4A, 0x56, 0x72, 0x00]),
+                                             FontUnicode('\u{0E0D}', [0x00, 0x00, 0x23, 0x22, 0x22, 0x2A, 0x34, 0x70]),
+                                             FontUnicode('\u{0E0E}', [0x00, 0x00, 0x1E, 0x21, 0x22, 0x23, 0x23, 0x15]),
+                                             FontUnicode('\u{0E0F}', [0x00, 0x00, 0x1E, 0x21, 0x22, 0x23, 0x23, 0x1A]),
+                                             FontUnicode('\u{0E10}', [0x00, 0x00, 0x1E, 0x01, 0x0E, 0x10, 0x0E, 0x15]),
+                                             FontUnicode('\u{0E11}', [0x00, 0x00, 0x25, 0x2B, 0x2A, 0x32, 0x22, 0x00]),
+                                             FontUnicode('\u{0E12}', [0x00, 0x00, 0x2B, 0x2A, 0x2A, 0x36, 0x22, 0x00]),
+                                             FontUnicode('\u{0E13}', [0x00, 0x00, 0x0E, 0x11, 0x53, 0x52, 0x72, 0x00]),
+                                             FontUnicode('\u{0E14}', [0x00, 0x00, 0x1E, 0x21, 0x25, 0x29, 0x26, 0x00]),
+                                             FontUnicode('\u{0E15}', [0x00, 0x00, 0x1A, 0x25, 0x25, 0x29, 0x26, 0x00]),
+                                             FontUnicode('\u{0E16}', [0x00, 0x00, 0x1E, 0x21, 0x22, 0x23, 0x23, 0x00]),
+                                             FontUnicode('\u{0E17}', [0x00, 0x00, 0x23, 0x22, 0x26, 0x2A, 0x32, 0x00]),
+                                             FontUnicode('\u{0E18}', [0x00, 0x00, 0x2D, 0x23, 0x2E, 0x30, 0x1E, 0x00]),
+                                             FontUnicode('\u{0E19}', [0x00, 0x00, 0x03, 0x22, 0x22, 0x32, 0x4E, 0x00]),
+                                             FontUnicode('\u{0E1A}', [0x00, 0x00, 0x23, 0x22, 0x22, 0x22, 0x3E, 0x00]),
+                                             FontUnicode('\u{0E1B}', [0x00, 0x20, 0x23, 0x22, 0x22, 0x22, 0x3E, 0x00]),
+                                             FontUnicode('\u{0E1C}', [0x00, 0x00, 0x23, 0x22, 0x2A, 0x2A, 0x14, 0x00]),
+                                             FontUnicode('\u{0E1D}', [0x00, 0x20, 0x23, 0x22, 0x2A, 0x2A, 0x14, 0x00]),
+                                             FontUnicode('\u{0E1E}', [0x00, 0x00, 0x2B, 0x2A, 0x2A, 0x2A, 0x14, 0x00]),
+                                             FontUnicode('\u{0E1F}', [0x00, 0x20, 0x2B, 0x2A, 0x2A, 0x2A, 0x14, 0x00]),
+                                             FontUnicode('\u{0E20}', [0x00, 0x00, 0x1E, 0x21, 0x26, 0x25, 0x23, 0x00]),
+                                             FontUnicode('\u{0E21}', [0x00, 0x00, 0x22, 0x22, 0x22, 0x2B, 0x33, 0x00]),
+                                             FontUnicode('\u{0E22}', [0x00, 0x00, 0x23, 0x22, 0x2E, 0x32, 0x3E, 0x00]),
+                                             FontUnicode('\u{0E23}', [0x00, 0x00, 0x3E, 0x01, 0x1E, 0x20, 0x1E, 0x00]),
+                                             FontUnicode('\u{0E24}', [0x00, 0x00, 0x1E, 0x21, 0x22, 0x23, 0x23, 0x20]),
+                                             FontUnicode('\u{0E25}', [0x00, 0x00, 0x1E, 0x21, 0x2C, 0x2A, 0x26, 0x00]),
+                                             FontUnicode('\u{0E26}', [0x00, 0x00, 0x1E, 0x21, 0x26, 0x25, 0x23, 0x20]),
+                                             FontUnicode('\u{0E27}', [0x00, 0x00, 0x1E, 0x20, 0x20, 0x23, 0x33, 0x00]),
+                                             FontUnicode('\u{0E28}', [0x00, 0x00, 0x5E, 0x23, 0x23, 0x22, 0x22, 0x00]),
+                                             FontUnicode('\u{0E29}', [0x00, 0x00, 0x23, 0x2A, 0x32, 0x22, 0x3E, 0x00]),
+                                             FontUnicode('\u{0E2A}', [0x00, 0x00, 0x5E, 0x21, 0x2C, 0x2A, 0x26, 0x00]),
+                                             FontUnicode('\u{0E2B}', [0x00, 0x00, 0x23, 0x22, 0x2A, 0x36, 0x22, 0x00]),
+                                             FontUnicode('\u{0E2C}', [0x00, 0x20, 0x2B, 0x2A, 0x2A, 0x36, 0x22, 0x00]),
+                                             FontUnicode('\u{0E2D}', [0x00, 0x00, 0x1E, 0x21, 0x26, 0x26, 0x1C, 0x00]),
+                                             FontUnicode('\u{0E2E}', [0x00, 0x00, 0x1A, 0x25, 0x26, 0x26, 0x1C, 0x00]),
+                                             FontUnicode('\u{0E2F}', [0x00, 0x00, 0x03, 0x12, 0x1A, 0x16, 0x10, 0x00]),
+                                             FontUnicode('\u{0E30}', [0x00, 0x00, 0x00, 0x03, 0x02, 0x03, 0x02, 0x00]),
+                                             FontUnicode('\u{0E31}', [0x01, 0x0E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{0E32}', [0x00, 0x00, 0x0E, 0x10, 0x10, 0x10, 0x10, 0x00]),
+                                             FontUnicode('\u{0E33}', [0x03, 0x03, 0x1C, 0x20, 0x20, 0x20, 0x20, 0x00]),
+                                             FontUnicode('\u{0E34}', [0x0E, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{0E35}', [0x16, 0x19, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{0E36}', [0x0E, 0x19, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{0E37}', [0x0A, 0x1D, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{0E38}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18]),
+                                             FontUnicode('\u{0E39}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x36]),
+                                             FontUnicode('\u{0E3A}', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08]),
+                                             FontUnicode('\u{0E3F}', [0x00, 0x02, 0x0F, 0x15, 0x0F, 0x15, 0x0F, 0x02]),
+                                             FontUnicode('\u{0E40}', [0x00, 0x00, 0x02, 0x02, 0x02, 0x03, 0x03, 0x00]),
+                                             FontUnicode('\u{0E41}', [0x00, 0x00, 0x12, 0x12, 0x12, 0x1B, 0x1B, 0x00]),
+                                             FontUnicode('\u{0E42}', [0x0F, 0x01, 0x02, 0x02, 0x02, 0x03, 0x03, 0x00]),
+                                             FontUnicode('\u{0E43}', [0x06, 0x09, 0x0E, 0x02, 0x02, 0x03, 0x03, 0x00]),
+                                             FontUnicode('\u{0E44}', [0x09, 0x06, 0x02, 0x02, 0x02, 0x03, 0x03, 0x00]),
+                                             FontUnicode('\u{0E45}', [0x00, 0x00, 0x0E, 0x10, 0x10, 0x10, 0x10, 0x10]),
+                                             FontUnicode('\u{0E46}', [0x00, 0x00, 0x16, 0x19, 0x12, 0x12, 0x10, 0x00]),
+                                             FontUnicode('\u{0E47}', [0x06, 0x0B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{0E48}', [0x40, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{0E49}', [0xC0, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{0E4A}', [0xA0, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{0E4B}', [0x40, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{0E4C}', [0x60, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{0E4D}', [0x06, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{0E4E}', [0x14, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                                             FontUnicode('\u{0E4F}', [0x00, 0x00, 0x0E, 0x11, 0x15, 0x11, 0x0E, 0x00]),
+                                             FontUnicode('\u{0E50}', [0x00, 0x00, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00]),
+                                             FontUnicode('\u{0E51}', [0x00, 0x00, 0x0E, 0x11, 0x15, 0x13, 0x10, 0x00]),
+                                             FontUnicode('\u{0E52}', [0x00, 0x00, 0x11, 0x13, 0x12, 0x12, 0x0C, 0x00]),
+                                             FontUnicode('\u{0E53}', [0x00, 0x00, 0x0A, 0x15, 0x13, 0x12, 0x12, 0x00]),
+                                             FontUnicode('\u{0E54}', [0x00, 0x00, 0x4E, 0x31, 0x14, 0x16, 0x0E, 0x00]),
+                                             FontUnicode('\u{0E55}', [0x00, 0x00, 0x4A, 0x35, 0x14, 0x16, 0x0E, 0x00]),
+                                             FontUnicode('\u{0E56}', [0x00, 0x00, 0x01, 0x0E, 0x10, 0x13, 0x0B, 0x00]),
+                                             FontUnicode('\u{0E57}', [0x00, 0x00, 0x11, 0x11, 0x15, 0x15, 0x0A, 0x00]),
+                                             FontUnicode('\u{0E58}', [0x00, 0x00, 0x03, 0x1A, 0x26, 0x10, 0x08, 0x00]),
+                                             FontUnicode('\u{0E59}', [0x00, 0x00, 0x43, 0x42, 0x5A, 0x26, 0x02, 0x00]),
+                                             FontUnicode('\u{0E5A}', [0x00, 0x00, 0x23, 0x32, 0x2A, 0x26, 0x20, 0x00]),
+                                             FontUnicode('\u{0E5B}', [0x00, 0x00, 0x00, 0x03, 0x32, 0x4E, 0x80, 0x00])];
+
+/// A convenient constant for Thai fonts (`U+0E01` - `U+0E5B`), that implements the `UnicodeFonts` trait.
+///
+/// ## `THAI_UNICODE[0]`: `U+0E01` `"ก"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// █░░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[1]`: `U+0E02` `"ข"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░█░░
+/// ░█░░██░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[2]`: `U+0E03` `"ฃ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░█░░█░░
+/// ██░░██░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[3]`: `U+0E04` `"ค"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// ██░░░█░░
+/// ██░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[4]`: `U+0E05` `"ฅ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░██░░░
+/// █░█░░█░░
+/// ██░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[5]`: `U+0E06` `"ฆ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░█░░
+/// ░█░░░█░░
+/// ░█░█░█░░
+/// ░██░██░░
+/// ░█░░░██░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[6]`: `U+0E07` `"ง"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ██░░░█░░
+/// ░█░░█░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[7]`: `U+0E08` `"จ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// ░░░░░█░░
+/// ░░██░█░░
+/// ░░█░░█░░
+/// ░░░███░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[8]`: `U+0E09` `"ฉ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// █░░░░█░░
+/// ██░░░█░░
+/// ░█░█░█░░
+/// ░░█░██░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[9]`: `U+0E0A` `"ช"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░██░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[10]`: `U+0E0B` `"ซ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░█░░██░
+/// ██░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[11]`: `U+0E0C` `"ฌ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░█░░█░
+/// ░█░█░░█░
+/// ░█░█░░█░
+/// ░██░█░█░
+/// ░█░░███░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[12]`: `U+0E0D` `"ญ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░█░█░░
+/// ░░█░██░░
+/// ░░░░███░
+/// ```
+///
+/// ## `THAI_UNICODE[13]`: `U+0E0E` `"ฎ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// █░░░░█░░
+/// ░█░░░█░░
+/// ██░░░█░░
+/// ██░░░█░░
+/// █░█░█░░░
+/// ```
+///
+/// ## `THAI_UNICODE[14]`: `U+0E0F` `"ฏ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// █░░░░█░░
+/// ░█░░░█░░
+/// ██░░░█░░
+/// ██░░░█░░
+/// ░█░██░░░
+/// ```
+///
+/// ## `THAI_UNICODE[15]`: `U+0E10` `"ฐ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// █░░░░░░░
+/// ░███░░░░
+/// ░░░░█░░░
+/// ░███░░░░
+/// █░█░█░░░
+/// ```
+///
+/// ## `THAI_UNICODE[16]`: `U+0E11` `"ฑ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░█░░█░░
+/// ██░█░█░░
+/// ░█░█░█░░
+/// ░█░░██░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[17]`: `U+0E12` `"ฒ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░█░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░██░██░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[18]`: `U+0E13` `"ณ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░███░░░░
+/// █░░░█░░░
+/// ██░░█░█░
+/// ░█░░█░█░
+/// ░█░░███░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[19]`: `U+0E14` `"ด"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// █░░░░█░░
+/// █░█░░█░░
+/// █░░█░█░░
+/// ░██░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[20]`: `U+0E15` `"ต"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░██░░░
+/// █░█░░█░░
+/// █░█░░█░░
+/// █░░█░█░░
+/// ░██░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[21]`: `U+0E16` `"ถ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// █░░░░█░░
+/// ░█░░░█░░
+/// ██░░░█░░
+/// ██░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[22]`: `U+0E17` `"ท"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░█░░
+/// ░█░░░█░░
+/// ░██░░█░░
+/// ░█░█░█░░
+/// ░█░░██░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[23]`: `U+0E18` `"ธ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░██░█░░
+/// ██░░░█░░
+/// ░███░█░░
+/// ░░░░██░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[24]`: `U+0E19` `"น"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░░░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░██░░
+/// ░███░░█░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[25]`: `U+0E1A` `"บ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[26]`: `U+0E1B` `"ป"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ██░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[27]`: `U+0E1C` `"ผ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░█░░
+/// ░█░░░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[28]`: `U+0E1D` `"ฝ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ██░░░█░░
+/// ░█░░░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[29]`: `U+0E1E` `"พ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░█░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[30]`: `U+0E1F` `"ฟ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ██░█░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░░█░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[31]`: `U+0E20` `"ภ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// █░░░░█░░
+/// ░██░░█░░
+/// █░█░░█░░
+/// ██░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[32]`: `U+0E21` `"ม"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ██░█░█░░
+/// ██░░██░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[33]`: `U+0E22` `"ย"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░█░░
+/// ░█░░░█░░
+/// ░███░█░░
+/// ░█░░██░░
+/// ░█████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[34]`: `U+0E23` `"ร"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█████░░
+/// █░░░░░░░
+/// ░████░░░
+/// ░░░░░█░░
+/// ░████░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[35]`: `U+0E24` `"ฤ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// █░░░░█░░
+/// ░█░░░█░░
+/// ██░░░█░░
+/// ██░░░█░░
+/// ░░░░░█░░
+/// ```
+///
+/// ## `THAI_UNICODE[36]`: `U+0E25` `"ล"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// █░░░░█░░
+/// ░░██░█░░
+/// ░█░█░█░░
+/// ░██░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[37]`: `U+0E26` `"ฦ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// █░░░░█░░
+/// ░██░░█░░
+/// █░█░░█░░
+/// ██░░░█░░
+/// ░░░░░█░░
+/// ```
+///
+/// ## `THAI_UNICODE[38]`: `U+0E27` `"ว"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ██░░░█░░
+/// ██░░██░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[39]`: `U+0E28` `"ศ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░█░
+/// ██░░░█░░
+/// ██░░░█░░
+/// ░█░░░█░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[40]`: `U+0E29` `"ษ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░█░░
+/// ░█░█░█░░
+/// ░█░░██░░
+/// ░█░░░█░░
+/// ░█████░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[41]`: `U+0E2A` `"ส"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░█░
+/// █░░░░█░░
+/// ░░██░█░░
+/// ░█░█░█░░
+/// ░██░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[42]`: `U+0E2B` `"ห"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░█░░
+/// ░█░░░█░░
+/// ░█░█░█░░
+/// ░██░██░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[43]`: `U+0E2C` `"ฬ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░█░░
+/// ██░█░█░░
+/// ░█░█░█░░
+/// ░█░█░█░░
+/// ░██░██░░
+/// ░█░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[44]`: `U+0E2D` `"อ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░████░░░
+/// █░░░░█░░
+/// ░██░░█░░
+/// ░██░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[45]`: `U+0E2E` `"ฮ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░██░░░
+/// █░█░░█░░
+/// ░██░░█░░
+/// ░██░░█░░
+/// ░░███░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[46]`: `U+0E2F` `"ฯ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░░░░
+/// ░█░░█░░░
+/// ░█░██░░░
+/// ░██░█░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[47]`: `U+0E30` `"ะ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░░░░
+/// ░█░░░░░░
+/// ██░░░░░░
+/// ░█░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[48]`: `U+0E31` `"\u{e31}"`
+///
+/// ```text
+/// █░░░░░░░
+/// ░███░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[49]`: `U+0E32` `"า"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░███░░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[50]`: `U+0E33` `"ำ"`
+///
+/// ```text
+/// ██░░░░░░
+/// ██░░░░░░
+/// ░░███░░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[51]`: `U+0E34` `"\u{e34}"`
+///
+/// ```text
+/// ░███░░░░
+/// █░░░█░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[52]`: `U+0E35` `"\u{e35}"`
+///
+/// ```text
+/// ░██░█░░░
+/// █░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[53]`: `U+0E36` `"\u{e36}"`
+///
+/// ```text
+/// ░███░░░░
+/// █░░██░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[54]`: `U+0E37` `"\u{e37}"`
+///
+/// ```text
+/// ░█░█░░░░
+/// █░███░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[55]`: `U+0E38` `"\u{e38}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░██░░░
+/// ```
+///
+/// ## `THAI_UNICODE[56]`: `U+0E39` `"\u{e39}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██░██░░
+/// ```
+///
+/// ## `THAI_UNICODE[57]`: `U+0E3A` `"\u{e3a}"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░█░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[58]`: `U+0E3F` `"฿"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░█░░░░░░
+/// ████░░░░
+/// █░█░█░░░
+/// ████░░░░
+/// █░█░█░░░
+/// ████░░░░
+/// ░█░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[59]`: `U+0E40` `"เ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[60]`: `U+0E41` `"แ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░░█░░░
+/// ░█░░█░░░
+/// ░█░░█░░░
+/// ██░██░░░
+/// ██░██░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[61]`: `U+0E42` `"โ"`
+///
+/// ```text
+/// ████░░░░
+/// █░░░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[62]`: `U+0E43` `"ใ"`
+///
+/// ```text
+/// ░██░░░░░
+/// █░░█░░░░
+/// ░███░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[63]`: `U+0E44` `"ไ"`
+///
+/// ```text
+/// █░░█░░░░
+/// ░██░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ░█░░░░░░
+/// ██░░░░░░
+/// ██░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[64]`: `U+0E45` `"ๅ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░███░░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ░░░░█░░░
+/// ```
+///
+/// ## `THAI_UNICODE[65]`: `U+0E46` `"ๆ"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░██░█░░░
+/// █░░██░░░
+/// ░█░░█░░░
+/// ░█░░█░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[66]`: `U+0E47` `"\u{e47}"`
+///
+/// ```text
+/// ░██░░░░░
+/// ██░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[67]`: `U+0E48` `"\u{e48}"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░░░█░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[68]`: `U+0E49` `"\u{e49}"`
+///
+/// ```text
+/// ░░░░░░██
+/// ░░░░░██░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[69]`: `U+0E4A` `"\u{e4a}"`
+///
+/// ```text
+/// ░░░░░█░█
+/// ░░░░░███
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[70]`: `U+0E4B` `"\u{e4b}"`
+///
+/// ```text
+/// ░░░░░░█░
+/// ░░░░░███
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[71]`: `U+0E4C` `"\u{e4c}"`
+///
+/// ```text
+/// ░░░░░██░
+/// ░░░░░░██
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[72]`: `U+0E4D` `"\u{e4d}"`
+///
+/// ```text
+/// ░██░░░░░
+/// ░██░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[73]`: `U+0E4E` `"\u{e4e}"`
+///
+/// ```text
+/// ░░█░█░░░
+/// ░█░█░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[74]`: `U+0E4F` `"๏"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░███░░░░
+/// █░░░█░░░
+/// █░█░█░░░
+/// █░░░█░░░
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[75]`: `U+0E50` `"๐"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░███░░░░
+/// █░░░█░░░
+/// █░░░█░░░
+/// █░░░█░░░
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[76]`: `U+0E51` `"๑"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░███░░░░
+/// █░░░█░░░
+/// █░█░█░░░
+/// ██░░█░░░
+/// ░░░░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[77]`: `U+0E52` `"๒"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░░░█░░░
+/// ██░░█░░░
+/// ░█░░█░░░
+/// ░█░░█░░░
+/// ░░██░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[78]`: `U+0E53` `"๓"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░█░░░░
+/// █░█░█░░░
+/// ██░░█░░░
+/// ░█░░█░░░
+/// ░█░░█░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[79]`: `U+0E54` `"๔"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░███░░█░
+/// █░░░██░░
+/// ░░█░█░░░
+/// ░██░█░░░
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[80]`: `U+0E55` `"๕"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░█░█░░█░
+/// █░█░██░░
+/// ░░█░█░░░
+/// ░██░█░░░
+/// ░███░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[81]`: `U+0E56` `"๖"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░░░░░░░
+/// ░███░░░░
+/// ░░░░█░░░
+/// ██░░█░░░
+/// ██░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[82]`: `U+0E57` `"๗"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// █░░░█░░░
+/// █░░░█░░░
+/// █░█░█░░░
+/// █░█░█░░░
+/// ░█░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[83]`: `U+0E58` `"๘"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░░░░
+/// ░█░██░░░
+/// ░██░░█░░
+/// ░░░░█░░░
+/// ░░░█░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[84]`: `U+0E59` `"๙"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░░█░
+/// ░█░░░░█░
+/// ░█░██░█░
+/// ░██░░█░░
+/// ░█░░░░░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[85]`: `U+0E5A` `"๚"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░█░░
+/// ░█░░██░░
+/// ░█░█░█░░
+/// ░██░░█░░
+/// ░░░░░█░░
+/// ░░░░░░░░
+/// ```
+///
+/// ## `THAI_UNICODE[86]`: `U+0E5B` `"๛"`
+///
+/// ```text
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ░░░░░░░░
+/// ██░░░░░░
+/// ░█░░██░░
+/// ░███░░█░
+/// ░░░░░░░█
+/// ░░░░░░░░
+/// ```
+pub const THAI_FONTS: ThaiFonts = ThaiFonts(THAI_UNICODE);
+/// Strong-typed collection wrapper for [THAI_UNICODE](./constant.THAI_UNICODE.html).
+pub struct ThaiFonts([FontUnicode; 87]);
+
+impl ThaiFonts {
+    /// Create a new collection of `THAI_UNICODE` fonts.
+    pub fn new() -> Self {
+        ThaiFonts(THAI_UNICODE)
+    }
+
+    /// Returns the glyph for a whole cluster, as returned by
+    /// [thai_clusters](./fn.thai_clusters.html), with its combining marks drawn over
+    /// the base character.
+    ///
+    /// Returns `None` if any character in the cluster is not a Thai character.
+    pub fn get_cluster(&self, cluster: &str) -> Option<[u8; 8]> {
+        let mut chars = cluster.chars();
+        let base = self.get(chars.next()?)?;
+        if chars.clone().any(|c| self.get(c).is_none()) {
+            return None;
+        }
+        Some(self.overlay(base, chars.as_str()))
+    }
+
+    /// Returns `base` with the Thai combining `marks` drawn onto it. Marks above are moved
+    /// left, as far as needed to clear the ink already in their rows, like the ascender of
+    /// `ป`. Marks below that would run into a descender, like the one of `ฎ`, go under it,
+    /// with everything drawn so far moved up into the empty rows at the top of the cell.
+    /// Any character of `marks` that is not a Thai combining mark is ignored.
+    pub fn overlay(&self, base: [u8; 8], marks: &str) -> [u8; 8] {
+        let mut glyph = base;
+        for mark in marks.chars().filter(|&c| is_thai_combining(c)) {
+            let mark = match self.get(mark) {
+                Some(mark) => mark,
+                None => continue,
+            };
+            let mark = match ink_rows(&mark) {
+                Some((top, _)) if top > 1 => {
+                    while overlaps(&glyph, &mark) && glyph[0] == 0 {
+                        glyph = moved(&glyph, -1, 0);
+                    }
+                    mark
+                }
+                Some(_) => clear_of(&glyph, &mark),
+                None => continue,
+            };
+            for (row, ink) in glyph.iter_mut().zip(mark.iter()) {
+                *row |= ink;
+            }
+        }
+        glyph
+    }
+}
+
+impl fmt::Debug for ThaiFonts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, stringify!(THAI_UNICODE))
+    }
+}
+
+impl PartialEq for ThaiFonts {
+    fn eq(&self, other: &ThaiFonts) -> bool {
+        self.0.iter()
+            .zip(other.0.iter())
+            .fold(true, |eq, (a, b)| eq && a == b)
+    }
+}
+
+impl Default for ThaiFonts {
+    fn default() -> Self {
+        ThaiFonts::new()
+    }
+}
+
+impl UnicodeFonts for ThaiFonts {
+    fn get(&self, key: char) -> Option<[u8; 8]> {
+        self.get_font(key).map(|font| font.into())
+    }
+
+    fn get_font(&self, key: char) -> Option<FontUnicode> {
+        match self.0.binary_search_by_key(&key, |&f| f.char()) {
+            Ok(idx) => Some(self.0[idx]),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn print_set(&self) {
+        println!();
+        println!("# `{:?}`", self);
+        for (idx, font) in self.0.iter().enumerate() {
+            if font.is_whitespace() {
+                println!("## {:3?}: 0x{:04X} \" \"", idx, font.char() as u32);
+                continue;
+            }
+            println!("## `{:?}[{:?}]`: `U+{:04X}` `{:?}`",
+                     self,
+                     idx,
+                     font.char() as u32,
+                     font.to_string());
+            println!();
+            println!("```text");
+            for x in &font.byte_array() {
+                for bit in 0..8 {
+                    match *x & 1 << bit {
+                        0 => print!("░"),
+                        _ => print!("█"),
+                    }
+                }
+                println!();
+            }
+            println!("```");
+            println!();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn to_vec(&self) -> Vec<(char, FontUnicode)> {
+        self.0.iter()
+            .fold(Vec::with_capacity(87), |mut v, font| {
+                v.push((font.char(), *font));
+                v
+            })
+    }
+}
+
+/// Returns `true` if `glyph` and `mark` have ink in the same place.
+fn overlaps(glyph: &[u8; 8], mark: &[u8; 8]) -> bool {
+    glyph.iter().zip(mark.iter()).any(|(row, ink)| row & ink != 0)
+}
+
+/// Returns `true` if `glyph` and `mark` have ink in the same place, or side by side.
+fn touches(glyph: &[u8; 8], mark: &[u8; 8]) -> bool {
+    glyph.iter().zip(mark.iter()).any(|(row, ink)| (row | row << 1 | row >> 1) & ink != 0)
+}
+
+/// Returns `mark` moved left just enough to leave a gap to the ink of `glyph`, or failing
+/// that, not to overlap it. Marks that fit nowhere are left in place.
+fn clear_of(glyph: &[u8; 8], mark: &[u8; 8]) -> [u8; 8] {
+    let left = ink_columns(mark).map_or(0, |(left, _)| left);
+    let shifts = || (0..=left).map(|shift| moved(mark, 0, -shift));
+    shifts().find(|mark| !touches(glyph, mark))
+            .or_else(|| shifts().find(|mark| !overlaps(glyph, mark)))
+            .unwrap_or(*mark)
+}
+
+/// Returns `true` for the Thai vowels and tone marks that combine with the preceding
+/// character, instead of taking a cell of their own.
+pub fn is_thai_combining(c: char) -> bool {
+    matches!(c, '\u{0E31}' | '\u{0E34}'..='\u{0E3A}' | '\u{0E47}'..='\u{0E4E}')
+}
+
+/// Returns the length in bytes of the run of Thai combining marks at the start of `rest`.
+pub(crate) fn thai_marks_len(rest: &str) -> usize {
+    rest.char_indices()
+        .find(|&(_, c)| !is_thai_combining(c))
+        .map(|(idx, _)| idx)
+        .unwrap_or_else(|| rest.len())
+}
+
+/// Iterator over the clusters of a Thai string, returned by
+/// [thai_clusters](./fn.thai_clusters.html).
+#[derive(Clone, Debug)]
+pub struct ThaiClusters<'a> {
+    text: &'a str,
+}
+
+impl<'a> Iterator for ThaiClusters<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut chars = self.text.char_indices();
+        chars.next()?;
+        let end = chars.find(|&(_, c)| !is_thai_combining(c))
+                       .map(|(idx, _)| idx)
+                       .unwrap_or_else(|| self.text.len());
+        let (cluster, rest) = self.text.split_at(end);
+        self.text = rest;
+        Some(cluster)
+    }
+}
+
+/// Splits `text` into clusters that each fit in a single cell: a base character followed
+/// by any combining vowels and tone marks.
+///
+/// ```
+/// use font8x8::unicode::thai_clusters;
+///
+/// let clusters: Vec<&str> = thai_clusters("\u{0E01}\u{0E38}\u{0E49}\u{0E07}").collect();
+/// assert_eq!(clusters, ["\u{0E01}\u{0E38}\u{0E49}", "\u{0E07}"]);
+/// ```
+pub fn thai_clusters<'a>(text: &'a str) -> ThaiClusters<'a> {
+    ThaiClusters { text }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thai_set_implements_default_trait_with_method_new() {
+        let thai_set: ThaiFonts = Default::default();
+        assert_eq!(thai_set, ThaiFonts::new());
+    }
+
+    #[test]
+    fn thai_fonts_constant_is_equal_to_a_new_instance() {
+        assert_eq!(THAI_FONTS, ThaiFonts::new());
+    }
+
+    #[test]
+    fn thai_fonts_constant_wraps_thai_unicode_constant() {
+        let thai = ThaiFonts::new();
+        assert!(thai.0.len() == THAI_UNICODE.len());
+        for (idx, font) in thai.0.iter().enumerate() {
+            assert_eq!(font, &THAI_UNICODE[idx]);
+        }
+    }
+
+    #[test]
+    fn thai_clusters_group_marks_with_their_base() {
+        let clusters: Vec<&str> = thai_clusters("\u{0E17}\u{0E35}\u{0E48}\u{0E19}\u{0E35}\u{0E48}").collect();
+        assert_eq!(clusters, ["\u{0E17}\u{0E35}\u{0E48}", "\u{0E19}\u{0E35}\u{0E48}"]);
+    }
+
+    #[test]
+    fn thai_clusters_keep_spacing_vowels_apart() {
+        let clusters: Vec<&str> = thai_clusters("\u{0E40}\u{0E01}\u{0E32}").collect();
+        assert_eq!(clusters, ["\u{0E40}", "\u{0E01}", "\u{0E32}"]);
+    }
+
+    #[test]
+    fn thai_fonts_get_cluster_overlays_marks() {
+        let base = THAI_FONTS.get('\u{0E01}').unwrap();
+        let below = THAI_FONTS.get('\u{0E38}').unwrap();
+        let tone = THAI_FONTS.get('\u{0E49}').unwrap();
+        let glyph = THAI_FONTS.get_cluster("\u{0E01}\u{0E38}\u{0E49}").unwrap();
+        for row in 0..8 {
+            assert_eq!(glyph[row], base[row] | below[row] | tone[row]);
+        }
+        assert_eq!(THAI_FONTS.get_cluster("a\u{0E38}"), None);
+    }
+
+    #[test]
+    fn thai_fonts_overlay_marks_clear_of_ascenders_and_descenders() {
+        let ink = |glyph: &[u8; 8]| glyph.iter().map(|row| row.count_ones()).sum::<u32>();
+        let clusters = [('\u{0E1B}', "\u{0E48}"),
+                        ('\u{0E1F}', "\u{0E49}"),
+                        ('\u{0E0E}', "\u{0E38}")];
+        for &(base, mark) in clusters.iter() {
+            let glyph = THAI_FONTS.overlay(THAI_FONTS.get(base).unwrap(), mark);
+            let base = THAI_FONTS.get(base).unwrap();
+            let mark = THAI_FONTS.get(mark.chars().next().unwrap()).unwrap();
+            assert_eq!(ink(&glyph), ink(&base) + ink(&mark), "{:?}", glyph);
+        }
+        // ฟ้: the tone mark moves left, off the ascender
+        let glyph = THAI_FONTS.get_cluster("\u{0E1F}\u{0E49}").unwrap();
+        assert_eq!(glyph[..2], [0x18, 0x2C]);
+        // ฎุ: the base moves up, and the vowel goes under the descender
+        let glyph = THAI_FONTS.get_cluster("\u{0E0E}\u{0E38}").unwrap();
+        assert_eq!(glyph[6..], [0x15, 0x18]);
+    }
+
+    #[test]
+    fn thai_combining_marks_do_not_collide() {
+        let above = ['\u{0E31}', '\u{0E34}', '\u{0E35}', '\u{0E36}', '\u{0E37}', '\u{0E47}', '\u{0E4D}'];
+        let tones = ['\u{0E48}', '\u{0E49}', '\u{0E4A}', '\u{0E4B}', '\u{0E4C}'];
+        for &vowel in above.iter() {
+            for &tone in tones.iter() {
+                let vowel = THAI_FONTS.get(vowel).unwrap();
+                let tone = THAI_FONTS.get(tone).unwrap();
+                for row in 0..8 {
+                    assert_eq!(vowel[row] & tone[row], 0);
+                }
+            }
+        }
+    }
+}
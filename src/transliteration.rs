@@ -0,0 +1,170 @@
+//! ASCII transliterations, for the characters that can't be drawn otherwise.
+//!
+//! Every transliteration is a non-empty string of printable ASCII characters, which
+//! are all in `BASIC_FONTS`: letters without a decomposition, like `"ł"` or `"ß"`, are
+//! spelled with the nearest letters, Cyrillic is romanized, and signs are written out,
+//! like `"€"` as `"EUR"`.
+use super::decomposed::decompose;
+
+/// Transliterations of Latin letters without a decomposition, of Cyrillic as in the
+/// BGN/PCGN romanization of Russian, and of common punctuation, currency and maths
+/// signs. Sorted by character.
+const TRANSLITERATIONS: [(char, &str); 329] = [('\u{00A0}', " "), ('\u{00A1}', "!"), ('\u{00A2}', "c"),
+                                               ('\u{00A3}', "GBP"), ('\u{00A4}', "?"), ('\u{00A5}', "JPY"),
+                                               ('\u{00A6}', "|"), ('\u{00A7}', "S"), ('\u{00A8}', "\""),
+                                               ('\u{00A9}', "(C)"), ('\u{00AA}', "a"), ('\u{00AB}', "<<"),
+                                               ('\u{00AC}', "~"), ('\u{00AD}', "-"), ('\u{00AE}', "(R)"),
+                                               ('\u{00AF}', "-"), ('\u{00B0}', "o"), ('\u{00B1}', "+/-"),
+                                               ('\u{00B2}', "2"), ('\u{00B3}', "3"), ('\u{00B4}', "'"),
+                                               ('\u{00B5}', "u"), ('\u{00B6}', "P"), ('\u{00B7}', "."),
+                                               ('\u{00B8}', ","), ('\u{00B9}', "1"), ('\u{00BA}', "o"),
+                                               ('\u{00BB}', ">>"), ('\u{00BC}', "1/4"), ('\u{00BD}', "1/2"),
+                                               ('\u{00BE}', "3/4"), ('\u{00BF}', "?"), ('\u{00C6}', "AE"),
+                                               ('\u{00D0}', "D"), ('\u{00D7}', "x"), ('\u{00D8}', "O"),
+                                               ('\u{00DE}', "Th"), ('\u{00DF}', "ss"), ('\u{00E6}', "ae"),
+                                               ('\u{00F0}', "d"), ('\u{00F7}', "/"), ('\u{00F8}', "o"),
+                                               ('\u{00FE}', "th"), ('\u{0110}', "D"), ('\u{0111}', "d"),
+                                               ('\u{0126}', "H"), ('\u{0127}', "h"), ('\u{0131}', "i"),
+                                               ('\u{0132}', "IJ"), ('\u{0133}', "ij"), ('\u{0138}', "q"),
+                                               ('\u{013F}', "L"), ('\u{0140}', "l"), ('\u{0141}', "L"),
+                                               ('\u{0142}', "l"), ('\u{0149}', "'n"), ('\u{014A}', "N"),
+                                               ('\u{014B}', "n"), ('\u{0152}', "OE"), ('\u{0153}', "oe"),
+                                               ('\u{017F}', "s"), ('\u{0180}', "b"), ('\u{0181}', "B"),
+                                               ('\u{0187}', "C"), ('\u{0188}', "c"), ('\u{018A}', "D"),
+                                               ('\u{0191}', "F"), ('\u{0192}', "f"), ('\u{0193}', "G"),
+                                               ('\u{0197}', "I"), ('\u{0198}', "K"), ('\u{0199}', "k"),
+                                               ('\u{019A}', "l"), ('\u{019D}', "N"), ('\u{019E}', "n"),
+                                               ('\u{01A4}', "P"), ('\u{01A5}', "p"), ('\u{01AB}', "t"),
+                                               ('\u{01AC}', "T"), ('\u{01AD}', "t"), ('\u{01AE}', "T"),
+                                               ('\u{01B2}', "V"), ('\u{01B3}', "Y"), ('\u{01B4}', "y"),
+                                               ('\u{01B5}', "Z"), ('\u{01B6}', "z"), ('\u{01C4}', "DZ"),
+                                               ('\u{01C5}', "Dz"), ('\u{01C6}', "dz"), ('\u{01C7}', "LJ"),
+                                               ('\u{01C8}', "Lj"), ('\u{01C9}', "lj"), ('\u{01CA}', "NJ"),
+                                               ('\u{01CB}', "Nj"), ('\u{01CC}', "nj"), ('\u{01E4}', "G"),
+                                               ('\u{01E5}', "g"), ('\u{01F1}', "DZ"), ('\u{01F2}', "Dz"),
+                                               ('\u{01F3}', "dz"), ('\u{0224}', "Z"), ('\u{0225}', "z"),
+                                               ('\u{0237}', "j"), ('\u{023A}', "A"), ('\u{023B}', "C"),
+                                               ('\u{023C}', "c"), ('\u{023D}', "L"), ('\u{023E}', "T"),
+                                               ('\u{0246}', "E"), ('\u{0247}', "e"), ('\u{0248}', "J"),
+                                               ('\u{0249}', "j"), ('\u{024C}', "R"), ('\u{024D}', "r"),
+                                               ('\u{024E}', "Y"), ('\u{024F}', "y"), ('\u{0400}', "E"),
+                                               ('\u{0401}', "Yo"), ('\u{0402}', "Dj"), ('\u{0403}', "Gj"),
+                                               ('\u{0404}', "Ye"), ('\u{0405}', "Dz"), ('\u{0406}', "I"),
+                                               ('\u{0407}', "Yi"), ('\u{0408}', "J"), ('\u{0409}', "Lj"),
+                                               ('\u{040A}', "Nj"), ('\u{040B}', "C"), ('\u{040C}', "Kj"),
+                                               ('\u{040D}', "I"), ('\u{040E}', "U"), ('\u{040F}', "Dz"),
+                                               ('\u{0410}', "A"), ('\u{0411}', "B"), ('\u{0412}', "V"),
+                                               ('\u{0413}', "G"), ('\u{0414}', "D"), ('\u{0415}', "E"),
+                                               ('\u{0416}', "Zh"), ('\u{0417}', "Z"), ('\u{0418}', "I"),
+                                               ('\u{0419}', "Y"), ('\u{041A}', "K"), ('\u{041B}', "L"),
+                                               ('\u{041C}', "M"), ('\u{041D}', "N"), ('\u{041E}', "O"),
+                                               ('\u{041F}', "P"), ('\u{0420}', "R"), ('\u{0421}', "S"),
+                                               ('\u{0422}', "T"), ('\u{0423}', "U"), ('\u{0424}', "F"),
+                                               ('\u{0425}', "Kh"), ('\u{0426}', "Ts"), ('\u{0427}', "Ch"),
+                                               ('\u{0428}', "Sh"), ('\u{0429}', "Shch"), ('\u{042A}', "\""),
+                                               ('\u{042B}', "Y"), ('\u{042C}', "'"), ('\u{042D}', "E"),
+                                               ('\u{042E}', "Yu"), ('\u{042F}', "Ya"), ('\u{0430}', "a"),
+                                               ('\u{0431}', "b"), ('\u{0432}', "v"), ('\u{0433}', "g"),
+                                               ('\u{0434}', "d"), ('\u{0435}', "e"), ('\u{0436}', "zh"),
+                                               ('\u{0437}', "z"), ('\u{0438}', "i"), ('\u{0439}', "y"),
+                                               ('\u{043A}', "k"), ('\u{043B}', "l"), ('\u{043C}', "m"),
+                                               ('\u{043D}', "n"), ('\u{043E}', "o"), ('\u{043F}', "p"),
+                                               ('\u{0440}', "r"), ('\u{0441}', "s"), ('\u{0442}', "t"),
+                                               ('\u{0443}', "u"), ('\u{0444}', "f"), ('\u{0445}', "kh"),
+                                               ('\u{0446}', "ts"), ('\u{0447}', "ch"), ('\u{0448}', "sh"),
+                                               ('\u{0449}', "shch"), ('\u{044A}', "\""), ('\u{044B}', "y"),
+                                               ('\u{044C}', "'"), ('\u{044D}', "e"), ('\u{044E}', "yu"),
+                                               ('\u{044F}', "ya"), ('\u{0450}', "e"), ('\u{0451}', "yo"),
+                                               ('\u{0452}', "dj"), ('\u{0453}', "gj"), ('\u{0454}', "ye"),
+                                               ('\u{0455}', "dz"), ('\u{0456}', "i"), ('\u{0457}', "yi"),
+                                               ('\u{0458}', "j"), ('\u{0459}', "lj"), ('\u{045A}', "nj"),
+                                               ('\u{045B}', "c"), ('\u{045C}', "kj"), ('\u{045D}', "i"),
+                                               ('\u{045E}', "u"), ('\u{045F}', "dz"), ('\u{0490}', "G"),
+                                               ('\u{0491}', "g"), ('\u{1E9E}', "SS"), ('\u{2010}', "-"),
+                                               ('\u{2011}', "-"), ('\u{2012}', "-"), ('\u{2013}', "-"),
+                                               ('\u{2014}', "-"), ('\u{2015}', "-"), ('\u{2016}', "||"),
+                                               ('\u{2017}', "_"), ('\u{2018}', "'"), ('\u{2019}', "'"),
+                                               ('\u{201A}', ","), ('\u{201B}', "'"), ('\u{201C}', "\""),
+                                               ('\u{201D}', "\""), ('\u{201E}', "\""), ('\u{201F}', "\""),
+                                               ('\u{2020}', "+"), ('\u{2021}', "++"), ('\u{2022}', "*"),
+                                               ('\u{2023}', ">"), ('\u{2024}', "."), ('\u{2025}', ".."),
+                                               ('\u{2026}', "..."), ('\u{2030}', "%o"), ('\u{2032}', "'"),
+                                               ('\u{2033}', "\""), ('\u{2034}', "'''"), ('\u{2039}', "<"),
+                                               ('\u{203A}', ">"), ('\u{203C}', "!!"), ('\u{2044}', "/"),
+                                               ('\u{2047}', "??"), ('\u{2048}', "?!"), ('\u{2049}', "!?"),
+                                               ('\u{204E}', "*"), ('\u{204F}', ";"), ('\u{2053}', "~"),
+                                               ('\u{20A0}', "ECU"), ('\u{20A1}', "CRC"), ('\u{20A2}', "BRC"),
+                                               ('\u{20A3}', "FRF"), ('\u{20A4}', "L"), ('\u{20A5}', "mil"),
+                                               ('\u{20A6}', "NGN"), ('\u{20A7}', "Pts"), ('\u{20A8}', "Rs"),
+                                               ('\u{20A9}', "KRW"), ('\u{20AA}', "ILS"), ('\u{20AB}', "VND"),
+                                               ('\u{20AC}', "EUR"), ('\u{20AD}', "LAK"), ('\u{20AE}', "MNT"),
+                                               ('\u{20AF}', "GRD"), ('\u{20B0}', "Pf"), ('\u{20B1}', "PHP"),
+                                               ('\u{20B2}', "PYG"), ('\u{20B3}', "ARA"), ('\u{20B4}', "UAH"),
+                                               ('\u{20B5}', "GHS"), ('\u{20B8}', "KZT"), ('\u{20B9}', "INR"),
+                                               ('\u{20BA}', "TRY"), ('\u{20BC}', "AZN"), ('\u{20BD}', "RUB"),
+                                               ('\u{20BE}', "GEL"), ('\u{20BF}', "BTC"), ('\u{2103}', "oC"),
+                                               ('\u{2105}', "c/o"), ('\u{2109}', "oF"), ('\u{2116}', "No"),
+                                               ('\u{2117}', "(P)"), ('\u{2120}', "SM"), ('\u{2122}', "TM"),
+                                               ('\u{2126}', "Ohm"), ('\u{212A}', "K"), ('\u{212B}', "A"),
+                                               ('\u{212E}', "e"), ('\u{2153}', "1/3"), ('\u{2154}', "2/3"),
+                                               ('\u{215B}', "1/8"), ('\u{215C}', "3/8"), ('\u{215D}', "5/8"),
+                                               ('\u{215E}', "7/8"), ('\u{2190}', "<-"), ('\u{2192}', "->"),
+                                               ('\u{2194}', "<->"), ('\u{21D0}', "<="), ('\u{21D2}', "=>"),
+                                               ('\u{21D4}', "<=>"), ('\u{2212}', "-"), ('\u{2215}', "/"),
+                                               ('\u{2217}', "*"), ('\u{2219}', "."), ('\u{221A}', "V"),
+                                               ('\u{221E}', "oo"), ('\u{223C}', "~"), ('\u{2248}', "~="),
+                                               ('\u{2260}', "!="), ('\u{2264}', "<="), ('\u{2265}', ">="),
+                                               ('\u{3000}', " "), ('\u{3001}', ","), ('\u{3002}', "."),
+                                               ('\u{3008}', "<"), ('\u{3009}', ">"), ('\u{300A}', "<<"),
+                                               ('\u{300B}', ">>"), ('\u{300C}', "\""), ('\u{300D}', "\""),
+                                               ('\u{300E}', "\""), ('\u{300F}', "\""), ('\u{3010}', "["),
+                                               ('\u{3011}', "]"), ('\u{301C}', "~")];
+
+/// Returns an ASCII transliteration of `c`, or `None` if there is none, or if `c` is
+/// ASCII already. Precomposed letters are transliterated as the base letter of their
+/// [decomposition](./fn.decompose.html).
+///
+/// ```
+/// use font8x8::unicode::transliterate;
+///
+/// assert_eq!(transliterate('ß'), Some("ss"));
+/// assert_eq!(transliterate('Ж'), Some("Zh"));
+/// assert_eq!(transliterate('€'), Some("EUR"));
+/// assert_eq!(transliterate('ǎ'), Some("a"));
+/// assert_eq!(transliterate('a'), None);
+/// assert_eq!(transliterate('中'), None);
+/// ```
+pub fn transliterate(c: char) -> Option<&'static str> {
+    match TRANSLITERATIONS.binary_search_by_key(&c, |&(key, _)| key) {
+        Ok(idx) => Some(TRANSLITERATIONS[idx].1),
+        Err(_) => decompose(c).map(|decomposition| &decomposition[..1]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{UnicodeFonts, BASIC_FONTS};
+
+    #[test]
+    fn transliterations_are_sorted_and_drawn_with_basic_fonts() {
+        for pair in TRANSLITERATIONS.windows(2) {
+            assert!(pair[0].0 < pair[1].0, "{:?}", pair[1]);
+        }
+        for &(key, transliteration) in TRANSLITERATIONS.iter() {
+            assert!(!key.is_ascii() && !transliteration.is_empty(), "{:?}", key);
+            for c in transliteration.chars() {
+                assert!(!c.is_control() && BASIC_FONTS.get(c).is_some(), "{:?}", key);
+            }
+        }
+    }
+
+    #[test]
+    fn transliterate_spells_out_letters_and_signs() {
+        let cases = [('ł', "l"), ('ß', "ss"), ('œ', "oe"), ('—', "-"), ('“', "\""), ('€', "EUR"),
+                     ('Ж', "Zh"), ('щ', "shch"), ('ḱ', "k"), ('Ǘ', "U")];
+        for &(c, transliteration) in cases.iter() {
+            assert_eq!(transliterate(c), Some(transliteration), "{:?}", c);
+        }
+    }
+}
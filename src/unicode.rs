@@ -1,13 +1,41 @@
 //! Unicode support for 8x8 fonts.
+pub use super::alternates::{Alternate, AlternateFonts};
+pub use super::arabic::{shape_arabic, ArabicFonts, ArabicShaper, ARABIC_UNICODE};
+pub use super::arrows::{ArrowFonts, ARROW_UNICODE};
 pub use super::basic::{BasicFonts, BASIC_UNICODE};
 pub use super::block::{BlockFonts, BLOCK_UNICODE};
 pub use super::box_chars::{BoxFonts, BOX_UNICODE};
+pub use super::braille::{braille_glyph, BrailleCanvas, BrailleFonts};
+pub use super::combining::{graphemes, is_combining_mark, mark_anchor, CombiningFonts, Graphemes, MarkAnchor,
+                            COMBINING_UNICODE};
+pub use super::computing::{separated_sextant_glyph, sextant_glyph, ComputingFonts, COMPUTING_UNICODE};
+pub use super::control::{ControlFonts, CONTROL_UNICODE};
+pub use super::currency::{CurrencyFonts, CURRENCY_UNICODE};
+pub use super::decomposed::{decompose, DecomposedFonts};
+pub use super::dingbats::{DingbatFonts, DINGBAT_UNICODE};
+pub use super::emoji::{emoji_sequences, is_emoji_modifier, EmojiFonts, EmojiSequences, EMOJI_UNICODE};
+pub use super::enclosed::{EnclosedFonts, ENCLOSED_UNICODE};
+pub use super::fullwidth::{is_wide, FullwidthFonts};
+pub use super::geometric::{GeometricFonts, GEOMETRIC_UNICODE};
 pub use super::greek::{GreekFonts, GREEK_UNICODE};
-pub use super::hiragana::{HiraganaFonts, HIRAGANA_UNICODE};
+pub use super::hangul::{HangulFonts, HANGUL_LEAD, HANGUL_TAIL, HANGUL_VOWEL};
+pub use super::hiragana::{compose_kana, is_voicing_mark, HiraganaFonts, HIRAGANA_UNICODE};
 pub use super::latin::{LatinFonts, LATIN_UNICODE};
 use super::legacy::NOTHING_TO_DISPLAY;
+pub use super::math::{MathFonts, MATH_UNICODE};
 pub use super::misc::{MiscFonts, MISC_UNICODE};
-pub use super::sga::{SgaFonts, SGA_UNICODE};
+pub use super::numbers::{NumberFonts, NUMBER_UNICODE};
+pub use super::pictures::{control_picture, hex_picture, PictureFonts, PICTURE_UNICODE};
+pub use super::powerline::{PowerlineFonts, POWERLINE_UNICODE};
+pub use super::punctuation::{is_zero_width, PunctuationFonts, PUNCTUATION_UNICODE};
+pub use super::render::{Fallback, Rendered, Renderer};
+pub use super::romaji::{romaji_to_hiragana, ConvertedKana, RomajiConverter, RomajiHiragana};
+pub use super::scripts::{ScriptFonts, SCRIPT_UNICODE};
+pub use super::sga::{from_sga, sga_chars, to_sga, SgaChars, SgaFonts, SGA_UNICODE};
+pub use super::symbols::{SymbolFonts, SYMBOL_UNICODE};
+pub use super::technical::{TechnicalFonts, TECHNICAL_UNICODE};
+pub use super::thai::{is_thai_combining, thai_clusters, ThaiClusters, ThaiFonts, THAI_UNICODE};
+pub use super::transliteration::transliterate;
 #[cfg(feature = "std")]
 pub use std::string::FromUtf16Error;
 
@@ -35,6 +63,22 @@ impl FontUnicode {
         self.1 == NOTHING_TO_DISPLAY
     }
 
+    /// Returns a `bool` indicating whether this font takes no cell of its own, like the
+    /// zero-width spaces and joiners, the combining diacritical marks, the Thai combining
+    /// marks, or the combining kana voicing marks.
+    pub fn is_non_spacing(&self) -> bool {
+        is_zero_width(self.0)
+        || is_combining_mark(self.0)
+        || is_thai_combining(self.0)
+        || is_voicing_mark(self.0)
+    }
+
+    /// Returns a `bool` indicating whether this font takes two cells in East Asian text,
+    /// like the fullwidth forms, kana or Hangul syllables.
+    pub fn is_wide(&self) -> bool {
+        is_wide(self.0)
+    }
+
     /// Consumes the current `FontUnicode` and returns the inner `(char, [u8; 8])` tuple.
     pub fn into_inner(self) -> (char, [u8; 8]) {
         self.into()
@@ -126,6 +170,23 @@ mod tests {
         assert!(my_font.is_whitespace());
     }
 
+    #[test]
+    fn font_unicode_api_method_is_non_spacing_returns_bool() {
+        assert!(FontUnicode('\u{200D}', NOTHING_TO_DISPLAY).is_non_spacing());
+        assert!(FontUnicode('\u{0E48}', [0x20; 8]).is_non_spacing());
+        assert!(FontUnicode('\u{0301}', [0x20; 8]).is_non_spacing());
+        assert!(FontUnicode('\u{3099}', NOTHING_TO_DISPLAY).is_non_spacing());
+        assert!(!FontUnicode('\u{2003}', NOTHING_TO_DISPLAY).is_non_spacing());
+        assert!(!FontUnicode('Ñ', [0x20; 8]).is_non_spacing());
+    }
+
+    #[test]
+    fn font_unicode_api_method_is_wide_returns_bool() {
+        assert!(FontUnicode('Ａ', [0x20; 8]).is_wide());
+        assert!(FontUnicode('あ', [0x20; 8]).is_wide());
+        assert!(!FontUnicode('A', [0x20; 8]).is_wide());
+    }
+
     #[test]
     fn font_unicode_api_method_into_inner_returns_inner_tuple() {
         let my_font = FontUnicode('Á', [110u8; 8]);